@@ -5,3 +5,4 @@ pub mod cellular_automata;
 pub mod dijkstra;
 pub mod distance;
 pub mod shadowcast;
+pub mod wfc;
@@ -0,0 +1,510 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::common::{Direction, Grid, Rand};
+
+const DIRECTIONS: [(Direction, i32, i32); 4] = [
+    (Direction::North, 0, -1),
+    (Direction::South, 0, 1),
+    (Direction::East, 1, 0),
+    (Direction::West, -1, 0),
+];
+
+/// How many times to start over after running into a contradiction
+const MAX_ATTEMPTS: usize = 10;
+
+/// An undecided cell waiting to be collapsed, lowest entropy first
+struct QueuedCell {
+    priority: f32,
+    entropy: f32,
+    x: usize,
+    y: usize,
+}
+
+impl PartialEq for QueuedCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedCell {}
+
+impl PartialOrd for QueuedCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap pops the largest, so lower entropy has to compare greater
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then_with(|| (other.y, other.x).cmp(&(self.y, self.x)))
+    }
+}
+
+fn direction_idx(direction: &Direction) -> usize {
+    match direction {
+        Direction::North => 0,
+        Direction::South => 1,
+        Direction::East => 2,
+        Direction::West => 3,
+    }
+}
+
+/// The tiles a wave function collapse can place, how often each should appear,
+/// and which tiles may sit next to each other in each direction.
+#[derive(Clone)]
+pub struct WfcRules<T> {
+    tiles: Vec<T>,
+    weights: Vec<f32>,
+    // adjacency[direction][a][b] is true when `b` may sit in `direction` from `a`
+    adjacency: [Vec<Vec<bool>>; 4],
+}
+
+impl<T> Default for WfcRules<T>
+where
+    T: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> WfcRules<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn new() -> Self {
+        Self {
+            tiles: vec![],
+            weights: vec![],
+            adjacency: [vec![], vec![], vec![], vec![]],
+        }
+    }
+
+    /// Learns tiles, weights and adjacencies from a small hand-drawn example.
+    /// Every pair of neighbouring cells in the example becomes an allowed pairing,
+    /// and tiles are weighted by how often they appear.
+    pub fn from_example(example: &Grid<T>) -> Self {
+        let mut rules = Self::new();
+
+        for (_, _, value) in example.iter_xy() {
+            let idx = rules.add_tile(value.clone(), 0.0);
+            rules.weights[idx] += 1.0;
+        }
+
+        for (x, y, value) in example.iter_xy() {
+            let a = rules.tile_idx(value).unwrap();
+
+            for (direction, dx, dy) in DIRECTIONS.iter() {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                if nx < 0 || ny < 0 || nx >= example.width() as i32 || ny >= example.height() as i32
+                {
+                    continue;
+                }
+
+                let b = rules
+                    .tile_idx(example.get(nx as usize, ny as usize).unwrap())
+                    .unwrap();
+
+                rules.adjacency[direction_idx(direction)][a][b] = true;
+            }
+        }
+
+        rules
+    }
+
+    /// Adds a tile, or returns the existing index if it is already known
+    pub fn add_tile(&mut self, value: T, weight: f32) -> usize {
+        if let Some(idx) = self.tile_idx(&value) {
+            return idx;
+        }
+
+        self.tiles.push(value);
+        self.weights.push(weight);
+
+        for rows in self.adjacency.iter_mut() {
+            for row in rows.iter_mut() {
+                row.push(false);
+            }
+
+            rows.push(vec![false; self.tiles.len()]);
+        }
+
+        self.tiles.len() - 1
+    }
+
+    pub fn set_weight(&mut self, value: &T, weight: f32) {
+        if let Some(idx) = self.tile_idx(value) {
+            self.weights[idx] = weight;
+        }
+    }
+
+    pub fn tile_idx(&self, value: &T) -> Option<usize> {
+        self.tiles.iter().position(|tile| tile == value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+}
+
+/// Simple tiled wave function collapse over a `Grid`.
+///
+/// Cells can be pre-seeded with a value, which the rest of the output has to agree with,
+/// and locked, which keeps the solver from touching them. A locked cell that was never
+/// seeded places no constraints on its neighbours, so a zone's locked grid can be passed
+/// straight in and whatever was stamped there is left alone.
+pub struct WaveFunctionCollapse<T> {
+    rules: WfcRules<T>,
+    width: usize,
+    height: usize,
+    seeded: Grid<Option<usize>>,
+    constraints: Option<Grid<bool>>,
+}
+
+impl<T> WaveFunctionCollapse<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn new(width: usize, height: usize, rules: WfcRules<T>) -> Self {
+        Self {
+            rules,
+            width,
+            height,
+            seeded: Grid::init(width, height, None),
+            constraints: None,
+        }
+    }
+
+    pub fn with_constraints(mut self, constraints: Grid<bool>) -> Self {
+        self.constraints = Some(constraints);
+        self
+    }
+
+    pub fn set_constraint(&mut self, x: usize, y: usize, locked: bool) {
+        if self.constraints.is_none() {
+            self.constraints = Some(Grid::init(self.width, self.height, false));
+        }
+
+        if let Some(ref mut constraints) = self.constraints {
+            constraints.set(x, y, locked);
+        }
+    }
+
+    /// Pre-seeds a cell with a value. Values the rules don't know about are ignored.
+    pub fn set(&mut self, x: usize, y: usize, value: &T) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        if let Some(idx) = self.rules.tile_idx(value) {
+            self.seeded.set(x, y, Some(idx));
+        }
+    }
+
+    fn is_locked(&self, x: usize, y: usize) -> bool {
+        self.constraints
+            .as_ref()
+            .and_then(|constraints| constraints.get(x, y))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Collapses the whole grid, retrying from scratch on a contradiction.
+    /// Locked cells that were never seeded come back as `None`.
+    /// Returns `None` if every attempt ran into a contradiction.
+    pub fn solve(&self, rand: &mut Rand) -> Option<Grid<Option<T>>> {
+        if self.rules.is_empty() {
+            return None;
+        }
+
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(wave) = self.attempt(rand) {
+                return Some(Grid::init_fill(self.width, self.height, |x, y| {
+                    let cell = wave.get(x, y).unwrap();
+
+                    if self.is_locked(x, y) && self.seeded.get(x, y).unwrap().is_none() {
+                        return None;
+                    }
+
+                    cell.iter()
+                        .position(|&possible| possible)
+                        .map(|idx| self.rules.tiles[idx].clone())
+                }));
+            }
+        }
+
+        None
+    }
+
+    fn attempt(&self, rand: &mut Rand) -> Option<Grid<Vec<bool>>> {
+        let num_tiles = self.rules.len();
+        let mut wave = Grid::init(self.width, self.height, vec![true; num_tiles]);
+        let mut pending = vec![];
+
+        for (x, y, seeded) in self.seeded.iter_xy() {
+            if let Some(idx) = seeded {
+                let mut cell = vec![false; num_tiles];
+                cell[*idx] = true;
+                wave.set(x, y, cell);
+                pending.push((x, y));
+            }
+        }
+
+        let mut queue = BinaryHeap::new();
+
+        if !self.propagate(&mut wave, pending, &mut queue, rand) {
+            return None;
+        }
+
+        for (x, y, cell) in wave.iter_xy() {
+            self.enqueue(&mut queue, x, y, cell, rand);
+        }
+
+        while let Some((x, y)) = self.lowest_entropy_cell(&wave, &mut queue) {
+            let cell = wave.get_mut(x, y).unwrap();
+            let chosen = self.pick_tile(cell, rand);
+
+            for (idx, possible) in cell.iter_mut().enumerate() {
+                *possible = idx == chosen;
+            }
+
+            if !self.propagate(&mut wave, vec![(x, y)], &mut queue, rand) {
+                return None;
+            }
+        }
+
+        Some(wave)
+    }
+
+    /// Shannon entropy of the options a cell has left, or `None` once it's decided
+    fn entropy(&self, cell: &[bool]) -> Option<f32> {
+        let mut total = 0.0;
+        let mut total_log = 0.0;
+        let mut options = 0;
+
+        for (idx, _) in cell.iter().enumerate().filter(|(_, possible)| **possible) {
+            let weight = self.rules.weights[idx].max(f32::EPSILON);
+            total += weight;
+            total_log += weight * weight.ln();
+            options += 1;
+        }
+
+        if options <= 1 {
+            return None;
+        }
+
+        Some(total.ln() - total_log / total)
+    }
+
+    /// Queues an undecided cell at its current entropy, with a little noise to break ties
+    fn enqueue(
+        &self,
+        queue: &mut BinaryHeap<QueuedCell>,
+        x: usize,
+        y: usize,
+        cell: &[bool],
+        rand: &mut Rand,
+    ) {
+        if self.is_locked(x, y) {
+            return;
+        }
+
+        if let Some(entropy) = self.entropy(cell) {
+            queue.push(QueuedCell {
+                priority: entropy + rand.random() * 1e-3,
+                entropy,
+                x,
+                y,
+            });
+        }
+    }
+
+    /// The undecided cell with the fewest options left. Cells are queued again whenever
+    /// they lose an option, so entries that no longer match the cell are skipped.
+    fn lowest_entropy_cell(
+        &self,
+        wave: &Grid<Vec<bool>>,
+        queue: &mut BinaryHeap<QueuedCell>,
+    ) -> Option<(usize, usize)> {
+        while let Some(queued) = queue.pop() {
+            let cell = wave.get(queued.x, queued.y).unwrap();
+
+            if self.entropy(cell) == Some(queued.entropy) {
+                return Some((queued.x, queued.y));
+            }
+        }
+
+        None
+    }
+
+    fn pick_tile(&self, cell: &[bool], rand: &mut Rand) -> usize {
+        let options = cell
+            .iter()
+            .enumerate()
+            .filter(|(_, possible)| **possible)
+            .map(|(idx, _)| (idx, self.rules.weights[idx].max(f32::EPSILON)))
+            .collect::<Vec<_>>();
+
+        let total = options.iter().map(|(_, weight)| weight).sum::<f32>();
+        let mut roll = rand.random() * total;
+
+        for &(idx, weight) in options.iter() {
+            if roll < weight {
+                return idx;
+            }
+
+            roll -= weight;
+        }
+
+        options.last().map(|(idx, _)| *idx).unwrap_or(0)
+    }
+
+    /// Removes options from neighbours until every cell agrees with the cells around it.
+    /// Returns false if some cell is left with nothing it could be.
+    fn propagate(
+        &self,
+        wave: &mut Grid<Vec<bool>>,
+        mut pending: Vec<(usize, usize)>,
+        queue: &mut BinaryHeap<QueuedCell>,
+        rand: &mut Rand,
+    ) -> bool {
+        while let Some((x, y)) = pending.pop() {
+            let cell = wave.get(x, y).unwrap().clone();
+
+            for (direction, dx, dy) in DIRECTIONS.iter() {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                if nx < 0 || ny < 0 || nx >= self.width as i32 || ny >= self.height as i32 {
+                    continue;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+
+                if self.is_locked(nx, ny) {
+                    continue;
+                }
+
+                let adjacency = &self.rules.adjacency[direction_idx(direction)];
+                let neighbor = wave.get_mut(nx, ny).unwrap();
+                let mut changed = false;
+                let mut remaining = 0;
+
+                for (b, possible) in neighbor.iter_mut().enumerate() {
+                    if !*possible {
+                        continue;
+                    }
+
+                    let supported = cell
+                        .iter()
+                        .enumerate()
+                        .any(|(a, &allowed)| allowed && adjacency[a][b]);
+
+                    if supported {
+                        remaining += 1;
+                    } else {
+                        *possible = false;
+                        changed = true;
+                    }
+                }
+
+                if remaining == 0 {
+                    return false;
+                }
+
+                if changed {
+                    self.enqueue(queue, nx, ny, neighbor, rand);
+                    pending.push((nx, ny));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> WfcRules<char> {
+        let example = Grid::init_fill(4, 4, |x, y| if (x + y) % 2 == 0 { '#' } else { '.' });
+
+        WfcRules::from_example(&example)
+    }
+
+    #[test]
+    fn test_from_example() {
+        let rules = checkerboard();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules.tile_idx(&'#'), Some(0));
+        assert_eq!(rules.tile_idx(&'.'), Some(1));
+    }
+
+    #[test]
+    fn test_solve_respects_adjacency() {
+        let wfc = WaveFunctionCollapse::new(10, 8, checkerboard());
+        let output = wfc.solve(&mut Rand::seed(1)).unwrap();
+
+        for (x, y, value) in output.iter_xy() {
+            if x + 1 < 10 {
+                assert_ne!(value, output.get(x + 1, y).unwrap());
+            }
+
+            if y + 1 < 8 {
+                assert_ne!(value, output.get(x, y + 1).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let example = Grid::init_fill(5, 5, |x, y| match (x, y) {
+            (2, _) => '|',
+            (_, 2) => '-',
+            _ => '.',
+        });
+        let wfc = WaveFunctionCollapse::new(20, 12, WfcRules::from_example(&example));
+
+        let a = wfc.solve(&mut Rand::seed(42)).unwrap();
+        let b = wfc.solve(&mut Rand::seed(42)).unwrap();
+
+        assert!(a.iter().zip(b.iter()).all(|(a, b)| a == b));
+    }
+
+    #[test]
+    fn test_seeded_and_locked_cells() {
+        let mut wfc = WaveFunctionCollapse::new(6, 6, checkerboard());
+
+        wfc.set(0, 0, &'.');
+        wfc.set_constraint(3, 3, true);
+
+        let output = wfc.solve(&mut Rand::seed(7)).unwrap();
+
+        assert_eq!(output.get(0, 0), Some(&Some('.')));
+        assert_eq!(output.get(1, 0), Some(&Some('#')));
+        assert_eq!(output.get(3, 3), Some(&None));
+    }
+
+    #[test]
+    fn test_contradiction() {
+        // 'b' only ever appears at the east edge, so nothing may sit east of it
+        let example = Grid::init_fill(2, 1, |x, _| if x == 0 { 'a' } else { 'b' });
+        let mut wfc = WaveFunctionCollapse::new(2, 1, WfcRules::from_example(&example));
+
+        wfc.set(0, 0, &'b');
+
+        assert!(wfc.solve(&mut Rand::seed(0)).is_none());
+    }
+}
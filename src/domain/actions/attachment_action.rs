@@ -0,0 +1,243 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        AttachmentSlot, AttachmentType, Energy, EnergyActionType, InInventory, Inventory, Item,
+        Prefab, Prefabs, Weapon, WeaponAttachment, WeaponAttachments,
+        actions::GameAction,
+        get_base_energy_cost,
+        inventory::InventoryChangedEvent,
+        systems::{
+            destruction_system::{DestructionCause, EntityDestroyedEvent},
+            game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+        },
+    },
+    engine::{Clock, StableId, StableIdRegistry},
+    rendering::Position,
+};
+
+/// Install an attachment item from the actor's inventory onto a weapon.
+/// Anything already occupying the slot is returned to the inventory.
+pub struct InstallAttachmentAction {
+    pub entity: Entity,
+    pub attachment_id: u64,
+    pub weapon_id: u64,
+}
+
+impl GameAction for InstallAttachmentAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let (attachment_entity, weapon_entity) = {
+            let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+                return false;
+            };
+
+            let Some(attachment_entity) = registry.get_entity(StableId(self.attachment_id)) else {
+                return false;
+            };
+
+            let Some(weapon_entity) = registry.get_entity(StableId(self.weapon_id)) else {
+                return false;
+            };
+
+            (attachment_entity, weapon_entity)
+        };
+
+        {
+            let Some(inventory) = world.get::<Inventory>(self.entity) else {
+                return false;
+            };
+
+            if !inventory.contains_id(self.attachment_id) || !inventory.contains_id(self.weapon_id)
+            {
+                return false;
+            }
+        }
+
+        let Some(attachment) = world
+            .get::<WeaponAttachment>(attachment_entity)
+            .map(|a| a.attachment_type)
+        else {
+            return false;
+        };
+
+        let can_install = world
+            .get::<WeaponAttachments>(weapon_entity)
+            .is_some_and(|slots| slots.can_install(attachment));
+
+        if !can_install || world.get::<Weapon>(weapon_entity).is_none() {
+            return false;
+        }
+
+        // The attachment becomes part of the weapon, so the item itself is used up
+        let attachment_weight = world
+            .get::<Item>(attachment_entity)
+            .map(|item| item.weight)
+            .unwrap_or(0.5);
+
+        if let Some(mut inventory) = world.get_mut::<Inventory>(self.entity) {
+            inventory.remove_item(self.attachment_id, attachment_weight);
+        }
+
+        let position = world
+            .get::<Position>(self.entity)
+            .map(|p| p.world())
+            .unwrap_or((0, 0, 0));
+
+        world.entity_mut(attachment_entity).remove::<InInventory>();
+        world.send_event(EntityDestroyedEvent::new(
+            attachment_entity,
+            position,
+            DestructionCause::Consumed,
+        ));
+
+        let previous = world
+            .get_mut::<WeaponAttachments>(weapon_entity)
+            .and_then(|mut slots| slots.install(attachment));
+
+        if let Some(mut weapon) = world.get_mut::<Weapon>(weapon_entity) {
+            if let Some(previous) = previous {
+                weapon.revert_attachment(previous);
+            }
+            weapon.apply_attachment(attachment);
+        }
+
+        if let Some(previous) = previous {
+            Prefabs::spawn_in_container(
+                world,
+                Prefab::new(previous.prefab_id(), position),
+                self.entity,
+            );
+        }
+
+        send_attachment_log(
+            world,
+            LogMessage::AttachmentInstalled {
+                actor: self.entity,
+                attachment_name: attachment.display_name().to_string(),
+                weapon: weapon_entity,
+            },
+        );
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.entity) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::Gunsmith));
+        }
+
+        world.send_event(InventoryChangedEvent);
+
+        true
+    }
+}
+
+impl Command for InstallAttachmentAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
+
+/// Remove attachments from a weapon, returning them to the actor's inventory.
+/// When no slot is given every installed attachment is stripped.
+pub struct RemoveAttachmentAction {
+    pub entity: Entity,
+    pub weapon_id: u64,
+    pub slot: Option<AttachmentSlot>,
+}
+
+impl GameAction for RemoveAttachmentAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let weapon_entity = {
+            let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+                return false;
+            };
+
+            let Some(weapon_entity) = registry.get_entity(StableId(self.weapon_id)) else {
+                return false;
+            };
+
+            weapon_entity
+        };
+
+        let owns_weapon = world
+            .get::<Inventory>(self.entity)
+            .is_some_and(|inventory| inventory.contains_id(self.weapon_id));
+
+        if !owns_weapon {
+            return false;
+        }
+
+        let removed: Vec<AttachmentType> = {
+            let Some(mut slots) = world.get_mut::<WeaponAttachments>(weapon_entity) else {
+                return false;
+            };
+
+            match self.slot {
+                Some(slot) => slots.remove(slot).into_iter().collect(),
+                None => {
+                    let installed = slots.installed();
+                    installed
+                        .iter()
+                        .filter_map(|a| slots.remove(a.slot()))
+                        .collect()
+                }
+            }
+        };
+
+        if removed.is_empty() {
+            return false;
+        }
+
+        if let Some(mut weapon) = world.get_mut::<Weapon>(weapon_entity) {
+            for attachment in removed.iter() {
+                weapon.revert_attachment(*attachment);
+            }
+        }
+
+        let position = world
+            .get::<Position>(self.entity)
+            .map(|p| p.world())
+            .unwrap_or((0, 0, 0));
+
+        for attachment in removed.iter() {
+            Prefabs::spawn_in_container(
+                world,
+                Prefab::new(attachment.prefab_id(), position),
+                self.entity,
+            );
+
+            send_attachment_log(
+                world,
+                LogMessage::AttachmentRemoved {
+                    actor: self.entity,
+                    attachment_name: attachment.display_name().to_string(),
+                    weapon: weapon_entity,
+                },
+            );
+        }
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.entity) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::Gunsmith));
+        }
+
+        world.send_event(InventoryChangedEvent);
+
+        true
+    }
+}
+
+impl Command for RemoveAttachmentAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
+
+fn send_attachment_log(world: &mut World, message: LogMessage) {
+    let tick = world
+        .get_resource::<Clock>()
+        .map(|c| c.get_tick())
+        .unwrap_or(0);
+
+    world.send_event(GameLogEvent {
+        message,
+        tick,
+        knowledge: KnowledgeLevel::Player,
+    });
+}
@@ -2,18 +2,23 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     common::Rand,
+    common::algorithm::bresenham::bresenham_line,
     domain::{
-        BumpAttack, Condition, ConditionSource, ConditionType, DefaultMeleeAttack,
-        DefaultRangedAttack, Destructible, Energy, EnergyActionType, EquipmentSlot, EquipmentSlots,
-        Health, HitBlink, HitEffect, KnockbackAnimation, Label, MaterialType, Player,
-        PlayerPosition, StatType, Stats, Weapon, WeaponFamily, WeaponType, Zone,
+        BodyLocation, BumpAttack, Collider, ColliderFlags, Condition, ConditionSource,
+        ConditionType, DefaultMeleeAttack, DefaultRangedAttack, Destructible, Energy,
+        EnergyActionType, EquipmentSlot, EquipmentSlots, FAN_HAMMER_ACCURACY_PENALTY,
+        FiringPattern, Health, HitBlink, HitEffect, KnockbackAnimation, Label, MaterialType,
+        PENETRATION_DAMAGE_FALLOFF, Player, PlayerPosition, SEVERE_HIT_DAMAGE,
+        STRAY_SHOT_ACCURACY_PENALTY, StatModifiers, StatType, Stats, Weapon, WeaponAttachments,
+        WeaponFamily, WeaponType, Zone,
         actions::GameAction,
-        get_base_energy_cost,
+        cone_falloff, get_base_energy_cost, off_hand_accuracy_penalty, shot_line,
         systems::{
             apply_condition_to_entity,
             condition_system::spawn_condition_particles,
             destruction_system::EntityDestroyedEvent,
             game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+            inflict_injury,
         },
     },
     engine::{Audio, Clock, StableId, StableIdRegistry},
@@ -29,12 +34,15 @@ pub struct AttackAction {
     pub weapon_stable_id: Option<StableId>,
     pub target_stable_id: StableId,
     pub is_bump_attack: bool,
+    /// Body location the attacker is aiming for, if any. Aimed shots cost extra energy.
+    pub aimed_location: Option<BodyLocation>,
 }
 
 fn resolve_hit_miss(
     attacker_entity: Entity,
     target_entity: Entity,
     world: &mut World,
+    accuracy_penalty: i32,
 ) -> (bool, bool) {
     if world.get::<Destructible>(target_entity).is_some()
         && world.get::<Health>(target_entity).is_none()
@@ -74,7 +82,7 @@ fn resolve_hit_miss(
     let raw_roll = rand.d12();
     let is_critical = raw_roll == 12;
 
-    let attacker_roll = raw_roll + weapon_proficiency;
+    let attacker_roll = raw_roll + weapon_proficiency - accuracy_penalty;
     let defender_roll = rand.d12();
     let defense_value = defender_roll + target_dodge;
 
@@ -92,6 +100,55 @@ fn apply_hit_blink(world: &mut World, target_entity: Entity) {
     }
 }
 
+/// How much of a target's armor pool can soak a hit to the given location:
+/// the armor worn over that location plus any natural armor. Creatures that
+/// can't wear equipment are covered everywhere.
+fn location_armor(world: &World, target_entity: Entity, location: BodyLocation) -> i32 {
+    let Some(equipment) = world.get::<EquipmentSlots>(target_entity) else {
+        return i32::MAX;
+    };
+
+    let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+        return i32::MAX;
+    };
+
+    let total_armor = world
+        .get::<Stats>(target_entity)
+        .map(|stats| stats.get_stat(StatType::Armor))
+        .unwrap_or(0);
+
+    let covering = location
+        .armor_slots()
+        .iter()
+        .filter_map(|slot| equipment.get_equipped_item(*slot))
+        .collect::<Vec<_>>();
+
+    let mut elsewhere = equipment
+        .slots
+        .values()
+        .flatten()
+        .copied()
+        .filter(|item_id| !covering.contains(item_id))
+        .collect::<Vec<_>>();
+    elsewhere.sort_unstable();
+    elsewhere.dedup();
+
+    let uncovered_armor: i32 = elsewhere
+        .into_iter()
+        .filter_map(|item_id| registry.get_entity(StableId(item_id)))
+        .filter_map(|item_entity| world.get::<StatModifiers>(item_entity))
+        .map(|modifiers| {
+            modifiers
+                .modifiers
+                .get(&StatType::Armor)
+                .map(|armor| armor.iter().map(|m| m.value).sum::<i32>())
+                .unwrap_or(0)
+        })
+        .sum();
+
+    (total_armor - uncovered_armor).max(0)
+}
+
 fn calculate_direction(
     from_pos: (usize, usize, usize),
     to_pos: (usize, usize, usize),
@@ -116,6 +173,119 @@ fn calculate_normalized_direction(
     }
 }
 
+/// Actors (anything with Health) standing on the given tiles, in tile order
+fn actors_at_tiles(
+    world: &mut World,
+    tiles: &[(usize, usize)],
+    z: usize,
+    exclude: Entity,
+) -> Vec<(Entity, (usize, usize, usize))> {
+    let mut actors = Vec::new();
+    let mut q_zones = world.query::<&Zone>();
+
+    for &(x, y) in tiles {
+        let zone_idx = world_to_zone_idx(x, y, z);
+        let Some(zone) = q_zones.iter(world).find(|zone| zone.idx == zone_idx) else {
+            continue;
+        };
+
+        let local = world_to_zone_local(x, y);
+        let Some(entities) = zone.entities.get(local.0, local.1) else {
+            continue;
+        };
+
+        for &entity in entities {
+            if entity != exclude && world.get::<Health>(entity).is_some() {
+                actors.push((entity, (x, y, z)));
+            }
+        }
+    }
+
+    actors
+}
+
+/// Solid, non-actor object on a tile that stops projectiles
+fn projectile_blocker_at(world: &mut World, pos: (usize, usize, usize)) -> Option<Entity> {
+    let zone_idx = world_to_zone_idx(pos.0, pos.1, pos.2);
+    let local = world_to_zone_local(pos.0, pos.1);
+
+    let mut q_zones = world.query::<&Zone>();
+    let zone = q_zones.iter(world).find(|zone| zone.idx == zone_idx)?;
+
+    zone.entities
+        .get(local.0, local.1)?
+        .iter()
+        .copied()
+        .find(|&entity| {
+            world.get::<Health>(entity).is_none()
+                && world
+                    .get::<Collider>(entity)
+                    .is_some_and(|c| c.flags.contains(ColliderFlags::BLOCKS_PROJECTILE))
+        })
+}
+
+/// First projectile blocker strictly between two points, if the line of fire is obstructed
+fn line_of_fire_blocker(
+    world: &mut World,
+    from: (usize, usize, usize),
+    to: (usize, usize, usize),
+) -> Option<(Entity, (usize, usize, usize))> {
+    let line = bresenham_line((from.0, from.1), (to.0, to.1));
+    let between = line.len().saturating_sub(1);
+
+    line.into_iter().take(between).skip(1).find_map(|(x, y)| {
+        projectile_blocker_at(world, (x, y, from.2)).map(|e| (e, (x, y, from.2)))
+    })
+}
+
+/// Path a round takes toward its target and on past it, up to the weapon's range
+struct ShotTrace {
+    /// Tiles crossed, ending on the tile where the round stopped
+    path: Vec<(usize, usize)>,
+    /// Solid object on the last tile of the path that stopped the round
+    blocker: Option<Entity>,
+}
+
+fn trace_shot(
+    world: &mut World,
+    from: (usize, usize, usize),
+    target: (usize, usize),
+    range: usize,
+) -> ShotTrace {
+    let mut path = Vec::new();
+
+    for (x, y) in shot_line((from.0, from.1), target, range) {
+        path.push((x, y));
+
+        if let Some(blocker) = projectile_blocker_at(world, (x, y, from.2)) {
+            return ShotTrace {
+                path,
+                blocker: Some(blocker),
+            };
+        }
+    }
+
+    ShotTrace {
+        path,
+        blocker: None,
+    }
+}
+
+fn spawn_shot_trail(
+    world: &mut World,
+    weapon: &Weapon,
+    from: (usize, usize, usize),
+    to: (usize, usize, usize),
+) {
+    world.resource_scope(|world, mut rand: Mut<Rand>| {
+        if let Some(effect_id) = &weapon.particle_effect_id {
+            spawn_particle_effect(world, effect_id, from, to, &mut rand);
+        } else {
+            spawn_bullet_trail_in_world(world, from, to, 60.0, &mut rand);
+        }
+    });
+}
+
 impl GameAction for AttackAction {
     fn try_apply(self, world: &mut World) -> bool {
         let Some(registry) = world.get_resource::<StableIdRegistry>() else {
@@ -174,7 +344,128 @@ impl Command for AttackAction {
     }
 }
 
+/// Fan the hammer: empty a revolver's cylinder at one target in a single action.
+/// Every shot is less accurate than an aimed one.
+pub struct FanHammerAction {
+    pub attacker_stable_id: StableId,
+    pub target_stable_id: StableId,
+}
+
+impl GameAction for FanHammerAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+            return false;
+        };
+
+        let Some(attacker_entity) = registry.get_entity(self.attacker_stable_id) else {
+            return false;
+        };
+
+        let Some(target_entity) = registry.get_entity(self.target_stable_id) else {
+            return false;
+        };
+
+        let Some(weapon_id) = world
+            .get::<EquipmentSlots>(attacker_entity)
+            .and_then(|equipment| equipment.get_equipped_item(EquipmentSlot::MainHand))
+        else {
+            return false;
+        };
+
+        let Some(weapon_entity) = registry.get_entity(StableId(weapon_id)) else {
+            return false;
+        };
+
+        let shots = match world.get::<Weapon>(weapon_entity) {
+            Some(weapon) if weapon.can_fan_hammer() => weapon.current_ammo.unwrap_or(0),
+            _ => return false,
+        };
+
+        if shots == 0 || world.get::<Energy>(attacker_entity).is_none() {
+            return false;
+        }
+
+        let attack = AttackAction {
+            attacker_stable_id: self.attacker_stable_id,
+            weapon_stable_id: Some(StableId(weapon_id)),
+            target_stable_id: self.target_stable_id,
+            is_bump_attack: false,
+            aimed_location: None,
+        };
+
+        let mut fired = 0;
+        for shot in 0..shots {
+            // Stop once the target has dropped
+            if world
+                .get::<Health>(target_entity)
+                .is_some_and(|health| health.current <= 0)
+            {
+                break;
+            }
+
+            let Some(attacker_pos) = world.get::<Position>(attacker_entity).map(|p| p.world())
+            else {
+                break;
+            };
+
+            let Some(target_pos) = world.get::<Position>(target_entity).map(|p| p.world()) else {
+                break;
+            };
+
+            let Some(weapon) = world.get::<Weapon>(weapon_entity).cloned() else {
+                break;
+            };
+
+            if !attack.fire_ranged_shot(
+                world,
+                attacker_entity,
+                target_entity,
+                target_pos,
+                &weapon,
+                Some(weapon_entity),
+                attacker_pos,
+                FAN_HAMMER_ACCURACY_PENALTY,
+                shot as f32 * 0.12,
+            ) {
+                break;
+            }
+
+            fired += 1;
+        }
+
+        if fired == 0 {
+            return false;
+        }
+
+        if let Some(mut energy) = world.get_mut::<Energy>(attacker_entity) {
+            let cost = get_base_energy_cost(EnergyActionType::FanHammer);
+            energy.consume_energy(cost);
+        }
+
+        true
+    }
+}
+
+impl Command for FanHammerAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
+
 impl AttackAction {
+    /// Where a hit lands: the aimed location on the intended target, otherwise a random one.
+    /// Shots that carry through to someone else land wherever they land.
+    fn hit_location(&self, world: &mut World, target_entity: Entity) -> BodyLocation {
+        if let Some(location) = self.aimed_location
+            && world.get::<StableId>(target_entity) == Some(&self.target_stable_id)
+        {
+            return location;
+        }
+
+        let mut rand = world.resource_mut::<Rand>();
+        BodyLocation::roll(&mut rand)
+    }
+
     fn resolve_weapon(
         &self,
         world: &World,
@@ -240,10 +531,15 @@ impl AttackAction {
             WeaponType::Ranged => {
                 // For bump attacks, should fall back to melee
                 if self.is_bump_attack {
-                    // This shouldn't happen due to weapon resolution logic, but handle gracefully
-                    let default_weapon = world
-                        .get::<DefaultMeleeAttack>(attacker_entity)
-                        .map(|default_melee| default_melee.weapon.clone());
+                    // A gun with a bayonet fixed stabs, otherwise use the default melee attack
+                    let default_weapon = weapon_entity
+                        .and_then(|e| world.get::<WeaponAttachments>(e))
+                        .and_then(|attachments| attachments.melee_weapon())
+                        .or_else(|| {
+                            world
+                                .get::<DefaultMeleeAttack>(attacker_entity)
+                                .map(|default_melee| default_melee.weapon.clone())
+                        });
                     if let Some(default_weapon) = default_weapon {
                         return self.apply_unified_melee_attack(
                             world,
@@ -291,7 +587,7 @@ impl AttackAction {
 
         for &target_entity in targets.iter() {
             let mut should_apply_hit_blink = false;
-            let (hit, _is_critical) = resolve_hit_miss(attacker_entity, target_entity, world);
+            let (hit, is_critical) = resolve_hit_miss(attacker_entity, target_entity, world, 0);
 
             let rolled_damage = if hit {
                 world.resource_scope(|_world, mut rand: Mut<Rand>| {
@@ -318,6 +614,7 @@ impl AttackAction {
                 target_entity,
                 rolled_damage,
                 hit,
+                is_critical,
                 &weapon.can_damage,
                 &weapon.hit_effects,
                 current_tick,
@@ -351,6 +648,123 @@ impl AttackAction {
         weapon: &Weapon,
         weapon_entity: Option<Entity>,
         attacker_pos: (usize, usize, usize),
+    ) -> bool {
+        if !self.fire_ranged_shot(
+            world,
+            attacker_entity,
+            target_entity,
+            target_pos,
+            weapon,
+            weapon_entity,
+            attacker_pos,
+            0,
+            0.0,
+        ) {
+            return false;
+        }
+
+        // A revolver in the off hand follows up on the same target
+        if weapon.can_dual_wield()
+            && let Some(weapon_entity) = weapon_entity
+        {
+            self.fire_off_hand_shot(world, attacker_entity, target_entity, weapon_entity);
+        }
+
+        // Consume energy, aiming for a body location takes longer
+        let action_type = if self.aimed_location.is_some() {
+            EnergyActionType::AimedShot
+        } else {
+            EnergyActionType::Shoot
+        };
+
+        if let Some(mut energy) = world.get_mut::<Energy>(attacker_entity) {
+            let cost = get_base_energy_cost(action_type);
+            energy.consume_energy(cost);
+        }
+
+        true
+    }
+
+    /// Follow a main-hand shot with the revolver in the off hand, if there is one.
+    /// The off-hand shot is less accurate the less practised the shooter is with a pistol.
+    fn fire_off_hand_shot(
+        &self,
+        world: &mut World,
+        attacker_entity: Entity,
+        target_entity: Entity,
+        main_weapon_entity: Entity,
+    ) {
+        let Some(off_hand_entity) = world
+            .get::<EquipmentSlots>(attacker_entity)
+            .and_then(|equipment| equipment.get_equipped_item(EquipmentSlot::OffHand))
+            .and_then(|item_id| {
+                world
+                    .get_resource::<StableIdRegistry>()
+                    .and_then(|registry| registry.get_entity(StableId(item_id)))
+            })
+        else {
+            return;
+        };
+
+        if off_hand_entity == main_weapon_entity {
+            return;
+        }
+
+        let Some(off_hand_weapon) = world
+            .get::<Weapon>(off_hand_entity)
+            .filter(|weapon| weapon.can_dual_wield())
+            .cloned()
+        else {
+            return;
+        };
+
+        // No point firing at someone the first shot already dropped
+        if world
+            .get::<Health>(target_entity)
+            .is_some_and(|health| health.current <= 0)
+        {
+            return;
+        }
+
+        let Some(attacker_pos) = world.get::<Position>(attacker_entity).map(|p| p.world()) else {
+            return;
+        };
+
+        let Some(target_pos) = world.get::<Position>(target_entity).map(|p| p.world()) else {
+            return;
+        };
+
+        let pistol_skill = world
+            .get::<Stats>(attacker_entity)
+            .map(|stats| stats.get_stat(StatType::Pistol))
+            .unwrap_or(0);
+
+        self.fire_ranged_shot(
+            world,
+            attacker_entity,
+            target_entity,
+            target_pos,
+            &off_hand_weapon,
+            Some(off_hand_entity),
+            attacker_pos,
+            off_hand_accuracy_penalty(pistol_skill),
+            0.15,
+        );
+    }
+
+    /// Fire one round (or one shotgun blast) following the weapon's firing pattern.
+    /// Spends ammo but not energy, so callers can chain several shots into one action.
+    fn fire_ranged_shot(
+        &self,
+        world: &mut World,
+        attacker_entity: Entity,
+        target_entity: Entity,
+        target_pos: (usize, usize, usize),
+        weapon: &Weapon,
+        weapon_entity: Option<Entity>,
+        attacker_pos: (usize, usize, usize),
+        accuracy_penalty: i32,
+        audio_delay: f32,
     ) -> bool {
         // Check ammo for equipped weapons (default weapons have infinite ammo via None)
         if weapon_entity.is_some() && weapon.current_ammo == Some(0) {
@@ -360,6 +774,7 @@ impl AttackAction {
                     audio
                         .clip(empty_audio)
                         .volume(0.2)
+                        .delay(audio_delay)
                         .position(attacker_pos)
                         .play();
                 }
@@ -383,64 +798,96 @@ impl AttackAction {
                 audio
                     .clip(shoot_audio)
                     .volume(0.1)
+                    .delay(audio_delay)
                     .position(attacker_pos)
                     .play();
             }
         }
 
-        let targets = vec![target_entity];
         let current_tick = world.resource::<Clock>().current_tick();
+        let pattern = weapon.firing_pattern();
+        let range = weapon.range.unwrap_or(1);
 
-        // Process shot on each target
-        for &target_entity in targets.iter() {
-            let mut should_apply_hit_blink = false;
-            let (hit, _is_critical) = resolve_hit_miss(attacker_entity, target_entity, world);
-
-            let rolled_damage = if hit {
-                world.resource_scope(|_world, mut rand: Mut<Rand>| {
-                    rand.roll(&weapon.damage_dice).unwrap_or(1)
-                })
-            } else {
-                0
-            };
+        match pattern {
+            FiringPattern::Single => {
+                let end = self.fire_along_line(
+                    world,
+                    attacker_entity,
+                    target_entity,
+                    target_pos,
+                    weapon,
+                    attacker_pos,
+                    accuracy_penalty,
+                    1,
+                    current_tick,
+                );
+                spawn_shot_trail(world, weapon, attacker_pos, end);
+            }
+            FiringPattern::Cone { .. } => {
+                let tiles = pattern.affected_tiles(
+                    (attacker_pos.0, attacker_pos.1),
+                    (target_pos.0, target_pos.1),
+                    range,
+                );
 
-            // Spawn particle effects
-            world.resource_scope(|world, mut rand: Mut<Rand>| {
-                if let Some(effect_id) = &weapon.particle_effect_id {
-                    spawn_particle_effect(world, effect_id, attacker_pos, target_pos, &mut rand);
-                } else {
-                    spawn_bullet_trail_in_world(world, attacker_pos, target_pos, 60.0, &mut rand);
+                let mut victims = actors_at_tiles(world, &tiles, attacker_pos.2, attacker_entity);
+                if !victims.iter().any(|(e, _)| *e == target_entity) {
+                    victims.push((target_entity, target_pos));
                 }
-            });
-
-            // Spawn delayed hit effect
-            self.spawn_ranged_hit_effect(
-                world,
-                target_entity,
-                target_pos,
-                attacker_pos,
-                weapon.particle_effect_id.as_ref(),
-                hit,
-            );
 
-            self.apply_damage_to_target(
-                world,
-                attacker_entity,
-                target_entity,
-                rolled_damage,
-                hit,
-                &weapon.can_damage,
-                &weapon.hit_effects,
-                current_tick,
-                &mut should_apply_hit_blink,
-                attacker_pos,
-                target_pos,
-                &weapon.attack_verb,
-                &weapon.attack_noun,
-            );
+                // Each actor in the arc catches pellets, fewer the further out they stand
+                for (victim, victim_pos) in victims {
+                    let distance = ((victim_pos.0 as f32 - attacker_pos.0 as f32).powi(2)
+                        + (victim_pos.1 as f32 - attacker_pos.1 as f32).powi(2))
+                    .sqrt();
+                    let falloff = cone_falloff(distance, range);
+
+                    // Pellets don't pass through cover
+                    if let Some((blocker, blocker_pos)) =
+                        line_of_fire_blocker(world, attacker_pos, victim_pos)
+                    {
+                        spawn_shot_trail(world, weapon, attacker_pos, blocker_pos);
+                        self.strike_blocker(
+                            world,
+                            attacker_entity,
+                            blocker,
+                            blocker_pos,
+                            weapon,
+                            attacker_pos,
+                            falloff,
+                            current_tick,
+                        );
+                        continue;
+                    }
 
-            if should_apply_hit_blink {
-                apply_hit_blink(world, target_entity);
+                    spawn_shot_trail(world, weapon, attacker_pos, victim_pos);
+                    self.resolve_projectile(
+                        world,
+                        attacker_entity,
+                        victim,
+                        victim_pos,
+                        weapon,
+                        attacker_pos,
+                        accuracy_penalty,
+                        falloff,
+                        victim != target_entity,
+                        current_tick,
+                    );
+                }
+            }
+            FiringPattern::Penetrating { max_targets } => {
+                let end = self.fire_along_line(
+                    world,
+                    attacker_entity,
+                    target_entity,
+                    target_pos,
+                    weapon,
+                    attacker_pos,
+                    accuracy_penalty,
+                    max_targets,
+                    current_tick,
+                );
+                spawn_shot_trail(world, weapon, attacker_pos, end);
             }
         }
 
@@ -459,13 +906,222 @@ impl AttackAction {
             }
         }
 
-        // Consume energy
-        if let Some(mut energy) = world.get_mut::<Energy>(attacker_entity) {
-            let cost = get_base_energy_cost(EnergyActionType::Shoot);
-            energy.consume_energy(cost);
+        true
+    }
+
+    /// Send one round down the line of fire. It rolls against the target and, on a miss
+    /// or when it over-penetrates, carries on toward whoever stands behind until it has
+    /// hit `max_hits` actors, lodges in something solid or runs out of range.
+    /// Returns the tile the round came to rest on.
+    fn fire_along_line(
+        &self,
+        world: &mut World,
+        attacker_entity: Entity,
+        target_entity: Entity,
+        target_pos: (usize, usize, usize),
+        weapon: &Weapon,
+        attacker_pos: (usize, usize, usize),
+        accuracy_penalty: i32,
+        max_hits: usize,
+        current_tick: u32,
+    ) -> (usize, usize, usize) {
+        let z = attacker_pos.2;
+        let trace = trace_shot(
+            world,
+            attacker_pos,
+            (target_pos.0, target_pos.1),
+            weapon.range.unwrap_or(1),
+        );
+
+        let mut hits = 0;
+        let mut multiplier = 1.0;
+        let mut passed_target = false;
+
+        for (i, &(x, y)) in trace.path.iter().enumerate() {
+            let tile_pos = (x, y, z);
+
+            if i + 1 == trace.path.len()
+                && let Some(blocker) = trace.blocker
+            {
+                self.strike_blocker(
+                    world,
+                    attacker_entity,
+                    blocker,
+                    tile_pos,
+                    weapon,
+                    attacker_pos,
+                    multiplier,
+                    current_tick,
+                );
+                return tile_pos;
+            }
+
+            let victims = if (x, y) == (target_pos.0, target_pos.1) {
+                passed_target = true;
+                vec![(target_entity, target_pos)]
+            } else if passed_target {
+                actors_at_tiles(world, &[(x, y)], z, attacker_entity)
+            } else {
+                continue;
+            };
+
+            for (victim, victim_pos) in victims {
+                let is_stray = victim != target_entity;
+                let penalty = if is_stray && hits == 0 {
+                    accuracy_penalty + STRAY_SHOT_ACCURACY_PENALTY
+                } else {
+                    accuracy_penalty
+                };
+
+                let hit = self.resolve_projectile(
+                    world,
+                    attacker_entity,
+                    victim,
+                    victim_pos,
+                    weapon,
+                    attacker_pos,
+                    penalty,
+                    multiplier,
+                    is_stray,
+                    current_tick,
+                );
+
+                if hit {
+                    hits += 1;
+                    multiplier *= PENETRATION_DAMAGE_FALLOFF;
+
+                    if hits >= max_hits {
+                        return victim_pos;
+                    }
+                }
+            }
         }
 
-        true
+        trace
+            .path
+            .last()
+            .map(|&(x, y)| (x, y, z))
+            .unwrap_or(target_pos)
+    }
+
+    /// A round stopped by something solid chips away at it when it's destructible
+    fn strike_blocker(
+        &self,
+        world: &mut World,
+        attacker_entity: Entity,
+        blocker: Entity,
+        blocker_pos: (usize, usize, usize),
+        weapon: &Weapon,
+        attacker_pos: (usize, usize, usize),
+        damage_multiplier: f32,
+        current_tick: u32,
+    ) {
+        let Some(material_type) = world.get::<Destructible>(blocker).map(|d| d.material_type)
+        else {
+            return;
+        };
+
+        let roll = world.resource_scope(|_world, mut rand: Mut<Rand>| {
+            rand.roll(&weapon.damage_dice).unwrap_or(1)
+        });
+        let rolled_damage = ((roll as f32 * damage_multiplier).round() as i32).max(1);
+
+        self.spawn_ranged_hit_effect(
+            world,
+            blocker,
+            blocker_pos,
+            attacker_pos,
+            weapon.particle_effect_id.as_ref(),
+            true,
+        );
+
+        let mut should_apply_hit_blink = false;
+        self.apply_damage_to_target(
+            world,
+            attacker_entity,
+            blocker,
+            rolled_damage,
+            true,
+            false,
+            &[material_type],
+            &[],
+            current_tick,
+            &mut should_apply_hit_blink,
+            attacker_pos,
+            blocker_pos,
+            &weapon.attack_verb,
+            &weapon.attack_noun,
+        );
+
+        if should_apply_hit_blink {
+            apply_hit_blink(world, blocker);
+        }
+    }
+
+    /// Roll a single projectile against one target and apply the result. Returns whether it hit.
+    /// Stray rounds that miss a bystander pass by without comment.
+    fn resolve_projectile(
+        &self,
+        world: &mut World,
+        attacker_entity: Entity,
+        target_entity: Entity,
+        target_pos: (usize, usize, usize),
+        weapon: &Weapon,
+        attacker_pos: (usize, usize, usize),
+        accuracy_penalty: i32,
+        damage_multiplier: f32,
+        is_stray: bool,
+        current_tick: u32,
+    ) -> bool {
+        let mut should_apply_hit_blink = false;
+        let (hit, is_critical) =
+            resolve_hit_miss(attacker_entity, target_entity, world, accuracy_penalty);
+
+        if is_stray && !hit {
+            return false;
+        }
+
+        let rolled_damage = if hit {
+            let roll = world.resource_scope(|_world, mut rand: Mut<Rand>| {
+                rand.roll(&weapon.damage_dice).unwrap_or(1)
+            });
+            ((roll as f32 * damage_multiplier).round() as i32).max(1)
+        } else {
+            0
+        };
+
+        // Spawn delayed hit effect
+        self.spawn_ranged_hit_effect(
+            world,
+            target_entity,
+            target_pos,
+            attacker_pos,
+            weapon.particle_effect_id.as_ref(),
+            hit,
+        );
+
+        self.apply_damage_to_target(
+            world,
+            attacker_entity,
+            target_entity,
+            rolled_damage,
+            hit,
+            is_critical,
+            &weapon.can_damage,
+            &weapon.hit_effects,
+            current_tick,
+            &mut should_apply_hit_blink,
+            attacker_pos,
+            target_pos,
+            &weapon.attack_verb,
+            &weapon.attack_noun,
+        );
+
+        if should_apply_hit_blink {
+            apply_hit_blink(world, target_entity);
+        }
+
+        hit
     }
 
     fn spawn_ranged_hit_effect(
@@ -511,6 +1167,7 @@ impl AttackAction {
         target_entity: Entity,
         rolled_damage: i32,
         hit: bool,
+        is_critical: bool,
         can_damage: &[MaterialType],
         hit_effects: &[HitEffect],
         current_tick: u32,
@@ -533,10 +1190,26 @@ impl AttackAction {
             }
         };
 
-        if let Some(mut health) = world.get_mut::<Health>(target_entity) {
+        if world.get::<Health>(target_entity).is_some() {
             if can_damage.contains(&MaterialType::Flesh) {
                 if hit {
-                    health.take_damage_from_source(rolled_damage, current_tick, attacker_stable_id);
+                    let location = self.hit_location(world, target_entity);
+                    let damage = ((rolled_damage as f32 * location.damage_multiplier()).round()
+                        as i32)
+                        .max(1);
+                    let armor_cover = location_armor(world, target_entity, location);
+
+                    let dealt = world
+                        .get_mut::<Health>(target_entity)
+                        .map(|mut health| {
+                            health.take_damage_with_armor_limit(
+                                damage,
+                                current_tick,
+                                attacker_stable_id,
+                                armor_cover,
+                            )
+                        })
+                        .unwrap_or(0);
                     *should_apply_hit_blink = true;
 
                     // Send attack hit log event
@@ -544,13 +1217,29 @@ impl AttackAction {
                         message: LogMessage::Attack {
                             attacker: attacker_entity,
                             target: target_entity,
-                            damage: rolled_damage,
+                            damage,
                             weapon_verb: weapon_verb.to_string(),
+                            location: Some(location),
                         },
                         tick: current_tick,
                         knowledge,
                     });
 
+                    // Crits and heavy hits to a limb leave a lasting injury
+                    if (is_critical || dealt >= SEVERE_HIT_DAMAGE)
+                        && let Some(injury) = location.injury()
+                        && inflict_injury(world, target_entity, injury)
+                    {
+                        world.send_event(GameLogEvent {
+                            message: LogMessage::Injured {
+                                entity: target_entity,
+                                injury,
+                            },
+                            tick: current_tick,
+                            knowledge,
+                        });
+                    }
+
                     // Apply hit effects to flesh targets
                     self.apply_hit_effects(world, attacker_entity, target_entity, hit_effects);
 
@@ -621,6 +1310,15 @@ impl AttackAction {
 
                 let direction = calculate_direction(attacker_pos, target_pos);
                 spawn_material_hit_in_world(world, target_pos, material_type, direction);
+            } else if material_type == MaterialType::Wood {
+                // Fire still takes to wood, even if nothing else does
+                let burning = hit_effects
+                    .iter()
+                    .filter(|effect| matches!(effect, HitEffect::Burning { .. }))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                self.apply_hit_effects(world, attacker_entity, target_entity, &burning);
             }
         }
     }
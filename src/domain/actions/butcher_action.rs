@@ -0,0 +1,154 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    common::Rand,
+    domain::{
+        Corpse, CorpseStage, Energy, EnergyActionType, Inventory, StackableType, StatType, Stats,
+        Weapon, WeaponFamily, actions::GameAction, get_energy_cost, give_or_drop_stacked,
+        log_message,
+    },
+    engine::{StableId, StableIdRegistry},
+};
+
+/// Chance each cut comes away whole with no Blade proficiency at all
+const BASE_CUT_CHANCE: f32 = 0.5;
+/// How much each point of Blade adds to it
+const CUT_CHANCE_PER_BLADE: f32 = 0.1;
+
+/// Whether the butcher has a blade to work with, drawn or carried
+pub fn has_blade(world: &World, butcher: Entity) -> bool {
+    let (Some(inventory), Some(registry)) = (
+        world.get::<Inventory>(butcher),
+        world.get_resource::<StableIdRegistry>(),
+    ) else {
+        return false;
+    };
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| registry.get_entity(StableId(id)))
+        .any(|item| {
+            world
+                .get::<Weapon>(item)
+                .is_some_and(|weapon| weapon.weapon_family == WeaponFamily::Blade)
+        })
+}
+
+/// Meat and venom turn along with the carcass, a hide keeps a while longer
+fn spoils(item: StackableType) -> bool {
+    matches!(item, StackableType::RawMeat | StackableType::VenomGland)
+}
+
+/// Butcher and skin a carcass with a bladed weapon. A steadier hand with a blade
+/// wastes less of it.
+pub struct ButcherAction {
+    pub butcher: Entity,
+    pub corpse: Entity,
+}
+
+impl ButcherAction {
+    fn refuse(world: &mut World, message: String) -> bool {
+        log_message(world, message);
+        false
+    }
+}
+
+impl GameAction for ButcherAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(corpse) = world.get::<Corpse>(self.corpse).cloned() else {
+            return false;
+        };
+        let name = corpse.label();
+
+        if corpse.stage == CorpseStage::Bones {
+            return Self::refuse(
+                world,
+                format!("There's nothing left on the {} to cut", name),
+            );
+        }
+
+        let yields = corpse
+            .creature
+            .butcher_yield()
+            .iter()
+            .filter(|(item, _)| corpse.stage == CorpseStage::Fresh || !spoils(*item))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if yields.is_empty() {
+            return Self::refuse(
+                world,
+                format!("There's nothing worth taking from the {}", name),
+            );
+        }
+
+        if !has_blade(world, self.butcher) {
+            return Self::refuse(
+                world,
+                format!("You need a {{Y|blade}} to butcher the {}", name),
+            );
+        }
+
+        let stats = world.get::<Stats>(self.butcher).cloned();
+        let blade = stats
+            .as_ref()
+            .map(|s| s.get_stat(StatType::Blade))
+            .unwrap_or(0);
+        let cut_chance = (BASE_CUT_CHANCE + blade as f32 * CUT_CHANCE_PER_BLADE).clamp(0.2, 1.0);
+
+        let mut taken = vec![];
+        let mut wasted = false;
+
+        for (item, count) in yields {
+            let kept = {
+                let mut rand = world.resource_mut::<Rand>();
+                (0..count).filter(|_| rand.bool(cut_chance)).count() as u32
+            };
+
+            wasted |= kept < count;
+
+            if kept > 0 {
+                give_or_drop_stacked(world, self.butcher, item, kept);
+                taken.push(if kept > 1 {
+                    format!("{{Y|{}}} x{}", item.prefab_id(), kept)
+                } else {
+                    format!("{{Y|{}}}", item.prefab_id())
+                });
+            }
+        }
+
+        if let Some(mut corpse) = world.get_mut::<Corpse>(self.corpse) {
+            corpse.butchered = true;
+        }
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.butcher) {
+            energy.consume_energy(get_energy_cost(EnergyActionType::Butcher, stats.as_ref()));
+        }
+
+        let message = if taken.is_empty() {
+            format!(
+                "You hack at the {} and {{R|ruin everything worth taking}}",
+                name
+            )
+        } else if wasted {
+            format!(
+                "You butcher the {} and take {}, ruining some of it",
+                name,
+                taken.join(", ")
+            )
+        } else {
+            format!("You butcher the {} and take {}", name, taken.join(", "))
+        };
+
+        log_message(world, message);
+
+        true
+    }
+}
+
+impl Command for ButcherAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -0,0 +1,61 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{DialogueRegistry, actions::GameAction, apply_dialogue_effect},
+    states::{CurrentGameState, DialogueContext, GameState},
+};
+
+/// Say one of the lines on offer in the current conversation, apply whatever comes of it
+/// and move on to the next node, or end the conversation if there isn't one
+pub struct ChooseDialogueOptionAction {
+    pub option_index: usize,
+}
+
+impl GameAction for ChooseDialogueOptionAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(context) = world.get_resource::<DialogueContext>() else {
+            return false;
+        };
+
+        let player = context.player_entity;
+        let speaker = context.speaker_entity;
+
+        let Some(option) = world
+            .get_resource::<DialogueRegistry>()
+            .and_then(|registry| registry.get_node(&context.tree_id, &context.node_id))
+            .and_then(|node| node.options.get(self.option_index))
+            .cloned()
+        else {
+            return false;
+        };
+
+        if !option.is_available(world, player, speaker) {
+            return false;
+        }
+
+        for effect in option.effects.iter() {
+            apply_dialogue_effect(world, effect, player, speaker);
+        }
+
+        match option.next {
+            Some(next) => {
+                if let Some(mut context) = world.get_resource_mut::<DialogueContext>() {
+                    context.node_id = next;
+                }
+            }
+            None => {
+                if let Some(mut game_state) = world.get_resource_mut::<CurrentGameState>() {
+                    game_state.next = GameState::Explore;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Command for ChooseDialogueOptionAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -0,0 +1,115 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        AiController, FactionId, Following, InInventory, Inventory, Item, QuestLog, QuestStatus,
+        QuestTarget, Reputation, StackableType, actions::GameAction, give_or_drop_stacked,
+        inventory::InventoryChangedEvent, log_message,
+    },
+    engine::StableId,
+    rendering::Position,
+};
+
+/// How much better the town thinks of you for every job seen through
+const QUEST_REPUTATION: i8 = 5;
+
+/// Hand in a quest the player has finished: give up whatever they were sent to fetch or
+/// carry, let anyone they escorted go about their business, and collect the reward
+pub struct CompleteQuestAction {
+    pub player_entity: Entity,
+    pub quest_id: String,
+}
+
+impl GameAction for CompleteQuestAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(quest) = world
+            .get::<QuestLog>(self.player_entity)
+            .and_then(|log| log.in_progress(&self.quest_id))
+            .filter(|quest| quest.status == QuestStatus::ReadyToTurnIn)
+            .cloned()
+        else {
+            return false;
+        };
+
+        let Some(player_id) = world.get::<StableId>(self.player_entity).map(|id| id.0) else {
+            return false;
+        };
+
+        let targets = world
+            .query::<(
+                Entity,
+                &QuestTarget,
+                Option<&InInventory>,
+                Option<&StableId>,
+            )>()
+            .iter(world)
+            .filter(|(_, target, _, _)| target.quest_id == quest.id)
+            .map(|(entity, _, in_inventory, id)| {
+                let carried = in_inventory.is_some_and(|i| i.owner_id == player_id);
+                (entity, carried, id.map(|id| id.0))
+            })
+            .collect::<Vec<_>>();
+
+        for (entity, carried, id) in targets {
+            if carried {
+                let weight = world.get::<Item>(entity).map(|i| i.weight).unwrap_or(0.0);
+                if let (Some(id), Some(mut inventory)) =
+                    (id, world.get_mut::<Inventory>(self.player_entity))
+                {
+                    inventory.remove_item(id, weight);
+                }
+                world.entity_mut(entity).despawn();
+                world.send_event(InventoryChangedEvent);
+                continue;
+            }
+
+            // Travelers settle down wherever they were brought
+            let pos = world.get::<Position>(entity).map(|p| p.world());
+            if let (Some(pos), Some(mut ai)) = (pos, world.get_mut::<AiController>(entity)) {
+                ai.home_position = pos;
+            }
+            world
+                .entity_mut(entity)
+                .remove::<(QuestTarget, Following)>();
+        }
+
+        give_or_drop_stacked(
+            world,
+            self.player_entity,
+            StackableType::GoldNugget,
+            quest.reward,
+        );
+
+        if world.get::<Reputation>(self.player_entity).is_none() {
+            world
+                .entity_mut(self.player_entity)
+                .insert(Reputation::default());
+        }
+
+        if let Some(mut reputation) = world.get_mut::<Reputation>(self.player_entity) {
+            reputation.adjust(FactionId::Townsfolk, QUEST_REPUTATION);
+        }
+
+        if let Some(mut log) = world.get_mut::<QuestLog>(self.player_entity)
+            && let Some(entry) = log.in_progress_mut(&quest.id)
+        {
+            entry.status = QuestStatus::Completed;
+        }
+
+        log_message(
+            world,
+            format!(
+                "{{G|Quest complete:}} {}. You're paid {} gold",
+                quest.title, quest.reward
+            ),
+        );
+
+        true
+    }
+}
+
+impl Command for CompleteQuestAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -2,14 +2,16 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     domain::{
-        Consumable, ConsumableEffect, Energy, EnergyActionType, Health, InInventory, Inventory,
-        Item, Level, StackCount, Stackable, Stats,
+        Canteen, Consumable, ConsumableEffect, Energy, EnergyActionType, Food, GameSettings,
+        Health, InInventory, Inventory, Item, Level, NeedType, Needs, StackCount, Stackable, Stats,
         actions::GameAction,
         get_base_energy_cost,
         inventory::InventoryChangedEvent,
+        log_message,
         systems::{
             destruction_system::{DestructionCause, EntityDestroyedEvent},
             game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+            stop_bleeding, treat_injuries,
         },
     },
     engine::{Clock, StableId, StableIdRegistry},
@@ -70,6 +72,21 @@ impl GameAction for ConsumeAction {
             }
         }
 
+        let food = world.get::<Food>(item_entity).cloned();
+
+        // Canteens have to have something left in them
+        if let ConsumableEffect::Drink = consumable.effect
+            && world
+                .get::<Canteen>(item_entity)
+                .is_none_or(|canteen| canteen.swigs == 0)
+        {
+            log_message(
+                world,
+                "The canteen is empty. Fill it at a river".to_string(),
+            );
+            return false;
+        }
+
         // Handle item consumption
         if consumable.consume_on_use {
             let item_to_destroy = if world.get::<Stackable>(item_entity).is_some()
@@ -111,7 +128,8 @@ impl GameAction for ConsumeAction {
         }
 
         // Apply the consumable effect and generate description
-        let effect_desc = match consumable.effect {
+        let use_verb = consumable.effect.use_verb();
+        let mut effect_desc = match consumable.effect {
             ConsumableEffect::Heal(amount) => {
                 // Check if we have all required components first
                 if world.get::<Health>(consumer_entity).is_some()
@@ -148,8 +166,49 @@ impl GameAction for ConsumeAction {
                 // TODO: Implement cure system when status effects are added
                 "cured status effects".to_string()
             }
+            ConsumableEffect::TreatInjury => {
+                let stopped_bleeding = stop_bleeding(world, consumer_entity);
+                let treated = treat_injuries(world, consumer_entity, 1);
+
+                match (treated.first(), stopped_bleeding) {
+                    (Some(injury), _) => format!("treated {}", injury.display_name()),
+                    (None, true) => "stopped the bleeding".to_string(),
+                    (None, false) => "nothing to treat".to_string(),
+                }
+            }
+            ConsumableEffect::Drink => {
+                let swigs = world
+                    .get_mut::<Canteen>(item_entity)
+                    .map(|mut canteen| {
+                        canteen.swigs -= 1;
+                        canteen.swigs
+                    })
+                    .unwrap_or(0);
+
+                if let Some(mut needs) = world.get_mut::<Needs>(consumer_entity) {
+                    needs.satisfy(NeedType::Thirst, Canteen::SWIG_MINUTES);
+                }
+
+                match swigs {
+                    0 => "that was the last of it".to_string(),
+                    1 => "1 swig left".to_string(),
+                    n => format!("{} swigs left", n),
+                }
+            }
         };
 
+        let survival_needs = world
+            .get_resource::<GameSettings>()
+            .is_some_and(|settings| settings.survival_needs);
+
+        if let Some(food) = food
+            && survival_needs
+            && let Some(mut needs) = world.get_mut::<Needs>(consumer_entity)
+        {
+            needs.satisfy(NeedType::Hunger, food.minutes);
+            effect_desc.push_str(", less hungry");
+        }
+
         // Send game log event
         let current_tick = world
             .get_resource::<Clock>()
@@ -159,6 +218,7 @@ impl GameAction for ConsumeAction {
             message: LogMessage::ItemConsumed {
                 consumer: consumer_entity,
                 item: item_entity,
+                verb: use_verb.to_string(),
                 effect_desc,
             },
             tick: current_tick,
@@ -0,0 +1,80 @@
+use bevy_ecs::prelude::*;
+
+use crate::domain::{
+    Energy, RecipeRegistry, actions::GameAction, give_or_drop_stacked, log_message,
+    missing_requirements, take_stacked,
+};
+
+/// Make something from one of the recipes in the `RecipeRegistry`, using up its
+/// ingredients
+pub struct CraftAction {
+    pub crafter: Entity,
+    pub recipe_id: String,
+}
+
+impl CraftAction {
+    pub fn new(crafter: Entity, recipe_id: &str) -> Self {
+        Self {
+            crafter,
+            recipe_id: recipe_id.to_string(),
+        }
+    }
+}
+
+impl GameAction for CraftAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(recipe) = world
+            .get_resource::<RecipeRegistry>()
+            .and_then(|registry| registry.get(&self.recipe_id))
+            .cloned()
+        else {
+            return false;
+        };
+
+        let missing = missing_requirements(world, self.crafter, &recipe);
+
+        if !missing.is_empty() {
+            log_message(
+                world,
+                format!(
+                    "{{R|Can't {}}}: {}",
+                    recipe.name.to_lowercase(),
+                    missing.join(", ")
+                ),
+            );
+            return false;
+        }
+
+        for ingredient in recipe.ingredients.iter() {
+            if !take_stacked(world, self.crafter, ingredient.item, ingredient.count) {
+                return false;
+            }
+        }
+
+        give_or_drop_stacked(world, self.crafter, recipe.output.item, recipe.output.count);
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.crafter) {
+            energy.consume_energy(recipe.energy);
+        }
+
+        let made = if recipe.output.count > 1 {
+            format!(
+                "{} x{}",
+                recipe.output.item.prefab_id(),
+                recipe.output.count
+            )
+        } else {
+            recipe.output.item.prefab_id().to_string()
+        };
+
+        log_message(world, format!("You make {{Y|{}}}", made));
+
+        true
+    }
+}
+
+impl Command for CraftAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -2,11 +2,15 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     domain::{
-        Energy, EnergyActionType, EquipmentSlots, Equippable, Equipped, Inventory,
-        UnequipItemAction, actions::GameAction, get_base_energy_cost,
+        Energy, EnergyActionType, EquipmentSlot, EquipmentSlots, Equippable, Equipped, Inventory,
+        Player, UnequipItemAction, Weapon,
+        actions::GameAction,
+        get_base_energy_cost,
         inventory::InventoryChangedEvent,
+        systems::game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
     },
-    engine::{StableId, StableIdRegistry},
+    engine::{Clock, StableId, StableIdRegistry},
+    rendering::Position,
 };
 
 pub struct EquipItemAction {
@@ -54,25 +58,57 @@ impl GameAction for EquipItemAction {
             // inventory.remove_item(self.item_id);
         }
 
-        // Auto-unequip existing items in target slots
+        // A second revolver goes in an empty off hand rather than replacing the first.
+        // With both hands full, a new one swaps out the main hand as usual
+        let is_off_hand =
+            dual_wields_with_main_hand(world, entity, item_entity, &slot_requirements);
+        let slot_requirements = if is_off_hand {
+            vec![EquipmentSlot::OffHand]
+        } else {
+            slot_requirements
+        };
+
+        // Auto-unequip whatever already fills the slots this item needs
         {
             let Some(equipment_slots) = world.get::<EquipmentSlots>(entity) else {
                 return false;
             };
 
-            // Collect items to unequip
+            // Collect items to unequip, once each even if they fill both hands
             let mut items_to_unequip = Vec::new();
-            for &slot in &slot_requirements {
-                if let Some(existing_item_id) = equipment_slots.get_equipped_item(slot) {
+            for slot in slot_requirements
+                .iter()
+                .flat_map(|slot| slot.occupied_slots())
+            {
+                if let Some(existing_item_id) = equipment_slots.get_equipped_item(slot)
+                    && existing_item_id != self.item_id
+                    && !items_to_unequip.contains(&existing_item_id)
+                {
                     items_to_unequip.push(existing_item_id);
                 }
             }
 
             // Unequip existing items
             for existing_item_id in items_to_unequip {
+                let existing_entity = world
+                    .get_resource::<StableIdRegistry>()
+                    .and_then(|registry| registry.get_entity(StableId(existing_item_id)));
+
                 // Create and apply UnequipItemAction for each existing item
                 let unequip_action = UnequipItemAction::new(existing_item_id);
-                unequip_action.apply(world);
+                if unequip_action.try_apply(world)
+                    && let Some(existing_entity) = existing_entity
+                {
+                    send_equip_log(
+                        world,
+                        entity,
+                        LogMessage::EquipConflict {
+                            actor: entity,
+                            removed: existing_entity,
+                            equipped: item_entity,
+                        },
+                    );
+                }
             }
         }
 
@@ -89,6 +125,17 @@ impl GameAction for EquipItemAction {
             .entity_mut(item_entity)
             .insert(Equipped::new(self.entity_id, slot_requirements));
 
+        if is_off_hand {
+            send_equip_log(
+                world,
+                entity,
+                LogMessage::OffHandEquipped {
+                    actor: entity,
+                    item: item_entity,
+                },
+            );
+        }
+
         // Consume energy if entity has energy (for player actions)
         if let Some(mut energy) = world.get_mut::<Energy>(entity) {
             let cost = get_base_energy_cost(EnergyActionType::EquipItem);
@@ -106,3 +153,75 @@ impl Command for EquipItemAction {
         self.try_apply(world);
     }
 }
+
+/// True when a one-handed weapon would pair with the weapon already in the main hand,
+/// i.e. a second revolver to go alongside the first in a free off hand.
+fn dual_wields_with_main_hand(
+    world: &World,
+    entity: Entity,
+    item_entity: Entity,
+    slot_requirements: &[EquipmentSlot],
+) -> bool {
+    if slot_requirements != [EquipmentSlot::MainHand] {
+        return false;
+    }
+
+    if !world
+        .get::<Weapon>(item_entity)
+        .is_some_and(|weapon| weapon.can_dual_wield())
+    {
+        return false;
+    }
+
+    let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+        return false;
+    };
+
+    let Some(equipment) = world.get::<EquipmentSlots>(entity) else {
+        return false;
+    };
+
+    if equipment
+        .get_equipped_item(EquipmentSlot::OffHand)
+        .is_some()
+    {
+        return false;
+    }
+
+    let Some(main_hand_entity) = equipment
+        .get_equipped_item(EquipmentSlot::MainHand)
+        .and_then(|main_hand_id| registry.get_entity(StableId(main_hand_id)))
+    else {
+        return false;
+    };
+
+    main_hand_entity != item_entity
+        && world
+            .get::<Weapon>(main_hand_entity)
+            .is_some_and(|weapon| weapon.can_dual_wield())
+}
+
+fn send_equip_log(world: &mut World, entity: Entity, message: LogMessage) {
+    let tick = world
+        .get_resource::<Clock>()
+        .map(|c| c.get_tick())
+        .unwrap_or(0);
+
+    let knowledge = if world.get::<Player>(entity).is_some() {
+        KnowledgeLevel::Player
+    } else {
+        KnowledgeLevel::Action {
+            actor: entity,
+            location: world
+                .get::<Position>(entity)
+                .map(|p| p.world())
+                .unwrap_or((0, 0, 0)),
+        }
+    };
+
+    world.send_event(GameLogEvent {
+        message,
+        tick,
+        knowledge,
+    });
+}
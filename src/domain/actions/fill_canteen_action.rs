@@ -0,0 +1,104 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    domain::{
+        Canteen, Energy, EnergyActionType, Needs, Terrain, Zone, actions::GameAction,
+        get_base_energy_cost, inventory::InventoryChangedEvent, log_message,
+    },
+    engine::{StableId, StableIdRegistry},
+    rendering::{Position, world_to_zone_local},
+};
+
+/// Whether there's a river or shallows on or right beside the given spot
+pub fn is_beside_water(world: &mut World, pos: (usize, usize, usize)) -> bool {
+    let zone_idx = Position::new_world(pos).zone_idx();
+    let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+    let mut q_zones = world.query::<&Zone>();
+    let Some(zone) = q_zones.iter(world).find(|z| z.idx == zone_idx) else {
+        return false;
+    };
+
+    (-1..=1).any(|dx: i32| {
+        (-1..=1).any(|dy: i32| {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+
+            nx >= 0
+                && ny >= 0
+                && (nx as usize) < ZONE_SIZE.0
+                && (ny as usize) < ZONE_SIZE.1
+                && matches!(
+                    zone.terrain.get(nx as usize, ny as usize),
+                    Some(Terrain::River | Terrain::Shallows)
+                )
+        })
+    })
+}
+
+/// Top off a canteen at a river, drinking your fill while you're there
+pub struct FillCanteenAction {
+    pub item_id: u64,
+    pub actor: Entity,
+}
+
+impl FillCanteenAction {
+    pub fn new(item_id: u64, actor: Entity) -> Self {
+        Self { item_id, actor }
+    }
+}
+
+impl GameAction for FillCanteenAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(item_entity) = world
+            .get_resource::<StableIdRegistry>()
+            .and_then(|registry| registry.get_entity(StableId(self.item_id)))
+        else {
+            return false;
+        };
+
+        if world.get::<Canteen>(item_entity).is_none() {
+            return false;
+        }
+
+        let Some(pos) = world.get::<Position>(self.actor).map(|p| p.world()) else {
+            return false;
+        };
+
+        if !is_beside_water(world, pos) {
+            log_message(
+                world,
+                "There's no water here to fill the canteen from".to_string(),
+            );
+            return false;
+        }
+
+        if let Some(mut canteen) = world.get_mut::<Canteen>(item_entity) {
+            canteen.swigs = canteen.capacity;
+        }
+
+        if let Some(mut needs) = world.get_mut::<Needs>(self.actor) {
+            needs.thirst = 0;
+        }
+
+        log_message(
+            world,
+            "You drink your fill and top off the {C|canteen}".to_string(),
+        );
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.actor) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::FillCanteen));
+        }
+
+        world.send_event(InventoryChangedEvent);
+
+        true
+    }
+}
+
+impl Command for FillCanteenAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -4,32 +4,62 @@ pub trait GameAction {
     fn try_apply(self, world: &mut World) -> bool;
 }
 
+mod attachment_action;
 mod attack_action;
+mod butcher_action;
+mod choose_dialogue_option_action;
+mod complete_quest_action;
 mod consume_action;
+mod craft_action;
 mod drop_item_action;
 mod equip_item_action;
+mod fill_canteen_action;
 mod move_action;
 mod open_container_action;
+mod open_dialogue_action;
+mod open_trade_action;
+mod order_attack_action;
+mod pick_lock_action;
 mod pickup_item_action;
 mod reload_action;
+mod set_trap_action;
+mod sleep_action;
 mod stack_split_util;
 mod throw_item_action;
+mod toggle_door_action;
 mod toggle_light_action;
+mod trade_item_action;
 mod transfer_item_action;
+mod treat_injuries_action;
 mod unequip_item_action;
 mod wait_action;
 
+pub use attachment_action::*;
 pub use attack_action::*;
+pub use butcher_action::*;
+pub use choose_dialogue_option_action::*;
+pub use complete_quest_action::*;
 pub use consume_action::*;
+pub use craft_action::*;
 pub use drop_item_action::*;
 pub use equip_item_action::*;
+pub use fill_canteen_action::*;
 pub use move_action::*;
 pub use open_container_action::*;
+pub use open_dialogue_action::*;
+pub use open_trade_action::*;
+pub use order_attack_action::*;
+pub use pick_lock_action::*;
 pub use pickup_item_action::*;
 pub use reload_action::*;
+pub use set_trap_action::*;
+pub use sleep_action::*;
 pub use stack_split_util::*;
 pub use throw_item_action::*;
+pub use toggle_door_action::*;
 pub use toggle_light_action::*;
+pub use trade_item_action::*;
 pub use transfer_item_action::*;
+pub use treat_injuries_action::*;
 pub use unequip_item_action::*;
 pub use wait_action::*;
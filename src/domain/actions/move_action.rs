@@ -2,10 +2,12 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     domain::{
-        Energy, EnergyActionType, GameSettings, Player, PlayerMovedEvent, SmoothMovement, Stats,
-        actions::GameAction, get_energy_cost,
+        ActiveConditions, Bridge, ColliderFlags, ConditionType, Energy, EnergyActionType, Ferry,
+        GameSettings, Player, PlayerMovedEvent, SmoothMovement, Stats, Terrain, Trap, Zone,
+        actions::GameAction, get_base_energy_cost, get_energy_cost, log_for_player, log_message,
+        systems::trap_system::spring_trap,
     },
-    rendering::{Glyph, Position},
+    rendering::{Glyph, Position, world_to_zone_idx, world_to_zone_local},
 };
 
 pub struct MoveAction {
@@ -13,8 +15,41 @@ pub struct MoveAction {
     pub new_position: (usize, usize, usize),
 }
 
+impl MoveAction {
+    /// Caught in a trap, the turn goes on working free of it instead
+    fn struggle(&self, world: &mut World) -> bool {
+        log_for_player(
+            world,
+            self.entity,
+            "You struggle against the {R|trap}".to_string(),
+        );
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.entity) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::Move));
+            true
+        } else {
+            false
+        }
+    }
+}
+
 impl GameAction for MoveAction {
     fn try_apply(self, world: &mut World) -> bool {
+        if world
+            .get::<ActiveConditions>(self.entity)
+            .is_some_and(|conditions| conditions.has_condition(&ConditionType::Pinned))
+        {
+            return self.struggle(world);
+        }
+
+        let footing = Footing::at(world, self.new_position);
+
+        // Stepping aboard a ferry carries you to the far bank, unless someone is standing on it
+        let ferry_landing = footing
+            .ferry_landing
+            .filter(|&landing| !Footing::at(world, landing).blocked);
+        let destination = ferry_landing.unwrap_or(self.new_position);
+
         let Some(mut position) = world.get_mut::<Position>(self.entity) else {
             return false;
         };
@@ -23,14 +58,14 @@ impl GameAction for MoveAction {
         let old_position = (position.x, position.y);
 
         // Update position immediately (for game logic)
-        position.x = self.new_position.0 as f32;
-        position.y = self.new_position.1 as f32;
-        position.z = self.new_position.2 as f32;
+        position.x = destination.0 as f32;
+        position.y = destination.1 as f32;
+        position.z = destination.2 as f32;
 
         // Add smooth movement animation if entity has a Glyph and setting is enabled
         let settings = world.resource::<GameSettings>();
         if settings.smooth_movement && world.get::<Glyph>(self.entity).is_some() {
-            let new_position = (self.new_position.0 as f32, self.new_position.1 as f32);
+            let new_position = (destination.0 as f32, destination.1 as f32);
 
             // Only add smooth movement if there's actual movement
             if old_position != new_position {
@@ -41,16 +76,34 @@ impl GameAction for MoveAction {
 
         if world.get::<Player>(self.entity).is_some() {
             world.send_event(PlayerMovedEvent {
-                x: self.new_position.0,
-                y: self.new_position.1,
-                z: self.new_position.2,
+                x: destination.0,
+                y: destination.1,
+                z: destination.2,
             });
+
+            if ferry_landing.is_some() {
+                log_message(
+                    world,
+                    "The ferryman poles you across to the far bank".to_string(),
+                );
+            }
+        }
+
+        // Traps only go off underfoot, the ferry carries you clear of them
+        if ferry_landing.is_none() {
+            for &trap in footing.traps.iter() {
+                spring_trap(world, trap, self.entity);
+            }
         }
 
         // Calculate energy cost first
-        let cost = {
+        let cost = if ferry_landing.is_some() {
+            get_base_energy_cost(EnergyActionType::TakeFerry)
+        } else {
             let stats = world.get::<Stats>(self.entity);
-            get_energy_cost(EnergyActionType::Move, stats)
+            let base = get_energy_cost(EnergyActionType::Move, stats);
+
+            (base as f32 * footing.cost_multiplier()).round() as i32
         };
 
         // Then consume energy - return false if no Energy component
@@ -68,3 +121,65 @@ impl Command for MoveAction {
         self.try_apply(world);
     }
 }
+
+/// What is underfoot on a tile: the ground itself and any crossing built over it
+struct Footing {
+    terrain: Terrain,
+    bridged: bool,
+    blocked: bool,
+    ferry_landing: Option<(usize, usize, usize)>,
+    traps: Vec<Entity>,
+}
+
+impl Footing {
+    fn at(world: &mut World, pos: (usize, usize, usize)) -> Self {
+        let mut footing = Self {
+            terrain: Terrain::Grass,
+            bridged: false,
+            blocked: false,
+            ferry_landing: None,
+            traps: vec![],
+        };
+
+        let zone_idx = world_to_zone_idx(pos.0, pos.1, pos.2);
+        let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+        let entities = {
+            let mut q_zones = world.query::<&Zone>();
+            let Some(zone) = q_zones.iter(world).find(|z| z.idx == zone_idx) else {
+                return footing;
+            };
+
+            footing.terrain = *zone.terrain.get(x, y).unwrap_or(&Terrain::Grass);
+            footing.blocked = zone
+                .colliders
+                .get_flags(x, y)
+                .contains(ColliderFlags::BLOCKS_WALK);
+
+            zone.entities.get(x, y).cloned().unwrap_or_default()
+        };
+
+        for entity in entities {
+            footing.bridged |= world.get::<Bridge>(entity).is_some();
+
+            if let Some(ferry) = world.get::<Ferry>(entity) {
+                footing.ferry_landing = Some(ferry.landing);
+            }
+
+            if world.get::<Trap>(entity).is_some() {
+                footing.traps.push(entity);
+            }
+        }
+
+        footing
+    }
+
+    /// Bridges keep your feet dry; otherwise water slows you down
+    fn cost_multiplier(&self) -> f32 {
+        if self.bridged {
+            1.0
+        } else {
+            self.terrain.move_cost_multiplier()
+        }
+    }
+}
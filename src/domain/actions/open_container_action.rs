@@ -4,7 +4,8 @@ use macroquad::prelude::trace;
 use crate::{
     common::Rand,
     domain::{
-        Inventory, LootTableRegistry, Prefab, Prefabs, UnopenedContainer, actions::GameAction,
+        Inventory, Lock, LootTableRegistry, Prefab, Prefabs, UnopenedContainer,
+        actions::GameAction, label_of, log_for_player, try_unlock_with_key,
     },
     engine::Audio,
     rendering::Position,
@@ -18,6 +19,17 @@ pub struct OpenContainerAction {
 
 impl GameAction for OpenContainerAction {
     fn try_apply(self, world: &mut World) -> bool {
+        if world.get::<Lock>(self.container_entity).is_some()
+            && !try_unlock_with_key(world, self.player_entity, self.container_entity)
+        {
+            let message = format!(
+                "The {} is locked. ({{Y|P}}) to pick it",
+                label_of(world, self.container_entity, "chest")
+            );
+            log_for_player(world, self.player_entity, message);
+            return false;
+        }
+
         // Check if this is an unopened container and generate loot if needed
         if let Some(unopened) = world.get::<UnopenedContainer>(self.container_entity) {
             let loot_table_id = unopened.0;
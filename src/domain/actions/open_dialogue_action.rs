@@ -0,0 +1,69 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Dialogue, DialogueRegistry, Label, REFUSE_TALK_RELATIONSHIP, actions::GameAction,
+        get_effective_relationship, log_message,
+    },
+    states::{CurrentGameState, GameState},
+};
+
+/// Strike up a conversation and open the dialogue screen at the start of their tree
+pub struct OpenDialogueAction {
+    pub player_entity: Entity,
+    pub speaker_entity: Entity,
+}
+
+impl GameAction for OpenDialogueAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(tree_id) = world
+            .get::<Dialogue>(self.speaker_entity)
+            .map(|d| d.tree.clone())
+        else {
+            return false;
+        };
+
+        let Some(start) = world
+            .get_resource::<DialogueRegistry>()
+            .and_then(|registry| registry.get(&tree_id))
+            .map(|tree| tree.start.clone())
+        else {
+            return false;
+        };
+
+        let relationship =
+            get_effective_relationship(self.speaker_entity, self.player_entity, world);
+
+        if relationship < REFUSE_TALK_RELATIONSHIP {
+            let label = world
+                .get::<Label>(self.speaker_entity)
+                .map(|l| l.get().to_string())
+                .unwrap_or_else(|| "Stranger".to_string());
+
+            log_message(
+                world,
+                format!("The {{Y|{}}} has nothing to say to you", label),
+            );
+            return false;
+        }
+
+        world.insert_resource(crate::states::DialogueContext {
+            player_entity: self.player_entity,
+            speaker_entity: self.speaker_entity,
+            tree_id,
+            node_id: start,
+        });
+
+        if let Some(mut game_state) = world.get_resource_mut::<CurrentGameState>() {
+            game_state.next = GameState::Dialogue;
+        }
+
+        true
+    }
+}
+
+impl Command for OpenDialogueAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -0,0 +1,62 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Label, Merchant, REFUSE_TRADE_RELATIONSHIP, actions::GameAction,
+        get_effective_relationship, log_message, restock_merchant,
+    },
+    states::{CurrentGameState, GameState},
+};
+
+/// Step up to a merchant's counter and open the trade screen
+pub struct OpenTradeAction {
+    pub player_entity: Entity,
+    pub merchant_entity: Entity,
+}
+
+impl GameAction for OpenTradeAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        if world.get::<Merchant>(self.merchant_entity).is_none() {
+            return false;
+        }
+
+        let relationship =
+            get_effective_relationship(self.merchant_entity, self.player_entity, world);
+
+        if relationship < REFUSE_TRADE_RELATIONSHIP {
+            let label = world
+                .get::<Label>(self.merchant_entity)
+                .map(|l| l.get().to_string())
+                .unwrap_or_else(|| "Merchant".to_string());
+
+            log_message(
+                world,
+                format!(
+                    "The {{Y|{}}} won't do business with the likes of you",
+                    label
+                ),
+            );
+            return false;
+        }
+
+        restock_merchant(world, self.merchant_entity);
+
+        world.insert_resource(crate::states::TradeContext {
+            player_entity: self.player_entity,
+            merchant_entity: self.merchant_entity,
+            standing: relationship,
+        });
+
+        if let Some(mut game_state) = world.get_resource_mut::<CurrentGameState>() {
+            game_state.next = GameState::Trade;
+        }
+
+        true
+    }
+}
+
+impl Command for OpenTradeAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
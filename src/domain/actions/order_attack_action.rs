@@ -0,0 +1,71 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{AiController, Companion, Label, Player, actions::GameAction, log_message},
+    engine::{StableId, StableIdRegistry},
+    rendering::Position,
+};
+
+/// Set every companion in the zone on the player's target. They keep at it until it's
+/// dead or they're told to follow again.
+pub struct OrderAttackAction {
+    pub player_entity: Entity,
+    pub target_stable_id: StableId,
+}
+
+impl GameAction for OrderAttackAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(target) = world
+            .resource::<StableIdRegistry>()
+            .get_entity(self.target_stable_id)
+        else {
+            return false;
+        };
+
+        if world.get::<Player>(target).is_some() || world.get::<Companion>(target).is_some() {
+            return false;
+        }
+
+        let Some(zone_idx) = world
+            .get::<Position>(self.player_entity)
+            .map(|p| p.zone_idx())
+        else {
+            return false;
+        };
+
+        let mut q_companions =
+            world.query_filtered::<(&Position, &mut AiController), With<Companion>>();
+        let mut ordered = 0;
+
+        for (position, mut ai) in q_companions.iter_mut(world) {
+            if position.zone_idx() != zone_idx {
+                continue;
+            }
+
+            ai.current_target_id = Some(self.target_stable_id);
+            ordered += 1;
+        }
+
+        if ordered == 0 {
+            return false;
+        }
+
+        let name = world
+            .get::<Label>(target)
+            .map(|label| label.get_base().to_string())
+            .unwrap_or_else(|| "target".to_string());
+
+        log_message(
+            world,
+            format!("You set your companions on the {{R|{}}}", name),
+        );
+
+        true
+    }
+}
+
+impl Command for OrderAttackAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -0,0 +1,128 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    common::Rand,
+    domain::{
+        Attributes, Door, Energy, EnergyActionType, Inventory, Key, Label, Lock,
+        actions::GameAction, get_base_energy_cost, log_for_player,
+    },
+    engine::{StableId, StableIdRegistry},
+};
+
+/// Chance of picking a lock with no Dexterity to speak of, before the lock's difficulty
+const BASE_PICK_CHANCE: f32 = 0.4;
+/// How much each point of Dexterity adds to it
+const PICK_CHANCE_PER_DEX: f32 = 0.08;
+/// How much each point of lock difficulty takes off it
+const PICK_CHANCE_PER_DIFFICULTY: f32 = 0.12;
+
+/// The key in the holder's inventory that fits the given lock, if they're carrying one
+pub fn find_key(world: &World, holder: Entity, lock: &Lock) -> Option<Entity> {
+    let (Some(inventory), Some(registry)) = (
+        world.get::<Inventory>(holder),
+        world.get_resource::<StableIdRegistry>(),
+    ) else {
+        return None;
+    };
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| registry.get_entity(StableId(id)))
+        .find(|&item| world.get::<Key>(item).is_some_and(|key| key.0 == lock.key))
+}
+
+/// Unlocks the target with a key the actor is carrying, telling the player about it.
+/// Returns false if it's still locked.
+pub fn try_unlock_with_key(world: &mut World, actor: Entity, target: Entity) -> bool {
+    let Some(lock) = world.get::<Lock>(target).cloned() else {
+        return true;
+    };
+
+    let Some(key) = find_key(world, actor, &lock) else {
+        return false;
+    };
+
+    world.entity_mut(target).remove::<Lock>();
+
+    let message = format!(
+        "You unlock the {} with the {{Y|{}}}",
+        lockable_label(world, target),
+        label_of(world, key, "key")
+    );
+    log_for_player(world, actor, message);
+
+    true
+}
+
+/// What to call a door, chest or key in the log, going by `fallback` if it has no label
+pub fn label_of(world: &World, entity: Entity, fallback: &str) -> String {
+    world
+        .get::<Label>(entity)
+        .map(|label| label.get_base().to_string())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// What to call whatever the lock is on, a door or otherwise a chest
+fn lockable_label(world: &World, target: Entity) -> String {
+    let fallback = if world.get::<Door>(target).is_some() {
+        "door"
+    } else {
+        "chest"
+    };
+
+    label_of(world, target, fallback)
+}
+
+/// Work at a lock with whatever's to hand. Steadier fingers get through it more often,
+/// and a failed attempt still costs the time.
+pub struct PickLockAction {
+    pub actor: Entity,
+    pub target: Entity,
+}
+
+impl GameAction for PickLockAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(lock) = world.get::<Lock>(self.target).cloned() else {
+            return false;
+        };
+
+        let dexterity = world
+            .get::<Attributes>(self.actor)
+            .map(|attributes| attributes.dexterity)
+            .unwrap_or(0);
+
+        let chance = (BASE_PICK_CHANCE + dexterity as f32 * PICK_CHANCE_PER_DEX
+            - lock.difficulty as f32 * PICK_CHANCE_PER_DIFFICULTY)
+            .clamp(0.05, 0.95);
+
+        let picked = world.resource_mut::<Rand>().bool(chance);
+        let label = lockable_label(world, self.target);
+
+        if picked {
+            world.entity_mut(self.target).remove::<Lock>();
+        }
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.actor) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::PickLock));
+        }
+
+        let message = if picked {
+            format!("The lock on the {} gives with a {{G|click}}", label)
+        } else {
+            format!(
+                "You work at the lock on the {}, but it {{R|won't give}}",
+                label
+            )
+        };
+        log_for_player(world, self.actor, message);
+
+        true
+    }
+}
+
+impl Command for PickLockAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -22,13 +22,19 @@ impl GameAction for ReloadAction {
             return false;
         };
 
-        let weapon_id = equipment.get_equipped_item(EquipmentSlot::MainHand);
-
-        let Some(weapon_id) = weapon_id else {
-            return false;
-        };
-
-        let Some(weapon_entity) = registry.get_entity(StableId(weapon_id)) else {
+        // Top up the main hand first, then a revolver carried in the off hand
+        let weapon_entity = [EquipmentSlot::MainHand, EquipmentSlot::OffHand]
+            .into_iter()
+            .filter_map(|slot| equipment.get_equipped_item(slot))
+            .filter_map(|weapon_id| registry.get_entity(StableId(weapon_id)))
+            .find(|weapon_entity| {
+                world.get::<Weapon>(*weapon_entity).is_some_and(|weapon| {
+                    weapon.weapon_type == WeaponType::Ranged
+                        && weapon.current_ammo.unwrap_or(0) < weapon.clip_size.unwrap_or(0)
+                })
+            });
+
+        let Some(weapon_entity) = weapon_entity else {
             return false;
         };
 
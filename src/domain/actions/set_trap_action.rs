@@ -0,0 +1,71 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Energy, EnergyActionType, FactionMember, Prefab, PrefabId, Prefabs, SpawnValue, Trap,
+        TrapKind, Zone, actions::GameAction, get_base_energy_cost, log_message, take_stacked,
+    },
+    rendering::{Position, world_to_zone_local},
+};
+
+/// Set a trap from the pack down at your feet. Your own faction knows it's there,
+/// everyone else has to spot it.
+pub struct SetTrapAction {
+    pub actor: Entity,
+    pub kind: TrapKind,
+}
+
+impl GameAction for SetTrapAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(position) = world.get::<Position>(self.actor) else {
+            return false;
+        };
+        let zone_idx = position.zone_idx();
+        let pos = position.world();
+        let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+        let already_trapped = {
+            let mut q_zones = world.query::<&Zone>();
+            q_zones
+                .iter(world)
+                .find(|z| z.idx == zone_idx)
+                .and_then(|zone| zone.entities.get(x, y))
+                .is_some_and(|entities| entities.iter().any(|&e| world.get::<Trap>(e).is_some()))
+        };
+
+        if already_trapped {
+            log_message(world, "There's already a trap set here".to_string());
+            return false;
+        }
+
+        if !take_stacked(world, self.actor, self.kind.kit(), 1) {
+            return false;
+        }
+
+        let mut config = Prefab::new(PrefabId::Trap, pos)
+            .with_metadata("kind".to_owned(), SpawnValue::TrapKind(self.kind));
+
+        if let Some(faction) = world.get::<FactionMember>(self.actor) {
+            config = config.with_metadata(
+                "faction".to_owned(),
+                SpawnValue::FactionId(faction.faction_id),
+            );
+        }
+
+        Prefabs::spawn_world(world, config);
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.actor) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::SetTrap));
+        }
+
+        log_message(world, format!("You set a {{Y|{}}}", self.kind.name()));
+
+        true
+    }
+}
+
+impl Command for SetTrapAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
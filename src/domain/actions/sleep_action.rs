@@ -0,0 +1,138 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    common::Rand,
+    domain::{
+        Bedroll, Campfire, Energy, FactionMember, Health, InInventory, LightSource, Needs,
+        Sleeping, actions::GameAction, are_hostile, log_message,
+    },
+    engine::{Clock, StableId, TICKS_PER_MINUTE},
+    rendering::Position,
+};
+
+/// How close a lit campfire has to be to bed down by it
+const CAMPFIRE_RANGE: usize = 3;
+/// Nobody sleeps with an enemy this close
+const HOSTILE_RANGE: usize = 12;
+const MIN_SLEEP_MINUTES: u32 = 60;
+const MAX_SLEEP_MINUTES: u32 = 8 * 60;
+/// Chance that something finds the camp before morning
+const AMBUSH_CHANCE: f32 = 0.2;
+
+fn tile_distance(a: (usize, usize, usize), b: (usize, usize, usize)) -> usize {
+    a.0.abs_diff(b.0).max(a.1.abs_diff(b.1))
+}
+
+/// Lie down on a bedroll by a lit campfire and sleep until rested, unless something
+/// comes out of the dark first
+pub struct SleepAction {
+    pub entity: Entity,
+}
+
+impl SleepAction {
+    fn refuse(world: &mut World, message: &str) -> bool {
+        log_message(world, message.to_string());
+        false
+    }
+}
+
+impl GameAction for SleepAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        if world.get::<Sleeping>(self.entity).is_some() {
+            return false;
+        }
+
+        let Some(sleeper_id) = world.get::<StableId>(self.entity).map(|id| id.0) else {
+            return false;
+        };
+
+        let Some(position) = world.get::<Position>(self.entity).cloned() else {
+            return false;
+        };
+        let pos = position.world();
+        let zone_idx = position.zone_idx();
+
+        let has_bedroll = world
+            .query_filtered::<&InInventory, With<Bedroll>>()
+            .iter(world)
+            .any(|in_inventory| in_inventory.owner_id == sleeper_id);
+
+        if !has_bedroll {
+            return Self::refuse(world, "You need a {Y|Bedroll} to sleep on");
+        }
+
+        let near_fire = world
+            .query_filtered::<(&Position, Option<&LightSource>), With<Campfire>>()
+            .iter(world)
+            .any(|(fire_pos, light)| {
+                fire_pos.zone_idx() == zone_idx
+                    && tile_distance(fire_pos.world(), pos) <= CAMPFIRE_RANGE
+                    && light.is_none_or(|l| l.is_enabled)
+            });
+
+        if !near_fire {
+            return Self::refuse(
+                world,
+                "It's too cold and dark to sleep out here. Bed down by a lit {R|campfire}",
+            );
+        }
+
+        let nearby = world
+            .query_filtered::<(Entity, &Position), (With<FactionMember>, With<Health>)>()
+            .iter(world)
+            .filter(|(entity, other_pos)| {
+                *entity != self.entity
+                    && other_pos.zone_idx() == zone_idx
+                    && tile_distance(other_pos.world(), pos) <= HOSTILE_RANGE
+            })
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+
+        if nearby
+            .into_iter()
+            .any(|other| are_hostile(self.entity, other, world))
+        {
+            return Self::refuse(world, "{R|You can't sleep with enemies nearby}");
+        }
+
+        let rest_ticks = world
+            .get::<Needs>(self.entity)
+            .map(|needs| needs.ticks_to_rest())
+            .unwrap_or(0);
+        let duration = rest_ticks.clamp(
+            MIN_SLEEP_MINUTES * TICKS_PER_MINUTE,
+            MAX_SLEEP_MINUTES * TICKS_PER_MINUTE,
+        );
+
+        let now = world.resource::<Clock>().current_tick();
+        let ambush_tick = {
+            let mut rand = world.resource_mut::<Rand>();
+            rand.bool(AMBUSH_CHANCE)
+                .then(|| now + rand.range_n(0, duration as i32) as u32)
+        };
+
+        world.entity_mut(self.entity).insert(Sleeping {
+            started_tick: now,
+            until_tick: now + duration,
+            ambush_tick,
+        });
+
+        // The night is paid for up front, any waking early hands back what's left
+        if let Some(mut energy) = world.get_mut::<Energy>(self.entity) {
+            energy.consume_energy(duration as i32);
+        }
+
+        log_message(
+            world,
+            "You roll out your bedroll by the fire and drift off".to_string(),
+        );
+
+        true
+    }
+}
+
+impl Command for SleepAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -2,7 +2,7 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     domain::{
-        InInventory, Inventory, Item, Prefab, PrefabId, Prefabs, StackCount, Stackable,
+        InInventory, Inventory, Item, Prefab, Prefabs, StackCount, Stackable,
         inventory::InventoryChangedEvent,
     },
     engine::{StableId, StableIdRegistry},
@@ -36,17 +36,9 @@ pub fn split_item_from_stack(
         original_stack.count -= 1;
     }
 
-    // Determine the PrefabId based on stackable type
-    let prefab_id = match stackable.stack_type {
-        crate::domain::StackableType::Dynamite => PrefabId::Dynamite,
-        crate::domain::StackableType::Apple => PrefabId::Apple,
-        crate::domain::StackableType::GoldNugget => PrefabId::GoldNugget,
-        crate::domain::StackableType::CanOfBeans => PrefabId::CanOfBeans,
-    };
-
     // Create new single item at the inventory owner's position (for safety)
     let spawn_position = position.unwrap_or((0, 0, 0));
-    let config = Prefab::new(prefab_id, spawn_position);
+    let config = Prefab::new(stackable.stack_type.prefab_id(), spawn_position);
     let new_entity = Prefabs::spawn_world(world, config);
 
     // Set the new item's stack count to 1
@@ -3,9 +3,14 @@ use bevy_ecs::prelude::*;
 use crate::{
     Rand,
     domain::{
-        Collider, Energy, EnergyActionType, Equipped, InInventory, Inventory, Item, Prefab,
-        PrefabId, Prefabs, StackCount, Stackable, Throwable, UnequipItemAction, Zone,
-        actions::GameAction, get_base_energy_cost, inventory::InventoryChangedEvent,
+        Collider, Energy, EnergyActionType, Equipped, InInventory, Inventory, Item, Label,
+        LightSource, Lightable, Prefab, Prefabs, StackCount, Stackable, Throwable,
+        UnequipItemAction, Zone,
+        actions::GameAction,
+        get_base_energy_cost, ignite_tile,
+        inventory::InventoryChangedEvent,
+        log_message,
+        systems::destruction_system::{DestructionCause, EntityDestroyedEvent},
     },
     engine::{StableId, StableIdRegistry},
     rendering::{Position, spawn_throw_trail_in_world, world_to_zone_idx, world_to_zone_local},
@@ -60,16 +65,8 @@ impl GameAction for ThrowItemAction {
             let stackable = world.get::<Stackable>(item_entity).cloned();
 
             if let Some(stackable) = stackable {
-                // Determine the PrefabId based on stackable type
-                let prefab_id = match stackable.stack_type {
-                    crate::domain::StackableType::Dynamite => PrefabId::Dynamite,
-                    crate::domain::StackableType::Apple => PrefabId::Apple,
-                    crate::domain::StackableType::GoldNugget => PrefabId::GoldNugget,
-                    crate::domain::StackableType::CanOfBeans => PrefabId::CanOfBeans,
-                };
-
                 // Create new single item at the target position
-                let config = Prefab::new(prefab_id, self.target_position);
+                let config = Prefab::new(stackable.stack_type.prefab_id(), self.target_position);
                 let new_entity = Prefabs::spawn_world(world, config);
 
                 // Set the new item's stack count to 1
@@ -128,6 +125,44 @@ impl GameAction for ThrowItemAction {
             );
         }
 
+        // A lit lamp breaks where it lands and spills burning oil
+        let is_lit = world.get::<Lightable>(thrown_item_entity).is_some()
+            && world
+                .get::<LightSource>(thrown_item_entity)
+                .is_some_and(|light| light.is_enabled);
+
+        if is_lit {
+            let label = world
+                .get::<Label>(thrown_item_entity)
+                .map(|label| label.get_base().to_string())
+                .unwrap_or_else(|| "lamp".to_string());
+
+            world.send_event(EntityDestroyedEvent::new(
+                thrown_item_entity,
+                self.target_position,
+                DestructionCause::Consumed,
+            ));
+
+            let message = if ignite_tile(world, self.target_position) {
+                format!(
+                    "The {} shatters and the ground goes up in {{R|flames}}",
+                    label
+                )
+            } else {
+                format!("The {} shatters and the oil sputters out", label)
+            };
+
+            log_message(world, message);
+
+            if let Some(mut energy) = world.get_mut::<Energy>(self.thrower_entity) {
+                energy.consume_energy(get_base_energy_cost(EnergyActionType::Throw));
+            }
+
+            world.send_event(InventoryChangedEvent);
+
+            return true;
+        }
+
         // Place thrown item at target position
         let position = Position::new_world(self.target_position);
         world
@@ -0,0 +1,100 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Collider, Door, Energy, EnergyActionType, Health, Lock, RecalculateColliderFlagsEvent,
+        Zone, actions::GameAction, get_base_energy_cost, label_of, log_for_player,
+        try_unlock_with_key,
+    },
+    rendering::{Glyph, Position, world_to_zone_local},
+};
+
+/// Swing a door open, or shut again if nobody's standing in it. A locked door only
+/// opens for someone carrying its key.
+pub struct ToggleDoorAction {
+    pub actor: Entity,
+    pub door: Entity,
+}
+
+impl ToggleDoorAction {
+    /// Whether anyone is standing in the doorway
+    fn is_doorway_blocked(world: &mut World, door: Entity) -> bool {
+        let Some(position) = world.get::<Position>(door) else {
+            return false;
+        };
+        let zone_idx = position.zone_idx();
+        let pos = position.world();
+        let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+        let mut q_zones = world.query::<&Zone>();
+        let Some(zone) = q_zones.iter(world).find(|z| z.idx == zone_idx) else {
+            return false;
+        };
+
+        zone.entities
+            .get(x, y)
+            .is_some_and(|entities| entities.iter().any(|&e| world.get::<Health>(e).is_some()))
+    }
+}
+
+impl GameAction for ToggleDoorAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(door) = world.get::<Door>(self.door).cloned() else {
+            return false;
+        };
+
+        if !door.is_open
+            && world.get::<Lock>(self.door).is_some()
+            && !try_unlock_with_key(world, self.actor, self.door)
+        {
+            let message = format!(
+                "The {} is locked. ({{Y|P}}) to pick it",
+                label_of(world, self.door, "door")
+            );
+            log_for_player(world, self.actor, message);
+            return false;
+        }
+
+        if door.is_open && Self::is_doorway_blocked(world, self.door) {
+            let message = format!(
+                "Something's in the way of the {}",
+                label_of(world, self.door, "door")
+            );
+            log_for_player(world, self.actor, message);
+            return false;
+        }
+
+        let Some(position) = world.get::<Position>(self.door) else {
+            return false;
+        };
+        let zone_idx = position.zone_idx();
+        let pos = position.world();
+        let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+        let mut door = door;
+        door.is_open = !door.is_open;
+
+        if let Some(mut glyph) = world.get_mut::<Glyph>(self.door) {
+            glyph.idx = door.glyph();
+        }
+
+        if let Some(mut collider) = world.get_mut::<Collider>(self.door) {
+            collider.flags = door.collider_flags();
+        }
+
+        world.entity_mut(self.door).insert(door);
+        world.send_event(RecalculateColliderFlagsEvent { zone_idx, x, y });
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.actor) {
+            energy.consume_energy(get_base_energy_cost(EnergyActionType::OpenDoor));
+        }
+
+        true
+    }
+}
+
+impl Command for ToggleDoorAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
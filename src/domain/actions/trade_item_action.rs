@@ -0,0 +1,166 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Energy, EnergyActionType, Inventory, Item, Label, Prefab, Prefabs, StackCount, Stackable,
+        TradeDirection, TransferItemAction, actions::GameAction, count_gold, get_base_energy_cost,
+        give_gold, inventory::InventoryChangedEvent, is_gold, log_message,
+        merge_into_existing_stack, take_gold, trade_price,
+    },
+    engine::{StableId, StableIdRegistry},
+    rendering::Position,
+};
+
+/// Buy or sell a single item across a merchant's counter, paid for in gold nuggets.
+/// Stacks change hands one at a time.
+pub struct TradeItemAction {
+    pub customer_entity: Entity,
+    pub merchant_entity: Entity,
+    pub item_stable_id: StableId,
+    pub direction: TradeDirection,
+}
+
+impl GameAction for TradeItemAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(item_entity) = world
+            .get_resource::<StableIdRegistry>()
+            .and_then(|registry| registry.get_entity(self.item_stable_id))
+        else {
+            return false;
+        };
+
+        // Gold is what pays for things, not something to haggle over
+        if is_gold(world, item_entity) {
+            return false;
+        }
+
+        let (seller, buyer) = match self.direction {
+            TradeDirection::Buy => (self.merchant_entity, self.customer_entity),
+            TradeDirection::Sell => (self.customer_entity, self.merchant_entity),
+        };
+
+        let owned_by_seller = world
+            .get::<Inventory>(seller)
+            .is_some_and(|inventory| inventory.contains_id(self.item_stable_id.0));
+
+        if !owned_by_seller {
+            return false;
+        }
+
+        let item_label = world
+            .get::<Label>(item_entity)
+            .map(|l| l.get().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let merchant_label = world
+            .get::<Label>(self.merchant_entity)
+            .map(|l| l.get().to_string())
+            .unwrap_or_else(|| "Merchant".to_string());
+
+        let price = trade_price(
+            world,
+            item_entity,
+            self.merchant_entity,
+            self.customer_entity,
+            self.direction,
+        );
+
+        if price == 0 {
+            log_message(
+                world,
+                format!("The {{Y|{}}} has no use for {}", merchant_label, item_label),
+            );
+            return false;
+        }
+
+        if count_gold(world, buyer) < price {
+            let message = match self.direction {
+                TradeDirection::Buy => {
+                    format!("You can't afford {} for {{Y|{}}} gold", item_label, price)
+                }
+                TradeDirection::Sell => format!(
+                    "The {{Y|{}}} can't spare {{Y|{}}} gold for {}",
+                    merchant_label, price, item_label
+                ),
+            };
+            log_message(world, message);
+            return false;
+        }
+
+        let weight = world
+            .get::<Item>(item_entity)
+            .map(|i| i.weight)
+            .unwrap_or(0.0);
+        let has_room = world
+            .get::<Inventory>(buyer)
+            .is_some_and(|inventory| inventory.has_space_for_weight(weight));
+
+        if !has_room {
+            let message = match self.direction {
+                TradeDirection::Buy => format!("You can't carry {}", item_label),
+                TradeDirection::Sell => {
+                    format!(
+                        "The {{Y|{}}} has no room for {}",
+                        merchant_label, item_label
+                    )
+                }
+            };
+            log_message(world, message);
+            return false;
+        }
+
+        take_gold(world, buyer, price);
+        give_gold(world, seller, price);
+
+        let stack = world
+            .get::<Stackable>(item_entity)
+            .map(|s| s.stack_type)
+            .zip(world.get::<StackCount>(item_entity).map(|s| s.count));
+
+        match stack {
+            Some((stack_type, count)) if count > 1 => {
+                if let Some(mut stack_count) = world.get_mut::<StackCount>(item_entity) {
+                    stack_count.count -= 1;
+                }
+
+                let pos = world
+                    .get::<Position>(buyer)
+                    .map(|p| p.world())
+                    .unwrap_or((0, 0, 0));
+                let config = Prefab::new(stack_type.prefab_id(), pos);
+
+                if let Some(item) = Prefabs::spawn_in_inventory(world, config, buyer) {
+                    merge_into_existing_stack(world, buyer, item);
+                }
+            }
+            _ => {
+                TransferItemAction {
+                    from_entity: seller,
+                    to_entity: buyer,
+                    item_stable_id: self.item_stable_id,
+                }
+                .try_apply(world);
+            }
+        }
+
+        let message = match self.direction {
+            TradeDirection::Buy => format!("You buy {} for {{Y|{}}} gold", item_label, price),
+            TradeDirection::Sell => format!("You sell {} for {{Y|{}}} gold", item_label, price),
+        };
+        log_message(world, message);
+
+        if let Some(mut energy) = world.get_mut::<Energy>(self.customer_entity) {
+            let cost = get_base_energy_cost(EnergyActionType::Trade);
+            energy.consume_energy(cost);
+        }
+
+        world.send_event(InventoryChangedEvent);
+
+        true
+    }
+}
+
+impl Command for TradeItemAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
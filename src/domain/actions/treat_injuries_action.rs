@@ -0,0 +1,87 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        Doctor, Energy, EnergyActionType,
+        actions::GameAction,
+        get_base_energy_cost, log_message,
+        systems::{
+            game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+            stop_bleeding, treat_injuries,
+        },
+    },
+    engine::{Clock, StableId, StableIdRegistry},
+};
+
+/// Have a doctor see to all of a patient's injuries and stop any bleeding
+pub struct TreatInjuriesAction {
+    pub patient_stable_id: StableId,
+    pub doctor_stable_id: StableId,
+}
+
+impl GameAction for TreatInjuriesAction {
+    fn try_apply(self, world: &mut World) -> bool {
+        let Some(registry) = world.get_resource::<StableIdRegistry>() else {
+            return false;
+        };
+
+        let Some(patient_entity) = registry.get_entity(self.patient_stable_id) else {
+            return false;
+        };
+
+        let Some(doctor_entity) = registry.get_entity(self.doctor_stable_id) else {
+            return false;
+        };
+
+        if world.get::<Doctor>(doctor_entity).is_none() {
+            return false;
+        }
+
+        let current_tick = world
+            .get_resource::<Clock>()
+            .map(|c| c.get_tick())
+            .unwrap_or(0);
+
+        let stopped_bleeding = stop_bleeding(world, patient_entity);
+        let treated = treat_injuries(world, patient_entity, usize::MAX);
+
+        if treated.is_empty() && !stopped_bleeding {
+            log_message(
+                world,
+                "The {Y|Doctor} looks you over and finds nothing to treat".to_string(),
+            );
+            return false;
+        }
+
+        if stopped_bleeding {
+            log_message(
+                world,
+                "The {Y|Doctor} stitches your wounds closed".to_string(),
+            );
+        }
+
+        for injury in treated {
+            world.send_event(GameLogEvent {
+                message: LogMessage::InjuryTreated {
+                    entity: patient_entity,
+                    injury,
+                },
+                tick: current_tick,
+                knowledge: KnowledgeLevel::Player,
+            });
+        }
+
+        if let Some(mut energy) = world.get_mut::<Energy>(patient_entity) {
+            let cost = get_base_energy_cost(EnergyActionType::SeeDoctor);
+            energy.consume_energy(cost);
+        }
+
+        true
+    }
+}
+
+impl Command for TreatInjuriesAction {
+    fn apply(self, world: &mut World) {
+        self.try_apply(world);
+    }
+}
@@ -26,7 +26,6 @@ impl AiController {
         }
     }
 
-    #[allow(dead_code)]
     pub fn with_ranges(mut self, leash: usize, wander: usize, detection: usize) -> Self {
         self.leash_range = leash;
         self.wander_range = wander;
@@ -35,9 +34,48 @@ impl AiController {
     }
 }
 
+/// Keeps an AI at someone's side instead of wandering about its home
+#[derive(Component, Serialize, Deserialize, Clone, Debug, SerializableComponent)]
+pub struct Following {
+    pub leader: StableId,
+}
+
+impl Following {
+    /// How close the follower likes to stay
+    pub const DISTANCE: f32 = 2.0;
+
+    pub fn new(leader: StableId) -> Self {
+        Self { leader }
+    }
+}
+
+/// Travels with the player and takes their orders. Saved along with the player rather
+/// than with whatever zone it happens to be standing in.
+#[derive(Component, Serialize, Deserialize, Clone, Debug, SerializableComponent)]
+pub struct Companion;
+
+/// What the player can tell a companion to do
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompanionOrder {
+    /// Stay at the player's side
+    Follow,
+    /// Hold the spot they're standing on until told otherwise
+    Wait,
+}
+
+/// Drawn to the smell of a carcass whenever there's nothing to fight
+#[derive(Component, Serialize, Deserialize, Clone, Debug, SerializableComponent)]
+pub struct Scavenger;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum AiTemplate {
     BasicAggressive,
+    /// Goes about its business and leaves hostiles be, only fighting back against
+    /// whoever lays into it first
+    Peaceful,
+    /// Follows its leader or holds its post, and only picks fights that come within
+    /// leash range of either
+    Companion,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
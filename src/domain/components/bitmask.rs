@@ -12,12 +12,14 @@ use quadboy_macros::profiled_system;
 pub enum BitmaskStyle {
     Rocks,
     Outline,
+    Planks,
 }
 
 impl BitmaskStyle {
     pub fn get_calculator(&self) -> BitmaskCalculator {
         match self {
             BitmaskStyle::Rocks => BitmaskCalculator::Basic,
+            BitmaskStyle::Planks => BitmaskCalculator::Basic,
             BitmaskStyle::Outline => BitmaskCalculator::Simple { idx_offset: 0 },
         }
     }
@@ -26,6 +28,7 @@ impl BitmaskStyle {
         match self {
             BitmaskStyle::Rocks => vec![BitmaskStyle::Rocks],
             BitmaskStyle::Outline => vec![BitmaskStyle::Outline],
+            BitmaskStyle::Planks => vec![BitmaskStyle::Planks],
         }
     }
 }
@@ -46,6 +49,7 @@ impl Default for Bitmasker {
         let mut glyphs = HashMap::new();
 
         glyphs.insert(BitmaskStyle::Rocks, vec![240, 241]);
+        glyphs.insert(BitmaskStyle::Planks, vec![240, 242]);
         glyphs.insert(
             BitmaskStyle::Outline,
             vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
@@ -15,7 +15,10 @@ bitflags! {
 
         // Common combinations
         const SOLID = Self::BLOCKS_WALK.bits() | Self::BLOCKS_SWIM.bits();
-        const WALL = Self::SOLID.bits() | Self::BLOCKS_FLY.bits() | Self::BLOCKS_SIGHT.bits();
+        const WALL = Self::SOLID.bits()
+            | Self::BLOCKS_FLY.bits()
+            | Self::BLOCKS_SIGHT.bits()
+            | Self::BLOCKS_PROJECTILE.bits();
         const WATER = Self::BLOCKS_WALK.bits();
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     common::{Palette, palette_to_char},
+    domain::StatType,
     engine::{SerializableComponent, StableId},
 };
 
@@ -52,6 +53,22 @@ impl ActiveConditions {
             .collect()
     }
 
+    pub fn is_burning(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c.condition_type, ConditionType::Burning { .. }))
+    }
+
+    /// Removes every Burning condition, whatever its damage
+    pub fn extinguish(&mut self) -> Vec<Condition> {
+        let (burning, rest) = std::mem::take(&mut self.conditions)
+            .into_iter()
+            .partition(|c| matches!(c.condition_type, ConditionType::Burning { .. }));
+
+        self.conditions = rest;
+        burning
+    }
+
     pub fn is_empty(&self) -> bool {
         self.conditions.is_empty()
     }
@@ -120,6 +137,20 @@ pub enum ConditionType {
         damage_per_tick: i32,
     },
 
+    // Environmental effects
+    Wet,
+
+    // Caught fast in a trap, going nowhere until it lets go
+    Pinned,
+
+    // Survival needs, kept in step with `Needs` rather than wearing off
+    Hungry,
+    Starving,
+    Thirsty,
+    Parched,
+    Tired,
+    Exhausted,
+
     // AI behavior modifiers
     Feared {
         flee_from: StableId,
@@ -137,6 +168,14 @@ impl ConditionType {
             ConditionType::Poisoned { .. } => 1000,
             ConditionType::Bleeding { .. } => 800,
             ConditionType::Burning { .. } => 600,
+            ConditionType::Wet => 600,
+            ConditionType::Pinned => 500,
+            ConditionType::Hungry
+            | ConditionType::Starving
+            | ConditionType::Thirsty
+            | ConditionType::Parched
+            | ConditionType::Tired
+            | ConditionType::Exhausted => u32::MAX,
             ConditionType::Feared { .. } => 600,
             ConditionType::Taunted { .. } => 400,
             ConditionType::Confused { .. } => 500,
@@ -148,6 +187,14 @@ impl ConditionType {
             ConditionType::Poisoned { .. } => false,
             ConditionType::Bleeding { can_stack, .. } => *can_stack,
             ConditionType::Burning { .. } => false,
+            ConditionType::Wet => false,
+            ConditionType::Pinned => false,
+            ConditionType::Hungry
+            | ConditionType::Starving
+            | ConditionType::Thirsty
+            | ConditionType::Parched
+            | ConditionType::Tired
+            | ConditionType::Exhausted => false,
             ConditionType::Feared { .. } => false,
             ConditionType::Taunted { .. } => false,
             ConditionType::Confused { .. } => false,
@@ -159,6 +206,11 @@ impl ConditionType {
             ConditionType::Poisoned { .. } => Palette::Green.into(),
             ConditionType::Bleeding { .. } => Palette::Red.into(),
             ConditionType::Burning { .. } => Palette::Orange.into(),
+            ConditionType::Wet => Palette::Blue.into(),
+            ConditionType::Pinned => Palette::Gray.into(),
+            ConditionType::Hungry | ConditionType::Starving => Palette::DarkYellow.into(),
+            ConditionType::Thirsty | ConditionType::Parched => Palette::DarkCyan.into(),
+            ConditionType::Tired | ConditionType::Exhausted => Palette::DarkPurple.into(),
             ConditionType::Feared { .. } => Palette::Purple.into(),
             ConditionType::Taunted { .. } => Palette::Yellow.into(),
             ConditionType::Confused { .. } => Palette::Cyan.into(),
@@ -170,6 +222,11 @@ impl ConditionType {
             ConditionType::Poisoned { .. } => Palette::Green,
             ConditionType::Bleeding { .. } => Palette::Red,
             ConditionType::Burning { .. } => Palette::Orange,
+            ConditionType::Wet => Palette::Blue,
+            ConditionType::Pinned => Palette::Gray,
+            ConditionType::Hungry | ConditionType::Starving => Palette::DarkYellow,
+            ConditionType::Thirsty | ConditionType::Parched => Palette::Cyan,
+            ConditionType::Tired | ConditionType::Exhausted => Palette::Purple,
             ConditionType::Feared { .. } => Palette::Purple,
             ConditionType::Taunted { .. } => Palette::Yellow,
             ConditionType::Confused { .. } => Palette::Cyan,
@@ -182,11 +239,60 @@ impl ConditionType {
             ConditionType::Poisoned { .. } => '☻',
             ConditionType::Bleeding { .. } => '☻',
             ConditionType::Burning { .. } => '◘',
+            ConditionType::Wet => '≈',
+            ConditionType::Pinned => '#',
+            ConditionType::Hungry | ConditionType::Starving => '%',
+            ConditionType::Thirsty | ConditionType::Parched => '!',
+            ConditionType::Tired | ConditionType::Exhausted => 'z',
             ConditionType::Feared { .. } => '☺',
             ConditionType::Taunted { .. } => '♥',
             ConditionType::Confused { .. } => '♫',
         }
     }
+
+    /// Survival conditions last as long as the need does, so they have no duration to show
+    pub fn is_need(&self) -> bool {
+        matches!(
+            self,
+            ConditionType::Hungry
+                | ConditionType::Starving
+                | ConditionType::Thirsty
+                | ConditionType::Parched
+                | ConditionType::Tired
+                | ConditionType::Exhausted
+        )
+    }
+
+    /// Stat penalties that last as long as the condition does
+    pub fn stat_penalties(&self) -> Vec<(StatType, i32)> {
+        match self {
+            ConditionType::Hungry => vec![(StatType::Fortitude, -1), (StatType::ArmorRegen, -1)],
+            ConditionType::Starving => vec![
+                (StatType::Fortitude, -3),
+                (StatType::ArmorRegen, -3),
+                (StatType::Knockback, -2),
+            ],
+            ConditionType::Thirsty => vec![(StatType::Speed, -5), (StatType::ReloadSpeed, -1)],
+            ConditionType::Parched => vec![
+                (StatType::Speed, -10),
+                (StatType::ReloadSpeed, -3),
+                (StatType::Dodge, -2),
+            ],
+            ConditionType::Tired => vec![
+                (StatType::Dodge, -1),
+                (StatType::Rifle, -1),
+                (StatType::Pistol, -1),
+            ],
+            ConditionType::Exhausted => vec![
+                (StatType::Dodge, -3),
+                (StatType::Rifle, -2),
+                (StatType::Pistol, -2),
+                (StatType::Shotgun, -2),
+                (StatType::Speed, -5),
+            ],
+            _ => vec![],
+        }
+    }
 }
 
 impl Display for ConditionType {
@@ -195,6 +301,14 @@ impl Display for ConditionType {
             ConditionType::Poisoned { .. } => write!(f, "Poisoned"),
             ConditionType::Bleeding { .. } => write!(f, "Bleeding"),
             ConditionType::Burning { .. } => write!(f, "Burning"),
+            ConditionType::Wet => write!(f, "Wet"),
+            ConditionType::Pinned => write!(f, "Pinned"),
+            ConditionType::Hungry => write!(f, "Hungry"),
+            ConditionType::Starving => write!(f, "Starving"),
+            ConditionType::Thirsty => write!(f, "Thirsty"),
+            ConditionType::Parched => write!(f, "Parched"),
+            ConditionType::Tired => write!(f, "Tired"),
+            ConditionType::Exhausted => write!(f, "Exhausted"),
             ConditionType::Feared { .. } => write!(f, "Feared"),
             ConditionType::Taunted { .. } => write!(f, "Taunted"),
             ConditionType::Confused { .. } => write!(f, "Confused"),
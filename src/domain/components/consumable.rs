@@ -25,4 +25,19 @@ pub enum ConsumableEffect {
     Poison(i32, u32),
     Buff(String, i32, u32),
     Cure,
+    /// Dress a wound: stops bleeding and treats one lasting injury
+    TreatInjury,
+    /// A swig from a `Canteen`
+    Drink,
+}
+
+impl ConsumableEffect {
+    /// Verb used when logging that the item was used
+    pub fn use_verb(&self) -> &'static str {
+        match self {
+            ConsumableEffect::TreatInjury => "applied",
+            ConsumableEffect::Drink => "drank from",
+            _ => "ate",
+        }
+    }
 }
@@ -0,0 +1,145 @@
+use crate::{
+    common::Palette,
+    domain::CreatureType,
+    engine::{SerializableComponent, TICKS_PER_MINUTE},
+};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How long a carcass stays fresh enough to eat
+const FRESH_TICKS: u32 = 12 * 60 * TICKS_PER_MINUTE;
+/// After this it's picked clean down to the bones
+const ROTTING_TICKS: u32 = 2 * 24 * 60 * TICKS_PER_MINUTE;
+/// Bones bleach out and blow away after this
+const BONES_TICKS: u32 = 5 * 24 * 60 * TICKS_PER_MINUTE;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CorpseStage {
+    Fresh,
+    Rotting,
+    Bones,
+}
+
+impl CorpseStage {
+    pub fn glyph(&self) -> (usize, Palette, Palette) {
+        match self {
+            CorpseStage::Fresh => (97, Palette::Brown, Palette::Red),
+            CorpseStage::Rotting => (97, Palette::DarkGreen, Palette::DarkBrown),
+            CorpseStage::Bones => (99, Palette::White, Palette::Gray),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            CorpseStage::Fresh => {
+                "Flies are already at it. A sharp blade ({Y|U}) would have the meat and hide off it before the buzzards do."
+            }
+            CorpseStage::Rotting => {
+                "Bloated and reeking. The meat's turned, but the hide might still be worth cutting ({Y|U})."
+            }
+            CorpseStage::Bones => {
+                "Picked clean and bleaching in the sun. Nothing left for anybody."
+            }
+        }
+    }
+}
+
+/// What's left of a creature after it dies, rotting away on `Clock` time
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Corpse {
+    pub creature: CreatureType,
+    /// What the creature was called while it was alive
+    pub name: String,
+    pub died_tick: u32,
+    pub stage: CorpseStage,
+    /// Cut down to the bones already, whatever its age
+    pub butchered: bool,
+}
+
+impl Corpse {
+    pub fn new(creature: CreatureType, name: &str, died_tick: u32) -> Self {
+        Self {
+            creature,
+            name: name.to_string(),
+            died_tick,
+            stage: CorpseStage::Fresh,
+            butchered: false,
+        }
+    }
+
+    /// The stage the corpse ought to be at by `tick`, `None` once it's gone entirely
+    pub fn stage_at(&self, tick: u32) -> Option<CorpseStage> {
+        let age = tick.saturating_sub(self.died_tick);
+
+        if age >= BONES_TICKS {
+            None
+        } else if age >= ROTTING_TICKS || self.butchered {
+            Some(CorpseStage::Bones)
+        } else if age >= FRESH_TICKS {
+            Some(CorpseStage::Rotting)
+        } else {
+            Some(CorpseStage::Fresh)
+        }
+    }
+
+    /// Scavengers tearing at a carcass rot it along faster
+    pub fn age(&mut self, ticks: u32) {
+        self.died_tick = self.died_tick.saturating_sub(ticks);
+    }
+
+    pub fn label(&self) -> String {
+        let noun = match self.creature {
+            CreatureType::Bandit => "Corpse",
+            _ => "Carcass",
+        };
+
+        match self.stage {
+            CorpseStage::Fresh => format!("{} {}", self.name, noun),
+            CorpseStage::Rotting => format!("Rotting {} {}", self.name, noun),
+            CorpseStage::Bones => format!("{} Bones", self.name),
+        }
+    }
+
+    /// Whether there's still anything on it worth a scavenger's time
+    pub fn is_edible(&self) -> bool {
+        self.stage != CorpseStage::Bones
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_boundaries() {
+        let died = 1000;
+        let corpse = Corpse::new(CreatureType::Coyote, "Coyote", died);
+
+        let cases = [
+            (0, Some(CorpseStage::Fresh)),
+            (FRESH_TICKS - 1, Some(CorpseStage::Fresh)),
+            (FRESH_TICKS, Some(CorpseStage::Rotting)),
+            (ROTTING_TICKS - 1, Some(CorpseStage::Rotting)),
+            (ROTTING_TICKS, Some(CorpseStage::Bones)),
+            (BONES_TICKS - 1, Some(CorpseStage::Bones)),
+            (BONES_TICKS, None),
+        ];
+
+        for (age, expected) in cases {
+            assert_eq!(corpse.stage_at(died + age), expected, "at age {}", age);
+        }
+
+        // A clock reading from before the death doesn't age it backwards
+        assert_eq!(corpse.stage_at(0), Some(CorpseStage::Fresh));
+    }
+
+    #[test]
+    fn test_butchered_stage() {
+        let mut corpse = Corpse::new(CreatureType::Coyote, "Coyote", 0);
+        corpse.butchered = true;
+
+        assert_eq!(corpse.stage_at(0), Some(CorpseStage::Bones));
+        assert_eq!(corpse.stage_at(BONES_TICKS - 1), Some(CorpseStage::Bones));
+        assert_eq!(corpse.stage_at(BONES_TICKS), None);
+    }
+}
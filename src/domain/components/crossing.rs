@@ -0,0 +1,15 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::SerializableComponent;
+
+/// Planks laid over a river so a road can cross it dry-shod
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Bridge;
+
+/// A raft moored at the bank of a wide river. Stepping aboard carries you to `landing`,
+/// the world position of the road on the far bank.
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Ferry {
+    pub landing: (usize, usize, usize),
+}
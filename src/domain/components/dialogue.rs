@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::SerializableComponent;
+
+/// Someone who will strike up a conversation when bumped into, following one of the
+/// dialogue trees in the `DialogueRegistry`
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Dialogue {
+    pub tree: String,
+}
+
+impl Dialogue {
+    pub fn new(tree: &str) -> Self {
+        Self {
+            tree: tree.to_string(),
+        }
+    }
+}
+
+/// Things a character has said or been told, so conversations can remember them
+#[derive(Component, Serialize, Deserialize, Clone, Default, SerializableComponent)]
+pub struct DialogueMemory {
+    pub flags: HashSet<String>,
+}
+
+impl DialogueMemory {
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    pub fn set_flag(&mut self, flag: &str) {
+        self.flags.insert(flag.to_string());
+    }
+}
@@ -0,0 +1,8 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::SerializableComponent;
+
+/// Townsfolk who will treat a patient's injuries when bumped into
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Doctor;
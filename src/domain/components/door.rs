@@ -0,0 +1,129 @@
+use crate::{domain::ColliderFlags, engine::SerializableComponent};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A door that swings shut to block the way through, and the view too unless it's barred
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Door {
+    pub is_open: bool,
+    pub open_glyph: usize,
+    pub closed_glyph: usize,
+    /// What the door blocks while it's shut
+    pub closed_flags: ColliderFlags,
+}
+
+impl Door {
+    pub fn new(is_open: bool, open_glyph: usize, closed_glyph: usize) -> Self {
+        Self {
+            is_open,
+            open_glyph,
+            closed_glyph,
+            closed_flags: ColliderFlags::WALL,
+        }
+    }
+
+    /// Iron bars keep you in but let you see out
+    pub fn barred(mut self) -> Self {
+        self.closed_flags = ColliderFlags::SOLID | ColliderFlags::BLOCKS_FLY;
+        self
+    }
+
+    pub fn collider_flags(&self) -> ColliderFlags {
+        if self.is_open {
+            ColliderFlags::empty()
+        } else {
+            self.closed_flags
+        }
+    }
+
+    pub fn glyph(&self) -> usize {
+        if self.is_open {
+            self.open_glyph
+        } else {
+            self.closed_glyph
+        }
+    }
+}
+
+/// Keeps a door or chest shut until it's opened with the right key, picked, or blown off
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Lock {
+    /// Which key fits it
+    pub key: String,
+    /// How hard the lock is to pick, from 1 for a rusty padlock up to 5 for a bank vault
+    pub difficulty: i32,
+}
+
+impl Lock {
+    pub fn new(key: &str, difficulty: i32) -> Self {
+        Self {
+            key: key.to_string(),
+            difficulty,
+        }
+    }
+}
+
+/// Opens any lock cut for the same key
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Key(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{Collider, Prefab, PrefabId, Prefabs, SpawnValue},
+        engine::StableIdRegistry,
+    };
+
+    #[test]
+    fn test_collider_flags() {
+        let open = Door::new(true, 0, 0);
+        let closed = Door::new(false, 0, 0);
+
+        assert_eq!(open.collider_flags(), ColliderFlags::empty());
+        assert_eq!(closed.collider_flags(), ColliderFlags::WALL);
+        assert!(
+            closed
+                .collider_flags()
+                .contains(ColliderFlags::BLOCKS_SIGHT)
+        );
+    }
+
+    #[test]
+    fn test_barred_collider_flags() {
+        let open = Door::new(true, 0, 0).barred();
+        let closed = Door::new(false, 0, 0).barred();
+
+        assert_eq!(open.collider_flags(), ColliderFlags::empty());
+        assert!(closed.collider_flags().contains(ColliderFlags::SOLID));
+        assert!(closed.collider_flags().contains(ColliderFlags::BLOCKS_FLY));
+        assert!(
+            !closed
+                .collider_flags()
+                .contains(ColliderFlags::BLOCKS_SIGHT)
+        );
+        assert!(
+            !closed
+                .collider_flags()
+                .contains(ColliderFlags::BLOCKS_PROJECTILE)
+        );
+    }
+
+    #[test]
+    fn test_locked_door_spawns_shut() {
+        let mut world = World::new();
+        world.insert_resource(Prefabs::new());
+        world.insert_resource(StableIdRegistry::new());
+
+        let config = Prefab::new(PrefabId::Door, (1, 1, 0))
+            .with_metadata("lock".to_string(), SpawnValue::String("bank".to_string()));
+        let door = Prefabs::spawn_world(&mut world, config);
+
+        assert!(world.get::<Lock>(door).is_some());
+        assert!(!world.get::<Door>(door).unwrap().is_open);
+        assert_eq!(
+            world.get::<Collider>(door).unwrap().flags,
+            ColliderFlags::WALL
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::engine::{AudioKey, SerializableComponent};
+use crate::{
+    domain::StackableType,
+    engine::{AudioKey, SerializableComponent},
+};
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +16,8 @@ pub enum CreatureType {
     Bat,
     Rat,
     Beetle,
+    Dog,
+    Gunslinger,
 }
 
 impl CreatureType {
@@ -25,6 +30,40 @@ impl CreatureType {
             CreatureType::Bat => AudioKey::Hiss1,
             CreatureType::Rat => AudioKey::Hiss1,
             CreatureType::Beetle => AudioKey::Hiss1,
+            CreatureType::Dog => AudioKey::Bark1,
+            CreatureType::Gunslinger => AudioKey::Pain1,
         }
     }
+
+    /// What a clean job of butchering and skinning one of these gets you
+    pub fn butcher_yield(self) -> &'static [(StackableType, u32)] {
+        match self {
+            CreatureType::Bear => &[(StackableType::RawMeat, 4), (StackableType::Hide, 2)],
+            CreatureType::Coyote => &[(StackableType::RawMeat, 2), (StackableType::Hide, 1)],
+            CreatureType::Rattlesnake => &[
+                (StackableType::RawMeat, 1),
+                (StackableType::Hide, 1),
+                (StackableType::VenomGland, 1),
+            ],
+            CreatureType::Rat => &[(StackableType::RawMeat, 1)],
+            CreatureType::Bandit
+            | CreatureType::Bat
+            | CreatureType::Beetle
+            | CreatureType::Dog
+            | CreatureType::Gunslinger => &[],
+        }
+    }
+
+    /// Whether it has the hands to work a door latch
+    pub fn can_open_doors(self) -> bool {
+        matches!(self, CreatureType::Bandit | CreatureType::Gunslinger)
+    }
+
+    /// Small enough to be caught in a snare
+    pub fn is_small_game(self) -> bool {
+        matches!(
+            self,
+            CreatureType::Coyote | CreatureType::Rattlesnake | CreatureType::Rat
+        )
+    }
 }
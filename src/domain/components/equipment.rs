@@ -34,6 +34,13 @@ impl EquipmentSlot {
             EquipmentSlot::Neck => "Neck",
         }
     }
+    /// The actual slots taken up, with both hands spelled out for two-handed items
+    pub fn occupied_slots(&self) -> Vec<EquipmentSlot> {
+        match self {
+            EquipmentSlot::BothHands => vec![EquipmentSlot::MainHand, EquipmentSlot::OffHand],
+            slot => vec![*slot],
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -80,6 +87,13 @@ impl EquipmentSlots {
     }
 
     pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<u64> {
+        // Two-handed items are held in both hand slots rather than a slot of their own
+        if slot == EquipmentSlot::BothHands {
+            let item_id = self.get_equipped_item(EquipmentSlot::MainHand)?;
+            self.unequip(EquipmentSlot::MainHand);
+            return Some(item_id);
+        }
+
         // Get the item ID from the slot
         let item_id = self.slots.get(&slot).and_then(|&id| id)?;
 
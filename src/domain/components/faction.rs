@@ -8,6 +8,18 @@ pub enum FactionId {
     Player,
     Bandits,
     Wildlife,
+    Townsfolk,
+}
+
+impl FactionId {
+    pub fn name(self) -> &'static str {
+        match self {
+            FactionId::Player => "Player",
+            FactionId::Bandits => "Bandits",
+            FactionId::Wildlife => "Wildlife",
+            FactionId::Townsfolk => "Townsfolk",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,3 +94,21 @@ impl FactionMember {
         self.modifiers.retain(|_, modifier| modifier.tick());
     }
 }
+
+/// How each faction has come to regard someone through their own deeds, on top of how
+/// their factions get along
+#[derive(Component, Serialize, Deserialize, Clone, Debug, Default, SerializableComponent)]
+pub struct Reputation {
+    pub standings: HashMap<FactionId, i8>,
+}
+
+impl Reputation {
+    pub fn get(&self, faction_id: FactionId) -> i8 {
+        self.standings.get(&faction_id).copied().unwrap_or(0)
+    }
+
+    pub fn adjust(&mut self, faction_id: FactionId, amount: i8) {
+        let standing = self.get(faction_id).saturating_add(amount).clamp(-100, 100);
+        self.standings.insert(faction_id, standing);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::engine::SerializableComponent;
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A patch of burning ground, alight until its fuel runs out
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Fire {
+    /// Minutes of burning left in whatever's underneath
+    pub fuel: u32,
+}
+
+impl Fire {
+    pub fn new(fuel: u32) -> Self {
+        Self { fuel }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    common::algorithm::bresenham::bresenham_line,
+    domain::components::{weapon::Weapon, weapon_family::WeaponFamily, weapon_type::WeaponType},
+};
+
+/// Accuracy lost on every shot when fanning the hammer
+pub const FAN_HAMMER_ACCURACY_PENALTY: i32 = 4;
+
+/// Damage multiplier applied to each actor a rifle round passes through
+pub const PENETRATION_DAMAGE_FALLOFF: f32 = 0.5;
+
+/// Accuracy lost when a round that missed its target carries on toward a bystander
+pub const STRAY_SHOT_ACCURACY_PENALTY: i32 = 3;
+
+/// Accuracy lost on an off-hand shot, before the Pistol stat offsets it
+pub const OFF_HAND_ACCURACY_PENALTY: i32 = 6;
+
+/// Pellet damage at the edge of a shotgun's range, relative to point blank
+const CONE_MIN_FALLOFF: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FiringPattern {
+    /// One round at one target
+    Single,
+    /// Pellets spread over an arc, striking every actor within it
+    Cone { spread_degrees: f32 },
+    /// Round continues through the first target into whatever stands behind it
+    Penetrating { max_targets: usize },
+}
+
+impl Weapon {
+    pub fn firing_pattern(&self) -> FiringPattern {
+        if self.weapon_type != WeaponType::Ranged {
+            return FiringPattern::Single;
+        }
+
+        match self.weapon_family {
+            WeaponFamily::Shotgun => FiringPattern::Cone {
+                spread_degrees: self.spread_degrees.unwrap_or(40.0),
+            },
+            WeaponFamily::Rifle => FiringPattern::Penetrating { max_targets: 2 },
+            _ => FiringPattern::Single,
+        }
+    }
+
+    /// Revolvers can empty the cylinder in one action
+    pub fn can_fan_hammer(&self) -> bool {
+        self.weapon_type == WeaponType::Ranged && self.weapon_family == WeaponFamily::Pistol
+    }
+
+    /// Revolvers can be carried one in each hand
+    pub fn can_dual_wield(&self) -> bool {
+        self.weapon_type == WeaponType::Ranged && self.weapon_family == WeaponFamily::Pistol
+    }
+}
+
+/// Accuracy penalty for an off-hand shot; practice with a pistol closes the gap
+pub fn off_hand_accuracy_penalty(pistol_skill: i32) -> i32 {
+    (OFF_HAND_ACCURACY_PENALTY - pistol_skill / 2).max(0)
+}
+
+impl FiringPattern {
+    /// Tiles affected by a shot from `from` aimed at `target`, not including `from`
+    pub fn affected_tiles(
+        &self,
+        from: (usize, usize),
+        target: (usize, usize),
+        range: usize,
+    ) -> Vec<(usize, usize)> {
+        match self {
+            FiringPattern::Single => vec![target],
+            FiringPattern::Cone { spread_degrees } => {
+                cone_tiles(from, target, range, *spread_degrees)
+            }
+            FiringPattern::Penetrating { .. } => shot_line(from, target, range),
+        }
+    }
+}
+
+/// Damage multiplier for a pellet travelling `distance` tiles of a `range` tile spread
+pub fn cone_falloff(distance: f32, range: usize) -> f32 {
+    if range == 0 {
+        return 1.0;
+    }
+
+    let t = ((distance - 1.0) / range as f32).clamp(0.0, 1.0);
+    1.0 - t * (1.0 - CONE_MIN_FALLOFF)
+}
+
+/// Every tile within `range` whose bearing is inside the spread arc around `target`
+pub fn cone_tiles(
+    from: (usize, usize),
+    target: (usize, usize),
+    range: usize,
+    spread_degrees: f32,
+) -> Vec<(usize, usize)> {
+    let aim_x = target.0 as f32 - from.0 as f32;
+    let aim_y = target.1 as f32 - from.1 as f32;
+
+    if aim_x == 0.0 && aim_y == 0.0 {
+        return vec![];
+    }
+
+    let aim_angle = aim_y.atan2(aim_x);
+    let half_spread = (spread_degrees / 2.0).to_radians();
+    let r = range as i32;
+    let mut tiles = Vec::new();
+
+    for dy in -r..=r {
+        for dx in -r..=r {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+
+            let x = from.0 as i32 + dx;
+            let y = from.1 as i32 + dy;
+
+            if x < 0 || y < 0 {
+                continue;
+            }
+
+            let distance = ((dx * dx + dy * dy) as f32).sqrt();
+
+            if distance > range as f32 + 0.5 {
+                continue;
+            }
+
+            let angle = (dy as f32).atan2(dx as f32);
+            let mut delta = (angle - aim_angle).abs();
+
+            if delta > std::f32::consts::PI {
+                delta = std::f32::consts::TAU - delta;
+            }
+
+            if delta <= half_spread {
+                tiles.push((x as usize, y as usize));
+            }
+        }
+    }
+
+    // The aimed tile is always in the blast, even when the arc is narrow
+    if !tiles.contains(&target) {
+        tiles.push(target);
+    }
+
+    tiles
+}
+
+/// Path of a round fired at `target`, continuing past it out to `range` tiles
+pub fn shot_line(
+    from: (usize, usize),
+    target: (usize, usize),
+    range: usize,
+) -> Vec<(usize, usize)> {
+    let dx = target.0 as f32 - from.0 as f32;
+    let dy = target.1 as f32 - from.1 as f32;
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length == 0.0 {
+        return vec![];
+    }
+
+    let scale = range.max(1) as f32 / length;
+    let end_x = (from.0 as f32 + dx * scale).round().max(0.0) as usize;
+    let end_y = (from.1 as f32 + dy * scale).round().max(0.0) as usize;
+
+    // Trace to the target first so the path always passes through the aimed tile
+    let mut line = bresenham_line(from, target);
+    line.extend(bresenham_line(target, (end_x, end_y)).into_iter().skip(1));
+
+    line.into_iter()
+        .skip(1)
+        .filter(|tile| {
+            let tx = tile.0 as f32 - from.0 as f32;
+            let ty = tile.1 as f32 - from.1 as f32;
+            (tx * tx + ty * ty).sqrt() <= range as f32 + 0.5
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_off_hand_accuracy_penalty() {
+        let cases = [
+            (0, OFF_HAND_ACCURACY_PENALTY),
+            (1, OFF_HAND_ACCURACY_PENALTY),
+            (2, OFF_HAND_ACCURACY_PENALTY - 1),
+            (6, OFF_HAND_ACCURACY_PENALTY - 3),
+            (12, 0),
+            (30, 0),
+        ];
+
+        for (pistol_skill, expected) in cases {
+            assert_eq!(
+                off_hand_accuracy_penalty(pistol_skill),
+                expected,
+                "pistol skill {}",
+                pistol_skill
+            );
+        }
+    }
+}
@@ -125,15 +125,29 @@ impl Health {
         current_tick: u32,
         source: Option<StableId>,
     ) {
+        self.take_damage_with_armor_limit(damage, current_tick, source, i32::MAX);
+    }
+
+    /// Take damage where armor can soak up at most `max_absorbed` of it, for hits
+    /// landing somewhere only partly covered. Returns the damage dealt to health.
+    pub fn take_damage_with_armor_limit(
+        &mut self,
+        damage: i32,
+        current_tick: u32,
+        source: Option<StableId>,
+        max_absorbed: i32,
+    ) -> i32 {
         if damage > 0 {
             self.last_damage_tick = current_tick;
             self.armor_regen_progress = 0;
             self.last_damage_source = source;
         }
 
-        if self.current_armor > 0 {
+        let before = self.current;
+
+        if self.current_armor > 0 && max_absorbed > 0 {
             // Armor absorbs damage first
-            let armor_absorbed = damage.min(self.current_armor);
+            let armor_absorbed = damage.min(self.current_armor).min(max_absorbed);
             self.current_armor -= armor_absorbed;
             let remaining_damage = damage - armor_absorbed;
 
@@ -145,6 +159,8 @@ impl Health {
             // No armor, damage goes directly to health
             self.current = (self.current - damage).max(0);
         }
+
+        before - self.current
     }
 
     pub fn is_dead(&self) -> bool {
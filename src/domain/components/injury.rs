@@ -0,0 +1,146 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::Rand,
+    domain::{EquipmentSlot, StatType},
+    engine::SerializableComponent,
+};
+
+/// Damage a hit must deal past armor to leave a lasting injury
+pub const SEVERE_HIT_DAMAGE: i32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BodyLocation {
+    Head,
+    Torso,
+    Arms,
+    Legs,
+}
+
+impl BodyLocation {
+    pub fn all() -> &'static [BodyLocation] {
+        &[
+            BodyLocation::Head,
+            BodyLocation::Torso,
+            BodyLocation::Arms,
+            BodyLocation::Legs,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BodyLocation::Head => "Head",
+            BodyLocation::Torso => "Torso",
+            BodyLocation::Arms => "Arm",
+            BodyLocation::Legs => "Leg",
+        }
+    }
+
+    /// Where an unaimed hit lands
+    pub fn roll(rand: &mut Rand) -> Self {
+        let roll = rand.random();
+
+        if roll < 0.1 {
+            BodyLocation::Head
+        } else if roll < 0.55 {
+            BodyLocation::Torso
+        } else if roll < 0.75 {
+            BodyLocation::Arms
+        } else {
+            BodyLocation::Legs
+        }
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        match self {
+            BodyLocation::Head => 1.5,
+            BodyLocation::Torso => 1.0,
+            BodyLocation::Arms => 0.75,
+            BodyLocation::Legs => 0.75,
+        }
+    }
+
+    /// Equipment slots whose armor covers this location
+    pub fn armor_slots(&self) -> &'static [EquipmentSlot] {
+        match self {
+            BodyLocation::Head => &[EquipmentSlot::Head],
+            BodyLocation::Torso | BodyLocation::Arms => &[EquipmentSlot::Body],
+            BodyLocation::Legs => &[EquipmentSlot::Legs, EquipmentSlot::Feet],
+        }
+    }
+
+    /// Lasting injury left behind by a severe hit to this location
+    pub fn injury(&self) -> Option<InjuryType> {
+        match self {
+            BodyLocation::Arms => Some(InjuryType::WoundedArm),
+            BodyLocation::Legs => Some(InjuryType::CrippledLeg),
+            BodyLocation::Head | BodyLocation::Torso => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InjuryType {
+    WoundedArm,
+    CrippledLeg,
+}
+
+impl InjuryType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            InjuryType::WoundedArm => "Wounded Arm",
+            InjuryType::CrippledLeg => "Crippled Leg",
+        }
+    }
+
+    /// Stat penalties that last until the injury is treated
+    pub fn stat_penalties(&self) -> Vec<(StatType, i32)> {
+        match self {
+            InjuryType::WoundedArm => vec![
+                (StatType::Rifle, -2),
+                (StatType::Shotgun, -2),
+                (StatType::Pistol, -2),
+                (StatType::Blade, -2),
+                (StatType::Cudgel, -2),
+                (StatType::Unarmed, -2),
+            ],
+            InjuryType::CrippledLeg => vec![(StatType::Speed, -10), (StatType::Dodge, -2)],
+        }
+    }
+}
+
+/// Injuries an actor is carrying. Unlike conditions they don't wear off,
+/// they have to be bandaged or seen to by a doctor.
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent, Default)]
+pub struct Injuries {
+    pub injuries: Vec<InjuryType>,
+}
+
+impl Injuries {
+    pub fn new() -> Self {
+        Self {
+            injuries: Vec::new(),
+        }
+    }
+
+    pub fn has(&self, injury: InjuryType) -> bool {
+        self.injuries.contains(&injury)
+    }
+
+    /// Add an injury, returning false if the actor already has it
+    pub fn add(&mut self, injury: InjuryType) -> bool {
+        if self.has(injury) {
+            return false;
+        }
+
+        self.injuries.push(injury);
+        true
+    }
+
+    pub fn remove(&mut self, injury: InjuryType) -> bool {
+        let count = self.injuries.len();
+        self.injuries.retain(|i| *i != injury);
+        self.injuries.len() != count
+    }
+}
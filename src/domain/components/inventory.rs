@@ -1,16 +1,22 @@
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::engine::{AudioKey, SerializableComponent};
+use crate::{
+    domain::PrefabId,
+    engine::{AudioKey, SerializableComponent},
+};
 
 #[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
 pub struct Item {
     pub weight: f32,
+    /// Worth in gold nuggets before rarity and haggling
+    #[serde(default)]
+    pub value: u32,
 }
 
 impl Item {
-    pub fn new(weight: f32) -> Self {
-        Self { weight }
+    pub fn new(weight: f32, value: u32) -> Self {
+        Self { weight, value }
     }
 }
 
@@ -108,6 +114,44 @@ pub enum StackableType {
     Dynamite,
     Apple,
     CanOfBeans,
+    Bandage,
+    RawMeat,
+    CookedMeat,
+    Gunpowder,
+    FuseCord,
+    Cloth,
+    Lead,
+    Cartridges,
+    Hide,
+    VenomGland,
+    BearTrap,
+    Tripwire,
+    Snare,
+}
+
+impl StackableType {
+    /// The prefab a single item of this kind is spawned from
+    pub fn prefab_id(self) -> PrefabId {
+        match self {
+            StackableType::GoldNugget => PrefabId::GoldNugget,
+            StackableType::Dynamite => PrefabId::Dynamite,
+            StackableType::Apple => PrefabId::Apple,
+            StackableType::CanOfBeans => PrefabId::CanOfBeans,
+            StackableType::Bandage => PrefabId::Bandage,
+            StackableType::RawMeat => PrefabId::RawMeat,
+            StackableType::CookedMeat => PrefabId::CookedMeat,
+            StackableType::Gunpowder => PrefabId::Gunpowder,
+            StackableType::FuseCord => PrefabId::FuseCord,
+            StackableType::Cloth => PrefabId::Cloth,
+            StackableType::Lead => PrefabId::Lead,
+            StackableType::Cartridges => PrefabId::Cartridges,
+            StackableType::Hide => PrefabId::Hide,
+            StackableType::VenomGland => PrefabId::VenomGland,
+            StackableType::BearTrap => PrefabId::BearTrap,
+            StackableType::Tripwire => PrefabId::Tripwire,
+            StackableType::Snare => PrefabId::Snare,
+        }
+    }
 }
 
 #[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
@@ -72,6 +72,10 @@ impl LightSource {
         Self::new(0.9, 0xFF7A2E, 4).with_flicker(0.8)
     }
 
+    pub fn wildfire() -> Self {
+        Self::new(0.8, 0xFF5A1E, 3).with_flicker(0.9)
+    }
+
     pub fn lantern() -> Self {
         Self::new(0.9, 0xFFC690, 6).with_flicker(0.4)
     }
@@ -0,0 +1,29 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{domain::LootTableId, engine::SerializableComponent};
+
+/// Townsfolk who buy and sell for gold nuggets when bumped into. Their shelves are
+/// filled from `stock` and topped back up once every `RESTOCK_DAYS`.
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Merchant {
+    pub stock: LootTableId,
+    /// The day the shelves were last filled, `None` until a customer first walks in
+    pub restocked_day: Option<u32>,
+}
+
+impl Merchant {
+    pub const RESTOCK_DAYS: u32 = 2;
+
+    pub fn new(stock: LootTableId) -> Self {
+        Self {
+            stock,
+            restocked_day: None,
+        }
+    }
+
+    pub fn needs_restock(&self, day: u32) -> bool {
+        self.restocked_day
+            .is_none_or(|restocked| day >= restocked + Self::RESTOCK_DAYS)
+    }
+}
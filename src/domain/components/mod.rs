@@ -8,37 +8,51 @@ pub mod collider_cache;
 pub mod condition_blink;
 pub mod conditions;
 pub mod consumable;
+pub mod corpse;
+pub mod crossing;
 pub mod default_melee_attack;
 pub mod default_ranged_attack;
 pub mod description;
 pub mod destructible;
+pub mod dialogue;
+pub mod doctor;
+pub mod door;
 pub mod enemy_type;
 pub mod energy;
 pub mod equipment;
 pub mod explosive;
 pub mod faction;
+pub mod fire;
+pub mod firing_pattern;
 pub mod fuse;
 pub mod health;
 pub mod hit_blink;
 pub mod hit_effect;
 pub mod in_active_zone;
+pub mod injury;
 pub mod inventory;
 pub mod label;
 pub mod level;
 pub mod lighting;
 pub mod loot_drop;
+pub mod merchant;
+pub mod quest;
 pub mod smooth_movement;
 pub mod stairs;
 pub mod stats;
+pub mod survival;
 pub mod throwable;
+pub mod tool;
+pub mod trap;
 pub mod vision;
 pub mod weapon;
+pub mod weapon_attachment;
 pub mod weapon_family;
 pub mod weapon_modifiers;
 pub mod weapon_type;
 pub mod zone_tracking;
 
-pub use ai_controller::{AiController, AiState};
+pub use ai_controller::{AiController, AiState, Companion, CompanionOrder, Following, Scavenger};
 pub use attributes::{AttributePoints, Attributes};
 pub use bitmask::*;
 pub use bump_attack::BumpAttack;
@@ -47,20 +61,31 @@ pub use collider_cache::ColliderCache;
 pub use condition_blink::{ConditionBlink, ConditionBlinkData};
 pub use conditions::{ActiveConditions, Condition, ConditionSource, ConditionType};
 pub use consumable::{Consumable, ConsumableEffect};
+pub use corpse::{Corpse, CorpseStage};
+pub use crossing::{Bridge, Ferry};
 pub use default_melee_attack::DefaultMeleeAttack;
 pub use default_ranged_attack::DefaultRangedAttack;
 pub use description::Description;
 pub use destructible::{Destructible, MaterialType};
+pub use dialogue::{Dialogue, DialogueMemory};
+pub use doctor::Doctor;
+pub use door::{Door, Key, Lock};
 pub use enemy_type::CreatureType;
 pub use energy::Energy;
 pub use equipment::{EquipmentSlot, EquipmentSlots, EquipmentType, Equippable, Equipped};
 pub use explosive::ExplosiveProperties;
-pub use faction::{FactionId, FactionMember};
+pub use faction::{FactionId, FactionMember, Reputation};
+pub use fire::Fire;
+pub use firing_pattern::{
+    FAN_HAMMER_ACCURACY_PENALTY, FiringPattern, PENETRATION_DAMAGE_FALLOFF,
+    STRAY_SHOT_ACCURACY_PENALTY, cone_falloff, off_hand_accuracy_penalty, shot_line,
+};
 pub use fuse::Fuse;
 pub use health::Health;
 pub use hit_blink::HitBlink;
 pub use hit_effect::{HitEffect, KnockbackAnimation};
 pub use in_active_zone::InActiveZone;
+pub use injury::{BodyLocation, Injuries, InjuryType, SEVERE_HIT_DAMAGE};
 pub use inventory::{
     InInventory, Inventory, InventoryAccessible, Item, StackCount, Stackable, StackableType,
     UnopenedContainer,
@@ -69,12 +94,18 @@ pub use label::Label;
 pub use level::Level;
 pub use lighting::{IgnoreLighting, LightBlocker, LightSource, Lightable};
 pub use loot_drop::LootDrop;
+pub use merchant::Merchant;
+pub use quest::{Quest, QuestLog, QuestObjective, QuestStatus, QuestTarget};
 pub use smooth_movement::SmoothMovement;
 pub use stairs::{StairDown, StairUp};
 pub use stats::{AttributeGroup, ModifierSource, StatModifier, StatModifiers, StatType, Stats};
+pub use survival::{Bedroll, Campfire, Canteen, Food, NeedType, Needs, Sleeping};
 pub use throwable::Throwable;
+pub use tool::{Tool, ToolType};
+pub use trap::{Trap, TrapKind, TrapKit};
 pub use vision::{ApplyVisibilityEffects, HideWhenNotVisible, IsExplored, IsVisible, Vision};
 pub use weapon::Weapon;
+pub use weapon_attachment::{AttachmentSlot, AttachmentType, WeaponAttachment, WeaponAttachments};
 pub use weapon_family::WeaponFamily;
 pub use weapon_modifiers::{
     ItemRarity, WeaponModifier, WeaponModifierType, pick_legendary_name, pick_random_prefix,
@@ -0,0 +1,126 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::SerializableComponent;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuestStatus {
+    Active,
+    /// The job's done, it just needs reporting back to whoever gave it
+    ReadyToTurnIn,
+    Completed,
+    Failed,
+}
+
+/// What a quest asks of the player. Kill and retrieve targets are only put into the
+/// world once their zone is loaded, `spawned` keeps them from turning up twice.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum QuestObjective {
+    Kill {
+        target: String,
+        place: String,
+        zone_idx: usize,
+        spawned: bool,
+    },
+    Retrieve {
+        item: String,
+        place: String,
+        zone_idx: usize,
+        spawned: bool,
+    },
+    Escort {
+        npc: String,
+        town: String,
+        zone_idx: usize,
+    },
+    Deliver {
+        item: String,
+        town: String,
+        zone_idx: usize,
+    },
+}
+
+impl QuestObjective {
+    /// The zone the objective takes the player to
+    pub fn zone_idx(&self) -> usize {
+        match self {
+            QuestObjective::Kill { zone_idx, .. }
+            | QuestObjective::Retrieve { zone_idx, .. }
+            | QuestObjective::Escort { zone_idx, .. }
+            | QuestObjective::Deliver { zone_idx, .. } => *zone_idx,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            QuestObjective::Kill { target, place, .. } => {
+                format!("Kill {{Y|{}}} at {}", target, place)
+            }
+            QuestObjective::Retrieve { item, place, .. } => {
+                format!("Recover the {{Y|{}}} from {}", item, place)
+            }
+            QuestObjective::Escort { npc, town, .. } => {
+                format!("See the {{Y|{}}} safely to {}", npc, town)
+            }
+            QuestObjective::Deliver { item, town, .. } => {
+                format!("Deliver the {{Y|{}}} to {}", item, town)
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Quest {
+    pub id: String,
+    pub title: String,
+    /// Who handed the quest out, and who gets told when it's done
+    pub giver: String,
+    pub objective: QuestObjective,
+    pub status: QuestStatus,
+    /// Gold nuggets paid out on completion
+    pub reward: u32,
+}
+
+impl Quest {
+    pub fn is_in_progress(&self) -> bool {
+        matches!(
+            self.status,
+            QuestStatus::Active | QuestStatus::ReadyToTurnIn
+        )
+    }
+}
+
+/// Every quest the player has taken on, finished or not. Only one quest with a given id
+/// can be in progress at a time.
+#[derive(Component, Serialize, Deserialize, Clone, Default, SerializableComponent)]
+pub struct QuestLog {
+    pub quests: Vec<Quest>,
+}
+
+impl QuestLog {
+    pub fn in_progress(&self, quest_id: &str) -> Option<&Quest> {
+        self.quests
+            .iter()
+            .find(|q| q.id == quest_id && q.is_in_progress())
+    }
+
+    pub fn in_progress_mut(&mut self, quest_id: &str) -> Option<&mut Quest> {
+        self.quests
+            .iter_mut()
+            .find(|q| q.id == quest_id && q.is_in_progress())
+    }
+}
+
+/// Marks the outlaw, item or traveler a quest hinges on
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct QuestTarget {
+    pub quest_id: String,
+}
+
+impl QuestTarget {
+    pub fn new(quest_id: &str) -> Self {
+        Self {
+            quest_id: quest_id.to_string(),
+        }
+    }
+}
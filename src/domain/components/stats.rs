@@ -4,7 +4,7 @@ use crate::engine::SerializableComponent;
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::Attributes;
+use super::{Attributes, InjuryType};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AttributeGroup {
@@ -33,6 +33,7 @@ pub enum StatType {
     PoisonDamage, // bonus damage to poison effects
     BleedDamage,  // bonus damage to bleed effects
     BurnDamage,   // bonus damage to burn effects
+    Perception,   // spotting hidden traps
 }
 
 impl StatType {
@@ -54,6 +55,7 @@ impl StatType {
             StatType::PoisonDamage => 3,
             StatType::BleedDamage => 3,
             StatType::BurnDamage => 3,
+            StatType::Perception => attributes.intelligence as i32,
         }
     }
 
@@ -75,6 +77,7 @@ impl StatType {
             StatType::PoisonDamage,
             StatType::BleedDamage,
             StatType::BurnDamage,
+            StatType::Perception,
         ]
     }
 
@@ -96,6 +99,7 @@ impl StatType {
             StatType::PoisonDamage => "Toxicity",
             StatType::BleedDamage => "Laceration",
             StatType::BurnDamage => "Combustion",
+            StatType::Perception => "Perception",
         }
     }
 
@@ -117,6 +121,7 @@ impl StatType {
             StatType::PoisonDamage => "Bonus damage to poison effects per tick",
             StatType::BleedDamage => "Bonus damage to bleeding effects per tick",
             StatType::BurnDamage => "Bonus damage to burning effects per tick",
+            StatType::Perception => "Chance to spot hidden traps",
         }
     }
 
@@ -133,7 +138,7 @@ impl StatType {
             | StatType::Dodge
             | StatType::ReloadSpeed => AttributeGroup::Dexterity,
             StatType::Fortitude => AttributeGroup::Constitution,
-            StatType::ArmorRegen => AttributeGroup::Intelligence,
+            StatType::ArmorRegen | StatType::Perception => AttributeGroup::Intelligence,
             StatType::Armor
             | StatType::PoisonDamage
             | StatType::BleedDamage
@@ -191,6 +196,14 @@ impl StatModifiers {
         }
     }
 
+    pub fn remove_injury_modifiers(&mut self, injury_type: InjuryType) {
+        for modifiers in self.modifiers.values_mut() {
+            modifiers.retain(
+                |m| !matches!(m.source, ModifierSource::Injury { injury } if injury == injury_type),
+            );
+        }
+    }
+
     pub fn get_total_for_stat(&self, stat_type: StatType) -> i32 {
         self.modifiers
             .get(&stat_type)
@@ -226,6 +239,13 @@ impl StatModifier {
             source: ModifierSource::Condition { condition_id },
         }
     }
+
+    pub fn injury(value: i32, injury: InjuryType) -> Self {
+        Self {
+            value,
+            source: ModifierSource::Injury { injury },
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -233,4 +253,5 @@ pub enum ModifierSource {
     Equipment { item_id: u64 },
     Intrinsic { name: String },
     Condition { condition_id: String },
+    Injury { injury: InjuryType },
 }
@@ -0,0 +1,152 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::ConditionType,
+    engine::{SerializableComponent, TICKS_PER_MINUTE},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NeedType {
+    Hunger,
+    Thirst,
+    Fatigue,
+}
+
+impl NeedType {
+    pub fn all() -> &'static [NeedType] {
+        &[NeedType::Hunger, NeedType::Thirst, NeedType::Fatigue]
+    }
+
+    /// Minutes without before the need starts to bite, and before it gets serious
+    fn thresholds(&self) -> (u32, u32) {
+        match self {
+            NeedType::Hunger => (12 * 60, 36 * 60),
+            NeedType::Thirst => (8 * 60, 20 * 60),
+            NeedType::Fatigue => (18 * 60, 30 * 60),
+        }
+    }
+
+    /// The condition for each stage, mild then severe
+    pub fn conditions(&self) -> [ConditionType; 2] {
+        match self {
+            NeedType::Hunger => [ConditionType::Hungry, ConditionType::Starving],
+            NeedType::Thirst => [ConditionType::Thirsty, ConditionType::Parched],
+            NeedType::Fatigue => [ConditionType::Tired, ConditionType::Exhausted],
+        }
+    }
+}
+
+/// How long the player has gone without food, water and sleep, in ticks
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent, Default)]
+pub struct Needs {
+    pub hunger: u32,
+    pub thirst: u32,
+    pub fatigue: u32,
+}
+
+impl Needs {
+    /// Ticks of fatigue shaken off for every tick spent asleep
+    pub const REST_RATE: u32 = 3;
+
+    pub fn get(&self, need: NeedType) -> u32 {
+        match need {
+            NeedType::Hunger => self.hunger,
+            NeedType::Thirst => self.thirst,
+            NeedType::Fatigue => self.fatigue,
+        }
+    }
+
+    fn get_mut(&mut self, need: NeedType) -> &mut u32 {
+        match need {
+            NeedType::Hunger => &mut self.hunger,
+            NeedType::Thirst => &mut self.thirst,
+            NeedType::Fatigue => &mut self.fatigue,
+        }
+    }
+
+    /// Needs stop growing a while past the worst stage, so a good meal always helps
+    pub fn add(&mut self, need: NeedType, ticks: u32) {
+        let (_, severe) = need.thresholds();
+        let cap = severe * 2 * TICKS_PER_MINUTE;
+        let value = self.get_mut(need);
+        *value = value.saturating_add(ticks).min(cap);
+    }
+
+    pub fn satisfy(&mut self, need: NeedType, minutes: u32) {
+        let value = self.get_mut(need);
+        *value = value.saturating_sub(minutes * TICKS_PER_MINUTE);
+    }
+
+    pub fn rest(&mut self, ticks: u32) {
+        self.fatigue = self
+            .fatigue
+            .saturating_sub(ticks.saturating_mul(Self::REST_RATE));
+    }
+
+    /// Ticks of sleep it takes to shake off all fatigue
+    pub fn ticks_to_rest(&self) -> u32 {
+        self.fatigue.div_ceil(Self::REST_RATE)
+    }
+
+    pub fn condition(&self, need: NeedType) -> Option<ConditionType> {
+        let minutes = self.get(need) / TICKS_PER_MINUTE;
+        let (mild, severe) = need.thresholds();
+        let [mild_condition, severe_condition] = need.conditions();
+
+        if minutes >= severe {
+            Some(severe_condition)
+        } else if minutes >= mild {
+            Some(mild_condition)
+        } else {
+            None
+        }
+    }
+}
+
+/// Takes the edge off hunger when eaten
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Food {
+    pub minutes: u32,
+}
+
+impl Food {
+    pub fn new(minutes: u32) -> Self {
+        Self { minutes }
+    }
+}
+
+/// Holds a few swigs of water, topped up at any river
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Canteen {
+    pub swigs: u32,
+    pub capacity: u32,
+}
+
+impl Canteen {
+    /// Minutes of thirst each swig takes care of
+    pub const SWIG_MINUTES: u32 = 4 * 60;
+
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            swigs: capacity,
+            capacity,
+        }
+    }
+}
+
+/// Something to sleep on
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Bedroll;
+
+/// A fire worth sleeping by
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Campfire;
+
+/// Asleep until `until_tick`, unless something comes out of the dark at `ambush_tick`
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Sleeping {
+    pub started_tick: u32,
+    pub until_tick: u32,
+    pub ambush_tick: Option<u32>,
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::SerializableComponent;
+
+/// The kinds of tool a recipe can call for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToolType {
+    Hatchet,
+    Pickaxe,
+}
+
+impl fmt::Display for ToolType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToolType::Hatchet => write!(f, "Hatchet"),
+            ToolType::Pickaxe => write!(f, "Pickaxe"),
+        }
+    }
+}
+
+/// An item that's good for more than hitting things with, counted when crafting
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Tool(pub ToolType);
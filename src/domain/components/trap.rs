@@ -0,0 +1,120 @@
+use crate::{
+    common::Palette,
+    domain::{CreatureType, FactionId, StackableType},
+    engine::SerializableComponent,
+};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrapKind {
+    BearTrap,
+    Tripwire,
+    Snare,
+}
+
+impl TrapKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            TrapKind::BearTrap => "Bear Trap",
+            TrapKind::Tripwire => "Tripwire",
+            TrapKind::Snare => "Snare",
+        }
+    }
+
+    /// What to call it partway through a sentence
+    pub fn name(self) -> &'static str {
+        match self {
+            TrapKind::BearTrap => "bear trap",
+            TrapKind::Tripwire => "tripwire",
+            TrapKind::Snare => "snare",
+        }
+    }
+
+    pub fn glyph(self) -> (usize, Palette, Palette) {
+        match self {
+            TrapKind::BearTrap => (98, Palette::Gray, Palette::DarkGray),
+            TrapKind::Tripwire => (112, Palette::Red, Palette::Brown),
+            TrapKind::Snare => (115, Palette::Brown, Palette::DarkBrown),
+        }
+    }
+
+    /// The item it's carried around as before it's set
+    pub fn kit(self) -> StackableType {
+        match self {
+            TrapKind::BearTrap => StackableType::BearTrap,
+            TrapKind::Tripwire => StackableType::Tripwire,
+            TrapKind::Snare => StackableType::Snare,
+        }
+    }
+
+    /// Whether stepping in it springs it. A snare loop is only big enough for small game.
+    pub fn catches(self, creature: Option<CreatureType>) -> bool {
+        match self {
+            TrapKind::Snare => creature.is_some_and(|c| c.is_small_game()),
+            TrapKind::BearTrap | TrapKind::Tripwire => true,
+        }
+    }
+}
+
+/// Set on the ground and waiting for someone to step in it. Hidden from anyone whose
+/// faction hasn't spotted it.
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct Trap {
+    pub kind: TrapKind,
+    /// Factions that know it's there, and steer clear
+    pub known_by: Vec<FactionId>,
+}
+
+impl Trap {
+    pub fn new(kind: TrapKind, set_by: Option<FactionId>) -> Self {
+        Self {
+            kind,
+            known_by: set_by.into_iter().collect(),
+        }
+    }
+
+    pub fn is_known_by(&self, faction_id: FactionId) -> bool {
+        self.known_by.contains(&faction_id)
+    }
+
+    pub fn reveal_to(&mut self, faction_id: FactionId) {
+        if !self.is_known_by(faction_id) {
+            self.known_by.push(faction_id);
+        }
+    }
+}
+
+/// An unset trap, ready to be put down somewhere
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct TrapKit(pub TrapKind);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catches() {
+        let cases = [
+            (TrapKind::Snare, Some(CreatureType::Rat), true),
+            (TrapKind::Snare, Some(CreatureType::Coyote), true),
+            (TrapKind::Snare, Some(CreatureType::Bear), false),
+            (TrapKind::Snare, Some(CreatureType::Bandit), false),
+            (TrapKind::Snare, None, false),
+            (TrapKind::BearTrap, Some(CreatureType::Bear), true),
+            (TrapKind::BearTrap, None, true),
+            (TrapKind::Tripwire, Some(CreatureType::Rat), true),
+            (TrapKind::Tripwire, None, true),
+        ];
+
+        for (kind, creature, expected) in cases {
+            assert_eq!(
+                kind.catches(creature),
+                expected,
+                "{:?} on {:?}",
+                kind,
+                creature
+            );
+        }
+    }
+}
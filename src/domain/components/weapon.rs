@@ -32,6 +32,8 @@ pub struct Weapon {
     pub reload_audio: Option<AudioKey>,
     pub reload_complete_audio: Option<AudioKey>,
     pub no_ammo_audio: Option<AudioKey>,
+    #[serde(default)]
+    pub spread_degrees: Option<f32>,
 }
 
 impl Weapon {
@@ -60,6 +62,7 @@ impl Weapon {
             reload_audio: None,
             reload_complete_audio: None,
             no_ammo_audio: None,
+            spread_degrees: None,
         }
     }
 
@@ -95,6 +98,7 @@ impl Weapon {
             reload_audio,
             reload_complete_audio,
             no_ammo_audio,
+            spread_degrees: None,
         }
     }
 
@@ -194,6 +198,7 @@ impl Weapon {
             strength: 1.0,
             chance: 1.0,
         }];
+        shotgun.spread_degrees = Some(40.0);
         shotgun.particle_effect_id = Some(ParticleEffectId::default_shotgun());
         shotgun
     }
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{HitEffect, MaterialType, PrefabId, Weapon, WeaponFamily},
+    engine::SerializableComponent,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AttachmentSlot {
+    Optic,
+    Cylinder,
+    Muzzle,
+    Underbarrel,
+}
+
+impl AttachmentSlot {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AttachmentSlot::Optic => "Optic",
+            AttachmentSlot::Cylinder => "Cylinder",
+            AttachmentSlot::Muzzle => "Muzzle",
+            AttachmentSlot::Underbarrel => "Underbarrel",
+        }
+    }
+
+    /// Attachment slots a weapon of the given family provides
+    pub fn for_family(family: WeaponFamily) -> Vec<AttachmentSlot> {
+        match family {
+            WeaponFamily::Rifle => vec![AttachmentSlot::Optic, AttachmentSlot::Underbarrel],
+            WeaponFamily::Shotgun => vec![AttachmentSlot::Muzzle, AttachmentSlot::Underbarrel],
+            WeaponFamily::Pistol => vec![AttachmentSlot::Optic, AttachmentSlot::Cylinder],
+            WeaponFamily::Blade | WeaponFamily::Cudgel | WeaponFamily::Unarmed => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AttachmentType {
+    Scope,
+    ExtendedCylinder,
+    ChokeTube,
+    Bayonet,
+}
+
+impl AttachmentType {
+    pub fn slot(&self) -> AttachmentSlot {
+        match self {
+            AttachmentType::Scope => AttachmentSlot::Optic,
+            AttachmentType::ExtendedCylinder => AttachmentSlot::Cylinder,
+            AttachmentType::ChokeTube => AttachmentSlot::Muzzle,
+            AttachmentType::Bayonet => AttachmentSlot::Underbarrel,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AttachmentType::Scope => "Scope",
+            AttachmentType::ExtendedCylinder => "Extended Cylinder",
+            AttachmentType::ChokeTube => "Choke Tube",
+            AttachmentType::Bayonet => "Bayonet",
+        }
+    }
+
+    /// Short tag shown in the weapon's label while installed
+    pub fn label_tag(&self) -> &'static str {
+        match self {
+            AttachmentType::Scope => "Scoped",
+            AttachmentType::ExtendedCylinder => "Ext",
+            AttachmentType::ChokeTube => "Choked",
+            AttachmentType::Bayonet => "Bayonet",
+        }
+    }
+
+    pub fn prefab_id(&self) -> PrefabId {
+        match self {
+            AttachmentType::Scope => PrefabId::Scope,
+            AttachmentType::ExtendedCylinder => PrefabId::ExtendedCylinder,
+            AttachmentType::ChokeTube => PrefabId::ChokeTube,
+            AttachmentType::Bayonet => PrefabId::Bayonet,
+        }
+    }
+
+    pub fn range_bonus(&self) -> i32 {
+        match self {
+            AttachmentType::Scope => 4,
+            _ => 0,
+        }
+    }
+
+    pub fn clip_size_bonus(&self) -> usize {
+        match self {
+            AttachmentType::ExtendedCylinder => 2,
+            _ => 0,
+        }
+    }
+
+    /// Change to a shotgun's spread cone, in degrees
+    pub fn spread_modifier(&self) -> f32 {
+        match self {
+            AttachmentType::ChokeTube => -15.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Melee weapon used when bump attacking with a gun carrying this attachment
+    pub fn melee_weapon(&self) -> Option<Weapon> {
+        match self {
+            AttachmentType::Bayonet => {
+                let mut bayonet = Weapon::new_melee(
+                    "1d6".to_string(),
+                    vec![MaterialType::Flesh],
+                    WeaponFamily::Blade,
+                    "bayonets".to_string(),
+                    "bayonet".to_string(),
+                );
+                bayonet.hit_effects = vec![HitEffect::Bleeding {
+                    damage_per_tick: 1,
+                    duration_ticks: 400,
+                    chance: 0.4,
+                    can_stack: false,
+                }];
+                Some(bayonet)
+            }
+            _ => None,
+        }
+    }
+
+    /// One line summary of what the attachment does, for item details
+    pub fn effect_summary(&self) -> String {
+        match self {
+            AttachmentType::Scope => format!("+{} range", self.range_bonus()),
+            AttachmentType::ExtendedCylinder => format!("+{} clip size", self.clip_size_bonus()),
+            AttachmentType::ChokeTube => format!("{:.0}° spread", self.spread_modifier()),
+            AttachmentType::Bayonet => "Bleeding melee attacks".to_string(),
+        }
+    }
+}
+
+/// Marks an item as a gun attachment that can be installed on a weapon
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct WeaponAttachment {
+    pub attachment_type: AttachmentType,
+}
+
+impl WeaponAttachment {
+    pub fn new(attachment_type: AttachmentType) -> Self {
+        Self { attachment_type }
+    }
+}
+
+/// Attachment slots on a weapon entity and what is installed in them.
+/// Installed attachments are stored as data, the attachment item itself is
+/// consumed on install and respawned when it's removed.
+#[derive(Component, Serialize, Deserialize, Clone, SerializableComponent)]
+pub struct WeaponAttachments {
+    pub slots: HashMap<AttachmentSlot, Option<AttachmentType>>,
+}
+
+impl WeaponAttachments {
+    pub fn for_family(family: WeaponFamily) -> Self {
+        let slots = AttachmentSlot::for_family(family)
+            .into_iter()
+            .map(|slot| (slot, None))
+            .collect();
+
+        Self { slots }
+    }
+
+    pub fn has_slot(&self, slot: AttachmentSlot) -> bool {
+        self.slots.contains_key(&slot)
+    }
+
+    pub fn can_install(&self, attachment: AttachmentType) -> bool {
+        self.has_slot(attachment.slot())
+    }
+
+    /// Install an attachment, returning whatever was previously in the slot
+    pub fn install(&mut self, attachment: AttachmentType) -> Option<AttachmentType> {
+        self.slots
+            .insert(attachment.slot(), Some(attachment))
+            .flatten()
+    }
+
+    pub fn remove(&mut self, slot: AttachmentSlot) -> Option<AttachmentType> {
+        self.slots.get_mut(&slot).and_then(|a| a.take())
+    }
+
+    /// Slots the weapon provides in a stable display order
+    pub fn available_slots(&self) -> Vec<AttachmentSlot> {
+        let mut slots = self.slots.keys().copied().collect::<Vec<_>>();
+        slots.sort_by_key(|slot| *slot as u8);
+        slots
+    }
+
+    /// Installed attachments in a stable display order
+    pub fn installed(&self) -> Vec<AttachmentType> {
+        let mut installed = self.slots.values().flatten().copied().collect::<Vec<_>>();
+        installed.sort_by_key(|a| a.slot() as u8);
+        installed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.values().all(|a| a.is_none())
+    }
+
+    /// Melee weapon granted by an installed attachment, if any
+    pub fn melee_weapon(&self) -> Option<Weapon> {
+        self.installed().iter().find_map(|a| a.melee_weapon())
+    }
+}
+
+impl Weapon {
+    /// Apply an attachment's stat changes to this weapon
+    pub fn apply_attachment(&mut self, attachment: AttachmentType) {
+        if let Some(range) = self.range.as_mut() {
+            *range = (*range as i32 + attachment.range_bonus()).max(1) as usize;
+        }
+
+        if let Some(clip_size) = self.clip_size.as_mut() {
+            *clip_size += attachment.clip_size_bonus();
+        }
+
+        if let Some(spread) = self.spread_degrees.as_mut() {
+            *spread = (*spread + attachment.spread_modifier()).max(5.0);
+        }
+    }
+
+    /// Undo an attachment's stat changes. Ammo above the new clip size is lost.
+    pub fn revert_attachment(&mut self, attachment: AttachmentType) {
+        if let Some(range) = self.range.as_mut() {
+            *range = (*range as i32 - attachment.range_bonus()).max(1) as usize;
+        }
+
+        if let Some(clip_size) = self.clip_size.as_mut() {
+            *clip_size = clip_size
+                .saturating_sub(attachment.clip_size_bonus())
+                .max(1);
+
+            if let Some(ammo) = self.current_ammo.as_mut() {
+                *ammo = (*ammo).min(*clip_size);
+            }
+        }
+
+        if let Some(spread) = self.spread_degrees.as_mut() {
+            *spread = (*spread - attachment.spread_modifier()).max(5.0);
+        }
+    }
+}
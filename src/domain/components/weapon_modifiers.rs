@@ -438,6 +438,17 @@ impl ItemRarity {
         }
     }
 
+    /// How much more than a plain one of these a merchant will ask for
+    pub fn value_multiplier(&self) -> u32 {
+        match self {
+            ItemRarity::Common => 1,
+            ItemRarity::Uncommon => 2,
+            ItemRarity::Rare => 4,
+            ItemRarity::Epic => 8,
+            ItemRarity::Legendary => 15,
+        }
+    }
+
     /// Roll for a random rarity with weighted chances
     pub fn roll_random(rand: &mut Rand) -> Self {
         let roll = rand.random();
@@ -5,11 +5,14 @@ use serde::{Deserialize, Serialize};
 use crate::{
     cfg::{MAP_SIZE, ZONE_SIZE},
     domain::{
-        AttackAction, Collider, ColliderFlags, Energy, EquipmentSlots, GameSettings, Inventory,
-        InventoryAccessible, IsExplored, MoveAction, OpenContainerAction, ReloadAction, StairDown,
-        StairUp, ToggleLightAction, TurnState, WaitAction, Zone,
+        AttackAction, ButcherAction, Collider, ColliderFlags, Corpse, Dialogue, Doctor, Door,
+        Energy, EquipmentSlot, EquipmentSlots, FanHammerAction, GameSettings, Inventory,
+        InventoryAccessible, IsExplored, Lock, Merchant, MoveAction, OpenContainerAction,
+        OpenDialogueAction, OpenTradeAction, OrderAttackAction, PickLockAction, ReloadAction,
+        StairDown, StairUp, ToggleDoorAction, ToggleLightAction, TreatInjuriesAction, TurnState,
+        WaitAction, Weapon, Zone,
     },
-    engine::{InputRate, KeyInput, Mouse, SerializableComponent, StableId, Time},
+    engine::{InputRate, KeyInput, Mouse, SerializableComponent, StableId, StableIdRegistry, Time},
     rendering::{Glyph, Position, Text, world_to_zone_idx, world_to_zone_local},
 };
 use quadboy_macros::profiled_system;
@@ -76,11 +79,31 @@ pub struct StaticEntitySpawnedEvent {
 pub fn player_input(
     mut cmds: Commands,
     q_player: Query<(Entity, &Position, Option<&EquipmentSlots>, &StableId), With<Player>>,
-    q_colliders: Query<&Position, (With<Collider>, Without<Player>)>,
+    q_colliders: Query<&Collider, Without<Player>>,
     q_containers: Query<Entity, (With<Inventory>, With<InventoryAccessible>)>,
     q_stairs_down: Query<&Position, (With<StairDown>, Without<Player>)>,
     q_stairs_up: Query<&Position, (With<StairUp>, Without<Player>)>,
-    q_stable_id: Query<&StableId>,
+    (
+        q_stable_id,
+        q_doctors,
+        q_merchants,
+        q_speakers,
+        q_corpses,
+        q_doors,
+        q_locks,
+        q_weapons,
+        registry,
+    ): (
+        Query<&StableId>,
+        Query<(), With<Doctor>>,
+        Query<(), With<Merchant>>,
+        Query<(), With<Dialogue>>,
+        Query<(), With<Corpse>>,
+        Query<(), With<Door>>,
+        Query<(), With<Lock>>,
+        Query<&Weapon>,
+        Res<StableIdRegistry>,
+    ),
     keys: Res<KeyInput>,
     time: Res<Time>,
     mut input_rate: Local<InputRate>,
@@ -99,6 +122,20 @@ pub fn player_input(
     };
     let (x, y, z) = position.world();
 
+    if keys.is_pressed(KeyCode::H)
+        && turn_state.is_players_turn
+        && let Some(target_cycling) = target_cycling.as_ref()
+        && let Some(selected_entity) = target_cycling.current_selected_entity
+        && let Ok(target_stable_id) = q_stable_id.get(selected_entity)
+    {
+        // Set companions on the selected target
+        cmds.queue(OrderAttackAction {
+            player_entity,
+            target_stable_id: *target_stable_id,
+        });
+        return;
+    }
+
     if keys.is_pressed(KeyCode::F) && turn_state.is_players_turn {
         // Fire at selected target if one exists
         if let Some(target_cycling) = target_cycling
@@ -116,12 +153,31 @@ pub fn player_input(
             if has_ranged_weapon {
                 // Find the selected target and get its StableId
                 if let Some(target_stable_id) = q_stable_id.get(selected_entity).ok() {
+                    let can_fan_hammer = equipment_slots
+                        .and_then(|equipment| equipment.get_equipped_item(EquipmentSlot::MainHand))
+                        .and_then(|weapon_id| registry.get_entity(StableId(weapon_id)))
+                        .and_then(|weapon_entity| q_weapons.get(weapon_entity).ok())
+                        .is_some_and(|weapon| {
+                            weapon.can_fan_hammer() && weapon.current_ammo.unwrap_or(0) > 0
+                        });
+
+                    // Shift+F fans the hammer, emptying a revolver into the target. Anything
+                    // else, or an empty cylinder, just takes the one shot
+                    if keys.is_down(KeyCode::LeftShift) && can_fan_hammer {
+                        cmds.queue(FanHammerAction {
+                            attacker_stable_id: *player_stable_id,
+                            target_stable_id: *target_stable_id,
+                        });
+                        return;
+                    }
+
                     // Execute attack action (targeted attack, not bump)
                     cmds.queue(AttackAction {
                         attacker_stable_id: *player_stable_id,
                         weapon_stable_id: None, // Use equipped weapon
                         target_stable_id: *target_stable_id,
                         is_bump_attack: false,
+                        aimed_location: target_cycling.aimed_location,
                     });
                     return;
                 }
@@ -145,6 +201,35 @@ pub fn player_input(
                 }
             }
         }
+
+        // Otherwise swing a door beside us open or shut
+        if turn_state.is_players_turn
+            && let Some(door) = Zone::get_neighbors((x, y, z), &q_zone)
+                .into_iter()
+                .flatten()
+                .find(|entity| q_doors.contains(*entity))
+        {
+            cmds.queue(ToggleDoorAction {
+                actor: player_entity,
+                door,
+            });
+            return;
+        }
+    }
+
+    if keys.is_pressed(KeyCode::P) && turn_state.is_players_turn {
+        // Pick the lock on a door or chest beside us
+        if let Some(target) = Zone::get_neighbors((x, y, z), &q_zone)
+            .into_iter()
+            .flatten()
+            .find(|entity| q_locks.contains(*entity))
+        {
+            cmds.queue(PickLockAction {
+                actor: player_entity,
+                target,
+            });
+            return;
+        }
     }
 
     if keys.is_down(KeyCode::LeftShift) {
@@ -196,6 +281,24 @@ pub fn player_input(
         return;
     }
 
+    if keys.is_pressed(KeyCode::U) {
+        // Butcher a carcass underfoot, or failing that one right beside us
+        let mut nearby = vec![Zone::get_at((x, y, z), &q_zone)];
+        nearby.extend(Zone::get_neighbors((x, y, z), &q_zone));
+
+        if let Some(corpse) = nearby
+            .into_iter()
+            .flatten()
+            .find(|entity| q_corpses.contains(*entity))
+        {
+            cmds.queue(ButcherAction {
+                butcher: player_entity,
+                corpse,
+            });
+            return;
+        }
+    }
+
     let movement_keys_down = keys.is_down(KeyCode::A)
         || keys.is_down(KeyCode::D)
         || keys.is_down(KeyCode::W)
@@ -292,13 +395,44 @@ pub fn player_input(
                     if let Some(target_entity) =
                         find_collider_entity_at((new_x, new_y, new_z), &q_colliders, &q_zone)
                     {
-                        if let Ok(target_stable_id) = q_stable_id.get(target_entity) {
+                        if q_doors.contains(target_entity) {
+                            // Bumping a closed door opens it
+                            cmds.queue(ToggleDoorAction {
+                                actor: player_entity,
+                                door: target_entity,
+                            });
+                            movement_timer.0 = now;
+                        } else if let Ok(target_stable_id) = q_stable_id.get(target_entity)
+                            && q_doctors.contains(target_entity)
+                        {
+                            // Bumping a doctor asks them to patch you up
+                            cmds.queue(TreatInjuriesAction {
+                                patient_stable_id: *player_stable_id,
+                                doctor_stable_id: *target_stable_id,
+                            });
+                            movement_timer.0 = now;
+                        } else if q_merchants.contains(target_entity) {
+                            // Bumping a merchant steps up to their counter
+                            cmds.queue(OpenTradeAction {
+                                player_entity,
+                                merchant_entity: target_entity,
+                            });
+                            movement_timer.0 = now;
+                        } else if q_speakers.contains(target_entity) {
+                            // Bumping someone with something to say strikes up a conversation
+                            cmds.queue(OpenDialogueAction {
+                                player_entity,
+                                speaker_entity: target_entity,
+                            });
+                            movement_timer.0 = now;
+                        } else if let Ok(target_stable_id) = q_stable_id.get(target_entity) {
                             // Bump attack - try to attack what we bumped into (actors, trees, walls, etc.)
                             cmds.queue(AttackAction {
                                 attacker_stable_id: *player_stable_id,
                                 weapon_stable_id: None, // Use equipped weapon
                                 target_stable_id: *target_stable_id,
                                 is_bump_attack: true,
+                                aimed_location: None,
                             });
                             movement_timer.0 = now;
                         }
@@ -385,25 +519,32 @@ pub fn render_player_debug(
     );
 }
 
+/// Open doors keep their collider but don't block anything, so they don't count
+fn is_blocking_collider(colliders: &Query<&Collider, Without<Player>>, entity: Entity) -> bool {
+    colliders
+        .get(entity)
+        .is_ok_and(|collider| !collider.flags.is_empty())
+}
+
 fn find_collider_entity_at(
     world_pos: (usize, usize, usize),
-    colliders: &Query<&Position, (With<Collider>, Without<Player>)>,
+    colliders: &Query<&Collider, Without<Player>>,
     q_zones: &Query<&Zone>,
 ) -> Option<Entity> {
     Zone::get_at(world_pos, q_zones)
         .iter()
-        .find(|e| colliders.contains(**e))
+        .find(|e| is_blocking_collider(colliders, **e))
         .copied()
 }
 
 fn has_collider_at(
     world_pos: (usize, usize, usize),
-    colliders: &Query<&Position, (With<Collider>, Without<Player>)>,
+    colliders: &Query<&Collider, Without<Player>>,
     q_zones: &Query<&Zone>,
 ) -> bool {
     Zone::get_at(world_pos, q_zones)
         .iter()
-        .any(|e| colliders.contains(*e))
+        .any(|e| is_blocking_collider(colliders, *e))
 }
 
 fn has_actor_at(world_pos: (usize, usize, usize), q_zones: &Query<&Zone>) -> bool {
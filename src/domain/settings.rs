@@ -1,4 +1,7 @@
+use std::collections::HashSet;
+
 use crate::{
+    domain::Weather,
     engine::SerializedEntity,
     rendering::{CameraMode, CrtCurvature, Position},
 };
@@ -20,6 +23,7 @@ pub struct GameSettings {
     pub crt_vignette: bool,
     pub crt_chromatic_ab: bool,
     pub smooth_movement: bool,
+    pub survival_needs: bool,
 }
 
 impl Default for GameSettings {
@@ -38,6 +42,7 @@ impl Default for GameSettings {
             crt_vignette: true,
             crt_chromatic_ab: true,
             smooth_movement: true,
+            survival_needs: true,
         }
     }
 }
@@ -48,6 +53,10 @@ pub struct PlayerSaveData {
     pub entity: SerializedEntity,
     #[serde(default)]
     pub inventory_items: Vec<SerializedEntity>,
+    /// Companions and escorted travelers go wherever the player does, so they're saved
+    /// here rather than with a zone
+    #[serde(default)]
+    pub companions: Vec<SerializedEntity>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -56,15 +65,28 @@ pub struct GameSaveData {
     pub save_timestamp: f64,
     pub tick: u32,
     pub seed: u32,
+    #[serde(default)]
+    pub discovered_pois: HashSet<usize>,
+    #[serde(default)]
+    pub weather: Weather,
 }
 
 impl GameSaveData {
-    pub fn new(player: PlayerSaveData, save_timestamp: f64, tick: u32, seed: u32) -> Self {
+    pub fn new(
+        player: PlayerSaveData,
+        save_timestamp: f64,
+        tick: u32,
+        seed: u32,
+        discovered_pois: HashSet<usize>,
+        weather: Weather,
+    ) -> Self {
         Self {
             player,
             save_timestamp,
             tick,
             seed,
+            discovered_pois,
+            weather,
         }
     }
 }
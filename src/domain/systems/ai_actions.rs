@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy_ecs::prelude::*;
 use macroquad::prelude::trace;
@@ -10,11 +10,13 @@ use crate::{
         distance::Distance,
     },
     domain::{
-        AiContext, AiController, AttackAction, DefaultRangedAttack, Energy, EnergyActionType,
-        EquipmentSlot, EquipmentSlots, MoveAction, MovementCapabilities, StairDown, StairUp,
-        WaitAction, Weapon, WeaponType, Zone, actions::GameAction, get_base_energy_cost,
+        AiContext, AiController, AttackAction, Corpse, CreatureType, DefaultRangedAttack, Door,
+        Energy, EnergyActionType, EquipmentSlot, EquipmentSlots, FactionMember, Following, Lock,
+        MoveAction, MovementCapabilities, Scavenger, StairDown, StairUp, ToggleDoorAction, Trap,
+        WaitAction, Weapon, WeaponType, Zone, actions::GameAction,
+        components::ai_controller::AiTemplate, get_actor, get_base_energy_cost,
     },
-    engine::{StableId, StableIdRegistry},
+    engine::{StableId, StableIdRegistry, TICKS_PER_MINUTE},
     rendering::{Position, world_to_zone_idx, world_to_zone_local, zone_local_to_world, zone_xyz},
 };
 use macroquad::rand;
@@ -130,7 +132,12 @@ pub fn ai_try_navigate_to_stair(
 }
 
 pub fn ai_try_attacking_nearby(world: &mut World, entity: Entity, context: &mut AiContext) -> bool {
-    let Some(nearest) = context.nearest_hostile() else {
+    // Go for the target if it's in reach, even one it was set on that isn't hostile
+    let Some(nearest) = context
+        .target
+        .filter(|target| target.distance <= 1.75)
+        .or_else(|| context.nearest_hostile().copied())
+    else {
         return false;
     };
 
@@ -148,6 +155,7 @@ pub fn ai_try_attacking_nearby(world: &mut World, entity: Entity, context: &mut
         weapon_stable_id: None, // Use equipped weapon or default attack
         target_stable_id: nearest.stable_id,
         is_bump_attack: true,
+        aimed_location: None,
     };
 
     attack.try_apply(world)
@@ -211,6 +219,7 @@ pub fn ai_try_ranged_attack(world: &mut World, entity: Entity, context: &mut AiC
                                     weapon_stable_id: Some(StableId(weapon_id)),
                                     target_stable_id: target.stable_id,
                                     is_bump_attack: false,
+                                    aimed_location: None,
                                 };
 
                                 return attack.try_apply(world);
@@ -256,6 +265,7 @@ pub fn ai_try_ranged_attack(world: &mut World, entity: Entity, context: &mut AiC
                     weapon_stable_id: None, // No specific weapon, use default
                     target_stable_id: target.stable_id,
                     is_bump_attack: false,
+                    aimed_location: None,
                 };
 
                 return attack.try_apply(world);
@@ -266,20 +276,61 @@ pub fn ai_try_ranged_attack(world: &mut World, entity: Entity, context: &mut AiC
     false
 }
 
-pub fn ai_try_select_target(_world: &mut World, _entity: Entity, context: &mut AiContext) -> bool {
+pub fn ai_try_select_target(world: &mut World, entity: Entity, context: &mut AiContext) -> bool {
     if let Some(t) = context.target {
         context.target = Some(t);
         return true;
     }
 
-    let Some(nearest) = context.nearest_hostile() else {
+    // Peaceful folk don't go looking for a fight, however little they think of someone
+    if world
+        .get::<AiController>(entity)
+        .is_some_and(|ai| ai.template == AiTemplate::Peaceful)
+    {
+        return false;
+    }
+
+    let Some(nearest) = context.nearest_hostile().copied() else {
         return false;
     };
 
-    context.target = Some(*nearest);
+    // Companions only pick fights that come near whoever or whatever they're guarding
+    if let Some((post, leash)) = guard_post(world, entity) {
+        let distance = Distance::diagonal(
+            [post.0 as i32, post.1 as i32, post.2 as i32],
+            [
+                nearest.pos.0 as i32,
+                nearest.pos.1 as i32,
+                nearest.pos.2 as i32,
+            ],
+        );
+
+        if distance > leash as f32 {
+            return false;
+        }
+    }
+
+    context.target = Some(nearest);
     true
 }
 
+/// Where a companion stands guard, at its leader's side or the spot it was told to hold,
+/// along with how far from it it'll go to see off trouble
+fn guard_post(world: &mut World, entity: Entity) -> Option<((usize, usize, usize), usize)> {
+    let ai = world.get::<AiController>(entity)?;
+
+    if ai.template != AiTemplate::Companion {
+        return None;
+    }
+
+    let (home, leash) = (ai.home_position, ai.leash_range);
+
+    match world.get::<Following>(entity).map(|f| f.leader) {
+        Some(leader_id) => get_actor(world, entity, leader_id).map(|leader| (leader.pos, leash)),
+        None => Some((home, leash)),
+    }
+}
+
 pub fn ai_try_move_toward_target(
     world: &mut World,
     entity: Entity,
@@ -292,6 +343,151 @@ pub fn ai_try_move_toward_target(
     ai_try_move_toward(world, entity, target.pos)
 }
 
+/// Followers close the distance to their leader, and otherwise wait on them
+pub fn ai_try_follow_leader(world: &mut World, entity: Entity) -> bool {
+    let Some(leader_id) = world.get::<Following>(entity).map(|f| f.leader) else {
+        return false;
+    };
+
+    let Some(leader) = get_actor(world, entity, leader_id) else {
+        return false;
+    };
+
+    if leader.distance > Following::DISTANCE && ai_try_move_toward(world, entity, leader.pos) {
+        return true;
+    }
+
+    ai_try_wait(world, entity)
+}
+
+/// Companions told to wait make their way back to their post if anything drew them off
+/// it, and otherwise stay put
+pub fn ai_try_hold_post(world: &mut World, entity: Entity) -> bool {
+    if world.get::<Following>(entity).is_some() {
+        return false;
+    }
+
+    let Some(post) = world
+        .get::<AiController>(entity)
+        .filter(|ai| ai.template == AiTemplate::Companion)
+        .map(|ai| ai.home_position)
+    else {
+        return false;
+    };
+
+    let Some(pos) = world.get::<Position>(entity).map(|p| p.world()) else {
+        return false;
+    };
+
+    if pos != post && ai_try_move_toward(world, entity, post) {
+        return true;
+    }
+
+    ai_try_wait(world, entity)
+}
+
+/// How much faster a carcass rots each turn a scavenger spends tearing at it
+const SCAVENGE_TICKS: u32 = 60 * TICKS_PER_MINUTE;
+
+/// Scavengers with nothing better to do follow their nose to the nearest carcass
+/// and feed on it until there's only bones left
+pub fn ai_try_scavenge(world: &mut World, entity: Entity) -> bool {
+    if world.get::<Scavenger>(entity).is_none() {
+        return false;
+    }
+
+    let Some(smell_range) = world
+        .get::<AiController>(entity)
+        .map(|ai| (ai.detection_range * 2) as f32)
+    else {
+        return false;
+    };
+
+    let Some(pos) = world.get::<Position>(entity).map(|p| p.world()) else {
+        return false;
+    };
+    let zone_idx = world_to_zone_idx(pos.0, pos.1, pos.2);
+
+    let nearest = world
+        .query::<(Entity, &Corpse, &Position)>()
+        .iter(world)
+        .filter(|(_, corpse, corpse_pos)| corpse.is_edible() && corpse_pos.zone_idx() == zone_idx)
+        .map(|(corpse_entity, _, corpse_pos)| {
+            let corpse_pos = corpse_pos.world();
+            let distance = Distance::diagonal(
+                [pos.0 as i32, pos.1 as i32, pos.2 as i32],
+                [
+                    corpse_pos.0 as i32,
+                    corpse_pos.1 as i32,
+                    corpse_pos.2 as i32,
+                ],
+            );
+            (corpse_entity, corpse_pos, distance)
+        })
+        .filter(|(_, _, distance)| *distance <= smell_range)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    let Some((corpse_entity, corpse_pos, distance)) = nearest else {
+        return false;
+    };
+
+    if distance <= 1.5 {
+        if let Some(mut corpse) = world.get_mut::<Corpse>(corpse_entity) {
+            corpse.age(SCAVENGE_TICKS);
+        }
+
+        return ai_try_wait(world, entity);
+    }
+
+    ai_try_move_toward(world, entity, corpse_pos)
+}
+
+/// Closed, unlocked doors on the given level that the entity has the hands to open,
+/// by world position
+fn closed_doors_it_can_open(
+    world: &mut World,
+    entity: Entity,
+    z: usize,
+) -> HashMap<(i32, i32), Entity> {
+    let can_open_doors = world
+        .get::<CreatureType>(entity)
+        .is_some_and(|creature| creature.can_open_doors());
+
+    if !can_open_doors {
+        return HashMap::new();
+    }
+
+    world
+        .query_filtered::<(Entity, &Door, &Position), Without<Lock>>()
+        .iter(world)
+        .filter(|(_, door, position)| !door.is_open && position.world().2 == z)
+        .map(|(door_entity, _, position)| {
+            let pos = position.world();
+            ((pos.0 as i32, pos.1 as i32), door_entity)
+        })
+        .collect()
+}
+
+/// Traps on the given level that the entity's faction knows are there, by world position
+fn known_traps(world: &mut World, entity: Entity, z: usize) -> HashSet<(i32, i32)> {
+    let Some(faction_id) = world
+        .get::<FactionMember>(entity)
+        .map(|member| member.faction_id)
+    else {
+        return HashSet::new();
+    };
+
+    world
+        .query::<(&Trap, &Position)>()
+        .iter(world)
+        .filter(|(trap, position)| trap.is_known_by(faction_id) && position.world().2 == z)
+        .map(|(_, position)| {
+            let pos = position.world();
+            (pos.0 as i32, pos.1 as i32)
+        })
+        .collect()
+}
+
 pub fn ai_try_move_toward(
     world: &mut World,
     entity: Entity,
@@ -315,6 +511,9 @@ pub fn ai_try_move_toward(
         target_pos.2 as i32,
     );
 
+    let doors = closed_doors_it_can_open(world, entity, start_z as usize);
+    let traps = known_traps(world, entity, start_z as usize);
+
     // Build zone cache first
     for zone in world.query::<&Zone>().iter(world) {
         zone_cache.insert(zone.idx, zone);
@@ -380,8 +579,13 @@ pub fn ai_try_move_toward(
             // Get cached collider flags at target position
             let collider_flags = zone.colliders.get_flags(local.0, local.1);
 
-            // Check if movement is blocked
-            if movement_flags.is_blocked_by(collider_flags) {
+            // Check if movement is blocked, a door it can open doesn't count
+            if movement_flags.is_blocked_by(collider_flags) && !doors.contains_key(&(to_x, to_y)) {
+                return f32::INFINITY;
+            }
+
+            // Walk around traps it knows about, unless that's where it's headed
+            if traps.contains(&(to_x, to_y)) && (to_x, to_y) != (target_x, target_y) {
                 return f32::INFINITY;
             }
 
@@ -407,6 +611,15 @@ pub fn ai_try_move_toward(
         return false;
     };
 
+    // A closed door in the way gets opened, and walked through next turn
+    if let Some(&door) = doors.get(move_to_target) {
+        return ToggleDoorAction {
+            actor: entity,
+            door,
+        }
+        .try_apply(world);
+    }
+
     let action = MoveAction {
         entity,
         new_position: (
@@ -672,3 +885,62 @@ pub fn ai_try_wander(world: &mut World, entity: Entity) -> bool {
 pub fn ai_try_wait(world: &mut World, entity: Entity) -> bool {
     WaitAction { entity }.try_apply(world)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Actor;
+
+    fn hostile(entity: Entity) -> Actor {
+        Actor {
+            entity,
+            stable_id: StableId(1),
+            pos: (12, 10, 0),
+            distance: 2.0,
+            relationship: -100,
+        }
+    }
+
+    #[test]
+    fn test_peaceful_ignores_hostiles() {
+        let mut world = World::new();
+        let other = world.spawn_empty().id();
+        let peaceful = world
+            .spawn(AiController::new(AiTemplate::Peaceful, (10, 10, 0)))
+            .id();
+        let aggressive = world
+            .spawn(AiController::new(AiTemplate::BasicAggressive, (10, 10, 0)))
+            .id();
+
+        // However badly the town's standing drops, nobody is picked out as a target
+        let mut context = AiContext {
+            detected: vec![hostile(other)],
+            target: None,
+        };
+        assert!(!ai_try_select_target(&mut world, peaceful, &mut context));
+        assert!(context.target.is_none());
+
+        let mut context = AiContext {
+            detected: vec![hostile(other)],
+            target: None,
+        };
+        assert!(ai_try_select_target(&mut world, aggressive, &mut context));
+        assert!(context.target.is_some());
+    }
+
+    #[test]
+    fn test_peaceful_fights_back() {
+        let mut world = World::new();
+        let attacker = world.spawn_empty().id();
+        let peaceful = world
+            .spawn(AiController::new(AiTemplate::Peaceful, (10, 10, 0)))
+            .id();
+
+        // Whoever landed the last hit is already the target
+        let mut context = AiContext {
+            detected: vec![],
+            target: Some(hostile(attacker)),
+        };
+        assert!(ai_try_select_target(&mut world, peaceful, &mut context));
+    }
+}
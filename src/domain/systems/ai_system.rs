@@ -5,11 +5,14 @@ use quadboy_macros::profiled_system;
 use crate::{
     common::Rand,
     domain::{
-        Actor, AiController, Energy, EnergyActionType, Health, TurnState, ai_try_attacking_nearby,
-        ai_try_move_toward_target, ai_try_ranged_attack, ai_try_select_target, ai_try_wait,
-        ai_try_wander, detect_actors, get_actor, get_base_energy_cost, try_handle_conditions,
+        Actor, AiController, ColliderFlags, Companion, Energy, EnergyActionType, Following, Health,
+        Player, PlayerMovedEvent, TurnState, Zone, ai_try_attacking_nearby, ai_try_follow_leader,
+        ai_try_hold_post, ai_try_move_toward_target, ai_try_ranged_attack, ai_try_scavenge,
+        ai_try_select_target, ai_try_wait, ai_try_wander, detect_actors, get_actor,
+        get_base_energy_cost, try_handle_conditions,
     },
-    rendering::{Position, spawn_alert_indicator},
+    engine::StableId,
+    rendering::{Position, spawn_alert_indicator, world_to_zone_idx, world_to_zone_local},
 };
 
 #[derive(Resource, Default)]
@@ -176,7 +179,22 @@ pub fn ai_turn(world: &mut World) {
         ai_try_wait(world, current_entity);
         return;
     } else {
-        // No target - try to wander (30% chance) or wait (70% chance)
+        // No target - keep up with whoever we're following
+        if ai_try_follow_leader(world, current_entity) {
+            return;
+        }
+
+        // Companions told to wait keep to their post
+        if ai_try_hold_post(world, current_entity) {
+            return;
+        }
+
+        // Scavengers go looking for something dead to eat
+        if ai_try_scavenge(world, current_entity) {
+            return;
+        }
+
+        // Otherwise try to wander (30% chance) or wait (70% chance)
         let Some(mut rand) = world.get_resource_mut::<Rand>() else {
             // If no random resource, just wait
             ai_try_wait(world, current_entity);
@@ -195,6 +213,72 @@ pub fn ai_turn(world: &mut World) {
     ai_try_wait(world, current_entity);
 }
 
+/// Followers left behind when the player crosses into another zone or takes the stairs
+/// catch up at their side, rather than being stranded in a zone that's gone dormant
+#[profiled_system]
+pub fn followers_keep_up(
+    mut e_player_moved: EventReader<PlayerMovedEvent>,
+    q_player: Query<&StableId, With<Player>>,
+    mut q_followers: Query<(&Following, &mut Position), Without<Player>>,
+    q_zones: Query<&Zone>,
+) {
+    let Some(moved) = e_player_moved.read().last() else {
+        return;
+    };
+
+    let Ok(player_id) = q_player.single() else {
+        return;
+    };
+
+    let player_zone_idx = world_to_zone_idx(moved.x, moved.y, moved.z);
+    let Some(zone) = q_zones.iter().find(|z| z.idx == player_zone_idx) else {
+        return;
+    };
+
+    let mut taken = vec![];
+
+    for (following, mut position) in q_followers.iter_mut() {
+        if following.leader != *player_id || position.zone_idx() == player_zone_idx {
+            continue;
+        }
+
+        let spot = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(|(dx, dy)| {
+            let x = moved.x.checked_add_signed(dx)?;
+            let y = moved.y.checked_add_signed(dy)?;
+            Some((x, y))
+        })
+        .filter(|&(x, y)| world_to_zone_idx(x, y, moved.z) == player_zone_idx)
+        .filter(|pos| !taken.contains(pos))
+        .find(|&(x, y)| {
+            let (local_x, local_y) = world_to_zone_local(x, y);
+            !zone
+                .colliders
+                .get_flags(local_x, local_y)
+                .contains(ColliderFlags::BLOCKS_WALK)
+        });
+
+        let Some((x, y)) = spot else {
+            continue;
+        };
+
+        taken.push((x, y));
+        position.x = x as f32;
+        position.y = y as f32;
+        position.z = moved.z as f32;
+    }
+}
+
 pub fn build_ai_context(world: &mut World, entity: Entity) -> AiContext {
     let detected = detect_actors(world, entity);
     let Some(ai_controller) = world.get::<AiController>(entity) else {
@@ -213,7 +297,13 @@ pub fn build_ai_context(world: &mut World, entity: Entity) -> AiContext {
             if let Some(attacker_id) = health.last_damage_source {
                 // Try to target the attacker, even if they're not in detection range
                 if let Some(attacker_actor) = get_actor(world, entity, attacker_id) {
-                    target = Some(attacker_actor);
+                    // Companions put a stray shot from the player down to bad luck
+                    let friendly_fire = world.get::<Companion>(entity).is_some()
+                        && world.get::<Player>(attacker_actor.entity).is_some();
+
+                    if !friendly_fire {
+                        target = Some(attacker_actor);
+                    }
                 }
             }
         }
@@ -1,7 +1,7 @@
 use super::destruction_system::EntityDestroyedEvent;
 use crate::{
     common::Rand,
-    domain::{ActiveConditions, Destructible, Player, Zone},
+    domain::{ActiveConditions, Destructible, Player, RecalculateColliderFlagsEvent, Zone},
     engine::{Audio, Clock},
     states::{CurrentGameState, GameState},
 };
@@ -15,6 +15,7 @@ pub fn on_entity_destroyed_cleanup(
     q_player: Query<&Player>,
     q_conditions: Query<&ActiveConditions>,
     mut q_zones: Query<&mut Zone>,
+    mut e_recalc_colliders: EventWriter<RecalculateColliderFlagsEvent>,
     audio_registry: Option<Res<Audio>>,
     mut rand: Option<ResMut<Rand>>,
     mut cmds: Commands,
@@ -32,7 +33,15 @@ pub fn on_entity_destroyed_cleanup(
 
         for mut z in q_zones.iter_mut() {
             let _ = z.entities.remove(&event.entity);
-            let _ = z.colliders.remove(&event.entity);
+
+            // Whatever it was blocking opens up once it's gone
+            if let Some((x, y)) = z.colliders.remove(&event.entity) {
+                e_recalc_colliders.write(RecalculateColliderFlagsEvent {
+                    zone_idx: z.idx,
+                    x,
+                    y,
+                });
+            }
         }
 
         // Play destruction audio if the entity has a destructible component
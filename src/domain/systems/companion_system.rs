@@ -0,0 +1,199 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    domain::{
+        AiController, Companion, CompanionOrder, Dialogue, FactionId, FactionMember, Following,
+        Label, Player, components::ai_controller::AiTemplate, log_message,
+    },
+    engine::StableId,
+    rendering::Position,
+};
+
+/// How far from whoever they're guarding a companion will go to pick a fight
+const COMPANION_LEASH_RANGE: usize = 6;
+const COMPANION_DETECTION_RANGE: usize = 8;
+
+fn name_of(world: &World, entity: Entity) -> String {
+    world
+        .get::<Label>(entity)
+        .map(|label| label.get_base().to_string())
+        .unwrap_or_else(|| "companion".to_string())
+}
+
+/// What a companion has been told to do, going by whether it's following anyone
+pub fn companion_order(world: &World, companion: Entity) -> Option<CompanionOrder> {
+    world.get::<Companion>(companion)?;
+
+    if world.get::<Following>(companion).is_some() {
+        Some(CompanionOrder::Follow)
+    } else {
+        Some(CompanionOrder::Wait)
+    }
+}
+
+/// Everyone travelling with the player: their companions, waiting or not, and anyone
+/// following them like a traveler on an escort. They're saved along with the player
+/// rather than with whatever zone they happen to be standing in.
+pub fn player_party(world: &mut World) -> Vec<Entity> {
+    world
+        .query_filtered::<Entity, Or<(With<Companion>, With<Following>)>>()
+        .iter(world)
+        .collect()
+}
+
+/// Takes someone on as a companion. They join the player's side, fall in behind them,
+/// and take orders through the given conversation from then on.
+pub fn recruit_companion(world: &mut World, player: Entity, companion: Entity, orders: &str) {
+    let Some(player_id) = world.get::<StableId>(player).copied() else {
+        return;
+    };
+    let Some(pos) = world.get::<Position>(companion).map(|p| p.world()) else {
+        return;
+    };
+
+    world.entity_mut(companion).insert((
+        Companion,
+        Following::new(player_id),
+        FactionMember::new(FactionId::Player),
+        Dialogue::new(orders),
+        AiController::new(AiTemplate::Companion, pos).with_ranges(
+            COMPANION_LEASH_RANGE,
+            0,
+            COMPANION_DETECTION_RANGE,
+        ),
+    ));
+
+    let message = format!(
+        "The {{Y|{}}} joins you. ({{Y|H}}) sets your companions on a target",
+        name_of(world, companion)
+    );
+    log_message(world, message);
+}
+
+/// Tells a companion to come along, or to hold the spot it's standing on
+pub fn order_companion(
+    world: &mut World,
+    player: Entity,
+    companion: Entity,
+    order: CompanionOrder,
+) {
+    if world.get::<Companion>(companion).is_none() {
+        return;
+    }
+
+    match order {
+        CompanionOrder::Follow => {
+            let Some(player_id) = world.get::<StableId>(player).copied() else {
+                return;
+            };
+
+            world
+                .entity_mut(companion)
+                .insert(Following::new(player_id));
+
+            // Leave whatever it was fighting and come along
+            if let Some(mut ai) = world.get_mut::<AiController>(companion) {
+                ai.current_target_id = None;
+            }
+        }
+        CompanionOrder::Wait => {
+            let pos = world.get::<Position>(companion).map(|p| p.world());
+
+            world.entity_mut(companion).remove::<Following>();
+
+            if let (Some(pos), Some(mut ai)) = (pos, world.get_mut::<AiController>(companion)) {
+                ai.home_position = pos;
+            }
+        }
+    }
+}
+
+/// A companion left waiting in a zone the player has gone far from gives up and comes
+/// after them, rather than being left behind for good
+pub fn recall_companion(world: &mut World, companion: Entity) {
+    let player = world
+        .query_filtered::<Entity, With<Player>>()
+        .iter(world)
+        .next();
+
+    let Some(player) = player else {
+        return;
+    };
+
+    if companion_order(world, companion) != Some(CompanionOrder::Wait) {
+        return;
+    }
+
+    order_companion(world, player, companion, CompanionOrder::Follow);
+
+    let message = format!(
+        "The {{Y|{}}} gives up waiting and comes looking for you",
+        name_of(world, companion)
+    );
+    log_message(world, message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::QuestTarget,
+        engine::{SerializableComponentRegistry, SerializedEntity, deserialize, serialize},
+    };
+
+    fn world_with_registry() -> World {
+        let mut reg = SerializableComponentRegistry::new();
+        reg.register::<Companion>();
+        reg.register::<Following>();
+        reg.register::<QuestTarget>();
+
+        let mut world = World::new();
+        world.insert_resource(reg);
+        world
+    }
+
+    #[test]
+    fn test_escort_saved_with_player() {
+        let mut world = world_with_registry();
+        let player_id = StableId(1);
+
+        world.spawn((Player, player_id));
+        let escort = world
+            .spawn((
+                Following::new(player_id),
+                QuestTarget::new("townsfolk_escort"),
+            ))
+            .id();
+        let waiting = world.spawn(Companion).id();
+        let bystander = world.spawn(QuestTarget::new("townsfolk_bounty")).id();
+
+        let party = player_party(&mut world);
+
+        assert!(party.contains(&escort));
+        assert!(party.contains(&waiting));
+        assert!(!party.contains(&bystander));
+        assert_eq!(party.len(), 2);
+
+        // Through the same JSON the save file holds, and back into a fresh world
+        let saved = party
+            .into_iter()
+            .map(|entity| serialize(entity, &world))
+            .collect::<Vec<_>>();
+        let json = serde_json::to_string(&saved).unwrap();
+        let loaded = serde_json::from_str::<Vec<SerializedEntity>>(&json).unwrap();
+
+        let mut world = world_with_registry();
+        for data in loaded {
+            deserialize(data, &mut world);
+        }
+
+        let escorts = world
+            .query::<(&Following, &QuestTarget)>()
+            .iter(&world)
+            .map(|(following, target)| (following.leader, target.quest_id.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(escorts, vec![(player_id, "townsfolk_escort".to_string())]);
+        assert_eq!(player_party(&mut world).len(), 2);
+    }
+}
@@ -2,8 +2,8 @@ use bevy_ecs::prelude::*;
 
 use crate::{
     domain::{
-        ActiveConditions, Condition, ConditionType, Health, Level, PlayerPosition, StatModifiers,
-        Stats,
+        ActiveConditions, Condition, ConditionType, Destructible, Health, Level, MaterialType,
+        PlayerPosition, StatModifiers, Stats, systems::destruction_system::EntityDestroyedEvent,
     },
     engine::Clock,
     rendering::{Position, world_to_zone_idx},
@@ -115,6 +115,61 @@ pub fn process_conditions(
     }
 }
 
+/// Objects have no health, so fire eats into the durability of anything wooden instead
+pub fn process_burning_objects(
+    mut q_objects: Query<
+        (Entity, &mut ActiveConditions, &mut Destructible, &Position),
+        Without<Health>,
+    >,
+    clock: Res<Clock>,
+    mut e_entity_destroyed: EventWriter<EntityDestroyedEvent>,
+    mut cmds: Commands,
+) {
+    let tick_delta = clock.tick_delta();
+
+    for (entity, mut conditions, mut destructible, position) in q_objects.iter_mut() {
+        if destructible.is_destroyed() {
+            continue;
+        }
+
+        let mut conditions_to_remove = vec![];
+
+        for (index, condition) in conditions.conditions.iter_mut().enumerate() {
+            if condition.tick(tick_delta) {
+                conditions_to_remove.push(index);
+                continue;
+            }
+
+            if let ConditionType::Burning { damage_per_tick } = condition.condition_type
+                && destructible.material_type == MaterialType::Wood
+            {
+                condition.accumulated_effect += tick_delta as f32;
+                if condition.accumulated_effect >= 80.0 {
+                    let damage_cycles = (condition.accumulated_effect / 80.0) as i32;
+                    destructible.take_damage(damage_cycles * damage_per_tick);
+                    condition.accumulated_effect -= (damage_cycles as f32) * 80.0;
+                }
+            }
+        }
+
+        for &index in conditions_to_remove.iter().rev() {
+            let condition = conditions.conditions.remove(index);
+
+            if let Some(spawner_entity) = condition.particle_spawner_entity {
+                cmds.entity(spawner_entity).despawn();
+            }
+        }
+
+        if destructible.is_destroyed() {
+            e_entity_destroyed.write(EntityDestroyedEvent::environmental(
+                entity,
+                position.world(),
+                Some(destructible.material_type),
+            ));
+        }
+    }
+}
+
 // Helper function to apply a condition to an entity
 pub fn apply_condition_to_entity(
     entity: Entity,
@@ -123,8 +178,24 @@ pub fn apply_condition_to_entity(
 ) -> Result<(), String> {
     // Get or create ActiveConditions component
     if let Some(mut conditions) = world.get_mut::<ActiveConditions>(entity) {
+        let is_burning = matches!(condition.condition_type, ConditionType::Burning { .. });
+
+        if is_burning && conditions.has_condition(&ConditionType::Wet) {
+            return Err("too wet to burn".to_string());
+        }
+
         // Check for stacking logic
         let mut spawners_to_cleanup = Vec::new();
+
+        // Getting soaked puts out any fire
+        if condition.condition_type == ConditionType::Wet {
+            for extinguished in conditions.extinguish() {
+                if let Some(spawner_entity) = extinguished.particle_spawner_entity {
+                    spawners_to_cleanup.push(spawner_entity);
+                }
+            }
+        }
+
         if !condition.condition_type.can_stack() {
             // Remove existing condition of this type and collect spawner entities to cleanup
             let removed_conditions = conditions.remove_condition(&condition.condition_type);
@@ -0,0 +1,108 @@
+use bevy_ecs::prelude::*;
+use quadboy_macros::profiled_system;
+
+use crate::{
+    domain::{
+        Corpse, CreatureType, Description, Label, Prefab, PrefabId, Prefabs, SpawnValue,
+        systems::destruction_system::{DestructionCause, EntityDestroyedEvent},
+    },
+    engine::Clock,
+    rendering::{Glyph, Position},
+};
+
+/// A label with its colour markup taken out, so a dead rattler stops shimmering
+fn plain_label(label: &str) -> String {
+    let mut plain = String::new();
+    let mut chars = label.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                for c in chars.by_ref() {
+                    if c == '|' {
+                        break;
+                    }
+                }
+            }
+            '}' => {}
+            _ => plain.push(c),
+        }
+    }
+
+    plain
+}
+
+/// Dead creatures leave a carcass behind where they fell
+#[profiled_system]
+pub fn on_creature_death_leave_corpse(
+    mut e_destroyed: EventReader<EntityDestroyedEvent>,
+    q_creatures: Query<(&CreatureType, Option<&Label>)>,
+    mut cmds: Commands,
+) {
+    for event in e_destroyed.read() {
+        if matches!(
+            event.cause,
+            DestructionCause::Scripted | DestructionCause::Consumed
+        ) {
+            continue;
+        }
+
+        let Ok((creature, label)) = q_creatures.get(event.entity) else {
+            continue;
+        };
+
+        let name = label
+            .map(|l| plain_label(l.get_base()))
+            .unwrap_or_else(|| format!("{:?}", creature));
+
+        let config = Prefab::new(PrefabId::Corpse, event.position)
+            .with_metadata("creature".to_owned(), SpawnValue::CreatureType(*creature))
+            .with_metadata("name".to_owned(), SpawnValue::String(name));
+
+        Prefabs::spawn(&mut cmds, config);
+    }
+}
+
+/// Rots corpses down to bones as the clock runs, and clears the bones away in the end
+#[profiled_system]
+pub fn decay_corpses(
+    mut q_corpses: Query<(
+        Entity,
+        &mut Corpse,
+        &mut Glyph,
+        &mut Label,
+        &mut Description,
+        &Position,
+    )>,
+    clock: Res<Clock>,
+    mut e_destroyed: EventWriter<EntityDestroyedEvent>,
+) {
+    let now = clock.current_tick();
+
+    for (entity, mut corpse, mut glyph, mut label, mut description, position) in
+        q_corpses.iter_mut()
+    {
+        let Some(stage) = corpse.stage_at(now) else {
+            e_destroyed.write(EntityDestroyedEvent::new(
+                entity,
+                position.world(),
+                DestructionCause::Environmental,
+            ));
+            continue;
+        };
+
+        if stage == corpse.stage {
+            continue;
+        }
+
+        corpse.stage = stage;
+
+        let (idx, fg1, fg2) = stage.glyph();
+        glyph.idx = idx;
+        glyph.fg1 = Some(fg1.into());
+        glyph.fg2 = Some(fg2.into());
+
+        *label = Label::new(corpse.label());
+        *description = Description::new(stage.description());
+    }
+}
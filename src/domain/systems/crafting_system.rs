@@ -0,0 +1,165 @@
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    domain::{Campfire, Inventory, LightSource, StackableType, Tool, ToolType, count_stacked},
+    engine::{StableId, StableIdRegistry},
+    rendering::Position,
+};
+
+/// Every recipe in the game, bundled in as JSON
+const RECIPE_DATA: &str = include_str!("../../assets/recipes.json");
+
+/// Somewhere a recipe has to be made, rather than anywhere at all
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CraftingStation {
+    /// A lit campfire right beside the crafter
+    Campfire,
+}
+
+impl CraftingStation {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            CraftingStation::Campfire => "a lit campfire",
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct RecipeItem {
+    pub item: StackableType,
+    pub count: u32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Recipe {
+    pub id: String,
+    pub name: String,
+    pub ingredients: Vec<RecipeItem>,
+    /// Tools that have to be in the crafter's inventory, they aren't used up
+    #[serde(default)]
+    pub tools: Vec<ToolType>,
+    #[serde(default)]
+    pub station: Option<CraftingStation>,
+    pub output: RecipeItem,
+    /// Energy spent making it
+    pub energy: i32,
+}
+
+#[derive(Resource)]
+pub struct RecipeRegistry {
+    recipes: Vec<Recipe>,
+}
+
+impl RecipeRegistry {
+    pub fn new() -> Self {
+        let recipes =
+            serde_json::from_str(RECIPE_DATA).expect("Bundled recipe data should be valid");
+
+        Self { recipes }
+    }
+
+    pub fn all(&self) -> &[Recipe] {
+        &self.recipes
+    }
+
+    pub fn get(&self, recipe_id: &str) -> Option<&Recipe> {
+        self.recipes.iter().find(|recipe| recipe.id == recipe_id)
+    }
+}
+
+impl Default for RecipeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether the crafter is carrying a tool of the given type
+pub fn has_tool(world: &World, crafter: Entity, tool: ToolType) -> bool {
+    let (Some(inventory), Some(registry)) = (
+        world.get::<Inventory>(crafter),
+        world.get_resource::<StableIdRegistry>(),
+    ) else {
+        return false;
+    };
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| registry.get_entity(StableId(id)))
+        .any(|item| world.get::<Tool>(item).is_some_and(|t| t.0 == tool))
+}
+
+/// Whether the crafter is standing right beside the given station
+pub fn is_at_station(world: &mut World, crafter: Entity, station: CraftingStation) -> bool {
+    let Some(position) = world.get::<Position>(crafter) else {
+        return false;
+    };
+    let (x, y, z) = position.world();
+
+    match station {
+        CraftingStation::Campfire => world
+            .query_filtered::<(&Position, Option<&LightSource>), With<Campfire>>()
+            .iter(world)
+            .any(|(fire_pos, light)| {
+                let (fx, fy, fz) = fire_pos.world();
+                fz == z
+                    && fx.abs_diff(x) <= 1
+                    && fy.abs_diff(y) <= 1
+                    && light.is_some_and(|l| l.is_enabled)
+            }),
+    }
+}
+
+/// What's stopping the crafter from making a recipe, in the order they'd notice it
+pub fn missing_requirements(world: &mut World, crafter: Entity, recipe: &Recipe) -> Vec<String> {
+    let mut missing = vec![];
+
+    if let Some(station) = recipe.station
+        && !is_at_station(world, crafter, station)
+    {
+        missing.push(format!("needs {}", station.describe()));
+    }
+
+    for tool in recipe.tools.iter() {
+        if !has_tool(world, crafter, *tool) {
+            missing.push(format!("needs a {}", tool));
+        }
+    }
+
+    for ingredient in recipe.ingredients.iter() {
+        let have = count_stacked(world, crafter, ingredient.item);
+        if have < ingredient.count {
+            missing.push(format!(
+                "needs {} more {}",
+                ingredient.count - have,
+                ingredient.item.prefab_id()
+            ));
+        }
+    }
+
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recipes_are_valid() {
+        let registry = RecipeRegistry::new();
+
+        assert!(!registry.all().is_empty());
+
+        for (i, recipe) in registry.all().iter().enumerate() {
+            assert!(
+                registry.all()[..i].iter().all(|r| r.id != recipe.id),
+                "recipe {} is listed twice",
+                recipe.id
+            );
+            assert!(!recipe.ingredients.is_empty(), "{} is free", recipe.id);
+            assert!(recipe.output.count > 0, "{} makes nothing", recipe.id);
+            assert!(recipe.energy > 0, "{} takes no time", recipe.id);
+        }
+    }
+}
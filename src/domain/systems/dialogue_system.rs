@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+
+use crate::domain::{
+    Attributes, CompanionOrder, CompleteQuestAction, DialogueMemory, FactionId, QuestLog,
+    QuestStatus, Reputation, StackableType, actions::GameAction, companion_order, count_stacked,
+    get_effective_relationship, give_or_drop_stacked, log_message, order_companion,
+    recruit_companion, start_quest, take_stacked,
+};
+
+/// Standing below which folks won't give you the time of day
+pub const REFUSE_TALK_RELATIONSHIP: i8 = -25;
+
+/// Every conversation in the game, bundled in as JSON
+const DIALOGUE_DATA: [&str; 7] = [
+    include_str!("../../assets/dialogue/gunslinger.json"),
+    include_str!("../../assets/dialogue/hired_gun.json"),
+    include_str!("../../assets/dialogue/loyal_dog.json"),
+    include_str!("../../assets/dialogue/preacher.json"),
+    include_str!("../../assets/dialogue/sheriff.json"),
+    include_str!("../../assets/dialogue/stray_dog.json"),
+    include_str!("../../assets/dialogue/townsfolk.json"),
+];
+
+/// Something that has to be true for a dialogue option to be offered
+#[derive(Deserialize, Clone, Debug)]
+pub enum DialogueCondition {
+    /// The speaker thinks at least this well of the player
+    MinStanding(i8),
+    /// The speaker thinks no better than this of the player
+    MaxStanding(i8),
+    HasItem {
+        item: StackableType,
+        count: u32,
+    },
+    /// The player is sharp enough to think of saying it
+    Intelligence(u32),
+    Flag(String),
+    NotFlag(String),
+    /// The player has taken on this quest and hasn't finished it yet
+    OnQuest(String),
+    NotOnQuest(String),
+    /// The player has done what this quest asked and can report back
+    QuestReady(String),
+    /// The speaker is a companion currently under this order
+    Ordered(CompanionOrder),
+}
+
+/// What happens when the player picks a dialogue option
+#[derive(Deserialize, Clone, Debug)]
+pub enum DialogueEffect {
+    GiveItem {
+        item: StackableType,
+        count: u32,
+    },
+    TakeItem {
+        item: StackableType,
+        count: u32,
+    },
+    Reputation {
+        faction: FactionId,
+        amount: i8,
+    },
+    StartQuest(String),
+    /// Pay out a quest the player has come back to report on
+    CompleteQuest(String),
+    SetFlag(String),
+    /// Take the speaker on as a companion, who takes orders through the given
+    /// conversation from then on
+    Recruit(String),
+    /// Give a companion an order
+    Order(CompanionOrder),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DialogueOption {
+    pub text: String,
+    /// Node to go to next, or the end of the conversation if missing
+    #[serde(default)]
+    pub next: Option<String>,
+    #[serde(default)]
+    pub conditions: Vec<DialogueCondition>,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DialogueNode {
+    pub text: String,
+    #[serde(default)]
+    pub options: Vec<DialogueOption>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DialogueTree {
+    pub id: String,
+    pub start: String,
+    pub nodes: HashMap<String, DialogueNode>,
+}
+
+#[derive(Resource)]
+pub struct DialogueRegistry {
+    trees: HashMap<String, DialogueTree>,
+}
+
+impl DialogueRegistry {
+    pub fn new() -> Self {
+        let trees = DIALOGUE_DATA
+            .iter()
+            .map(|data| {
+                let tree: DialogueTree =
+                    serde_json::from_str(data).expect("Bundled dialogue data should be valid");
+                (tree.id.clone(), tree)
+            })
+            .collect();
+
+        Self { trees }
+    }
+
+    pub fn get(&self, tree_id: &str) -> Option<&DialogueTree> {
+        self.trees.get(tree_id)
+    }
+
+    pub fn get_node(&self, tree_id: &str, node_id: &str) -> Option<&DialogueNode> {
+        self.get(tree_id).and_then(|tree| tree.nodes.get(node_id))
+    }
+}
+
+impl Default for DialogueRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DialogueCondition {
+    pub fn is_met(&self, world: &World, player: Entity, speaker: Entity) -> bool {
+        match self {
+            DialogueCondition::MinStanding(min) => {
+                get_effective_relationship(speaker, player, world) >= *min
+            }
+            DialogueCondition::MaxStanding(max) => {
+                get_effective_relationship(speaker, player, world) <= *max
+            }
+            DialogueCondition::HasItem { item, count } => {
+                count_stacked(world, player, *item) >= *count
+            }
+            DialogueCondition::Intelligence(min) => world
+                .get::<Attributes>(player)
+                .is_some_and(|a| a.intelligence >= *min),
+            DialogueCondition::Flag(flag) => world
+                .get::<DialogueMemory>(player)
+                .is_some_and(|m| m.has_flag(flag)),
+            DialogueCondition::NotFlag(flag) => !world
+                .get::<DialogueMemory>(player)
+                .is_some_and(|m| m.has_flag(flag)),
+            DialogueCondition::OnQuest(quest_id) => world
+                .get::<QuestLog>(player)
+                .is_some_and(|log| log.in_progress(quest_id).is_some()),
+            DialogueCondition::NotOnQuest(quest_id) => world
+                .get::<QuestLog>(player)
+                .is_none_or(|log| log.in_progress(quest_id).is_none()),
+            DialogueCondition::QuestReady(quest_id) => world
+                .get::<QuestLog>(player)
+                .and_then(|log| log.in_progress(quest_id))
+                .is_some_and(|quest| quest.status == QuestStatus::ReadyToTurnIn),
+            DialogueCondition::Ordered(order) => companion_order(world, speaker) == Some(*order),
+        }
+    }
+}
+
+impl DialogueOption {
+    pub fn is_available(&self, world: &World, player: Entity, speaker: Entity) -> bool {
+        self.conditions
+            .iter()
+            .all(|c| c.is_met(world, player, speaker))
+    }
+
+    /// The option's line, tagged with any attribute check it took to think of it
+    pub fn display_text(&self) -> String {
+        let check = self.conditions.iter().find_map(|c| match c {
+            DialogueCondition::Intelligence(min) => Some(format!("{{C|[INT {}]}} ", min)),
+            _ => None,
+        });
+
+        format!("{}{}", check.unwrap_or_default(), self.text)
+    }
+}
+
+fn item_text(item: StackableType, count: u32) -> String {
+    if count > 1 {
+        format!("{} x{}", item.prefab_id(), count)
+    } else {
+        item.prefab_id().to_string()
+    }
+}
+
+pub fn apply_dialogue_effect(
+    world: &mut World,
+    effect: &DialogueEffect,
+    player: Entity,
+    speaker: Entity,
+) {
+    match effect {
+        DialogueEffect::GiveItem { item, count } => {
+            give_or_drop_stacked(world, player, *item, *count);
+            log_message(world, format!("You receive {}", item_text(*item, *count)));
+        }
+        DialogueEffect::TakeItem { item, count } => {
+            if take_stacked(world, player, *item, *count) {
+                log_message(world, format!("You hand over {}", item_text(*item, *count)));
+            }
+        }
+        DialogueEffect::Reputation { faction, amount } => {
+            if world.get::<Reputation>(player).is_none() {
+                world.entity_mut(player).insert(Reputation::default());
+            }
+
+            if let Some(mut reputation) = world.get_mut::<Reputation>(player) {
+                reputation.adjust(*faction, *amount);
+            }
+
+            let message = if *amount >= 0 {
+                format!("{{G|The {} think better of you}}", faction.name())
+            } else {
+                format!("{{R|The {} think less of you}}", faction.name())
+            };
+            log_message(world, message);
+        }
+        DialogueEffect::StartQuest(quest_id) => {
+            start_quest(world, quest_id, player, speaker);
+        }
+        DialogueEffect::CompleteQuest(quest_id) => {
+            CompleteQuestAction {
+                player_entity: player,
+                quest_id: quest_id.clone(),
+            }
+            .try_apply(world);
+        }
+        DialogueEffect::SetFlag(flag) => {
+            if world.get::<DialogueMemory>(player).is_none() {
+                world.entity_mut(player).insert(DialogueMemory::default());
+            }
+
+            if let Some(mut memory) = world.get_mut::<DialogueMemory>(player) {
+                memory.set_flag(flag);
+            }
+        }
+        DialogueEffect::Recruit(orders) => {
+            recruit_companion(world, player, speaker, orders);
+        }
+        DialogueEffect::Order(order) => {
+            order_companion(world, player, speaker, *order);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialogue_trees_lead_to_existing_nodes() {
+        let registry = DialogueRegistry::new();
+
+        assert_eq!(registry.trees.len(), DIALOGUE_DATA.len());
+
+        for tree in registry.trees.values() {
+            assert!(
+                tree.nodes.contains_key(&tree.start),
+                "{} starts at missing node {}",
+                tree.id,
+                tree.start
+            );
+
+            for (node_id, node) in tree.nodes.iter() {
+                for option in node.options.iter() {
+                    if let Some(next) = &option.next {
+                        assert!(
+                            tree.nodes.contains_key(next),
+                            "{}:{} leads to missing node {}",
+                            tree.id,
+                            node_id,
+                            next
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,16 @@ use quadboy_macros::profiled_system;
 
 use crate::domain::components::Label;
 
+/// Label tags for each attachment installed on a weapon, shared by both label paths
+fn attachment_modifiers(
+    attachments: &crate::domain::components::WeaponAttachments,
+) -> impl Iterator<Item = (i32, String)> {
+    attachments
+        .installed()
+        .into_iter()
+        .map(|attachment| (60, format!("{{C|[{}]}}", attachment.label_tag())))
+}
+
 #[profiled_system]
 pub fn update_labels(
     mut q: Query<(Entity, &mut Label)>,
@@ -11,6 +21,7 @@ pub fn update_labels(
     q_fuse: Query<&crate::domain::components::Fuse>,
     q_light_source: Query<&crate::domain::components::LightSource>,
     q_stack_count: Query<&crate::domain::components::StackCount>,
+    q_attachments: Query<&crate::domain::components::WeaponAttachments>,
 ) {
     for (entity, mut label) in q.iter_mut() {
         if label.is_dirty() {
@@ -48,6 +59,11 @@ pub fn update_labels(
                 modifiers.push((200, format!("x{}", stack.count)));
             }
 
+            // Check weapon attachments
+            if let Ok(attachments) = q_attachments.get(entity) {
+                modifiers.extend(attachment_modifiers(attachments));
+            }
+
             // Sort by priority and append
             modifiers.sort_by_key(|(priority, _)| *priority);
             for (_, text) in modifiers {
@@ -108,6 +124,24 @@ pub fn mark_dirty_on_stack_change(
     }
 }
 
+#[profiled_system]
+pub fn mark_dirty_on_attachment_change(
+    mut q: Query<&mut Label>,
+    q_attachments: Query<
+        Entity,
+        (
+            Changed<crate::domain::components::WeaponAttachments>,
+            With<Label>,
+        ),
+    >,
+) {
+    for entity in q_attachments.iter() {
+        if let Ok(mut label) = q.get_mut(entity) {
+            label.mark_dirty();
+        }
+    }
+}
+
 #[profiled_system]
 pub fn ensure_labels_initialized(
     mut q_labels: Query<(Entity, &mut Label), Added<Label>>,
@@ -116,6 +150,7 @@ pub fn ensure_labels_initialized(
     q_fuse: Query<&crate::domain::components::Fuse>,
     q_light_source: Query<&crate::domain::components::LightSource>,
     q_stack_count: Query<&crate::domain::components::StackCount>,
+    q_attachments: Query<&crate::domain::components::WeaponAttachments>,
 ) {
     for (entity, mut label) in q_labels.iter_mut() {
         // Build the cached label with all modifiers for newly created labels
@@ -154,6 +189,11 @@ pub fn ensure_labels_initialized(
             modifiers.push((200, format!("x{}", stack.count)));
         }
 
+        // Check weapon attachments
+        if let Ok(attachments) = q_attachments.get(entity) {
+            modifiers.extend(attachment_modifiers(attachments));
+        }
+
         // Sort by priority and append
         modifiers.sort_by_key(|(priority, _)| *priority);
         for (_, text) in modifiers {
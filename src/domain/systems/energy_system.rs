@@ -17,10 +17,21 @@ pub enum EnergyActionType {
     TransferItem,
     ToggleLight,
     Shoot,
+    AimedShot,
+    FanHammer,
     Attack,
     Reload,
     Eat,
     Throw,
+    Gunsmith,
+    SeeDoctor,
+    Trade,
+    TakeFerry,
+    FillCanteen,
+    Butcher,
+    OpenDoor,
+    PickLock,
+    SetTrap,
 }
 
 #[derive(Resource, Default)]
@@ -83,10 +94,21 @@ pub fn get_base_energy_cost(action: EnergyActionType) -> i32 {
         EnergyActionType::TransferItem => 10,
         EnergyActionType::ToggleLight => 25,
         EnergyActionType::Shoot => 150,
+        EnergyActionType::AimedShot => 225,
+        EnergyActionType::FanHammer => 200,
         EnergyActionType::Attack => 150,
         EnergyActionType::Reload => 50,
         EnergyActionType::Eat => 50,
         EnergyActionType::Throw => 150,
+        EnergyActionType::Gunsmith => 100,
+        EnergyActionType::SeeDoctor => 300,
+        EnergyActionType::Trade => 50,
+        EnergyActionType::TakeFerry => 400,
+        EnergyActionType::FillCanteen => 100,
+        EnergyActionType::Butcher => 400,
+        EnergyActionType::OpenDoor => 50,
+        EnergyActionType::PickLock => 300,
+        EnergyActionType::SetTrap => 300,
     }
 }
 
@@ -107,6 +129,12 @@ pub fn get_energy_cost(action: EnergyActionType, stats: Option<&Stats>) -> i32 {
                 cost = (cost - (reload_speed * 2)).max(1); // Ensure minimum cost of 1
             }
         }
+        EnergyActionType::Butcher => {
+            if let Some(stats) = stats {
+                let blade = stats.get_stat(StatType::Blade);
+                cost = (cost - (blade * 20)).max(100);
+            }
+        }
         _ => {} // Other actions use base cost for stat modifications
     }
 
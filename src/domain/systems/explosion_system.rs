@@ -3,14 +3,17 @@ use macroquad::{math::Vec2, prelude::trace};
 
 use crate::{
     cfg::ZONE_SIZE,
+    common::Rand,
     domain::{
-        Destructible, Health, PlayerPosition, Zone,
+        Condition, ConditionSource, ConditionType, Destructible, Health, Lock, MaterialType,
+        PlayerPosition, Zone, apply_condition_to_entity, ignite_tile,
         systems::destruction_system::EntityDestroyedEvent,
     },
     engine::{Audio, AudioKey, Clock},
     rendering::{
         AlphaCurve, ColorCurve, Distribution, GlyphAnimation, ParticleSpawner, Position,
         SequenceTiming, SpawnArea, world_to_zone_idx, world_to_zone_local, world_to_zone_local_f32,
+        zone_local_to_world,
     },
     states::CleanupStatePlay,
 };
@@ -26,6 +29,11 @@ pub struct ExplosionEvent {
     pub audio: Option<AudioKey>,
 }
 
+/// Burn damage dealt to wood left standing after a blast
+const IGNITE_BURN_DAMAGE: i32 = 2;
+/// Chance each tile caught in a blast sets the brush on it alight
+const IGNITE_TILE_CHANCE: f32 = 0.4;
+
 impl ExplosionEvent {
     pub fn new(
         position: (usize, usize, usize),
@@ -72,6 +80,7 @@ pub fn explosion_system(
     q_zones: Query<&Zone>,
     mut q_health: Query<&mut Health>,
     mut q_destructible: Query<&mut Destructible>,
+    q_locks: Query<(), With<Lock>>,
     q_positions: Query<&Position>,
     clock: Res<Clock>,
     audio: Option<Res<Audio>>,
@@ -305,9 +314,25 @@ pub fn explosion_system(
                     continue;
                 }
 
+                if explosion.destroys_terrain {
+                    let target =
+                        zone_local_to_world(explosion_zone_idx, target_local_x, target_local_y);
+
+                    cmds.queue(move |world: &mut World| {
+                        if world.resource_mut::<Rand>().bool(IGNITE_TILE_CHANCE) {
+                            ignite_tile(world, target);
+                        }
+                    });
+                }
+
                 // Get entities at this position
                 if let Some(entities_at_pos) = zone.entities.get(target_local_x, target_local_y) {
                     for &entity in entities_at_pos {
+                        // Whatever's left of a lock after the blast doesn't hold anything shut
+                        if q_locks.contains(entity) {
+                            cmds.entity(entity).remove::<Lock>();
+                        }
+
                         // Apply damage to entities with Health
                         if let Ok(mut health) = q_health.get_mut(entity) {
                             health.take_damage(damage, clock.get_tick());
@@ -337,6 +362,21 @@ pub fn explosion_system(
                                     pos.world(),
                                     Some(destructible.material_type),
                                 ));
+                            } else if destructible.material_type == MaterialType::Wood {
+                                // Whatever wood survives the blast catches light
+                                cmds.queue(move |world: &mut World| {
+                                    let burning = ConditionType::Burning {
+                                        damage_per_tick: IGNITE_BURN_DAMAGE,
+                                    };
+                                    let duration = burning.get_base_duration_ticks();
+                                    let condition = Condition::new(
+                                        burning,
+                                        duration,
+                                        1.0,
+                                        ConditionSource::Environment,
+                                    );
+                                    let _ = apply_condition_to_entity(entity, condition, world);
+                                });
                             }
                         }
                     }
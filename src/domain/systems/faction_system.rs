@@ -2,7 +2,7 @@ use bevy_ecs::prelude::*;
 use quadboy_macros::profiled_system;
 use std::collections::HashMap;
 
-use crate::domain::{FactionId, FactionMember};
+use crate::domain::{FactionId, FactionMember, Reputation};
 
 #[derive(Resource)]
 pub struct FactionRelations {
@@ -23,10 +23,21 @@ impl FactionRelations {
         relations.insert((FactionId::Bandits, FactionId::Wildlife), -100);
         relations.insert((FactionId::Wildlife, FactionId::Bandits), -100);
 
+        // Townsfolk leave the player be, but stand against bandits and beasts
+        relations.insert((FactionId::Player, FactionId::Townsfolk), 0);
+        relations.insert((FactionId::Townsfolk, FactionId::Player), 0);
+
+        relations.insert((FactionId::Bandits, FactionId::Townsfolk), -100);
+        relations.insert((FactionId::Townsfolk, FactionId::Bandits), -100);
+
+        relations.insert((FactionId::Wildlife, FactionId::Townsfolk), -100);
+        relations.insert((FactionId::Townsfolk, FactionId::Wildlife), -100);
+
         // Same faction relations (neutral)
         relations.insert((FactionId::Player, FactionId::Player), 0);
         relations.insert((FactionId::Bandits, FactionId::Bandits), 0);
         relations.insert((FactionId::Wildlife, FactionId::Wildlife), 0);
+        relations.insert((FactionId::Townsfolk, FactionId::Townsfolk), 0);
 
         Self { relations }
     }
@@ -58,8 +69,23 @@ pub fn get_effective_relationship(entity_a: Entity, entity_b: Entity, world: &Wo
         return 0;
     };
 
-    let base_relationship =
-        faction_relations.get_base_relationship(faction_a.faction_id, faction_b.faction_id);
+    // Reputation either side has earned with the other's faction counts on top of
+    // how the factions themselves get along
+    let reputation = world
+        .get::<Reputation>(entity_a)
+        .map(|r| r.get(faction_b.faction_id))
+        .unwrap_or(0)
+        .saturating_add(
+            world
+                .get::<Reputation>(entity_b)
+                .map(|r| r.get(faction_a.faction_id))
+                .unwrap_or(0),
+        );
+
+    let base_relationship = faction_relations
+        .get_base_relationship(faction_a.faction_id, faction_b.faction_id)
+        .saturating_add(reputation)
+        .clamp(-100, 100);
 
     // Apply modifiers from entity_a's perspective
     let mut effective_relationship = base_relationship;
@@ -0,0 +1,445 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use quadboy_macros::profiled_system;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Rand,
+    domain::{
+        ActiveConditions, Condition, ConditionSource, ConditionType, Destructible, Fire, Health,
+        InActiveZone, MaterialType, PlayerPosition, Prefab, PrefabId, Prefabs, SpawnValue, Terrain,
+        TerrainNoise, Weather, Zone, apply_condition_to_entity, is_beside_water,
+        systems::{
+            destruction_system::{DestructionCause, EntityDestroyedEvent},
+            weather_system::intervals_elapsed,
+        },
+    },
+    engine::{Clock, TICKS_PER_MINUTE},
+    rendering::{Glyph, Position, world_to_zone_local, zone_local_to_world},
+};
+
+/// Fire spreads and burns down once per in-game minute
+const FIRE_INTERVAL: u32 = TICKS_PER_MINUTE;
+/// Minutes a patch of brush burns for, before any wood on it is counted
+const MIN_FUEL: u32 = 3;
+const MAX_FUEL: u32 = 8;
+/// Chance each minute that a fire catches a neighbouring tile of the driest fuel in still air
+const BASE_SPREAD_CHANCE: f32 = 0.15;
+/// How much a tailwind helps the fire along, or a headwind holds it back
+const WIND_SPREAD_FACTOR: f32 = 1.5;
+/// Standing wood catches at least this readily, whatever the ground under it
+const WOOD_FLAMMABILITY: f32 = 0.5;
+/// Chance each minute that rain puts out a fire
+const RAIN_DOUSE_CHANCE: f32 = 0.5;
+/// Chance each minute that a creature on fire sets the ground under it alight
+const CREATURE_IGNITE_CHANCE: f32 = 0.3;
+const FIRE_BURN_DAMAGE: i32 = 2;
+
+/// Whether there's standing wood on the tile that a fire could take hold of
+fn has_wood(world: &World, entities: &[Entity]) -> bool {
+    entities.iter().any(|&entity| {
+        world.get::<Health>(entity).is_none()
+            && world
+                .get::<Destructible>(entity)
+                .is_some_and(|d| d.material_type == MaterialType::Wood && !d.is_destroyed())
+    })
+}
+
+/// How readily a tile catches, from the ground and anything wooden standing on it
+fn tile_flammability(world: &World, zone: &Zone, x: usize, y: usize) -> f32 {
+    let ground = zone
+        .terrain
+        .get(x, y)
+        .map(|terrain| terrain.flammability())
+        .unwrap_or(0.0);
+
+    let wood = zone
+        .entities
+        .get(x, y)
+        .is_some_and(|entities| has_wood(world, entities));
+
+    if wood {
+        ground.max(WOOD_FLAMMABILITY)
+    } else {
+        ground
+    }
+}
+
+/// Sets an actor or wooden object burning, unless it already is or it's too wet to catch
+fn set_alight(world: &mut World, entity: Entity) {
+    if world
+        .get::<ActiveConditions>(entity)
+        .is_some_and(|conditions| conditions.is_burning())
+    {
+        return;
+    }
+
+    let burning = ConditionType::Burning {
+        damage_per_tick: FIRE_BURN_DAMAGE,
+    };
+    let duration = burning.get_base_duration_ticks();
+    let condition = Condition::new(burning, duration, 1.0, ConditionSource::Environment);
+    let _ = apply_condition_to_entity(entity, condition, world);
+}
+
+/// Whatever is on the tile that fire can hurt, creatures and wood alike
+fn burnable_on_tile(world: &World, entities: &[Entity]) -> Vec<Entity> {
+    entities
+        .iter()
+        .copied()
+        .filter(|&entity| {
+            world.get::<Health>(entity).is_some()
+                || world
+                    .get::<Destructible>(entity)
+                    .is_some_and(|d| d.material_type == MaterialType::Wood)
+        })
+        .collect()
+}
+
+fn fire_at(world: &mut World, pos: (usize, usize, usize)) -> Option<Entity> {
+    world
+        .query_filtered::<(Entity, &Position), With<Fire>>()
+        .iter(world)
+        .find(|(_, position)| position.world() == pos)
+        .map(|(entity, _)| entity)
+}
+
+/// Starts a fire on the given tile if there's anything there to burn. Rain and water
+/// nearby keep it from catching. Returns whether it caught.
+pub fn ignite_tile(world: &mut World, pos: (usize, usize, usize)) -> bool {
+    if world
+        .get_resource::<Weather>()
+        .is_some_and(|weather| weather.current().is_wet())
+    {
+        return false;
+    }
+
+    if is_beside_water(world, pos) || fire_at(world, pos).is_some() {
+        return false;
+    }
+
+    let zone_idx = Position::new_world(pos).zone_idx();
+    let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+    let (flammability, entities) = {
+        let mut q_zones = world.query::<&Zone>();
+        let Some(zone) = q_zones.iter(world).find(|z| z.idx == zone_idx) else {
+            return false;
+        };
+
+        let entities = zone
+            .entities
+            .get(x, y)
+            .map(|entities| entities.to_vec())
+            .unwrap_or_default();
+
+        (tile_flammability(world, zone, x, y), entities)
+    };
+
+    if flammability <= 0.0 {
+        return false;
+    }
+
+    let fuel = world
+        .resource_mut::<Rand>()
+        .range_n(MIN_FUEL as i32, MAX_FUEL as i32 + 1);
+
+    let config =
+        Prefab::new(PrefabId::Fire, pos).with_metadata("fuel".to_owned(), SpawnValue::Int(fuel));
+    Prefabs::spawn_world(world, config);
+
+    for entity in burnable_on_tile(world, &entities) {
+        set_alight(world, entity);
+    }
+
+    true
+}
+
+/// Chance a fire spreads one tile over in the given direction, with the wind at its back
+/// or in its face
+fn spread_chance(flammability: f32, dryness: f32, wind: (f32, f32), dir: (i32, i32)) -> f32 {
+    let length = ((dir.0 * dir.0 + dir.1 * dir.1) as f32).sqrt();
+    let along_wind = (dir.0 as f32 * wind.0 + dir.1 as f32 * wind.1) / length;
+    let wind_factor = (1.0 + along_wind * WIND_SPREAD_FACTOR).max(0.1);
+
+    BASE_SPREAD_CHANCE * flammability * dryness * wind_factor
+}
+
+/// Spreads fires through the active zone with the wind, burns them down, and leaves
+/// ash where the brush used to be. Anything already burning sets the ground under it
+/// alight, and the rain puts it all out in the end.
+#[profiled_system]
+pub fn spread_fire(world: &mut World) {
+    let intervals = intervals_elapsed(world.resource::<Clock>(), FIRE_INTERVAL);
+
+    if intervals == 0 {
+        return;
+    }
+
+    let Some(player_zone_idx) = world
+        .get_resource::<PlayerPosition>()
+        .map(|player_pos| player_pos.zone_idx())
+    else {
+        return;
+    };
+
+    let weather_type = world.resource::<Weather>().current();
+
+    // Burning things light up the ground under them
+    let sparks = world.resource_scope(|world, mut rand: Mut<Rand>| {
+        world
+            .query_filtered::<(&ActiveConditions, &Position, Has<Health>), With<InActiveZone>>()
+            .iter(world)
+            .filter(|(conditions, position, is_creature)| {
+                position.zone_idx() == player_zone_idx
+                    && conditions.is_burning()
+                    && (!is_creature || rand.bool(CREATURE_IGNITE_CHANCE))
+            })
+            .map(|(_, position, _)| position.world())
+            .collect::<Vec<_>>()
+    });
+
+    for pos in sparks {
+        ignite_tile(world, pos);
+    }
+
+    let fires = world
+        .query_filtered::<(Entity, &Position), (With<Fire>, With<InActiveZone>)>()
+        .iter(world)
+        .filter(|(_, position)| position.zone_idx() == player_zone_idx)
+        .map(|(entity, position)| (entity, position.world()))
+        .collect::<Vec<_>>();
+
+    if fires.is_empty() {
+        return;
+    }
+
+    let burning_tiles = fires.iter().map(|(_, pos)| *pos).collect::<HashSet<_>>();
+    let mut burnt_out = vec![];
+    let mut fed = vec![];
+    let mut to_ignite = HashSet::new();
+    let mut to_burn = vec![];
+
+    world.resource_scope(|world, mut rand: Mut<Rand>| {
+        let mut q_zones = world.query::<&Zone>();
+        let Some(zone) = q_zones.iter(world).find(|z| z.idx == player_zone_idx) else {
+            return;
+        };
+
+        for &(entity, pos) in fires.iter() {
+            if weather_type.is_wet() && rand.bool(RAIN_DOUSE_CHANCE) {
+                burnt_out.push((entity, pos, false));
+                continue;
+            }
+
+            let (x, y) = world_to_zone_local(pos.0, pos.1);
+            let entities = zone
+                .entities
+                .get(x, y)
+                .map(|entities| entities.to_vec())
+                .unwrap_or_default();
+
+            to_burn.extend(burnable_on_tile(world, &entities));
+
+            // Standing wood keeps a fire fed until it comes down
+            if has_wood(world, &entities) {
+                fed.push(entity);
+            } else if world
+                .get::<Fire>(entity)
+                .is_some_and(|fire| fire.fuel <= intervals)
+            {
+                burnt_out.push((entity, pos, true));
+                continue;
+            }
+
+            for dx in -1..=1_i32 {
+                for dy in -1..=1_i32 {
+                    let nx = x as i32 + dx;
+                    let ny = y as i32 + dy;
+
+                    if (dx == 0 && dy == 0)
+                        || nx < 0
+                        || ny < 0
+                        || nx >= ZONE_SIZE.0 as i32
+                        || ny >= ZONE_SIZE.1 as i32
+                    {
+                        continue;
+                    }
+
+                    let (nx, ny) = (nx as usize, ny as usize);
+                    let neighbor = zone_local_to_world(player_zone_idx, nx, ny);
+
+                    if burning_tiles.contains(&neighbor) || to_ignite.contains(&neighbor) {
+                        continue;
+                    }
+
+                    let chance = spread_chance(
+                        tile_flammability(world, zone, nx, ny),
+                        weather_type.dryness(),
+                        weather_type.wind(),
+                        (dx, dy),
+                    );
+
+                    if chance > 0.0 && rand.bool(chance.min(1.0)) {
+                        to_ignite.insert(neighbor);
+                    }
+                }
+            }
+        }
+    });
+
+    for &(entity, _) in fires.iter() {
+        if fed.contains(&entity) {
+            continue;
+        }
+
+        if let Some(mut fire) = world.get_mut::<Fire>(entity) {
+            fire.fuel = fire.fuel.saturating_sub(intervals);
+        }
+    }
+
+    for entity in to_burn {
+        set_alight(world, entity);
+    }
+
+    for pos in to_ignite {
+        ignite_tile(world, pos);
+    }
+
+    let mut to_ash = HashMap::new();
+
+    for (entity, pos, burned) in burnt_out {
+        world.send_event(EntityDestroyedEvent::new(
+            entity,
+            pos,
+            DestructionCause::Environmental,
+        ));
+
+        if burned {
+            to_ash.insert(world_to_zone_local(pos.0, pos.1), pos);
+        }
+    }
+
+    burn_to_ash(world, player_zone_idx, to_ash);
+}
+
+/// Turns the burnt brush on each tile over to ash
+fn burn_to_ash(
+    world: &mut World,
+    zone_idx: usize,
+    tiles: HashMap<(usize, usize), (usize, usize, usize)>,
+) {
+    if tiles.is_empty() {
+        return;
+    }
+
+    let mut changed = HashSet::new();
+    {
+        let mut q_zones = world.query::<&mut Zone>();
+        let Some(mut zone) = q_zones.iter_mut(world).find(|z| z.idx == zone_idx) else {
+            return;
+        };
+
+        for &(x, y) in tiles.keys() {
+            if zone
+                .terrain
+                .get(x, y)
+                .is_some_and(|t| t.flammability() > 0.0)
+            {
+                zone.terrain.set(x, y, Terrain::Ash);
+                changed.insert((x, y));
+            }
+        }
+    }
+
+    world.resource_scope(|world, mut terrain_noise: Mut<TerrainNoise>| {
+        let mut q_terrain_tiles =
+            world.query_filtered::<(&Position, &mut Terrain, &mut Glyph), With<InActiveZone>>();
+
+        for (position, mut terrain, mut glyph) in q_terrain_tiles.iter_mut(world) {
+            if position.zone_idx() != zone_idx {
+                continue;
+            }
+
+            let world_pos = position.world();
+            let local = world_to_zone_local(world_pos.0, world_pos.1);
+
+            if !changed.contains(&local) {
+                continue;
+            }
+
+            let style = terrain_noise.style(Terrain::Ash, (world_pos.0, world_pos.1));
+
+            *terrain = Terrain::Ash;
+            glyph.idx = style.idx;
+            glyph.fg1 = style.fg1.map(|c| c.into());
+            glyph.fg2 = style.fg2.map(|c| c.into());
+            glyph.bg = style.bg.map(|c| c.into());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::WeatherType;
+
+    const CALM: (f32, f32) = (0.0, 0.0);
+    const EAST: (i32, i32) = (1, 0);
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_spread_chance_by_material() {
+        let dry = WeatherType::Clear.dryness();
+
+        let brush = spread_chance(Terrain::DyingGrass.flammability(), dry, CALM, EAST);
+        let grass = spread_chance(Terrain::Grass.flammability(), dry, CALM, EAST);
+        let wood = spread_chance(WOOD_FLAMMABILITY, dry, CALM, EAST);
+
+        assert_close(brush, BASE_SPREAD_CHANCE);
+        assert_close(wood, BASE_SPREAD_CHANCE * WOOD_FLAMMABILITY);
+        assert!(brush > grass);
+
+        for terrain in [Terrain::Sand, Terrain::Dirt, Terrain::River, Terrain::Ash] {
+            assert_eq!(
+                spread_chance(terrain.flammability(), dry, CALM, EAST),
+                0.0,
+                "{:?} caught",
+                terrain
+            );
+        }
+    }
+
+    #[test]
+    fn test_spread_chance_weather_and_wind() {
+        let brush = Terrain::DyingGrass.flammability();
+
+        assert_eq!(
+            spread_chance(brush, WeatherType::Rain.dryness(), CALM, EAST),
+            0.0
+        );
+        assert!(
+            spread_chance(brush, WeatherType::DustStorm.dryness(), CALM, EAST)
+                > spread_chance(brush, WeatherType::Clear.dryness(), CALM, EAST)
+        );
+
+        let wind = (1.0, 0.0);
+        let downwind = spread_chance(brush, 1.0, wind, EAST);
+        let across = spread_chance(brush, 1.0, wind, (0, 1));
+        let upwind = spread_chance(brush, 1.0, wind, (-1, 0));
+
+        assert_close(across, BASE_SPREAD_CHANCE);
+        assert!(downwind > across && across > upwind);
+        // A fire still creeps into the wind, just barely
+        assert!(upwind > 0.0);
+    }
+}
@@ -3,7 +3,7 @@ use quadboy_macros::profiled_system;
 use std::collections::VecDeque;
 
 use crate::{
-    domain::{Label, Player, Zone, Zones},
+    domain::{BodyLocation, InjuryType, Label, Player, Zone, Zones},
     engine::Clock,
     rendering::{Position, Visibility, world_to_zone_idx, world_to_zone_local},
 };
@@ -23,6 +23,7 @@ pub enum LogMessage {
         target: Entity,
         damage: i32,
         weapon_verb: String,
+        location: Option<BodyLocation>,
     },
     AttackMiss {
         attacker: Entity,
@@ -33,6 +34,10 @@ pub enum LogMessage {
         entity: Entity,
         killer: Option<Entity>,
     },
+    Injured {
+        entity: Entity,
+        injury: InjuryType,
+    },
 
     // Status Effects
     PoisonApplied {
@@ -46,6 +51,10 @@ pub enum LogMessage {
     BurningApplied {
         target: Entity,
     },
+    InjuryTreated {
+        entity: Entity,
+        injury: InjuryType,
+    },
 
     // Items
     ItemPickup {
@@ -61,8 +70,28 @@ pub enum LogMessage {
     ItemConsumed {
         consumer: Entity,
         item: Entity,
+        verb: String,
         effect_desc: String,
     },
+    EquipConflict {
+        actor: Entity,
+        removed: Entity,
+        equipped: Entity,
+    },
+    OffHandEquipped {
+        actor: Entity,
+        item: Entity,
+    },
+    AttachmentInstalled {
+        actor: Entity,
+        attachment_name: String,
+        weapon: Entity,
+    },
+    AttachmentRemoved {
+        actor: Entity,
+        attachment_name: String,
+        weapon: Entity,
+    },
 
     // Progression
     XpGain {
@@ -114,13 +143,19 @@ impl LogMessage {
         match self {
             LogMessage::Attack { .. }
             | LogMessage::AttackMiss { .. }
-            | LogMessage::Death { .. } => LogCategory::Combat,
+            | LogMessage::Death { .. }
+            | LogMessage::Injured { .. } => LogCategory::Combat,
             LogMessage::PoisonApplied { .. }
             | LogMessage::BleedingApplied { .. }
-            | LogMessage::BurningApplied { .. } => LogCategory::Status,
+            | LogMessage::BurningApplied { .. }
+            | LogMessage::InjuryTreated { .. } => LogCategory::Status,
             LogMessage::ItemPickup { .. }
             | LogMessage::ItemDrop { .. }
-            | LogMessage::ItemConsumed { .. } => LogCategory::Item,
+            | LogMessage::ItemConsumed { .. }
+            | LogMessage::EquipConflict { .. }
+            | LogMessage::OffHandEquipped { .. }
+            | LogMessage::AttachmentInstalled { .. }
+            | LogMessage::AttachmentRemoved { .. } => LogCategory::Item,
             LogMessage::XpGain { .. } | LogMessage::LevelUp { .. } => LogCategory::Progression,
             LogMessage::Discovery { .. } => LogCategory::Discovery,
             LogMessage::GameSaved | LogMessage::GameLoaded | LogMessage::Custom(_) => {
@@ -189,6 +224,23 @@ impl GameLog {
     }
 }
 
+/// Puts a message in front of the player, stamped with the current tick
+pub fn log_message(world: &mut World, message: String) {
+    let tick = world.resource::<Clock>().current_tick();
+    world.send_event(GameLogEvent {
+        message: LogMessage::Custom(message),
+        tick,
+        knowledge: KnowledgeLevel::Player,
+    });
+}
+
+/// Only logs when it's the player acting, the AI just gets on with it
+pub fn log_for_player(world: &mut World, actor: Entity, message: String) {
+    if world.get::<Player>(actor).is_some() {
+        log_message(world, message);
+    }
+}
+
 #[profiled_system]
 pub fn process_game_log_events(
     mut e_log: EventReader<GameLogEvent>,
@@ -275,13 +327,24 @@ fn format_log_message(
             target,
             damage,
             weapon_verb,
+            location,
         } => {
             let attacker_label = get_entity_label(*attacker, q_labels, q_player);
             let target_label = get_entity_label(*target, q_labels, q_player);
-            format!(
-                "{} {} {} for {} damage",
-                attacker_label, weapon_verb, target_label, damage
-            )
+            match location {
+                Some(location) => format!(
+                    "{} {} {} in the {} for {} damage",
+                    attacker_label,
+                    weapon_verb,
+                    target_label,
+                    location.display_name().to_lowercase(),
+                    damage
+                ),
+                None => format!(
+                    "{} {} {} for {} damage",
+                    attacker_label, weapon_verb, target_label, damage
+                ),
+            }
         }
 
         LogMessage::AttackMiss {
@@ -308,6 +371,15 @@ fn format_log_message(
             }
         }
 
+        LogMessage::Injured { entity, injury } => {
+            if q_player.get(*entity).is_ok() {
+                format!("{{C|You}} suffer a {{R|{}}}", injury.display_name())
+            } else {
+                let entity_label = get_entity_label(*entity, q_labels, q_player);
+                format!("{} suffers a {{R|{}}}", entity_label, injury.display_name())
+            }
+        }
+
         LogMessage::PoisonApplied { source, target } => {
             let source_label = get_entity_label(*source, q_labels, q_player);
             let is_player_target = q_player.get(*target).is_ok();
@@ -349,6 +421,15 @@ fn format_log_message(
             }
         }
 
+        LogMessage::InjuryTreated { entity, injury } => {
+            let entity_label = get_entity_label(*entity, q_labels, q_player);
+            format!(
+                "{}'s {{G|{}}} has been treated",
+                entity_label,
+                injury.display_name()
+            )
+        }
+
         LogMessage::ItemPickup {
             picker,
             item,
@@ -380,13 +461,60 @@ fn format_log_message(
         LogMessage::ItemConsumed {
             consumer,
             item,
+            verb,
             effect_desc,
         } => {
             let consumer_label = get_entity_label(*consumer, q_labels, q_player);
             let item_label = get_entity_label(*item, q_labels, q_player);
             format!(
-                "{} ate {} {{u|({})}}",
-                consumer_label, item_label, effect_desc
+                "{} {} {} {{u|({})}}",
+                consumer_label, verb, item_label, effect_desc
+            )
+        }
+
+        LogMessage::EquipConflict {
+            actor,
+            removed,
+            equipped,
+        } => {
+            let actor_label = get_entity_label(*actor, q_labels, q_player);
+            let removed_label = get_entity_label(*removed, q_labels, q_player);
+            let equipped_label = get_entity_label(*equipped, q_labels, q_player);
+            format!(
+                "{} puts away {} to take up {}",
+                actor_label, removed_label, equipped_label
+            )
+        }
+
+        LogMessage::OffHandEquipped { actor, item } => {
+            let actor_label = get_entity_label(*actor, q_labels, q_player);
+            let item_label = get_entity_label(*item, q_labels, q_player);
+            format!("{} takes {} in the {{Y|off hand}}", actor_label, item_label)
+        }
+
+        LogMessage::AttachmentInstalled {
+            actor,
+            attachment_name,
+            weapon,
+        } => {
+            let actor_label = get_entity_label(*actor, q_labels, q_player);
+            let weapon_label = get_entity_label(*weapon, q_labels, q_player);
+            format!(
+                "{} fitted a {{Y|{}}} to {}",
+                actor_label, attachment_name, weapon_label
+            )
+        }
+
+        LogMessage::AttachmentRemoved {
+            actor,
+            attachment_name,
+            weapon,
+        } => {
+            let actor_label = get_entity_label(*actor, q_labels, q_player);
+            let weapon_label = get_entity_label(*weapon, q_labels, q_player);
+            format!(
+                "{} removed the {{Y|{}}} from {}",
+                actor_label, attachment_name, weapon_label
             )
         }
 
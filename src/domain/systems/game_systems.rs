@@ -8,22 +8,30 @@ use quadboy_macros::profiled_system;
 
 use crate::{
     domain::{
-        PlayerPosition, TurnState, Zones, ai_turn, recalculate_collider_flags_system,
+        PlayerPosition, TurnState, Zones, ai_turn, followers_keep_up, on_quest_inventory_changed,
+        on_quest_player_moved, on_quest_target_destroyed, recalculate_collider_flags_system,
         systems::{
             armor_regen_system::armor_regen_system,
             cleanup_system::on_entity_destroyed_cleanup,
             condition_blink_system::sync_condition_blink_system,
-            condition_system::{process_conditions, spawn_condition_particles},
+            condition_system::{
+                process_burning_objects, process_conditions, spawn_condition_particles,
+            },
+            corpse_system::{decay_corpses, on_creature_death_leave_corpse},
             death_check_system::death_check_system,
             explosion_system::explosion_system,
+            fire_system::spread_fire,
             fuse_system::fuse_system,
             game_log_system::process_game_log_events,
             health_system::update_health_system,
             loot_drop_system::on_entity_destroyed_loot,
             stats_system::{equipment_stat_modifier_system, recalculate_stats_system},
+            survival_system::{process_sleep, update_needs},
+            trap_system::{hide_unspotted_traps, spot_traps},
         },
         tick_faction_modifiers, turn_scheduler, update_entity_visibility_flags,
         update_lighting_system, update_player_position_resource, update_player_vision,
+        weather_system::{lightning_system, rain_system, update_weather},
         xp_system::{apply_xp_gain, award_xp_on_kill, handle_level_up},
     },
     rendering::position_systems::{place_static_entities, update_dynamic_entity_pos},
@@ -45,13 +53,20 @@ pub fn register_game_systems(world: &mut World) {
         world.register_system(recalculate_collider_flags_system),
         world.register_system(equipment_stat_modifier_system),
         world.register_system(recalculate_stats_system),
+        world.register_system(update_weather),
+        world.register_system(rain_system),
+        world.register_system(lightning_system),
+        world.register_system(spread_fire),
         world.register_system(process_conditions),
+        world.register_system(update_needs),
+        world.register_system(process_burning_objects),
         world.register_system(spawn_condition_particles),
         world.register_system(sync_condition_blink_system),
         world.register_system(death_check_system),
         world.register_system(update_health_system),
         world.register_system(armor_regen_system),
         world.register_system(tick_faction_modifiers),
+        world.register_system(process_sleep),
         world.register_system(turn_scheduler),
         world.register_system(fuse_system),
         world.register_system(explosion_system),
@@ -59,13 +74,21 @@ pub fn register_game_systems(world: &mut World) {
         world.register_system(apply_xp_gain),
         world.register_system(handle_level_up),
         world.register_system(process_game_log_events),
+        world.register_system(decay_corpses),
+        world.register_system(on_creature_death_leave_corpse),
         world.register_system(on_entity_destroyed_loot),
+        world.register_system(on_quest_target_destroyed),
         world.register_system(on_entity_destroyed_cleanup),
+        world.register_system(followers_keep_up),
+        world.register_system(on_quest_player_moved),
+        world.register_system(on_quest_inventory_changed),
         world.register_system(ai_turn),
     ];
     let post = vec![
         world.register_system(update_lighting_system),
         world.register_system(update_player_vision),
+        world.register_system(spot_traps),
+        world.register_system(hide_unspotted_traps),
         world.register_system(update_entity_visibility_flags),
     ];
 
@@ -0,0 +1,90 @@
+use bevy_ecs::prelude::*;
+
+use crate::domain::{
+    ActiveConditions, ConditionType, Injuries, InjuryType, StatModifier, StatModifiers,
+};
+
+/// Give an entity a lasting injury and its stat penalties.
+/// Returns false if they were already carrying it.
+pub fn inflict_injury(world: &mut World, entity: Entity, injury: InjuryType) -> bool {
+    if let Some(mut injuries) = world.get_mut::<Injuries>(entity) {
+        if !injuries.add(injury) {
+            return false;
+        }
+    } else {
+        let mut injuries = Injuries::new();
+        injuries.add(injury);
+        world.entity_mut(entity).insert(injuries);
+    }
+
+    if let Some(mut stat_modifiers) = world.get_mut::<StatModifiers>(entity) {
+        for (stat_type, value) in injury.stat_penalties() {
+            stat_modifiers.add_modifier(stat_type, StatModifier::injury(value, injury));
+        }
+    }
+
+    true
+}
+
+/// Treat up to `limit` of an entity's injuries, lifting their penalties.
+/// Returns the injuries that were treated.
+pub fn treat_injuries(world: &mut World, entity: Entity, limit: usize) -> Vec<InjuryType> {
+    let treated = {
+        let Some(mut injuries) = world.get_mut::<Injuries>(entity) else {
+            return vec![];
+        };
+
+        let treated = injuries
+            .injuries
+            .iter()
+            .take(limit)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for injury in treated.iter() {
+            injuries.remove(*injury);
+        }
+
+        treated
+    };
+
+    if let Some(mut stat_modifiers) = world.get_mut::<StatModifiers>(entity) {
+        for injury in treated.iter() {
+            stat_modifiers.remove_injury_modifiers(*injury);
+        }
+    }
+
+    treated
+}
+
+/// Dress an entity's wounds, stopping any bleeding. Returns true if they were bleeding.
+pub fn stop_bleeding(world: &mut World, entity: Entity) -> bool {
+    let spawners = {
+        let Some(mut conditions) = world.get_mut::<ActiveConditions>(entity) else {
+            return false;
+        };
+
+        let bleeding = conditions
+            .conditions
+            .iter()
+            .filter(|c| matches!(c.condition_type, ConditionType::Bleeding { .. }))
+            .map(|c| c.condition_type.clone())
+            .collect::<Vec<_>>();
+
+        if bleeding.is_empty() {
+            return false;
+        }
+
+        bleeding
+            .iter()
+            .flat_map(|condition_type| conditions.remove_condition(condition_type))
+            .filter_map(|condition| condition.particle_spawner_entity)
+            .collect::<Vec<_>>()
+    };
+
+    for spawner in spawners {
+        world.despawn(spawner);
+    }
+
+    true
+}
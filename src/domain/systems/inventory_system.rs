@@ -1,8 +1,11 @@
 use bevy_ecs::prelude::*;
 
 use crate::{
-    domain::{InInventory, Item, PickupItemAction, Player},
-    engine::{KeyInput, StableId},
+    domain::{
+        InInventory, Inventory, Item, PickupItemAction, Player, Prefab, Prefabs, StackCount,
+        Stackable, StackableType, inventory::InventoryChangedEvent,
+    },
+    engine::{KeyInput, StableId, StableIdRegistry},
     rendering::Position,
 };
 use macroquad::input::KeyCode;
@@ -36,3 +39,137 @@ pub fn handle_item_pickup(
         }
     }
 }
+
+fn stacks_of(world: &World, owner: Entity, stack_type: StackableType) -> Vec<(u64, Entity)> {
+    let (Some(inventory), Some(registry)) = (
+        world.get::<Inventory>(owner),
+        world.get_resource::<StableIdRegistry>(),
+    ) else {
+        return vec![];
+    };
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| registry.get_entity(StableId(id)).map(|e| (id, e)))
+        .filter(|&(_, entity)| {
+            world
+                .get::<Stackable>(entity)
+                .is_some_and(|s| s.stack_type == stack_type)
+        })
+        .collect()
+}
+
+/// How many of a stackable item an inventory holds across all of its stacks
+pub fn count_stacked(world: &World, owner: Entity, stack_type: StackableType) -> u32 {
+    stacks_of(world, owner, stack_type)
+        .into_iter()
+        .filter_map(|(_, entity)| world.get::<StackCount>(entity))
+        .map(|s| s.count)
+        .sum()
+}
+
+/// Takes stackable items out of an inventory, emptying stacks as it goes.
+/// Takes nothing and returns false if there aren't enough.
+pub fn take_stacked(
+    world: &mut World,
+    owner: Entity,
+    stack_type: StackableType,
+    amount: u32,
+) -> bool {
+    if count_stacked(world, owner, stack_type) < amount {
+        return false;
+    }
+
+    let mut remaining = amount;
+
+    for (id, entity) in stacks_of(world, owner, stack_type) {
+        if remaining == 0 {
+            break;
+        }
+
+        let Some(mut stack) = world.get_mut::<StackCount>(entity) else {
+            continue;
+        };
+
+        if stack.count > remaining {
+            stack.count -= remaining;
+            break;
+        }
+
+        remaining -= stack.count;
+
+        let weight = world.get::<Item>(entity).map(|i| i.weight).unwrap_or(0.0);
+        if let Some(mut inventory) = world.get_mut::<Inventory>(owner) {
+            inventory.remove_item(id, weight);
+        }
+        world.entity_mut(entity).despawn();
+    }
+
+    world.send_event(InventoryChangedEvent);
+
+    true
+}
+
+/// Puts stackable items into an inventory, topping up existing stacks before starting
+/// new ones. Returns how many didn't fit.
+pub fn give_stacked(
+    world: &mut World,
+    owner: Entity,
+    stack_type: StackableType,
+    amount: u32,
+) -> u32 {
+    let mut remaining = amount;
+
+    for (_, entity) in stacks_of(world, owner, stack_type) {
+        if let Some(mut stack) = world.get_mut::<StackCount>(entity) {
+            remaining = stack.add(remaining);
+        }
+    }
+
+    let pos = world
+        .get::<Position>(owner)
+        .map(|p| p.world())
+        .unwrap_or((0, 0, 0));
+
+    while remaining > 0 {
+        let config = Prefab::new(stack_type.prefab_id(), pos);
+        let Some(item) = Prefabs::spawn_in_inventory(world, config, owner) else {
+            break;
+        };
+
+        let count = remaining.min(StackCount::MAX_STACK_SIZE);
+        if let Some(mut stack) = world.get_mut::<StackCount>(item) {
+            stack.count = count;
+        }
+        remaining -= count;
+    }
+
+    world.send_event(InventoryChangedEvent);
+
+    remaining
+}
+
+/// Like `give_stacked`, but whatever won't fit in the pack gets set down at the owner's feet
+pub fn give_or_drop_stacked(
+    world: &mut World,
+    owner: Entity,
+    stack_type: StackableType,
+    amount: u32,
+) {
+    let leftover = give_stacked(world, owner, stack_type, amount);
+
+    if leftover == 0 {
+        return;
+    }
+
+    let pos = world
+        .get::<Position>(owner)
+        .map(|p| p.world())
+        .unwrap_or((0, 0, 0));
+    let dropped = Prefabs::spawn_world(world, Prefab::new(stack_type.prefab_id(), pos));
+
+    if let Some(mut stack) = world.get_mut::<StackCount>(dropped) {
+        stack.count = leftover.min(StackCount::MAX_STACK_SIZE);
+    }
+}
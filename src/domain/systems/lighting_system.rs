@@ -10,7 +10,7 @@ use crate::{
     },
     domain::{
         ColliderFlags, EquipmentSlots, Equipped, InActiveZone, LightSource, Overworld,
-        PlayerPosition, Zone, Zones,
+        PlayerPosition, Weather, Zone, Zones,
     },
     engine::{Clock, StableId, StableIdRegistry},
     rendering::{LightingData, Position, world_to_zone_local},
@@ -31,6 +31,7 @@ pub fn update_lighting_system(
     clock: Res<Clock>,
     player_pos: Res<PlayerPosition>,
     overworld: Res<Overworld>,
+    weather: Res<Weather>,
     zones: Res<Zones>,
     mut lighting_data: ResMut<LightingData>,
     registry: Res<StableIdRegistry>,
@@ -68,7 +69,9 @@ pub fn update_lighting_system(
             let final_color = (final_r << 16) | (final_g << 8) | final_b;
             let final_intensity = daylight.intensity * biome_intensity;
 
-            (final_color, final_intensity)
+            weather
+                .current()
+                .modulate_ambient(final_color, final_intensity)
         } else {
             (
                 biome_type.get_ambient_color(),
@@ -6,29 +6,40 @@ pub mod armor_regen_system;
 pub mod bump_attack_system;
 pub mod cleanup_system;
 pub mod collider_recalc_system;
+pub mod companion_system;
 pub mod condition_blink_system;
 pub mod condition_system;
+pub mod corpse_system;
+pub mod crafting_system;
 pub mod death_check_system;
 pub mod destruction_system;
+pub mod dialogue_system;
 pub mod dynamic_label_system;
 pub mod energy_system;
 pub mod explosion_system;
 pub mod faction_system;
+pub mod fire_system;
 pub mod fuse_system;
 pub mod game_log_system;
 pub mod game_systems;
 pub mod health_system;
 pub mod hit_blink_system;
+pub mod injury_system;
 pub mod inventory_system;
 pub mod knockback_animation_system;
 pub mod lighting_system;
 pub mod loot_drop_system;
+pub mod quest_system;
 pub mod smooth_movement_system;
 pub mod stable_id_system;
 pub mod stats_system;
+pub mod survival_system;
 pub mod targeting;
+pub mod trade_system;
+pub mod trap_system;
 pub mod vision_system;
 pub mod weapon_generation;
+pub mod weather_system;
 pub mod xp_system;
 
 pub use ai_actions::*;
@@ -36,16 +47,23 @@ pub use ai_conditions::*;
 pub use ai_system::*;
 pub use ai_util::*;
 pub use collider_recalc_system::*;
+pub use companion_system::*;
 pub use condition_blink_system::*;
 pub use condition_system::*;
+pub use crafting_system::*;
+pub use dialogue_system::*;
 pub use energy_system::*;
 pub use explosion_system::*;
 pub use faction_system::*;
+pub use fire_system::ignite_tile;
 pub use game_log_system::*;
 pub use game_systems::*;
+pub use injury_system::*;
 pub use inventory_system::*;
 pub use lighting_system::*;
+pub use quest_system::*;
 pub use stable_id_system::*;
 pub use targeting::*;
+pub use trade_system::*;
 pub use vision_system::*;
 pub use weapon_generation::*;
@@ -0,0 +1,584 @@
+use bevy_ecs::prelude::*;
+use quadboy_macros::profiled_system;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Rand,
+    domain::{
+        Collider, ColliderFlags, CompleteQuestAction, Following, InInventory, Label, Overworld,
+        Player, PlayerMovedEvent, PoiType, Prefab, PrefabId, Prefabs, Quest, QuestLog,
+        QuestObjective, QuestStatus, QuestTarget, SpawnValue, Terrain, Zone,
+        inventory::InventoryChangedEvent,
+        log_message,
+        systems::{
+            destruction_system::EntityDestroyedEvent,
+            game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+        },
+    },
+    engine::{Clock, StableId},
+    rendering::{Position, world_to_zone_idx, zone_local_to_world, zone_xyz},
+};
+
+/// Quests are sent to one of this many of the closest places that fit, so the same
+/// giver doesn't always point at the same spot
+const QUEST_DESTINATION_CHOICES: usize = 3;
+
+const OUTLAW_NICKNAMES: [&str; 8] = [
+    "'Mad Dog'",
+    "'Black Jack'",
+    "'Rattlesnake'",
+    "'Three-Finger'",
+    "'Deacon'",
+    "'Calico'",
+    "'Smiling'",
+    "'Gentleman'",
+];
+
+const OUTLAW_SURNAMES: [&str; 8] = [
+    "McCall", "Ketchum", "Doolin", "Reno", "Slade", "Tolliver", "Crowe", "Hardin",
+];
+
+/// Things a preacher might have lost to the wilds, with the glyph each is drawn with
+const RELICS: [(&str, i32); 4] = [
+    ("Silver Crucifix", 43),
+    ("Family Bible", 112),
+    ("Communion Chalice", 40),
+    ("Saint's Reliquary", 29),
+];
+
+/// Goods that need carrying between towns, with the glyph each is drawn with
+const GOODS: [(&str, i32); 4] = [
+    ("Mail Sack", 26),
+    ("Crate of Medicine", 29),
+    ("Bolt of Calico", 106),
+    ("Sealed Letter", 112),
+];
+
+/// How many zones apart two zones are, counting each level up or down as one
+pub fn zone_distance(a: usize, b: usize) -> usize {
+    let (ax, ay, az) = zone_xyz(a);
+    let (bx, by, bz) = zone_xyz(b);
+
+    ax.abs_diff(bx).max(ay.abs_diff(by)) + az.abs_diff(bz)
+}
+
+/// Picks one of the closest candidate zones, each with a name to send the player to
+fn pick_nearby(
+    world: &mut World,
+    from: usize,
+    mut candidates: Vec<(usize, String)>,
+) -> Option<(usize, String)> {
+    candidates.sort_by_key(|(idx, _)| (zone_distance(from, *idx), *idx));
+    candidates.truncate(QUEST_DESTINATION_CHOICES);
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let idx = world.resource_mut::<Rand>().pick_idx(&candidates);
+    Some(candidates.swap_remove(idx))
+}
+
+fn nearby_poi(world: &mut World, from: usize, types: &[PoiType]) -> Option<(usize, String)> {
+    let candidates = world
+        .resource::<Overworld>()
+        .pois
+        .iter()
+        .filter(|(_, poi)| types.contains(&poi.poi_type))
+        .map(|(idx, poi)| (*idx, poi.name.clone()))
+        .collect();
+
+    pick_nearby(world, from, candidates)
+}
+
+/// Another town for the player to head to. There's one town to each level, so this is
+/// usually a trip up or down
+fn nearby_town(world: &mut World, from: usize) -> Option<(usize, String)> {
+    let home = town_name(world, from);
+    let candidates = world
+        .resource::<Overworld>()
+        .towns
+        .values()
+        .flat_map(|towns| towns.iter())
+        .filter(|(_, town)| home.as_ref() != Some(&town.name))
+        .map(|(idx, town)| (*idx, town.name.clone()))
+        .collect();
+
+    pick_nearby(world, from, candidates)
+}
+
+fn town_name(world: &World, zone_idx: usize) -> Option<String> {
+    let (_, _, z) = zone_xyz(zone_idx);
+
+    world
+        .resource::<Overworld>()
+        .towns
+        .get(&z)
+        .and_then(|towns| towns.get(&zone_idx))
+        .map(|town| town.name.clone())
+}
+
+/// Rolls up a fresh quest of the given kind, sending the player somewhere near the giver
+fn generate_quest(
+    world: &mut World,
+    quest_id: &str,
+    giver: Entity,
+    giver_zone: usize,
+) -> Option<(String, QuestObjective, u32)> {
+    match quest_id {
+        "sheriff_bounty" => {
+            let (zone_idx, place) = nearby_poi(world, giver_zone, &[PoiType::OutlawHideout])?;
+            let mut rand = world.resource_mut::<Rand>();
+            let target = format!(
+                "{} {}",
+                rand.pick(&OUTLAW_NICKNAMES),
+                rand.pick(&OUTLAW_SURNAMES)
+            );
+            let reward = rand.range_n(20, 35) as u32;
+
+            Some((
+                format!("Wanted: {}", target),
+                QuestObjective::Kill {
+                    target,
+                    place,
+                    zone_idx,
+                    spawned: false,
+                },
+                reward,
+            ))
+        }
+        "preacher_relic" => {
+            let (zone_idx, place) = nearby_poi(
+                world,
+                giver_zone,
+                &[
+                    PoiType::GhostTown,
+                    PoiType::AncientRuins,
+                    PoiType::CrashedStagecoach,
+                    PoiType::Mine,
+                ],
+            )?;
+            let mut rand = world.resource_mut::<Rand>();
+            let (item, _) = rand.pick(&RELICS);
+            let reward = rand.range_n(15, 25) as u32;
+
+            Some((
+                format!("The {}", item),
+                QuestObjective::Retrieve {
+                    item: item.to_string(),
+                    place,
+                    zone_idx,
+                    spawned: false,
+                },
+                reward,
+            ))
+        }
+        "townsfolk_delivery" => {
+            let (zone_idx, town) = nearby_town(world, giver_zone)?;
+            let mut rand = world.resource_mut::<Rand>();
+            let (item, _) = rand.pick(&GOODS);
+            let reward = rand.range_n(10, 18) as u32;
+
+            Some((
+                format!("Delivery to {}", town),
+                QuestObjective::Deliver {
+                    item: item.to_string(),
+                    town,
+                    zone_idx,
+                },
+                reward,
+            ))
+        }
+        "townsfolk_escort" => {
+            let (zone_idx, town) = nearby_town(world, giver_zone)?;
+            let npc = world
+                .get::<Label>(giver)
+                .map(|l| l.get().to_string())
+                .unwrap_or_else(|| "Traveler".to_string());
+            let reward = world.resource_mut::<Rand>().range_n(12, 20) as u32;
+
+            Some((
+                format!("Safe Passage to {}", town),
+                QuestObjective::Escort {
+                    npc,
+                    town,
+                    zone_idx,
+                },
+                reward,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Hands the player a new quest from `giver`. Returns false if they're already on it or
+/// there's nowhere nearby to send them.
+pub fn start_quest(world: &mut World, quest_id: &str, player: Entity, giver: Entity) -> bool {
+    if world
+        .get::<QuestLog>(player)
+        .is_some_and(|log| log.in_progress(quest_id).is_some())
+    {
+        return false;
+    }
+
+    let Some(giver_zone) = world.get::<Position>(giver).map(|p| p.zone_idx()) else {
+        return false;
+    };
+
+    let Some((title, objective, reward)) = generate_quest(world, quest_id, giver, giver_zone)
+    else {
+        log_message(
+            world,
+            "There's no work going that'll take you anywhere nearby".to_string(),
+        );
+        return false;
+    };
+
+    let giver_label = world
+        .get::<Label>(giver)
+        .map(|l| l.get().to_string())
+        .unwrap_or_else(|| "Stranger".to_string());
+    let giver_name = match town_name(world, giver_zone) {
+        Some(town) => format!("the {} in {}", giver_label, town),
+        None => format!("the {}", giver_label),
+    };
+
+    let Some(player_id) = world.get::<StableId>(player).copied() else {
+        return false;
+    };
+
+    let description = objective.describe();
+
+    match &objective {
+        QuestObjective::Kill { zone_idx, .. } | QuestObjective::Retrieve { zone_idx, .. } => {
+            // Mark the spot on the player's map
+            world
+                .resource_mut::<Overworld>()
+                .discovered_pois
+                .insert(*zone_idx);
+        }
+        QuestObjective::Deliver { item, .. } => {
+            let (_, glyph) = GOODS
+                .iter()
+                .find(|(name, _)| name == item)
+                .copied()
+                .unwrap_or(GOODS[0]);
+            let pos = world
+                .get::<Position>(player)
+                .map(|p| p.world())
+                .unwrap_or((0, 0, 0));
+            let config = Prefab::new(PrefabId::QuestItem, pos)
+                .with_metadata("label".to_string(), SpawnValue::String(item.clone()))
+                .with_metadata("glyph".to_string(), SpawnValue::Int(glyph));
+
+            // Too heavy to carry and it's left at the player's feet
+            let parcel = Prefabs::spawn_in_inventory(world, config.clone(), player)
+                .unwrap_or_else(|| Prefabs::spawn_world(world, config));
+            world.entity_mut(parcel).insert(QuestTarget::new(quest_id));
+        }
+        QuestObjective::Escort { .. } => {
+            world
+                .entity_mut(giver)
+                .insert((QuestTarget::new(quest_id), Following::new(player_id)));
+        }
+    }
+
+    let target_zone = objective.zone_idx();
+
+    let quest = Quest {
+        id: quest_id.to_string(),
+        title: title.clone(),
+        giver: giver_name,
+        objective,
+        status: QuestStatus::Active,
+        reward,
+    };
+
+    if world.get::<QuestLog>(player).is_none() {
+        world.entity_mut(player).insert(QuestLog::default());
+    }
+
+    if let Some(mut log) = world.get_mut::<QuestLog>(player) {
+        log.quests.push(quest);
+    }
+
+    log_message(
+        world,
+        format!("{{Y|New quest:}} {}. {}", title, description),
+    );
+
+    // The zone might already be loaded, in which case nothing else will spawn the target
+    let loaded = world
+        .query::<&Zone>()
+        .iter(world)
+        .any(|zone| zone.idx == target_zone);
+
+    if loaded {
+        spawn_quest_targets(world, target_zone);
+    }
+
+    true
+}
+
+/// A random spot in the zone that nothing is standing on, away from the zone's edges
+fn find_open_tile(world: &mut World, zone_idx: usize) -> Option<(usize, usize, usize)> {
+    const MARGIN: usize = 3;
+
+    let mut open = {
+        let mut q_zones = world.query::<&Zone>();
+        let zone = q_zones.iter(world).find(|z| z.idx == zone_idx)?;
+
+        zone.terrain
+            .iter_xy()
+            .filter(|(x, y, terrain)| {
+                (MARGIN..ZONE_SIZE.0 - MARGIN).contains(x)
+                    && (MARGIN..ZONE_SIZE.1 - MARGIN).contains(y)
+                    && !matches!(terrain, Terrain::River | Terrain::OpenAir)
+            })
+            .map(|(x, y, _)| zone_local_to_world(zone_idx, x, y))
+            .collect::<Vec<_>>()
+    };
+
+    let blocked = world
+        .query::<(&Position, &Collider)>()
+        .iter(world)
+        .filter(|(position, collider)| {
+            position.zone_idx() == zone_idx
+                && collider
+                    .flags
+                    .intersects(ColliderFlags::BLOCKS_WALK | ColliderFlags::IS_ACTOR)
+        })
+        .map(|(position, _)| position.world())
+        .collect::<Vec<_>>();
+
+    open.retain(|pos| !blocked.contains(pos));
+
+    if open.is_empty() {
+        return None;
+    }
+
+    let mut rand = world.resource_mut::<Rand>();
+    Some(rand.pick(&open))
+}
+
+/// Puts any outlaws and lost items the player's quests are waiting on into a zone that's
+/// just been loaded
+pub fn spawn_quest_targets(world: &mut World, zone_idx: usize) {
+    let Some((player, pending)) = world
+        .query_filtered::<(Entity, &QuestLog), With<Player>>()
+        .iter(world)
+        .next()
+        .map(|(player, log)| {
+            let pending = log
+                .quests
+                .iter()
+                .filter(|q| q.is_in_progress() && q.objective.zone_idx() == zone_idx)
+                .filter(|q| {
+                    matches!(
+                        q.objective,
+                        QuestObjective::Kill { spawned: false, .. }
+                            | QuestObjective::Retrieve { spawned: false, .. }
+                    )
+                })
+                .map(|q| (q.id.clone(), q.objective.clone()))
+                .collect::<Vec<_>>();
+
+            (player, pending)
+        })
+    else {
+        return;
+    };
+
+    for (quest_id, objective) in pending {
+        let Some(pos) = find_open_tile(world, zone_idx) else {
+            continue;
+        };
+
+        let config = match &objective {
+            QuestObjective::Kill { target, .. } => Prefab::new(PrefabId::BanditLeader, pos)
+                .with_metadata("label".to_string(), SpawnValue::String(target.clone())),
+            QuestObjective::Retrieve { item, .. } => {
+                let (_, glyph) = RELICS
+                    .iter()
+                    .find(|(name, _)| name == item)
+                    .copied()
+                    .unwrap_or(RELICS[0]);
+
+                Prefab::new(PrefabId::QuestItem, pos)
+                    .with_metadata("label".to_string(), SpawnValue::String(item.clone()))
+                    .with_metadata("glyph".to_string(), SpawnValue::Int(glyph))
+            }
+            _ => continue,
+        };
+
+        let entity = Prefabs::spawn_world(world, config);
+        world.entity_mut(entity).insert(QuestTarget::new(&quest_id));
+
+        if let Some(mut log) = world.get_mut::<QuestLog>(player)
+            && let Some(quest) = log.in_progress_mut(&quest_id)
+        {
+            match &mut quest.objective {
+                QuestObjective::Kill { spawned, .. } | QuestObjective::Retrieve { spawned, .. } => {
+                    *spawned = true;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Outlaws with a bounty on them dying finishes the job, anyone else a quest depends on
+/// dying or being destroyed sinks it
+#[profiled_system]
+pub fn on_quest_target_destroyed(
+    mut e_destroyed: EventReader<EntityDestroyedEvent>,
+    mut e_game_log: EventWriter<GameLogEvent>,
+    q_targets: Query<(&QuestTarget, Option<&Label>)>,
+    mut q_player: Query<&mut QuestLog, With<Player>>,
+    clock: Res<Clock>,
+) {
+    let Ok(mut log) = q_player.single_mut() else {
+        return;
+    };
+
+    for event in e_destroyed.read() {
+        let Ok((target, label)) = q_targets.get(event.entity) else {
+            continue;
+        };
+
+        let Some(quest) = log.in_progress_mut(&target.quest_id) else {
+            continue;
+        };
+
+        let name = label.map(|l| l.get()).unwrap_or("target");
+
+        let message = match quest.objective {
+            QuestObjective::Kill { .. } => {
+                quest.status = QuestStatus::ReadyToTurnIn;
+                format!(
+                    "{{Y|{}}} is dead. Report back to {} to collect",
+                    name, quest.giver
+                )
+            }
+            _ => {
+                quest.status = QuestStatus::Failed;
+                format!("{{R|Quest failed:}} {}. The {} is lost", quest.title, name)
+            }
+        };
+
+        e_game_log.write(GameLogEvent {
+            message: LogMessage::Custom(message),
+            tick: clock.current_tick(),
+            knowledge: KnowledgeLevel::Player,
+        });
+    }
+}
+
+/// Picking up a lost item is as good as having found it, until it's dropped again
+#[profiled_system]
+pub fn on_quest_inventory_changed(
+    mut e_inventory_changed: EventReader<InventoryChangedEvent>,
+    mut e_game_log: EventWriter<GameLogEvent>,
+    q_targets: Query<(&QuestTarget, &InInventory)>,
+    mut q_player: Query<(&StableId, &mut QuestLog), With<Player>>,
+    clock: Res<Clock>,
+) {
+    if e_inventory_changed.read().count() == 0 {
+        return;
+    }
+
+    let Ok((player_id, mut log)) = q_player.single_mut() else {
+        return;
+    };
+
+    for quest in log.quests.iter_mut().filter(|q| q.is_in_progress()) {
+        let QuestObjective::Retrieve { item, .. } = quest.objective.clone() else {
+            continue;
+        };
+
+        let carried = q_targets
+            .iter()
+            .any(|(target, inv)| target.quest_id == quest.id && inv.owner_id == player_id.0);
+
+        let message = match (quest.status, carried) {
+            (QuestStatus::Active, true) => {
+                quest.status = QuestStatus::ReadyToTurnIn;
+                format!(
+                    "You found the {{Y|{}}}. Bring it back to {}",
+                    item, quest.giver
+                )
+            }
+            (QuestStatus::ReadyToTurnIn, false) => {
+                quest.status = QuestStatus::Active;
+                format!("You no longer have the {{Y|{}}}", item)
+            }
+            _ => continue,
+        };
+
+        e_game_log.write(GameLogEvent {
+            message: LogMessage::Custom(message),
+            tick: clock.current_tick(),
+            knowledge: KnowledgeLevel::Player,
+        });
+    }
+}
+
+/// Deliveries and escorts are done the moment the player reaches the town with the goods
+/// or the traveler in tow
+#[profiled_system]
+pub fn on_quest_player_moved(
+    mut cmds: Commands,
+    mut e_player_moved: EventReader<PlayerMovedEvent>,
+    q_targets: Query<(&QuestTarget, Option<&InInventory>, Option<&Position>)>,
+    mut q_player: Query<(Entity, &StableId, &mut QuestLog), With<Player>>,
+    overworld: Res<Overworld>,
+) {
+    let Some(moved) = e_player_moved.read().last() else {
+        return;
+    };
+
+    let Ok((player_entity, player_id, mut log)) = q_player.single_mut() else {
+        return;
+    };
+
+    let player_zone = world_to_zone_idx(moved.x, moved.y, moved.z);
+
+    // Any part of the destination town will do, not just the zone the quest pointed at
+    let (_, _, z) = zone_xyz(player_zone);
+    let Some(player_town) = overworld
+        .towns
+        .get(&z)
+        .and_then(|towns| towns.get(&player_zone))
+        .map(|town| town.name.as_str())
+    else {
+        return;
+    };
+
+    for quest in log.quests.iter_mut() {
+        if quest.status != QuestStatus::Active {
+            continue;
+        }
+
+        let arrived = match &quest.objective {
+            QuestObjective::Deliver { town, .. } if town == player_town => {
+                q_targets.iter().any(|(target, inv, _)| {
+                    target.quest_id == quest.id && inv.is_some_and(|i| i.owner_id == player_id.0)
+                })
+            }
+            QuestObjective::Escort { town, .. } if town == player_town => {
+                q_targets.iter().any(|(target, _, pos)| {
+                    target.quest_id == quest.id && pos.is_some_and(|p| p.zone_idx() == player_zone)
+                })
+            }
+            _ => false,
+        };
+
+        if arrived {
+            quest.status = QuestStatus::ReadyToTurnIn;
+            cmds.queue(CompleteQuestAction {
+                player_entity,
+                quest_id: quest.id.clone(),
+            });
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use bevy_ecs::prelude::*;
+use quadboy_macros::profiled_system;
+
+use crate::{
+    common::Rand,
+    domain::{
+        ActiveConditions, Collider, ColliderFlags, Condition, ConditionSource, ConditionType,
+        Energy, GameSettings, Health, NeedType, Needs, Player, Prefab, PrefabId, Prefabs, Sleeping,
+        StatModifier, StatModifiers, Terrain, Zone, log_message,
+        systems::game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+    },
+    engine::Clock,
+    rendering::{Position, world_to_zone_local, zone_local_to_world},
+};
+
+/// How far from the sleeper an ambush shows up
+const AMBUSH_MIN_DISTANCE: usize = 6;
+const AMBUSH_MAX_DISTANCE: usize = 10;
+
+fn onset_message(condition: &ConditionType) -> &'static str {
+    match condition {
+        ConditionType::Hungry => "{Y|Your stomach growls.} You're getting hungry",
+        ConditionType::Starving => "{R|You're starving.} Your strength is leaving you",
+        ConditionType::Thirsty => "{Y|Your mouth is dry.} You're getting thirsty",
+        ConditionType::Parched => "{R|You're parched.} Your head swims in the heat",
+        ConditionType::Tired => "{Y|Your eyelids droop.} You could use some sleep",
+        ConditionType::Exhausted => "{R|You're dead on your feet.} You need to sleep",
+        _ => "",
+    }
+}
+
+/// Keeps the player's survival conditions, and the stat penalties they carry, in step
+/// with how hungry, thirsty and tired they are. Turning needs off clears them all.
+#[profiled_system]
+pub fn update_needs(
+    mut cmds: Commands,
+    mut q_player: Query<
+        (
+            Entity,
+            Option<&mut Needs>,
+            Option<&mut ActiveConditions>,
+            &mut StatModifiers,
+            Has<Sleeping>,
+        ),
+        With<Player>,
+    >,
+    clock: Res<Clock>,
+    settings: Res<GameSettings>,
+    mut e_game_log: EventWriter<GameLogEvent>,
+) {
+    let Ok((player, needs, conditions, mut stat_modifiers, is_sleeping)) = q_player.single_mut()
+    else {
+        return;
+    };
+
+    let Some(mut needs) = needs else {
+        cmds.entity(player).insert(Needs::default());
+        return;
+    };
+
+    let delta = clock.tick_delta();
+
+    if delta > 0 && settings.survival_needs {
+        needs.add(NeedType::Hunger, delta);
+        needs.add(NeedType::Thirst, delta);
+
+        if is_sleeping {
+            needs.rest(delta);
+        } else {
+            needs.add(NeedType::Fatigue, delta);
+        }
+    }
+
+    let desired = NeedType::all()
+        .iter()
+        .filter(|_| settings.survival_needs)
+        .filter_map(|need| needs.condition(*need))
+        .collect::<Vec<_>>();
+
+    let current = conditions
+        .as_ref()
+        .map(|c| {
+            c.conditions
+                .iter()
+                .map(|c| c.condition_type.clone())
+                .filter(|c| c.is_need())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if desired.len() == current.len() && desired.iter().all(|c| current.contains(c)) {
+        return;
+    }
+
+    let mut new_conditions = vec![];
+
+    for need in NeedType::all() {
+        for condition_type in need.conditions() {
+            stat_modifiers.remove_condition_modifiers(&condition_type.to_string());
+        }
+    }
+
+    for condition_type in desired.iter() {
+        for (stat, value) in condition_type.stat_penalties() {
+            stat_modifiers.add_modifier(
+                stat,
+                StatModifier::condition(value, condition_type.to_string()),
+            );
+        }
+
+        if !current.contains(condition_type) {
+            e_game_log.write(GameLogEvent {
+                message: LogMessage::Custom(onset_message(condition_type).to_string()),
+                tick: clock.current_tick(),
+                knowledge: KnowledgeLevel::Player,
+            });
+        }
+
+        new_conditions.push(Condition::new(
+            condition_type.clone(),
+            condition_type.get_base_duration_ticks(),
+            1.0,
+            ConditionSource::environment(),
+        ));
+    }
+
+    match conditions {
+        Some(mut conditions) => {
+            for condition_type in current.iter().filter(|c| !desired.contains(c)) {
+                for removed in conditions.remove_condition(condition_type) {
+                    if let Some(spawner) = removed.particle_spawner_entity {
+                        cmds.entity(spawner).despawn();
+                    }
+                }
+            }
+
+            for condition in new_conditions {
+                if !current.contains(&condition.condition_type) {
+                    conditions.add_condition(condition);
+                }
+            }
+        }
+        None => {
+            let mut conditions = ActiveConditions::new();
+            for condition in new_conditions {
+                conditions.add_condition(condition);
+            }
+            cmds.entity(player).insert(conditions);
+        }
+    }
+}
+
+/// A random open spot at ambush range from the sleeper, inside their zone
+fn find_ambush_tile(
+    world: &mut World,
+    center: (usize, usize, usize),
+) -> Option<(usize, usize, usize)> {
+    let zone_idx = Position::new_world(center).zone_idx();
+    let (cx, cy) = world_to_zone_local(center.0, center.1);
+
+    let mut open = {
+        let mut q_zones = world.query::<&Zone>();
+        let zone = q_zones.iter(world).find(|z| z.idx == zone_idx)?;
+
+        zone.terrain
+            .iter_xy()
+            .filter(|(x, y, terrain)| {
+                let distance = x.abs_diff(cx).max(y.abs_diff(cy));
+                (AMBUSH_MIN_DISTANCE..=AMBUSH_MAX_DISTANCE).contains(&distance)
+                    && !matches!(terrain, Terrain::River | Terrain::OpenAir)
+            })
+            .map(|(x, y, _)| zone_local_to_world(zone_idx, x, y))
+            .collect::<Vec<_>>()
+    };
+
+    let blocked = world
+        .query::<(&Position, &Collider)>()
+        .iter(world)
+        .filter(|(position, collider)| {
+            position.zone_idx() == zone_idx
+                && collider
+                    .flags
+                    .intersects(ColliderFlags::BLOCKS_WALK | ColliderFlags::IS_ACTOR)
+        })
+        .map(|(position, _)| position.world())
+        .collect::<Vec<_>>();
+
+    open.retain(|pos| !blocked.contains(pos));
+
+    if open.is_empty() {
+        return None;
+    }
+
+    let mut rand = world.resource_mut::<Rand>();
+    Some(rand.pick(&open))
+}
+
+/// Something comes out of the dark while the player sleeps: a handful of coyotes, or
+/// bandits after an easy mark at night
+fn spawn_ambush(world: &mut World, player_pos: (usize, usize, usize)) -> bool {
+    let is_night = {
+        let hour = world.resource::<Clock>().get_hour();
+        !(6..20).contains(&hour)
+    };
+
+    let (prefab, count) = {
+        let mut rand = world.resource_mut::<Rand>();
+        if is_night && rand.bool(0.5) {
+            (PrefabId::Bandit, 2)
+        } else {
+            (PrefabId::Coyote, rand.range_n(2, 4) as usize)
+        }
+    };
+
+    let mut spawned = 0;
+    for _ in 0..count {
+        let Some(pos) = find_ambush_tile(world, player_pos) else {
+            continue;
+        };
+        Prefabs::spawn_world(world, Prefab::new(prefab.clone(), pos));
+        spawned += 1;
+    }
+
+    spawned > 0
+}
+
+fn wake(world: &mut World, player: Entity, message: &str) {
+    world.entity_mut(player).remove::<Sleeping>();
+
+    // Sleep was paid for up front in energy; whatever's left of it is forgiven
+    if let Some(mut energy) = world.get_mut::<Energy>(player) {
+        energy.value = energy.value.max(0);
+    }
+
+    log_message(world, message.to_string());
+}
+
+/// Wakes the player once they've slept their fill, or early when something attacks
+pub fn process_sleep(world: &mut World) {
+    let mut q_sleeper = world.query_filtered::<(
+        Entity,
+        &Sleeping,
+        &Position,
+        Option<&Health>,
+        Option<&Needs>,
+    ), With<Player>>();
+
+    let Ok((player, sleeping, position, health, needs)) = q_sleeper.single(world) else {
+        return;
+    };
+
+    let sleeping = sleeping.clone();
+    let player_pos = position.world();
+    let was_hurt = health.is_some_and(|h| h.last_damage_tick > sleeping.started_tick);
+    let rested = needs.is_none_or(|n| n.fatigue == 0);
+    let now = world.resource::<Clock>().current_tick();
+
+    if was_hurt {
+        wake(world, player, "{R|Pain jolts you awake!}");
+        return;
+    }
+
+    if let Some(ambush_tick) = sleeping.ambush_tick
+        && now >= ambush_tick
+    {
+        if spawn_ambush(world, player_pos) {
+            wake(world, player, "{R|You wake to movement in the dark!}");
+        } else if let Some(mut sleeping) = world.get_mut::<Sleeping>(player) {
+            // Nowhere for anything to come from, sleep on
+            sleeping.ambush_tick = None;
+        }
+        return;
+    }
+
+    if now >= sleeping.until_tick {
+        let message = if rested {
+            "You wake up rested"
+        } else {
+            "You wake up, still tired"
+        };
+        wake(world, player, message);
+    }
+}
@@ -4,8 +4,8 @@ use macroquad::input::KeyCode;
 use crate::{
     common::Palette,
     domain::{
-        DefaultMeleeAttack, EquipmentSlot, EquipmentSlots, Health, IgnoreLighting, Label, Level,
-        Player, StatType, Stats, Weapon, WeaponFamily, WeaponType, Zone,
+        BodyLocation, DefaultMeleeAttack, EquipmentSlot, EquipmentSlots, Health, IgnoreLighting,
+        Label, Level, Player, StatType, Stats, Weapon, WeaponFamily, WeaponType, Zone,
     },
     engine::{KeyInput, Mouse, StableId, StableIdRegistry},
     rendering::{
@@ -21,6 +21,7 @@ pub struct TargetCycling {
     pub targets: Vec<(Entity, (f32, f32, f32), f32)>, // Entity, position, distance
     pub current_index: Option<usize>,
     pub current_selected_entity: Option<Entity>,
+    pub aimed_location: Option<BodyLocation>,
 }
 
 #[derive(Component)]
@@ -37,6 +38,7 @@ pub fn init_targeting_resource(cmds: &mut Commands) {
         targets: Vec::new(),
         current_index: None,
         current_selected_entity: None,
+        aimed_location: None,
     });
 }
 
@@ -222,6 +224,19 @@ pub fn update_target_cycling(mut target_cycling: ResMut<TargetCycling>, keys: Re
             target_cycling.current_selected_entity = Some(*entity);
         }
     }
+
+    // Z cycles the aimed body location: none -> head -> torso -> arms -> legs -> none
+    if keys.is_pressed(KeyCode::Z) {
+        let locations = BodyLocation::all();
+        target_cycling.aimed_location = match target_cycling.aimed_location {
+            None => locations.first().copied(),
+            Some(location) => locations
+                .iter()
+                .position(|l| *l == location)
+                .and_then(|idx| locations.get(idx + 1))
+                .copied(),
+        };
+    }
 }
 
 pub fn update_mouse_targeting(mut target_cycling: ResMut<TargetCycling>, mouse: Res<Mouse>) {
@@ -401,7 +416,10 @@ pub fn render_target_info(
                 // Show and update target info text (just the name)
                 *text_visibility = Visibility::Visible;
 
-                text.value = name;
+                text.value = match target_cycling.aimed_location {
+                    Some(location) => format!("{} [{}]", name, location.display_name()),
+                    None => name,
+                };
                 text_pos.x = pos.0.floor() + 1.;
                 text_pos.y = pos.1.floor();
                 text_pos.z = pos.2.floor();
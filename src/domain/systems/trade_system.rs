@@ -0,0 +1,247 @@
+use bevy_ecs::prelude::*;
+
+use crate::{
+    common::Rand,
+    domain::{
+        Inventory, Item, ItemRarity, LootTableRegistry, Merchant, Prefab, Prefabs, StackCount,
+        Stackable, StackableType, count_stacked, get_effective_relationship, give_stacked,
+        take_stacked,
+    },
+    engine::{Clock, StableId, StableIdRegistry},
+    rendering::Position,
+};
+
+/// Standing below which a merchant won't deal with you at all
+pub const REFUSE_TRADE_RELATIONSHIP: i8 = -25;
+
+/// How many different wares a merchant puts out when restocking
+const STOCK_SIZE: usize = 10;
+
+/// Gold a merchant has on hand to buy with after restocking
+const PURSE_SIZE: u32 = 150;
+
+/// Which way goods are going, from the customer's side of the counter
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+}
+
+/// What one of an item is worth in gold nuggets, rarity included
+pub fn unit_value(item: Option<&Item>, rarity: Option<&ItemRarity>) -> u32 {
+    let value = item.map(|i| i.value).unwrap_or(0);
+    let multiplier = rarity.map(|r| r.value_multiplier()).unwrap_or(1);
+
+    value * multiplier
+}
+
+pub fn item_unit_value(world: &World, item: Entity) -> u32 {
+    unit_value(world.get::<Item>(item), world.get::<ItemRarity>(item))
+}
+
+/// What a whole stack of an item is worth in gold nuggets
+pub fn item_value(world: &World, item: Entity) -> u32 {
+    let count = world.get::<StackCount>(item).map(|s| s.count).unwrap_or(1);
+    item_unit_value(world, item) * count
+}
+
+pub fn is_gold(world: &World, item: Entity) -> bool {
+    world
+        .get::<Stackable>(item)
+        .is_some_and(|s| s.stack_type == StackableType::GoldNugget)
+}
+
+/// Price of a single item worth `value`. Merchants mark goods up when selling and down
+/// when buying, and the better they think of the customer the fairer the deal.
+pub fn haggle(value: u32, standing: i8, direction: TradeDirection) -> u32 {
+    let value = value as f32;
+    let standing = standing as f32 / 100.0;
+
+    match direction {
+        TradeDirection::Buy => (value * (1.5 - 0.4 * standing)).ceil().max(1.0) as u32,
+        TradeDirection::Sell => (value * (0.5 + 0.2 * standing)).floor() as u32,
+    }
+}
+
+pub fn trade_price(
+    world: &World,
+    item: Entity,
+    merchant: Entity,
+    customer: Entity,
+    direction: TradeDirection,
+) -> u32 {
+    let standing = get_effective_relationship(merchant, customer, world);
+    haggle(item_unit_value(world, item), standing, direction)
+}
+
+/// Gold nuggets carried across every stack in an inventory
+pub fn count_gold(world: &World, owner: Entity) -> u32 {
+    count_stacked(world, owner, StackableType::GoldNugget)
+}
+
+/// Pays out gold nuggets, or returns false without paying anything if there isn't enough
+pub fn take_gold(world: &mut World, owner: Entity, amount: u32) -> bool {
+    take_stacked(world, owner, StackableType::GoldNugget, amount)
+}
+
+pub fn give_gold(world: &mut World, owner: Entity, amount: u32) {
+    give_stacked(world, owner, StackableType::GoldNugget, amount);
+}
+
+/// Folds a single freshly spawned item into a stack of the same kind the owner already has
+pub fn merge_into_existing_stack(world: &mut World, owner: Entity, item: Entity) {
+    let Some(stack_type) = world.get::<Stackable>(item).map(|s| s.stack_type) else {
+        return;
+    };
+
+    let (Some(inventory), Some(registry)) = (
+        world.get::<Inventory>(owner),
+        world.get_resource::<StableIdRegistry>(),
+    ) else {
+        return;
+    };
+
+    let existing = inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| registry.get_entity(StableId(id)))
+        .find(|&entity| {
+            entity != item
+                && world
+                    .get::<Stackable>(entity)
+                    .is_some_and(|s| s.stack_type == stack_type)
+                && world
+                    .get::<StackCount>(entity)
+                    .is_some_and(|s| s.count < StackCount::MAX_STACK_SIZE)
+        });
+
+    let Some(existing) = existing else {
+        return;
+    };
+
+    let item_id = world.get::<StableId>(item).map(|id| id.0);
+    let weight = world.get::<Item>(item).map(|i| i.weight).unwrap_or(0.0);
+
+    if let Some(mut stack) = world.get_mut::<StackCount>(existing) {
+        stack.add(1);
+    }
+
+    if let (Some(item_id), Some(mut inventory)) = (item_id, world.get_mut::<Inventory>(owner)) {
+        inventory.remove_item(item_id, weight);
+    }
+
+    world.entity_mut(item).despawn();
+}
+
+/// Fills a merchant's shelves and purse back up if it has been long enough since they
+/// last restocked. Goods sold to them stay on the shelves until someone buys them.
+pub fn restock_merchant(world: &mut World, merchant_entity: Entity) {
+    let day = world.resource::<Clock>().get_day();
+
+    let Some(merchant) = world.get::<Merchant>(merchant_entity) else {
+        return;
+    };
+
+    if !merchant.needs_restock(day) {
+        return;
+    }
+
+    let stock = merchant.stock;
+
+    let wares = world
+        .get::<Inventory>(merchant_entity)
+        .zip(world.get_resource::<StableIdRegistry>())
+        .map(|(inventory, registry)| {
+            inventory
+                .item_ids
+                .iter()
+                .filter_map(|&id| registry.get_entity(StableId(id)))
+                .filter(|&entity| !is_gold(world, entity))
+                .count()
+        })
+        .unwrap_or(0);
+
+    let prefab_ids = world.resource_scope(|world, mut rand: Mut<Rand>| {
+        world.resource::<LootTableRegistry>().roll_multiple(
+            stock,
+            STOCK_SIZE.saturating_sub(wares),
+            &mut rand,
+        )
+    });
+
+    let pos = world
+        .get::<Position>(merchant_entity)
+        .map(|p| p.world())
+        .unwrap_or((0, 0, 0));
+
+    for prefab_id in prefab_ids {
+        let config = Prefab::new(prefab_id, pos);
+
+        if let Some(item) = Prefabs::spawn_in_inventory(world, config, merchant_entity) {
+            merge_into_existing_stack(world, merchant_entity, item);
+        }
+    }
+
+    let purse = count_gold(world, merchant_entity);
+    if purse < PURSE_SIZE {
+        give_gold(world, merchant_entity, PURSE_SIZE - purse);
+    }
+
+    if let Some(mut merchant) = world.get_mut::<Merchant>(merchant_entity) {
+        merchant.restocked_day = Some(day);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haggle_neutral_prices() {
+        assert_eq!(haggle(10, 0, TradeDirection::Buy), 15);
+        assert_eq!(haggle(10, 0, TradeDirection::Sell), 5);
+    }
+
+    #[test]
+    fn test_haggle_bounds() {
+        // Nothing is ever handed over for free, but worthless junk fetches nothing
+        assert_eq!(haggle(0, 0, TradeDirection::Buy), 1);
+        assert_eq!(haggle(0, 100, TradeDirection::Buy), 1);
+        assert_eq!(haggle(0, 100, TradeDirection::Sell), 0);
+        assert_eq!(haggle(1, -100, TradeDirection::Sell), 0);
+
+        for value in [1, 3, 10, 250] {
+            for standing in -100..=100 {
+                let buy = haggle(value, standing, TradeDirection::Buy);
+                let sell = haggle(value, standing, TradeDirection::Sell);
+
+                assert!(buy >= 1);
+                assert!(
+                    sell < buy,
+                    "selling {} at {} pays {} against buying for {}",
+                    value,
+                    standing,
+                    sell,
+                    buy
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_haggle_standing() {
+        let buy = |standing| haggle(100, standing, TradeDirection::Buy);
+        let sell = |standing| haggle(100, standing, TradeDirection::Sell);
+
+        assert_eq!(buy(100), 110);
+        assert_eq!(buy(-100), 190);
+        assert_eq!(sell(100), 70);
+        assert_eq!(sell(-100), 30);
+
+        // Better standing never makes the deal worse
+        for standing in -100..100 {
+            assert!(buy(standing + 1) <= buy(standing));
+            assert!(sell(standing + 1) >= sell(standing));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use bevy_ecs::prelude::*;
+use quadboy_macros::profiled_system;
+
+use crate::{
+    common::{Rand, algorithm::distance::Distance},
+    domain::{
+        Condition, ConditionSource, ConditionType, CreatureType, ExplosionEvent,
+        ExplosiveProperties, FactionId, Health, InActiveZone, Label, MovementCapabilities,
+        MovementFlags, Player, Prefab, Prefabs, StatType, Stats, Trap, TrapKind, Zone, Zones,
+        apply_condition_to_entity,
+        systems::{
+            destruction_system::EntityDestroyedEvent,
+            game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+        },
+    },
+    engine::Clock,
+    rendering::{LightingData, Position, Visibility, world_to_zone_local},
+};
+
+/// Chance each turn of spotting a hidden trap in full light, before Perception
+const BASE_SPOT_CHANCE: f32 = 0.05;
+/// How much each point of Perception adds to it
+const SPOT_CHANCE_PER_PERCEPTION: f32 = 0.04;
+/// How close a hidden trap has to be before there's any chance of spotting it
+const SPOT_RANGE: f32 = 5.0;
+const BEAR_TRAP_DAMAGE: i32 = 8;
+/// How long the jaws hold on, about five turns
+const BEAR_TRAP_PIN_TICKS: u32 = 500;
+/// A snared critter isn't going anywhere until someone comes to collect it
+const SNARE_PIN_TICKS: u32 = 6000;
+
+fn pin(world: &mut World, victim: Entity, duration: u32) {
+    let condition = Condition::new(
+        ConditionType::Pinned,
+        duration,
+        1.0,
+        ConditionSource::Environment,
+    );
+    let _ = apply_condition_to_entity(victim, condition, world);
+}
+
+/// Springs a trap on whoever just stepped into it. Fliers pass over, and snares only
+/// take small game. Returns whether it went off.
+pub fn spring_trap(world: &mut World, trap_entity: Entity, victim: Entity) -> bool {
+    let Some(kind) = world.get::<Trap>(trap_entity).map(|trap| trap.kind) else {
+        return false;
+    };
+
+    let Some(pos) = world.get::<Position>(trap_entity).map(|p| p.world()) else {
+        return false;
+    };
+
+    if world.get::<Health>(victim).is_none()
+        || world
+            .get::<MovementCapabilities>(victim)
+            .is_some_and(|m| m.flags.contains(MovementFlags::CAN_FLY))
+        || !kind.catches(world.get::<CreatureType>(victim).copied())
+    {
+        return false;
+    }
+
+    let tick = world.resource::<Clock>().current_tick();
+
+    match kind {
+        TrapKind::BearTrap => {
+            if let Some(mut health) = world.get_mut::<Health>(victim) {
+                health.take_damage(BEAR_TRAP_DAMAGE, tick);
+            }
+            pin(world, victim, BEAR_TRAP_PIN_TICKS);
+        }
+        TrapKind::Snare => pin(world, victim, SNARE_PIN_TICKS),
+        TrapKind::Tripwire => {
+            let charge = ExplosiveProperties::dynamite();
+            world.send_event(
+                ExplosionEvent::new(
+                    pos,
+                    charge.radius,
+                    charge.base_damage,
+                    charge.falloff_rate,
+                    charge.explosion_audio,
+                )
+                .with_source(trap_entity),
+            );
+        }
+    }
+
+    world.send_event(EntityDestroyedEvent::environmental(trap_entity, pos, None));
+
+    // Jaws and nooses can be reset, the dynamite's gone for good
+    if kind != TrapKind::Tripwire {
+        Prefabs::spawn_world(world, Prefab::new(kind.kit().prefab_id(), pos));
+    }
+
+    let message = if world.get::<Player>(victim).is_some() {
+        match kind {
+            TrapKind::Tripwire => "You catch your boot on a {R|tripwire}!".to_string(),
+            _ => format!("You step in a {{R|{}}}!", kind.name()),
+        }
+    } else {
+        let name = world
+            .get::<Label>(victim)
+            .map(|label| label.get_base().to_string())
+            .unwrap_or_else(|| "something".to_string());
+
+        match kind {
+            TrapKind::Tripwire => format!("The {} trips a {{R|tripwire}}!", name),
+            _ => format!("The {} is caught in a {{Y|{}}}", name, kind.name()),
+        }
+    };
+
+    world.send_event(GameLogEvent {
+        message: LogMessage::Custom(message),
+        tick,
+        knowledge: KnowledgeLevel::Action {
+            actor: victim,
+            location: pos,
+        },
+    });
+
+    true
+}
+
+/// Gives the player a chance each turn to notice hidden traps nearby, better with
+/// sharp eyes and good light
+#[profiled_system]
+pub fn spot_traps(
+    q_player: Query<(&Position, Option<&Stats>), With<Player>>,
+    mut q_traps: Query<(&mut Trap, &Position), With<InActiveZone>>,
+    q_zones: Query<&Zone>,
+    zones: Res<Zones>,
+    lighting_data: Res<LightingData>,
+    clock: Res<Clock>,
+    mut rand: ResMut<Rand>,
+    mut e_log: EventWriter<GameLogEvent>,
+) {
+    if clock.tick_delta_accum() == 0 {
+        return;
+    }
+
+    let Ok((player_pos, stats)) = q_player.single() else {
+        return;
+    };
+
+    let player_pos = player_pos.world();
+    let perception = stats.map(|s| s.get_stat(StatType::Perception)).unwrap_or(0);
+    let base_chance = BASE_SPOT_CHANCE + perception as f32 * SPOT_CHANCE_PER_PERCEPTION;
+
+    let Some(zone) = zones
+        .cache
+        .get(&Position::new_world(player_pos).zone_idx())
+        .and_then(|&zone_entity| q_zones.get(zone_entity).ok())
+    else {
+        return;
+    };
+
+    for (mut trap, position) in q_traps.iter_mut() {
+        if trap.is_known_by(FactionId::Player) {
+            continue;
+        }
+
+        let pos = position.world();
+
+        if position.zone_idx() != zone.idx
+            || Distance::diagonal(
+                [
+                    player_pos.0 as i32,
+                    player_pos.1 as i32,
+                    player_pos.2 as i32,
+                ],
+                [pos.0 as i32, pos.1 as i32, pos.2 as i32],
+            ) > SPOT_RANGE
+        {
+            continue;
+        }
+
+        let (x, y) = world_to_zone_local(pos.0, pos.1);
+
+        if !zone.visible.get(x, y).copied().unwrap_or(false) {
+            continue;
+        }
+
+        let light = lighting_data
+            .get_light(x, y)
+            .map(|light| light.intensity)
+            .unwrap_or(0.0)
+            .max(lighting_data.get_ambient_intensity())
+            .min(1.0);
+
+        if !rand.bool(base_chance * light) {
+            continue;
+        }
+
+        trap.reveal_to(FactionId::Player);
+
+        e_log.write(GameLogEvent {
+            message: LogMessage::Custom(format!("You spot a {{Y|{}}}", trap.kind.name())),
+            tick: clock.current_tick(),
+            knowledge: KnowledgeLevel::Player,
+        });
+    }
+}
+
+/// Keeps traps out of sight until the player knows they're there
+#[profiled_system]
+pub fn hide_unspotted_traps(mut q_traps: Query<(&Trap, &mut Visibility), Changed<Trap>>) {
+    for (trap, mut visibility) in q_traps.iter_mut() {
+        *visibility = if trap.is_known_by(FactionId::Player) {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     common::algorithm::shadowcast::{ShadowcastSettings, shadowcast},
     domain::{
         ApplyVisibilityEffects, BitmaskGlyph, ColliderFlags, InActiveZone, IsExplored, IsVisible,
-        Player, PlayerPosition, RefreshBitmask, Vision, Zone, Zones,
+        Player, PlayerPosition, RefreshBitmask, Vision, Weather, Zone, Zones,
     },
     engine::Clock,
     rendering::{LightingData, Position, world_to_zone_idx, world_to_zone_local},
@@ -20,6 +20,7 @@ pub fn update_player_vision(
     clock: ResMut<Clock>,
     zones: Res<Zones>,
     lighting_data: Res<LightingData>,
+    weather: Res<Weather>,
 ) {
     // if clock.is_frozen() {
     //     return;
@@ -56,7 +57,8 @@ pub fn update_player_vision(
         let max_vision_range = vision.range;
 
         let daylight = lighting_data.get_ambient_intensity().pow(3.);
-        let vision_range = (daylight * max_vision_range as f32).round().max(2.0) as f64;
+        let visibility = daylight * weather.current().vision_multiplier();
+        let vision_range = (visibility * max_vision_range as f32).round().max(2.0) as f64;
 
         (player_x, player_y, max_vision_range, vision_range)
     };
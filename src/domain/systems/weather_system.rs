@@ -0,0 +1,394 @@
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::*;
+use macroquad::math::{Vec2, Vec4};
+use quadboy_macros::profiled_system;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Rand,
+    domain::{
+        ActiveConditions, Condition, ConditionSource, ConditionType, Destructible, Equipped,
+        GameLogEvent, Health, InActiveZone, KnowledgeLevel, LightBlocker, LightSource, Lightable,
+        LogMessage, MaterialType, Overworld, Player, PlayerPosition, Terrain, TerrainNoise,
+        Weather, WeatherType, Zone, Zones, apply_condition_to_entity,
+    },
+    engine::{Audio, Clock, StableId, StableIdRegistry, TICKS_PER_MINUTE},
+    rendering::{
+        AlphaCurve, AmbientTransition, ColorCurve, Distribution, Glyph, GlyphAnimation,
+        ParticleSpawner, Position, SpawnArea, VelocityCurve, world_to_zone_local,
+    },
+};
+
+/// Weather effects on the world are applied once per in-game minute
+const WEATHER_INTERVAL: u32 = TICKS_PER_MINUTE;
+const AMBIENT_VOLUME: f32 = 0.35;
+/// Chance each minute that the rain gets to an exposed lantern
+const LANTERN_DOUSE_CHANCE: f32 = 0.2;
+/// Tiles sampled each minute when rain muddies the ground or the sun dries it out
+const MUD_SAMPLES: usize = 40;
+const DRY_SAMPLES: usize = 15;
+/// Chance each minute of a lightning flash during a thunderstorm, and that it hits something
+const LIGHTNING_CHANCE: f32 = 0.12;
+const LIGHTNING_IGNITE_CHANCE: f32 = 0.35;
+const LIGHTNING_BURN_DAMAGE: i32 = 2;
+
+/// Marks the particle spawner drawing the current weather over the active zone
+#[derive(Component)]
+pub struct WeatherParticles;
+
+/// How many intervals of the given length the clock crossed since the last update
+pub fn intervals_elapsed(clock: &Clock, interval: u32) -> u32 {
+    let now = clock.current_tick();
+    let before = now.saturating_sub(clock.tick_delta());
+
+    now / interval - before / interval
+}
+
+fn weather_particles(weather_type: WeatherType) -> Option<ParticleSpawner> {
+    let center = Vec2::new(ZONE_SIZE.0 as f32 / 2.0, ZONE_SIZE.1 as f32 / 2.0);
+    let area = SpawnArea::Rectangle {
+        width: ZONE_SIZE.0 as f32,
+        height: ZONE_SIZE.1 as f32,
+        distribution: Distribution::Uniform,
+    };
+
+    match weather_type {
+        WeatherType::Clear => None,
+        WeatherType::DustStorm => Some(
+            ParticleSpawner::new(center)
+                .glyph_animation(GlyphAnimation::RandomPool {
+                    glyphs: vec!['·', '∙', '°', '~'],
+                    change_rate: Some(4.0),
+                    last_change: 0.0,
+                })
+                .color_curve(ColorCurve::Linear {
+                    values: vec![0xD68910, 0x994318],
+                })
+                .alpha_curve(AlphaCurve::EaseOut {
+                    values: vec![0.7, 0.0],
+                })
+                .velocity_curve(VelocityCurve::Linear {
+                    values: vec![Vec2::new(14.0, 1.0), Vec2::new(10.0, -1.0)],
+                })
+                .spawn_area(area)
+                .gravity(Vec2::ZERO)
+                .priority(90)
+                .spawn_rate(160.0)
+                .lifetime_range(0.8..1.6),
+        ),
+        WeatherType::Rain => Some(
+            ParticleSpawner::new(center)
+                .glyph_animation(GlyphAnimation::RandomPool {
+                    glyphs: vec!['|', '\'', ','],
+                    change_rate: None,
+                    last_change: 0.0,
+                })
+                .color_curve(ColorCurve::Constant(0x53ACE7))
+                .alpha_curve(AlphaCurve::EaseOut {
+                    values: vec![0.6, 0.1],
+                })
+                .velocity_curve(VelocityCurve::Constant(Vec2::new(-2.0, 16.0)))
+                .spawn_area(area)
+                .gravity(Vec2::ZERO)
+                .priority(90)
+                .spawn_rate(140.0)
+                .lifetime_range(0.3..0.6),
+        ),
+        WeatherType::Thunderstorm => Some(
+            ParticleSpawner::new(center)
+                .glyph_animation(GlyphAnimation::RandomPool {
+                    glyphs: vec!['\'', ',', '`', '·'],
+                    change_rate: Some(6.0),
+                    last_change: 0.0,
+                })
+                .color_curve(ColorCurve::Constant(0x7D91A7))
+                .alpha_curve(AlphaCurve::EaseOut {
+                    values: vec![0.5, 0.0],
+                })
+                .velocity_curve(VelocityCurve::Linear {
+                    values: vec![Vec2::new(-12.0, 2.0), Vec2::new(-8.0, 0.0)],
+                })
+                .spawn_area(area)
+                .gravity(Vec2::ZERO)
+                .priority(90)
+                .spawn_rate(50.0)
+                .lifetime_range(0.6..1.2),
+        ),
+    }
+}
+
+/// Works out the weather over the player's zone and swaps the particles, ambience and log
+/// message over when it changes, whether the sky turned or the player walked out from under it
+#[profiled_system]
+pub fn update_weather(
+    mut cmds: Commands,
+    mut weather: ResMut<Weather>,
+    clock: Res<Clock>,
+    player_pos: Res<PlayerPosition>,
+    overworld: Res<Overworld>,
+    mut rand: ResMut<Rand>,
+    audio: Option<ResMut<Audio>>,
+    q_particles: Query<Entity, With<WeatherParticles>>,
+    mut e_game_log: EventWriter<GameLogEvent>,
+) {
+    let tick = clock.current_tick();
+    let weather_type = weather.weather_at(&overworld, player_pos.zone_idx(), tick, &mut rand);
+    let previous = weather.active;
+
+    if previous == Some(weather_type) {
+        return;
+    }
+
+    weather.active = Some(weather_type);
+
+    // A freshly loaded game picks up the weather quietly
+    if previous.is_some() {
+        e_game_log.write(GameLogEvent {
+            message: LogMessage::Custom(weather_type.arrival_message().to_string()),
+            tick,
+            knowledge: KnowledgeLevel::Player,
+        });
+    }
+
+    if let Some(mut audio) = audio {
+        audio.set_ambient(weather_type.ambient_audio(), AMBIENT_VOLUME);
+    }
+
+    for entity in q_particles.iter() {
+        cmds.entity(entity).despawn();
+    }
+
+    if let Some(spawner) = weather_particles(weather_type) {
+        cmds.queue(move |world: &mut World| {
+            let entity = spawner.spawn_persistent(world, None);
+            world.entity_mut(entity).insert(WeatherParticles);
+        });
+    }
+}
+
+/// Rain soaks anyone out in it, puts out fires and lanterns and turns dirt to mud.
+/// Once it stops the mud slowly dries back out.
+#[profiled_system]
+pub fn rain_system(
+    mut cmds: Commands,
+    weather: Res<Weather>,
+    clock: Res<Clock>,
+    player_pos: Res<PlayerPosition>,
+    zones: Res<Zones>,
+    registry: Res<StableIdRegistry>,
+    mut rand: ResMut<Rand>,
+    mut terrain_noise: ResMut<TerrainNoise>,
+    mut q_zones: Query<&mut Zone>,
+    q_exposed: Query<(Entity, &Position, Option<&ActiveConditions>), With<InActiveZone>>,
+    q_actors: Query<(), With<Health>>,
+    mut q_lanterns: Query<(
+        &mut LightSource,
+        &mut Lightable,
+        Option<&Position>,
+        Option<&Equipped>,
+        Has<InActiveZone>,
+    )>,
+    q_positions: Query<(&Position, Has<Player>)>,
+    mut q_terrain_tiles: Query<(&Position, &mut Terrain, &mut Glyph), With<InActiveZone>>,
+    mut e_game_log: EventWriter<GameLogEvent>,
+) {
+    let intervals = intervals_elapsed(&clock, WEATHER_INTERVAL);
+
+    if intervals == 0 {
+        return;
+    }
+
+    let player_zone_idx = player_pos.zone_idx();
+    let is_raining = weather.current().is_wet();
+
+    if is_raining {
+        for (entity, position, conditions) in q_exposed.iter() {
+            if position.zone_idx() != player_zone_idx {
+                continue;
+            }
+
+            let is_burning = conditions.is_some_and(|c| c.is_burning());
+
+            if !q_actors.contains(entity) && !is_burning {
+                continue;
+            }
+
+            cmds.queue(move |world: &mut World| soak(world, entity));
+        }
+
+        for (mut light, mut lightable, position, equipped, in_active_zone) in q_lanterns.iter_mut()
+        {
+            if !light.is_enabled {
+                continue;
+            }
+
+            // Carried lanterns are wherever their owner is
+            let exposed_at = match equipped {
+                Some(equipped) => registry
+                    .get_entity(StableId(equipped.owner_id))
+                    .and_then(|owner| q_positions.get(owner).ok())
+                    .map(|(pos, is_player)| (pos.zone_idx(), is_player)),
+                None => position
+                    .filter(|_| in_active_zone)
+                    .map(|pos| (pos.zone_idx(), false)),
+            };
+
+            let Some((zone_idx, owner_is_player)) = exposed_at else {
+                continue;
+            };
+
+            if zone_idx != player_zone_idx || !rand.bool(LANTERN_DOUSE_CHANCE) {
+                continue;
+            }
+
+            light.is_enabled = false;
+            lightable.update_label(false);
+
+            if owner_is_player {
+                e_game_log.write(GameLogEvent {
+                    message: LogMessage::Custom("The rain puts out your {Y|lantern}.".to_string()),
+                    tick: clock.current_tick(),
+                    knowledge: KnowledgeLevel::Player,
+                });
+            }
+        }
+    }
+
+    let Some(zone_entity) = zones.cache.get(&player_zone_idx) else {
+        return;
+    };
+
+    let Ok(mut zone) = q_zones.get_mut(*zone_entity) else {
+        return;
+    };
+
+    let (from, to, samples) = if is_raining {
+        (Terrain::Dirt, Terrain::Mud, MUD_SAMPLES)
+    } else {
+        (Terrain::Mud, Terrain::Dirt, DRY_SAMPLES)
+    };
+
+    let mut changed = HashSet::new();
+
+    for _ in 0..samples * intervals.min(10) as usize {
+        let x = rand.range_n(0, ZONE_SIZE.0 as i32) as usize;
+        let y = rand.range_n(0, ZONE_SIZE.1 as i32) as usize;
+
+        if zone.terrain.get(x, y) == Some(&from) {
+            zone.terrain.set(x, y, to);
+            changed.insert((x, y));
+        }
+    }
+
+    if changed.is_empty() {
+        return;
+    }
+
+    for (position, mut terrain, mut glyph) in q_terrain_tiles.iter_mut() {
+        if position.zone_idx() != player_zone_idx {
+            continue;
+        }
+
+        let world_pos = position.world();
+        let local = world_to_zone_local(world_pos.0, world_pos.1);
+
+        if !changed.contains(&local) {
+            continue;
+        }
+
+        let style = terrain_noise.style(to, (world_pos.0, world_pos.1));
+
+        *terrain = to;
+        glyph.idx = style.idx;
+        glyph.fg1 = style.fg1.map(|c| c.into());
+        glyph.fg2 = style.fg2.map(|c| c.into());
+        glyph.bg = style.bg.map(|c| c.into());
+    }
+}
+
+/// Soaks an entity through, or keeps it soaked if it already is
+fn soak(world: &mut World, entity: Entity) {
+    let duration = ConditionType::Wet.get_base_duration_ticks();
+
+    if let Some(mut conditions) = world.get_mut::<ActiveConditions>(entity)
+        && let Some(wet) = conditions
+            .conditions
+            .iter_mut()
+            .find(|c| c.condition_type == ConditionType::Wet)
+    {
+        wet.duration_remaining = duration;
+        return;
+    }
+
+    let condition = Condition::new(
+        ConditionType::Wet,
+        duration,
+        1.0,
+        ConditionSource::Environment,
+    );
+    let _ = apply_condition_to_entity(entity, condition, world);
+}
+
+/// Thunderstorms light up the sky now and then, and sometimes the bolt finds a tree
+#[profiled_system]
+pub fn lightning_system(
+    mut cmds: Commands,
+    weather: Res<Weather>,
+    clock: Res<Clock>,
+    player_pos: Res<PlayerPosition>,
+    mut rand: ResMut<Rand>,
+    mut ambient_transition: ResMut<AmbientTransition>,
+    q_tall_wood: Query<
+        (Entity, &Position, &Destructible),
+        (With<LightBlocker>, With<InActiveZone>),
+    >,
+    mut e_game_log: EventWriter<GameLogEvent>,
+) {
+    if !weather.current().has_lightning() {
+        return;
+    }
+
+    let intervals = intervals_elapsed(&clock, WEATHER_INTERVAL);
+
+    if !(0..intervals).any(|_| rand.bool(LIGHTNING_CHANCE)) {
+        return;
+    }
+
+    ambient_transition.flash(Vec4::new(0.85, 0.9, 1.0, 1.0), 0.6);
+
+    let player_zone_idx = player_pos.zone_idx();
+    let targets = q_tall_wood
+        .iter()
+        .filter(|(_, position, destructible)| {
+            position.zone_idx() == player_zone_idx
+                && destructible.material_type == MaterialType::Wood
+                && !destructible.is_destroyed()
+        })
+        .map(|(entity, position, _)| (entity, position.world()))
+        .collect::<Vec<_>>();
+
+    let strike = (!targets.is_empty() && rand.bool(LIGHTNING_IGNITE_CHANCE))
+        .then(|| targets[rand.pick_idx(&targets)]);
+
+    let Some((entity, _)) = strike else {
+        return;
+    };
+
+    e_game_log.write(GameLogEvent {
+        message: LogMessage::Custom(
+            "{Y|Lightning} strikes nearby and sets wood alight!".to_string(),
+        ),
+        tick: clock.current_tick(),
+        knowledge: KnowledgeLevel::Player,
+    });
+
+    cmds.queue(move |world: &mut World| {
+        let burning = ConditionType::Burning {
+            damage_per_tick: LIGHTNING_BURN_DAMAGE,
+        };
+        let duration = burning.get_base_duration_ticks();
+        let condition = Condition::new(burning, duration, 1.0, ConditionSource::Environment);
+        let _ = apply_condition_to_entity(entity, condition, world);
+    });
+}
@@ -1,7 +1,7 @@
 use bevy_ecs::world::World;
 use std::fmt::Display;
 
-use crate::domain::{BiomeRegistry, LootTableId, Terrain, ZoneFactory};
+use crate::domain::{BiomeRegistry, LootTableId, PrefabId, Terrain, ZoneFactory};
 
 pub trait Biome: Send + Sync {
     fn base_terrain(&self) -> Terrain;
@@ -9,6 +9,9 @@ pub trait Biome: Send + Sync {
     fn ground_loot_table_id(&self) -> LootTableId;
     fn chest_loot_table_id(&self) -> LootTableId;
     fn enemy_table_id(&self) -> LootTableId;
+    /// Scattered plants and the rough share of open tiles they cover,
+    /// used when a neighbouring zone blends this biome across its border
+    fn foliage(&self) -> &'static [(PrefabId, f32)];
     fn generate(&self, zone: &mut ZoneFactory, world: &World);
 }
 
@@ -20,6 +23,7 @@ pub enum BiomeType {
     DustyPlains,
     Cavern,
     MushroomForest,
+    FloodedMine,
     Mountain,
     Swamp,
 }
@@ -33,6 +37,7 @@ impl BiomeType {
             BiomeType::DustyPlains => 0x2A2518,
             BiomeType::Cavern => 0x15151B,
             BiomeType::MushroomForest => 0x1A101F,
+            BiomeType::FloodedMine => 0x10161C,
             BiomeType::Mountain => 0x1D2631,
             BiomeType::Swamp => 0x1F151F,
         }
@@ -46,6 +51,7 @@ impl BiomeType {
             BiomeType::DustyPlains => 0.95,
             BiomeType::Cavern => 0.4,
             BiomeType::MushroomForest => 0.5,
+            BiomeType::FloodedMine => 0.3,
             BiomeType::Mountain => 0.85,
             BiomeType::Swamp => 0.8,
         }
@@ -61,6 +67,7 @@ impl BiomeType {
             | BiomeType::Swamp => true,
             BiomeType::Cavern => false,
             BiomeType::MushroomForest => false,
+            BiomeType::FloodedMine => false,
         }
     }
 
@@ -76,6 +83,7 @@ impl BiomeType {
                 BiomeType::DustyPlains => Terrain::Dirt,
                 BiomeType::Cavern => Terrain::Dirt,
                 BiomeType::MushroomForest => Terrain::Sand,
+                BiomeType::FloodedMine => Terrain::Dirt,
                 BiomeType::Mountain => Terrain::Dirt,
                 BiomeType::Swamp => Terrain::Dirt,
             }
@@ -94,6 +102,7 @@ impl BiomeType {
                 BiomeType::DustyPlains => Terrain::DyingGrass,
                 BiomeType::Cavern => Terrain::Sand,
                 BiomeType::MushroomForest => Terrain::Dirt,
+                BiomeType::FloodedMine => Terrain::Gravel,
                 BiomeType::Mountain => Terrain::Gravel,
                 BiomeType::Swamp => Terrain::Swamp,
             }
@@ -110,6 +119,7 @@ impl Display for BiomeType {
             BiomeType::DustyPlains => write!(f, "DustyPlains"),
             BiomeType::Cavern => write!(f, "Cavern"),
             BiomeType::MushroomForest => write!(f, "MushroomForest"),
+            BiomeType::FloodedMine => write!(f, "FloodedMine"),
             BiomeType::Mountain => write!(f, "Mountain"),
             BiomeType::Swamp => write!(f, "Swamp"),
         }
@@ -1,7 +1,7 @@
 use crate::{
     cfg::ZONE_SIZE,
     common::{Grid, Rand},
-    domain::{Biome, LootTableId, Prefab, PrefabId, Terrain, ZoneFactory},
+    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory},
     rendering::zone_local_to_world,
 };
 use bevy_ecs::world::World;
@@ -37,16 +37,25 @@ impl Biome for CavernBiome {
         LootTableId::CavernEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::GiantMushroom, 0.01)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         apply_base_terrain(zone, self.base_terrain());
 
         let boulder_grid = generate_cavern_boulder_ca(zone, &mut rand);
+        let mushroom_grid = generate_giant_mushrooms(zone, &mut rand, Some(&boulder_grid));
 
-        place_feature_grid(zone, &boulder_grid, PrefabId::Boulder);
-
-        generate_giant_mushrooms(zone, &mut rand, Some(&boulder_grid));
+        let wall_grid = place_connected_features(
+            zone,
+            &[
+                (&boulder_grid, PrefabId::Boulder),
+                (&mushroom_grid, PrefabId::GiantMushroom),
+            ],
+        );
 
         spawn_loot_and_enemies(
             zone,
@@ -55,7 +64,7 @@ impl Biome for CavernBiome {
             self.chest_loot_table_id(),
             world,
             &mut rand,
-            Some(&boulder_grid),
+            Some(&wall_grid),
         );
     }
 }
@@ -64,7 +73,9 @@ fn generate_giant_mushrooms(
     zone: &mut ZoneFactory,
     rand: &mut Rand,
     exclude_grid: Option<&Grid<bool>>,
-) {
+) -> Grid<bool> {
+    let mut mushroom_grid = Grid::init(ZONE_SIZE.0, ZONE_SIZE.1, false);
+
     for x in 0..ZONE_SIZE.0 {
         for y in 0..ZONE_SIZE.1 {
             if zone.is_locked_tile(x, y) {
@@ -79,8 +90,10 @@ fn generate_giant_mushrooms(
 
             let wpos = zone_local_to_world(zone.zone_idx, x, y);
             if rand.bool(0.0025 * wpos.2 as f32) {
-                zone.push_entity(x, y, Prefab::new(PrefabId::GiantMushroom, wpos));
+                mushroom_grid.set(x, y, true);
             }
         }
     }
+
+    mushroom_grid
 }
@@ -1,7 +1,10 @@
 use crate::{
     cfg::ZONE_SIZE,
     common::{Grid, Palette, Rand},
-    domain::{Biome, LootTableId, Prefab, PrefabId, SpawnValue, Terrain, ZoneFactory},
+    domain::{
+        Biome, LootTableId, Prefab, PrefabId, SpawnValue, Terrain, ZoneConnectivity, ZoneFactory,
+        ZoneTemplateId,
+    },
     rendering::zone_local_to_world,
 };
 use bevy_ecs::world::World;
@@ -37,13 +40,27 @@ impl Biome for DesertBiome {
         LootTableId::DesertEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::Cactus, 0.02)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         apply_base_terrain(zone, self.base_terrain());
+        stamp_zone_templates(
+            zone,
+            &mut rand,
+            &[
+                (ZoneTemplateId::BanditCamp, 0.15),
+                (ZoneTemplateId::Homestead, 0.1),
+                (ZoneTemplateId::Graveyard, 0.05),
+            ],
+        );
 
         let constraint_grid = collect_constraint_grid(zone);
-        let boulder_grid = generate_desert_boulder_ca(&constraint_grid, &mut rand);
+        let mut boulder_grid = generate_desert_boulder_ca(&constraint_grid, &mut rand);
+        ZoneConnectivity::connect(zone, &mut boulder_grid, &[]);
 
         place_desert_boulders(zone, &boulder_grid);
 
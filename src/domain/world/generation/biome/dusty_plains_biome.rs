@@ -1,7 +1,9 @@
 use crate::{
     cfg::ZONE_SIZE,
     common::{Grid, Palette, Rand},
-    domain::{Biome, LootTableId, Prefab, PrefabId, SpawnValue, Terrain, ZoneFactory},
+    domain::{
+        Biome, LootTableId, Prefab, PrefabId, SpawnValue, Terrain, ZoneFactory, ZoneTemplateId,
+    },
     rendering::zone_local_to_world,
 };
 use bevy_ecs::world::World;
@@ -37,10 +39,23 @@ impl Biome for DustyPlainsBiome {
         LootTableId::DustyPlainsEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::Cactus, 0.015), (PrefabId::Tree, 0.005)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         apply_base_terrain(zone, self.base_terrain());
+        stamp_zone_templates(
+            zone,
+            &mut rand,
+            &[
+                (ZoneTemplateId::Homestead, 0.2),
+                (ZoneTemplateId::Graveyard, 0.1),
+                (ZoneTemplateId::BanditCamp, 0.1),
+            ],
+        );
 
         let constraint_grid = collect_constraint_grid(zone);
 
@@ -66,7 +81,7 @@ fn generate_dusty_plains_cacti(
 ) {
     for x in 0..ZONE_SIZE.0 {
         for y in 0..ZONE_SIZE.1 {
-            if zone.is_locked_tile(x, y) {
+            if zone.is_locked_tile(x, y) || zone.grid_data.is_occupied_tile(x, y) {
                 continue;
             }
 
@@ -91,7 +106,7 @@ fn generate_dusty_plains_pine_trees(
 ) {
     for x in 0..ZONE_SIZE.0 {
         for y in 0..ZONE_SIZE.1 {
-            if zone.is_locked_tile(x, y) {
+            if zone.is_locked_tile(x, y) || zone.grid_data.is_occupied_tile(x, y) {
                 continue;
             }
 
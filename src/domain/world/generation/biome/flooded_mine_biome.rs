@@ -0,0 +1,191 @@
+use crate::{
+    cfg::{SURFACE_LEVEL_Z, ZONE_SIZE},
+    common::{Grid, Rand},
+    domain::{
+        Biome, LootTableId, Prefab, PrefabId, Terrain, ZoneConnectivity, ZoneFactory, ZoneGrid,
+    },
+    rendering::{zone_local_to_world, zone_xyz},
+};
+use bevy_ecs::world::World;
+
+use super::super::biome_helpers::*;
+
+const GOLD_VEIN_CHANCE: f32 = 0.08;
+
+pub struct FloodedMineBiome;
+
+impl FloodedMineBiome {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Biome for FloodedMineBiome {
+    fn base_terrain(&self) -> Terrain {
+        Terrain::Gravel
+    }
+
+    fn road_terrain(&self) -> Terrain {
+        Terrain::Dirt
+    }
+
+    fn ground_loot_table_id(&self) -> LootTableId {
+        LootTableId::FloodedMineGroundLoot
+    }
+
+    fn chest_loot_table_id(&self) -> LootTableId {
+        LootTableId::FloodedMineChestLoot
+    }
+
+    fn enemy_table_id(&self) -> LootTableId {
+        LootTableId::FloodedMineEnemies
+    }
+
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[]
+    }
+
+    fn generate(&self, zone: &mut ZoneFactory, world: &World) {
+        let mut rand = Rand::seed(zone.zone_idx as u32);
+
+        apply_base_terrain(zone, self.base_terrain());
+
+        let (mut rock_grid, haulage) = carve_mine_workings(zone, &mut rand);
+
+        ZoneConnectivity::connect(zone, &mut rock_grid, &[haulage[0]]);
+
+        flood_mine_workings(zone, &rock_grid);
+
+        place_mine_rock(zone, &mut rand, &rock_grid);
+
+        lay_mine_rails(zone, &rock_grid, &haulage);
+
+        spawn_loot_and_enemies(
+            zone,
+            self.ground_loot_table_id(),
+            self.enemy_table_id(),
+            self.chest_loot_table_id(),
+            world,
+            &mut rand,
+            Some(&rock_grid),
+        );
+    }
+}
+
+/// Starts from solid rock and digs out haulage ways (running north/south), drifts (running
+/// east/west) and a handful of stopes. Returns the rock that is left and the haulage tiles.
+fn carve_mine_workings(zone: &ZoneFactory, rand: &mut Rand) -> (Grid<bool>, Vec<(usize, usize)>) {
+    let mut rock = Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
+        !should_keep_clear_cavern(zone, x, y) && !zone.grid_data.is_locked_tile(x, y)
+    });
+
+    let mut haulage = vec![];
+    let num_haulage_ways = rand.range_n(1, 3);
+
+    for _ in 0..num_haulage_ways {
+        let x = rand.range_n(8, ZONE_SIZE.0 as i32 - 8) as usize;
+
+        for y in 2..ZONE_SIZE.1 - 2 {
+            rock.set(x, y, false);
+            haulage.push((x, y));
+        }
+    }
+
+    let num_drifts = rand.range_n(2, 5);
+
+    for _ in 0..num_drifts {
+        let y = rand.range_n(3, ZONE_SIZE.1 as i32 - 3) as usize;
+        let start = rand.range_n(2, ZONE_SIZE.0 as i32 / 2) as usize;
+        let end = rand.range_n(ZONE_SIZE.0 as i32 / 2, ZONE_SIZE.0 as i32 - 2) as usize;
+
+        for x in start..end {
+            rock.set(x, y, false);
+        }
+    }
+
+    let num_stopes = rand.range_n(3, 7);
+
+    for _ in 0..num_stopes {
+        let width = rand.range_n(3, 8) as usize;
+        let height = rand.range_n(2, 5) as usize;
+        let x = rand.range_n(2, (ZONE_SIZE.0 - width - 2) as i32) as usize;
+        let y = rand.range_n(2, (ZONE_SIZE.1 - height - 2) as i32) as usize;
+
+        for sx in x..x + width {
+            for sy in y..y + height {
+                rock.set(sx, sy, false);
+            }
+        }
+    }
+
+    (rock, haulage)
+}
+
+/// Water pools in the low spots of the workings, and the deeper the level the more of it there is
+fn flood_mine_workings(zone: &mut ZoneFactory, rock_grid: &Grid<bool>) {
+    let (_, _, z) = zone_xyz(zone.zone_idx);
+    let depth = z.saturating_sub(SURFACE_LEVEL_Z + 2) as f32;
+    let shallows_threshold = (0.6 - depth * 0.03).max(0.4);
+    let deep_threshold = shallows_threshold + 0.12;
+
+    let water = ZoneGrid::perlin(zone.zone_idx as u32 + 7000, 0.08, 2, 2.0);
+
+    for x in 0..ZONE_SIZE.0 {
+        for y in 0..ZONE_SIZE.1 {
+            if zone.is_locked_tile(x, y) || *rock_grid.get(x, y).unwrap_or(&true) {
+                continue;
+            }
+
+            let level = *water.get(x, y).unwrap_or(&0.0);
+
+            if level > deep_threshold {
+                zone.set_terrain(x, y, Terrain::River);
+            } else if level > shallows_threshold {
+                zone.set_terrain(x, y, Terrain::Shallows);
+            }
+        }
+    }
+}
+
+/// Rock that faces an open tunnel sometimes shows a vein of gold
+fn place_mine_rock(zone: &mut ZoneFactory, rand: &mut Rand, rock_grid: &Grid<bool>) {
+    for x in 0..ZONE_SIZE.0 {
+        for y in 0..ZONE_SIZE.1 {
+            if !*rock_grid.get(x, y).unwrap_or(&false) {
+                continue;
+            }
+
+            let faces_tunnel = [(-1, 0), (1, 0), (0, -1), (0, 1)].iter().any(|(dx, dy)| {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                nx >= 0 && ny >= 0 && !*rock_grid.get(nx as usize, ny as usize).unwrap_or(&true)
+            });
+
+            let prefab_id = if faces_tunnel && rand.bool(GOLD_VEIN_CHANCE) {
+                PrefabId::GoldVein
+            } else {
+                PrefabId::Boulder
+            };
+
+            let wpos = zone_local_to_world(zone.zone_idx, x, y);
+            zone.push_entity(x, y, Prefab::new(prefab_id, wpos));
+        }
+    }
+}
+
+/// Rails run the length of each haulage way, except where it is under deep water
+fn lay_mine_rails(zone: &mut ZoneFactory, rock_grid: &Grid<bool>, haulage: &[(usize, usize)]) {
+    for &(x, y) in haulage {
+        if zone.is_locked_tile(x, y) || *rock_grid.get(x, y).unwrap_or(&true) {
+            continue;
+        }
+
+        if zone.grid_data.terrain.get(x, y) == Some(&Terrain::River) {
+            continue;
+        }
+
+        let wpos = zone_local_to_world(zone.zone_idx, x, y);
+        zone.push_entity(x, y, Prefab::new(PrefabId::MineRail, wpos));
+    }
+}
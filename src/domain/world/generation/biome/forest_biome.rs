@@ -1,7 +1,7 @@
 use crate::{
     cfg::ZONE_SIZE,
     common::{Grid, Rand},
-    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory},
+    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory, ZoneTemplateId},
 };
 use bevy_ecs::world::World;
 
@@ -37,11 +37,23 @@ impl Biome for ForestBiome {
         LootTableId::ForestEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::PineTree, 0.15)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         // Apply base terrain
         apply_base_terrain(zone, self.base_terrain());
+        stamp_zone_templates(
+            zone,
+            &mut rand,
+            &[
+                (ZoneTemplateId::Homestead, 0.1),
+                (ZoneTemplateId::Graveyard, 0.1),
+            ],
+        );
 
         // Generate boulders first
         let constraints = collect_constraint_grid(zone);
@@ -56,11 +68,15 @@ impl Biome for ForestBiome {
         let tree_grid = generate_forest_tree_ca(&boulder_constraint_grid, &mut rand);
 
         // Place generated features
-        place_feature_grid(zone, &boulder_grid, PrefabId::Boulder);
-        place_feature_grid(zone, &tree_grid, PrefabId::PineTree);
+        let exclude = place_connected_features(
+            zone,
+            &[
+                (&boulder_grid, PrefabId::Boulder),
+                (&tree_grid, PrefabId::PineTree),
+            ],
+        );
 
         // Spawn loot and enemies with standard 1% chances
-        let exclude = combine_grids(&boulder_grid, &tree_grid);
         spawn_loot_and_enemies(
             zone,
             self.ground_loot_table_id(),
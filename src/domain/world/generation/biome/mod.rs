@@ -2,6 +2,7 @@ pub mod biome_type;
 pub mod cavern_biome;
 pub mod desert_biome;
 pub mod dusty_plains_biome;
+pub mod flooded_mine_biome;
 pub mod forest_biome;
 pub mod mountain_biome;
 pub mod mushroom_forest_biome;
@@ -12,6 +13,7 @@ pub use biome_type::*;
 pub use cavern_biome::*;
 pub use desert_biome::*;
 pub use dusty_plains_biome::*;
+pub use flooded_mine_biome::*;
 pub use forest_biome::*;
 pub use mountain_biome::*;
 pub use mushroom_forest_biome::*;
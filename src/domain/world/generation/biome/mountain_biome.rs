@@ -2,7 +2,7 @@ use crate::{
     cfg::ZONE_SIZE,
     common::algorithm::{ca_rules::*, cellular_automata::*},
     common::{Grid, Rand},
-    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory},
+    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory, ZoneTemplateId},
 };
 use bevy_ecs::world::World;
 
@@ -37,11 +37,23 @@ impl Biome for MountainBiome {
         LootTableId::MountainEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::PineTree, 0.08)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         // Apply base terrain (grass)
         apply_base_terrain(zone, self.base_terrain());
+        stamp_zone_templates(
+            zone,
+            &mut rand,
+            &[
+                (ZoneTemplateId::MineEntrance, 0.3),
+                (ZoneTemplateId::BanditCamp, 0.1),
+            ],
+        );
 
         // Generate constraint grid first
         let constraints = collect_constraint_grid(zone);
@@ -57,11 +69,15 @@ impl Biome for MountainBiome {
         let pine_grid = generate_mountain_pine_trees(&boulder_constraint_grid, &mut rand);
 
         // Place generated features
-        place_feature_grid(zone, &boulder_grid, PrefabId::Boulder);
-        place_feature_grid(zone, &pine_grid, PrefabId::PineTree);
+        let exclude = place_connected_features(
+            zone,
+            &[
+                (&boulder_grid, PrefabId::Boulder),
+                (&pine_grid, PrefabId::PineTree),
+            ],
+        );
 
         // Spawn loot and enemies
-        let exclude = combine_grids(&boulder_grid, &pine_grid);
         spawn_loot_and_enemies(
             zone,
             self.ground_loot_table_id(),
@@ -2,10 +2,9 @@ use crate::{
     cfg::ZONE_SIZE,
     common::{
         Grid, Rand,
-        algorithm::{ca_rules::*, cellular_automata::*},
+        algorithm::{ca_rules::*, cellular_automata::*, wfc::*},
     },
-    domain::{Biome, LootTableId, Prefab, PrefabId, Terrain, ZoneFactory},
-    rendering::zone_local_to_world,
+    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory},
 };
 use bevy_ecs::world::World;
 
@@ -40,16 +39,26 @@ impl Biome for MushroomForestBiome {
         LootTableId::MushroomForestEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::GiantMushroom, 0.12)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         apply_base_terrain(zone, self.base_terrain());
 
         let boulder_grid = generate_mushroom_forest_boulder_ca(zone, &mut rand);
+        let mushroom_grid =
+            generate_mushroom_forest_mushrooms(zone, &mut rand, Some(&boulder_grid));
 
-        place_feature_grid(zone, &boulder_grid, PrefabId::Boulder);
-
-        generate_mushroom_forest_mushrooms(zone, &mut rand, Some(&boulder_grid));
+        let wall_grid = place_connected_features(
+            zone,
+            &[
+                (&boulder_grid, PrefabId::Boulder),
+                (&mushroom_grid, PrefabId::GiantMushroom),
+            ],
+        );
 
         spawn_loot_and_enemies(
             zone,
@@ -58,7 +67,7 @@ impl Biome for MushroomForestBiome {
             self.chest_loot_table_id(),
             world,
             &mut rand,
-            Some(&boulder_grid),
+            Some(&wall_grid),
         );
     }
 }
@@ -115,29 +124,58 @@ fn generate_mushroom_forest_boulder_ca(zone: &ZoneFactory, rand: &mut Rand) -> G
     ca.grid().clone()
 }
 
+/// Example the grove layout is learned from. `M` is a giant mushroom, `,` the clearing
+/// that rings every grove and `.` open ground, so groves never butt straight onto it.
+const MUSHROOM_GROVE_EXAMPLE: [&str; 7] = [
+    "..............",
+    "....,,,.......",
+    "...,MM,,......",
+    "...,MMM,......",
+    "....,M,.......",
+    ".....,........",
+    "..............",
+];
+
 fn generate_mushroom_forest_mushrooms(
     zone: &mut ZoneFactory,
     rand: &mut Rand,
     exclude_grid: Option<&Grid<bool>>,
-) {
-    for x in 0..ZONE_SIZE.0 {
-        for y in 0..ZONE_SIZE.1 {
-            if zone.is_locked_tile(x, y) {
-                continue;
-            }
-
-            if let Some(grid) = exclude_grid
-                && *grid.get(x, y).unwrap_or(&false)
-            {
-                continue;
-            }
-
-            let wpos = zone_local_to_world(zone.zone_idx, x, y);
+) -> Grid<bool> {
+    let example = Grid::init_fill(
+        MUSHROOM_GROVE_EXAMPLE[0].len(),
+        MUSHROOM_GROVE_EXAMPLE.len(),
+        |x, y| MUSHROOM_GROVE_EXAMPLE[y].as_bytes()[x] as char,
+    );
+
+    // Open ground is played down so groves get the chance to grow out from a mushroom
+    // before the clearing closes in around it
+    let mut rules = WfcRules::from_example(&example);
+    rules.set_weight(&'.', 20.0);
+    rules.set_weight(&'M', 7.0);
+
+    let mut wfc = WaveFunctionCollapse::new(ZONE_SIZE.0, ZONE_SIZE.1, rules);
+
+    if let Some(exclude_grid) = exclude_grid {
+        wfc = wfc.with_constraints(exclude_grid.clone());
+    }
 
-            // Much higher chance for giant mushrooms in mushroom forests
-            if rand.bool(0.025) {
-                zone.push_entity(x, y, Prefab::new(PrefabId::GiantMushroom, wpos));
+    // Roads, rivers and stairs are seeded as open ground, so groves keep a clearing
+    // between themselves and anything already stamped into the zone
+    for y in 0..ZONE_SIZE.1 {
+        for x in 0..ZONE_SIZE.0 {
+            if zone.is_locked_tile(x, y) {
+                wfc.set_constraint(x, y, true);
+                wfc.set(x, y, &'.');
             }
         }
     }
+
+    let groves = wfc.solve(rand);
+
+    Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
+        groves
+            .as_ref()
+            .and_then(|groves| groves.get(x, y))
+            .is_some_and(|tile| *tile == Some('M'))
+    })
 }
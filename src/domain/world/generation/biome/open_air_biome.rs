@@ -1,4 +1,4 @@
-use crate::domain::{Biome, LootTableId, Terrain, ZoneFactory};
+use crate::domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory};
 use bevy_ecs::world::World;
 
 use super::super::biome_helpers::*;
@@ -32,6 +32,10 @@ impl Biome for OpenAirBiome {
         LootTableId::OpenAirEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, _world: &World) {
         apply_base_terrain(zone, self.base_terrain());
     }
@@ -1,7 +1,9 @@
 use crate::{
     cfg::ZONE_SIZE,
     common::{Grid, Rand},
-    domain::{Biome, LootTableId, PrefabId, Terrain, ZoneFactory},
+    domain::{
+        Biome, LootTableId, PrefabId, Terrain, ZoneConnectivity, ZoneFactory, ZoneTemplateId,
+    },
 };
 use bevy_ecs::world::World;
 
@@ -37,17 +39,23 @@ impl Biome for SwampBiome {
         LootTableId::SwampEnemies
     }
 
+    fn foliage(&self) -> &'static [(PrefabId, f32)] {
+        &[(PrefabId::BaldCypress, 0.2)]
+    }
+
     fn generate(&self, zone: &mut ZoneFactory, world: &World) {
         let mut rand = Rand::seed(zone.zone_idx as u32);
 
         apply_base_terrain(zone, self.base_terrain());
+        stamp_zone_templates(zone, &mut rand, &[(ZoneTemplateId::Graveyard, 0.1)]);
 
         let constraints = collect_constraint_grid(zone);
 
         let water_grid = generate_swamp_water_ca(&constraints, &mut rand);
         place_water_terrain(zone, &water_grid);
 
-        let tree_grid = generate_swamp_tree_ca(&constraints, &mut rand);
+        let mut tree_grid = generate_swamp_tree_ca(&constraints, &mut rand);
+        ZoneConnectivity::connect(zone, &mut tree_grid, &[]);
         place_feature_grid(zone, &tree_grid, PrefabId::BaldCypress);
 
         let exclude = tree_grid;
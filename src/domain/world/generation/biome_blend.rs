@@ -0,0 +1,180 @@
+use crate::{
+    cfg::{SURFACE_LEVEL_Z, ZONE_SIZE},
+    common::{Grid, Rand},
+    domain::{
+        BiomeRegistry, BiomeType, LootTableId, OverworldZone, Prefab, PrefabId, Terrain,
+        ZoneFactory, ZoneGrid,
+    },
+    rendering::{zone_local_to_world, zone_xyz},
+};
+
+/// How many tiles in from each edge a zone starts to take on its neighbour's look
+const BLEND_MARGIN: usize = 10;
+
+/// What a neighbouring biome brings across the border
+pub struct BlendNeighbor {
+    pub biome_type: BiomeType,
+    pub base_terrain: Terrain,
+    pub enemy_table_id: LootTableId,
+    pub foliage: &'static [(PrefabId, f32)],
+}
+
+/// Decides, for every tile near a zone edge, whether it belongs to the neighbouring biome
+/// instead. The chance falls off with distance from the edge following
+/// `ZoneGrid::edge_gradient`, and is broken up with noise so the border doesn't run straight.
+/// Right on the edge it is an even split, so both sides of a border meet halfway.
+pub struct BiomeBlend {
+    base_terrain: Terrain,
+    foliage: &'static [(PrefabId, f32)],
+    neighbors: Vec<BlendNeighbor>,
+    claims: Grid<Option<usize>>,
+}
+
+impl BiomeBlend {
+    pub fn new(ozone: &OverworldZone, registry: &BiomeRegistry) -> Self {
+        let Some(biome) = registry.get(ozone.biome_type) else {
+            return Self::none();
+        };
+
+        let mut neighbors = vec![];
+        let mut edge_neighbor = [None; 4];
+        let edges = [
+            ozone.neighbor_biomes.north,
+            ozone.neighbor_biomes.south,
+            ozone.neighbor_biomes.east,
+            ozone.neighbor_biomes.west,
+        ];
+
+        for (edge, neighbor_biome) in edges.into_iter().enumerate() {
+            let Some(neighbor_biome) = neighbor_biome.filter(|b| *b != ozone.biome_type) else {
+                continue;
+            };
+
+            let Some(neighbor) = registry.get(neighbor_biome) else {
+                continue;
+            };
+
+            let idx = neighbors
+                .iter()
+                .position(|n: &BlendNeighbor| n.biome_type == neighbor_biome)
+                .unwrap_or_else(|| {
+                    neighbors.push(BlendNeighbor {
+                        biome_type: neighbor_biome,
+                        base_terrain: neighbor.base_terrain(),
+                        enemy_table_id: neighbor.enemy_table_id(),
+                        foliage: neighbor.foliage(),
+                    });
+                    neighbors.len() - 1
+                });
+
+            edge_neighbor[edge] = Some(idx);
+        }
+
+        let gradient = ZoneGrid::edge_gradient(BLEND_MARGIN, 1.0);
+        let perlin = ZoneGrid::perlin(ozone.zone_idx as u32 + 9000, 0.15, 2, 2.0);
+        let mut rand = Rand::seed(ozone.zone_idx as u32 + 9000);
+
+        let claims = Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
+            let noise = (*perlin.get(x, y).unwrap_or(&0.5) + rand.random()) / 2.0;
+            let weight = 0.5 * (1.0 - *gradient.get(x, y).unwrap_or(&1.0));
+
+            if noise >= weight {
+                return None;
+            }
+
+            // The closest edge decides whose border this is
+            let distances = [y, ZONE_SIZE.1 - 1 - y, ZONE_SIZE.0 - 1 - x, x];
+            let (edge, _) = distances
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, distance)| **distance)
+                .unwrap();
+
+            edge_neighbor[edge]
+        });
+
+        Self {
+            base_terrain: biome.base_terrain(),
+            foliage: biome.foliage(),
+            neighbors,
+            claims,
+        }
+    }
+
+    pub fn none() -> Self {
+        Self {
+            base_terrain: Terrain::OpenAir,
+            foliage: &[],
+            neighbors: vec![],
+            claims: Grid::init(ZONE_SIZE.0, ZONE_SIZE.1, None),
+        }
+    }
+
+    /// The neighbouring biome this tile has been handed to, if any
+    pub fn claimed_by(&self, x: usize, y: usize) -> Option<&BlendNeighbor> {
+        self.claims
+            .get(x, y)
+            .copied()
+            .flatten()
+            .and_then(|idx| self.neighbors.get(idx))
+    }
+
+    /// Reworks the claimed tiles once the biome has generated: the ground turns to the
+    /// neighbour's, and the biome's own plants give way to the neighbour's at its density.
+    /// Underground, new plants are left out so carved tunnels stay open.
+    pub fn apply(zone: &mut ZoneFactory) {
+        if zone.blend.neighbors.is_empty() {
+            return;
+        }
+
+        let (_, _, z) = zone_xyz(zone.zone_idx);
+        let scatter_foliage = z <= SURFACE_LEVEL_Z;
+        let mut rand = Rand::seed(zone.zone_idx as u32 + 9001);
+
+        for x in 0..ZONE_SIZE.0 {
+            for y in 0..ZONE_SIZE.1 {
+                if zone.is_locked_tile(x, y) {
+                    continue;
+                }
+
+                let Some(neighbor) = zone.blend.claimed_by(x, y) else {
+                    continue;
+                };
+
+                let neighbor_terrain = neighbor.base_terrain;
+                let neighbor_foliage = neighbor.foliage;
+                let own_foliage = zone.blend.foliage;
+
+                if zone.grid_data.terrain.get(x, y) == Some(&zone.blend.base_terrain) {
+                    zone.set_terrain(x, y, neighbor_terrain);
+                }
+
+                let Some(entities) = zone.grid_data.entities.get_mut(x, y) else {
+                    continue;
+                };
+
+                entities.retain(|prefab| {
+                    !own_foliage
+                        .iter()
+                        .any(|(prefab_id, _)| *prefab_id == prefab.prefab_id)
+                });
+
+                if !scatter_foliage || !entities.is_empty() {
+                    continue;
+                }
+
+                let mut roll = rand.random();
+
+                for (prefab_id, density) in neighbor_foliage {
+                    if roll < *density {
+                        let wpos = zone_local_to_world(zone.zone_idx, x, y);
+                        zone.push_entity(x, y, Prefab::new(prefab_id.clone(), wpos));
+                        break;
+                    }
+
+                    roll -= *density;
+                }
+            }
+        }
+    }
+}
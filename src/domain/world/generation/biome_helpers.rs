@@ -6,7 +6,8 @@ use crate::{
         algorithm::{ca_rules::*, cellular_automata::*},
     },
     domain::{
-        LootTableId, LootTableRegistry, Prefab, PrefabId, Terrain, ZoneConstraintType, ZoneFactory,
+        LootTableId, LootTableRegistry, Prefab, PrefabId, TemplateTransform, Terrain,
+        ZoneConnectivity, ZoneConstraintType, ZoneFactory, ZoneTemplateId,
     },
     rendering::zone_local_to_world,
 };
@@ -15,6 +16,7 @@ use bevy_ecs::world::World;
 const LOOT_SPAWN_CHANCE: f32 = 0.01; // 1% chance for loot
 const ENEMY_SPAWN_CHANCE: f32 = 0.008; // .08% chance for enemies
 const CHEST_SPAWN_CHANCE: f32 = 0.003; // 0.3% chance for chests (rarer than regular loot)
+const LOCKED_CHEST_CHANCE: f32 = 0.3; // 30% of those are locked
 
 pub fn apply_base_terrain(zone: &mut ZoneFactory, terrain: Terrain) {
     for x in 0..ZONE_SIZE.0 {
@@ -26,6 +28,25 @@ pub fn apply_base_terrain(zone: &mut ZoneFactory, terrain: Terrain) {
     }
 }
 
+/// Rolls each template in turn and stamps those that succeed, randomly rotated and mirrored.
+/// Towns and points of interest bring their own layouts, so their zones are skipped.
+pub fn stamp_zone_templates(
+    zone: &mut ZoneFactory,
+    rand: &mut Rand,
+    templates: &[(ZoneTemplateId, f32)],
+) {
+    if zone.ozone.town.is_some() || zone.ozone.poi.is_some() {
+        return;
+    }
+
+    for (template_id, chance) in templates {
+        if rand.bool(*chance) {
+            let transform = TemplateTransform::random(rand);
+            template_id.template().stamp(zone, rand, transform);
+        }
+    }
+}
+
 pub fn collect_constraint_grid(zone: &mut ZoneFactory) -> Grid<bool> {
     Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| zone.is_locked_tile(x, y))
 }
@@ -33,7 +54,7 @@ pub fn collect_constraint_grid(zone: &mut ZoneFactory) -> Grid<bool> {
 pub fn place_feature_grid(zone: &mut ZoneFactory, feature_grid: &Grid<bool>, prefab_id: PrefabId) {
     for x in 0..ZONE_SIZE.0 {
         for y in 0..ZONE_SIZE.1 {
-            if *feature_grid.get(x, y).unwrap_or(&false) {
+            if *feature_grid.get(x, y).unwrap_or(&false) && !zone.grid_data.is_occupied_tile(x, y) {
                 let wpos = zone_local_to_world(zone.zone_idx, x, y);
                 zone.push_entity(x, y, Prefab::new(prefab_id.clone(), wpos));
             }
@@ -52,9 +73,18 @@ pub fn spawn_loot_and_enemies(
 ) {
     let loot_registry = world.get_resource::<LootTableRegistry>().unwrap();
 
+    // Points of interest carry their own enemies and chest loot
+    let (enemy_table_id, chest_loot_id) = match &zone.ozone.poi {
+        Some(poi) => (
+            poi.poi_type.enemy_table_id(),
+            poi.poi_type.chest_loot_table_id(),
+        ),
+        None => (enemy_table_id, chest_loot_id),
+    };
+
     for x in 0..ZONE_SIZE.0 {
         for y in 0..ZONE_SIZE.1 {
-            if zone.is_locked_tile(x, y) {
+            if zone.is_locked_tile(x, y) || zone.grid_data.is_occupied_tile(x, y) {
                 continue;
             }
 
@@ -66,6 +96,12 @@ pub fn spawn_loot_and_enemies(
 
             let wpos = zone_local_to_world(zone.zone_idx, x, y);
 
+            // Near a border, enemies wander in from the neighbouring biome
+            let enemy_table_id = match zone.blend.claimed_by(x, y) {
+                Some(neighbor) if zone.ozone.poi.is_none() => neighbor.enemy_table_id,
+                _ => enemy_table_id,
+            };
+
             // Check for enemy spawn (1% chance)
             if rand.bool(ENEMY_SPAWN_CHANCE) && !loot_registry.is_empty(enemy_table_id) {
                 let enemy = loot_registry.roll_guaranteed(enemy_table_id, rand);
@@ -75,24 +111,109 @@ pub fn spawn_loot_and_enemies(
             else if rand.bool(LOOT_SPAWN_CHANCE) && !loot_registry.is_empty(ground_loot_id) {
                 let loot = loot_registry.roll_guaranteed(ground_loot_id, rand);
                 zone.push_entity(x, y, Prefab::new(loot, wpos));
-            } else if rand.bool(CHEST_SPAWN_CHANCE) {
+            } else if rand.bool(CHEST_SPAWN_CHANCE) && !closes_passage(exclude_grid, x, y) {
                 let mut chest_prefab = Prefab::new(PrefabId::Chest, wpos);
                 chest_prefab.metadata.insert(
                     "loot_table_id".to_string(),
                     SpawnValue::LootTableId(chest_loot_id),
                 );
+                if rand.bool(LOCKED_CHEST_CHANCE) {
+                    chest_prefab.metadata.insert(
+                        "lock_difficulty".to_string(),
+                        SpawnValue::Int(rand.range_n(1, 4)),
+                    );
+                }
                 zone.push_entity(x, y, chest_prefab);
             }
         }
     }
 }
 
+/// Whether something solid at this tile would cut off the open tiles around it from each
+/// other, like a chest dropped in the middle of a one tile wide tunnel
+fn closes_passage(walls: Option<&Grid<bool>>, x: usize, y: usize) -> bool {
+    let Some(walls) = walls else {
+        return false;
+    };
+
+    let open = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+    ]
+    .into_iter()
+    .filter(|(dx, dy)| {
+        let nx = x as i32 + dx;
+        let ny = y as i32 + dy;
+
+        nx >= 0
+            && ny >= 0
+            && nx < ZONE_SIZE.0 as i32
+            && ny < ZONE_SIZE.1 as i32
+            && !*walls.get(nx as usize, ny as usize).unwrap_or(&true)
+    })
+    .collect::<Vec<_>>();
+
+    // Count the separate groups of open neighbours, joining any that touch
+    let mut group = (0..open.len()).collect::<Vec<_>>();
+
+    for a in 0..open.len() {
+        for b in a + 1..open.len() {
+            let touching = open[a].0.abs_diff(open[b].0) <= 1 && open[a].1.abs_diff(open[b].1) <= 1;
+
+            if touching {
+                let (from, to) = (group[b], group[a]);
+                group
+                    .iter_mut()
+                    .filter(|g| **g == from)
+                    .for_each(|g| *g = to);
+            }
+        }
+    }
+
+    group.sort_unstable();
+    group.dedup();
+
+    group.len() > 1
+}
+
 pub fn combine_grids(grid1: &Grid<bool>, grid2: &Grid<bool>) -> Grid<bool> {
     Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
         *grid1.get(x, y).unwrap_or(&false) || *grid2.get(x, y).unwrap_or(&false)
     })
 }
 
+pub fn intersect_grids(grid1: &Grid<bool>, grid2: &Grid<bool>) -> Grid<bool> {
+    Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
+        *grid1.get(x, y).unwrap_or(&false) && *grid2.get(x, y).unwrap_or(&false)
+    })
+}
+
+/// Places layers of blocking features, first digging through any of them where needed so
+/// the stairs and every edge opening stay reachable. Returns the blocking tiles that are left.
+pub fn place_connected_features(
+    zone: &mut ZoneFactory,
+    features: &[(&Grid<bool>, PrefabId)],
+) -> Grid<bool> {
+    let mut blocking = Grid::init(ZONE_SIZE.0, ZONE_SIZE.1, false);
+    for (grid, _) in features {
+        blocking = combine_grids(&blocking, grid);
+    }
+
+    ZoneConnectivity::connect(zone, &mut blocking, &[]);
+
+    for (grid, prefab_id) in features {
+        place_feature_grid(zone, &intersect_grids(grid, &blocking), prefab_id.clone());
+    }
+
+    blocking
+}
+
 pub fn generate_desert_boulder_ca(constraint_grid: &Grid<bool>, rand: &mut Rand) -> Grid<bool> {
     let initial_grid = Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
         if *constraint_grid.get(x, y).unwrap_or(&true) {
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use crate::domain::{
-    Biome, BiomeType, CavernBiome, DesertBiome, DustyPlainsBiome, ForestBiome, MountainBiome,
-    MushroomForestBiome, OpenAirBiome, SwampBiome,
+    Biome, BiomeType, CavernBiome, DesertBiome, DustyPlainsBiome, FloodedMineBiome, ForestBiome,
+    MountainBiome, MushroomForestBiome, OpenAirBiome, SwampBiome,
 };
 
 pub struct BiomeRegistry {
@@ -11,6 +11,7 @@ pub struct BiomeRegistry {
     dusty_plains: Arc<DustyPlainsBiome>,
     cavern: Arc<CavernBiome>,
     mushroom_forest: Arc<MushroomForestBiome>,
+    flooded_mine: Arc<FloodedMineBiome>,
     open_air: Arc<OpenAirBiome>,
     mountain: Arc<MountainBiome>,
     swamp: Arc<SwampBiome>,
@@ -24,6 +25,7 @@ impl BiomeRegistry {
             dusty_plains: Arc::new(DustyPlainsBiome::new()),
             cavern: Arc::new(CavernBiome::new()),
             mushroom_forest: Arc::new(MushroomForestBiome::new()),
+            flooded_mine: Arc::new(FloodedMineBiome::new()),
             open_air: Arc::new(OpenAirBiome::new()),
             mountain: Arc::new(MountainBiome::new()),
             swamp: Arc::new(SwampBiome::new()),
@@ -37,6 +39,7 @@ impl BiomeRegistry {
             BiomeType::DustyPlains => Some(self.dusty_plains.clone()),
             BiomeType::Cavern => Some(self.cavern.clone()),
             BiomeType::MushroomForest => Some(self.mushroom_forest.clone()),
+            BiomeType::FloodedMine => Some(self.flooded_mine.clone()),
             BiomeType::OpenAir => Some(self.open_air.clone()),
             BiomeType::Mountain => Some(self.mountain.clone()),
             BiomeType::Swamp => Some(self.swamp.clone()),
@@ -76,43 +76,65 @@ impl ConstraintHandler {
     ) {
         // Handle stairs going up (from down constraints)
         for constraint in &ozone.constraints.down.0 {
-            if constraint.constraint == ZoneConstraintType::StairDown {
-                let (x, y) = constraint.position;
-                let world_pos = zone_local_to_world(ozone.zone_idx, x, y);
-                let stair_config = Prefab::new(PrefabId::StairUp, world_pos);
+            let prefab_id = match constraint.constraint {
+                ZoneConstraintType::StairDown => PrefabId::StairUp,
+                ZoneConstraintType::Shaft => PrefabId::MineLadder,
+                _ => continue,
+            };
 
-                if let Some(entities_at_pos) = entities.get_mut(x, y) {
-                    entities_at_pos.push(stair_config);
-                }
-                locked.set(x, y, true);
-                road_builder.add_connection(RoadConnection {
-                    category: RoadCategory::Stairs,
-                    pos: (x, y),
-                    width: 1,
-                });
-            }
+            Self::place_stair(
+                ozone.zone_idx,
+                constraint.position,
+                prefab_id,
+                entities,
+                locked,
+            );
+            road_builder.add_connection(RoadConnection {
+                category: RoadCategory::Stairs,
+                pos: constraint.position,
+                width: 1,
+            });
         }
 
         // Handle stairs going down (from up constraints)
         for constraint in &ozone.constraints.up.0 {
-            if constraint.constraint == ZoneConstraintType::StairDown {
-                let (x, y) = constraint.position;
-                let world_pos = zone_local_to_world(ozone.zone_idx, x, y);
-                let stair_config = Prefab::new(PrefabId::StairDown, world_pos);
+            let prefab_id = match constraint.constraint {
+                ZoneConstraintType::StairDown => PrefabId::StairDown,
+                ZoneConstraintType::Shaft => PrefabId::MineShaft,
+                _ => continue,
+            };
 
-                if let Some(entities_at_pos) = entities.get_mut(x, y) {
-                    entities_at_pos.push(stair_config);
-                }
-                locked.set(x, y, true);
-                road_builder.add_connection(RoadConnection {
-                    category: RoadCategory::Stairs,
-                    pos: (x, y),
-                    width: 1,
-                });
-            }
+            Self::place_stair(
+                ozone.zone_idx,
+                constraint.position,
+                prefab_id,
+                entities,
+                locked,
+            );
+            road_builder.add_connection(RoadConnection {
+                category: RoadCategory::Stairs,
+                pos: constraint.position,
+                width: 1,
+            });
         }
     }
 
+    fn place_stair(
+        zone_idx: usize,
+        (x, y): (usize, usize),
+        prefab_id: PrefabId,
+        entities: &mut Grid<Vec<Prefab>>,
+        locked: &mut Grid<bool>,
+    ) {
+        let world_pos = zone_local_to_world(zone_idx, x, y);
+        let stair_config = Prefab::new(prefab_id, world_pos);
+
+        if let Some(entities_at_pos) = entities.get_mut(x, y) {
+            entities_at_pos.push(stair_config);
+        }
+        locked.set(x, y, true);
+    }
+
     fn place_boulder(
         zone_idx: usize,
         x: usize,
@@ -337,6 +359,7 @@ impl ConstraintHandler {
             crate::domain::BiomeType::Desert => PrefabId::Cactus,
             crate::domain::BiomeType::DustyPlains => PrefabId::Cactus,
             crate::domain::BiomeType::Cavern => PrefabId::GiantMushroom,
+            crate::domain::BiomeType::FloodedMine => PrefabId::Boulder,
             crate::domain::BiomeType::Mountain => PrefabId::PineTree,
             _ => return,
         };
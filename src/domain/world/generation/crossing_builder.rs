@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Grid,
+    domain::{CrossingType, Prefab, PrefabId, RoadCrossing, SpawnValue, Terrain, ZoneFactory},
+    rendering::zone_local_to_world,
+};
+
+pub struct CrossingBuilder;
+
+impl CrossingBuilder {
+    /// Puts the crossing over the river tiles a road runs across. Fords are already
+    /// shallows by now, so only bridges and ferries need anything placed. A road that
+    /// only clips the edge of a ferried river for less than its full width wades instead.
+    pub fn build(
+        zone: &mut ZoneFactory,
+        road_grid: &Grid<bool>,
+        river_tiles: &[(usize, usize)],
+        road_crossing: RoadCrossing,
+    ) {
+        match road_crossing.crossing_type {
+            CrossingType::Ford => {}
+            CrossingType::Bridge => Self::build_bridge(zone, river_tiles),
+            CrossingType::Ferry => {
+                for crossing in Self::group_crossings(river_tiles) {
+                    if crossing.len() < road_crossing.river_type.width() {
+                        Self::build_ford(zone, &crossing);
+                    } else if !Self::build_ferry(zone, road_grid, &crossing) {
+                        Self::build_bridge(zone, &crossing);
+                    }
+                }
+            }
+        }
+    }
+
+    fn build_ford(zone: &mut ZoneFactory, tiles: &[(usize, usize)]) {
+        for &(x, y) in tiles {
+            zone.set_terrain(x, y, Terrain::Shallows);
+        }
+    }
+
+    fn build_bridge(zone: &mut ZoneFactory, tiles: &[(usize, usize)]) {
+        for &(x, y) in tiles {
+            let wpos = zone_local_to_world(zone.zone_idx, x, y);
+            zone.push_entity(x, y, Prefab::new(PrefabId::WoodenBridge, wpos));
+        }
+    }
+
+    /// Moors a raft at each end of the crossing, each one carrying passengers to the
+    /// road on the opposite bank. Returns false when the road only reaches one bank in
+    /// this zone, in which case there is nowhere for a ferry to go.
+    fn build_ferry(
+        zone: &mut ZoneFactory,
+        road_grid: &Grid<bool>,
+        crossing: &[(usize, usize)],
+    ) -> bool {
+        let banks = Self::road_banks(zone, road_grid, crossing);
+
+        let mut best = None;
+        let mut best_distance = 0;
+
+        for (i, a) in banks.iter().enumerate() {
+            for b in banks.iter().skip(i + 1) {
+                let distance = a.0.abs_diff(b.0).pow(2) + a.1.abs_diff(b.1).pow(2);
+
+                if distance > best_distance {
+                    best_distance = distance;
+                    best = Some((*a, *b));
+                }
+            }
+        }
+
+        let Some((bank_a, bank_b)) = best else {
+            return false;
+        };
+
+        let (Some(dock_a), Some(dock_b)) = (
+            Self::nearest_tile(crossing, bank_a),
+            Self::nearest_tile(crossing, bank_b),
+        ) else {
+            return false;
+        };
+
+        if dock_a == dock_b {
+            return false;
+        }
+
+        for (dock, landing) in [(dock_a, bank_b), (dock_b, bank_a)] {
+            let wpos = zone_local_to_world(zone.zone_idx, dock.0, dock.1);
+            let landing = zone_local_to_world(zone.zone_idx, landing.0, landing.1);
+
+            zone.push_entity(
+                dock.0,
+                dock.1,
+                Prefab::new(PrefabId::Ferry, wpos)
+                    .with_metadata("landing_x".to_owned(), SpawnValue::Int(landing.0 as i32))
+                    .with_metadata("landing_y".to_owned(), SpawnValue::Int(landing.1 as i32))
+                    .with_metadata("landing_z".to_owned(), SpawnValue::Int(landing.2 as i32)),
+            );
+        }
+
+        true
+    }
+
+    /// Dry road tiles touching the crossing
+    fn road_banks(
+        zone: &ZoneFactory,
+        road_grid: &Grid<bool>,
+        crossing: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
+        let mut banks = vec![];
+
+        for &(x, y) in crossing {
+            for (nx, ny) in Self::neighbors(x, y) {
+                if *road_grid.get(nx, ny).unwrap_or(&false)
+                    && zone.grid_data.terrain.get(nx, ny) != Some(&Terrain::River)
+                    && !banks.contains(&(nx, ny))
+                {
+                    banks.push((nx, ny));
+                }
+            }
+        }
+
+        banks
+    }
+
+    fn nearest_tile(tiles: &[(usize, usize)], to: (usize, usize)) -> Option<(usize, usize)> {
+        tiles
+            .iter()
+            .min_by_key(|(x, y)| x.abs_diff(to.0).pow(2) + y.abs_diff(to.1).pow(2))
+            .copied()
+    }
+
+    /// Splits the crossed river tiles into separate crossings, one per stretch of water
+    fn group_crossings(tiles: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+        let remaining = tiles.iter().copied().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let mut groups = vec![];
+
+        for &start in tiles {
+            if !seen.insert(start) {
+                continue;
+            }
+
+            let mut group = vec![];
+            let mut open = vec![start];
+
+            while let Some((x, y)) = open.pop() {
+                group.push((x, y));
+
+                for neighbor in Self::neighbors(x, y) {
+                    if remaining.contains(&neighbor) && seen.insert(neighbor) {
+                        open.push(neighbor);
+                    }
+                }
+            }
+
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    fn neighbors(x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| (dx, dy)))
+            .filter(|&(dx, dy)| dx != 0 || dy != 0)
+            .filter_map(move |(dx, dy)| {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                (nx >= 0 && ny >= 0 && nx < ZONE_SIZE.0 as i32 && ny < ZONE_SIZE.1 as i32)
+                    .then_some((nx as usize, ny as usize))
+            })
+    }
+}
@@ -14,6 +14,7 @@ pub enum LootTableId {
     DustyPlainsGroundLoot,
     CavernGroundLoot,
     MushroomForestGroundLoot,
+    FloodedMineGroundLoot,
     OpenAirGroundLoot,
     MountainGroundLoot,
     SwampGroundLoot,
@@ -24,6 +25,7 @@ pub enum LootTableId {
     DustyPlainsChestLoot,
     CavernChestLoot,
     MushroomForestChestLoot,
+    FloodedMineChestLoot,
     CommonChestLoot,
     MountainChestLoot,
     SwampChestLoot,
@@ -34,10 +36,30 @@ pub enum LootTableId {
     DustyPlainsEnemies,
     CavernEnemies,
     MushroomForestEnemies,
+    FloodedMineEnemies,
     OpenAirEnemies,
     MountainEnemies,
     SwampEnemies,
 
+    // Point of interest tables
+    MineChestLoot,
+    MineEnemies,
+    GhostTownChestLoot,
+    GhostTownEnemies,
+    HideoutChestLoot,
+    HideoutEnemies,
+    OasisChestLoot,
+    OasisEnemies,
+    StagecoachChestLoot,
+    StagecoachEnemies,
+    RuinsChestLoot,
+    RuinsEnemies,
+
+    // Merchant stock tables
+    GeneralStoreStock,
+    SaloonStock,
+    StablesStock,
+
     // Death loot tables
     BanditLoot,
     BrownBearLoot,
@@ -46,6 +68,7 @@ pub enum LootTableId {
     CoyoteLoot,
     GiantFireflyLoot,
     BoulderLoot,
+    GoldVeinLoot,
     RatLoot,
     BeetleLoot,
 }
@@ -88,6 +111,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Pickaxe, 2.0)
                 .add(PrefabId::Apple, 3.0)
                 .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::WoolShirt, 2.0)
                 .add(PrefabId::Overcoat, 1.0)
                 .add(PrefabId::SteelToeBoots, 1.0)
@@ -106,6 +130,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Hatchet, 1.0)
                 .add(PrefabId::Campfire, 3.0)
                 .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Canteen, 1.0)
                 .add(PrefabId::Dynamite, 2.0)
                 .add(PrefabId::Bedroll, 2.0)
                 .build(),
@@ -120,6 +145,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Poncho, 3.0)
                 .add(PrefabId::Duster, 2.0)
                 .add(PrefabId::CanOfBeans, 4.0)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::Dynamite, 1.0)
                 .add(PrefabId::SteelToeBoots, 1.0)
                 .add(PrefabId::DoubleBarrelShotgun, 0.3)
@@ -152,6 +178,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Pickaxe, 3.0)
                 .add(PrefabId::Apple, 2.0)
                 .add(PrefabId::CanOfBeans, 3.5)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::WoolShirt, 2.0)
                 .add(PrefabId::Poncho, 2.0)
                 .add(PrefabId::SteelToeBoots, 1.5)
@@ -181,6 +208,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Lantern, 5.0)
                 .add(PrefabId::CavalrySword, 1.0)
                 .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::Dynamite, 2.0)
                 .add(PrefabId::Overcoat, 1.0)
                 .add(PrefabId::SteelToeBoots, 2.0)
@@ -211,6 +239,7 @@ impl LootTableRegistry {
                 .add(PrefabId::CavalrySword, 1.0)
                 .add(PrefabId::Apple, 3.0)
                 .add(PrefabId::CanOfBeans, 3.5)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::Hatchet, 2.0)
                 .add(PrefabId::WoolShirt, 2.0)
                 .add(PrefabId::Overcoat, 1.0)
@@ -221,6 +250,34 @@ impl LootTableRegistry {
                 .build(),
         );
 
+        // Flooded mine loot (abandoned tools and the odd nugget the miners left behind)
+        self.tables.insert(
+            LootTableId::FloodedMineGroundLoot,
+            LootTable::builder()
+                .add(PrefabId::Pickaxe, 2.0)
+                .add(PrefabId::Lantern, 1.5)
+                .add(PrefabId::Dynamite, 2.0)
+                .add(PrefabId::GoldNugget, 1.5)
+                .add(PrefabId::CanOfBeans, 1.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::FloodedMineChestLoot,
+            LootTable::builder()
+                .add(PrefabId::Dynamite, 5.0)
+                .add(PrefabId::Pickaxe, 4.0)
+                .add(PrefabId::Lantern, 4.0)
+                .add(PrefabId::GoldNugget, 4.0)
+                .add(PrefabId::CanOfBeans, 2.0)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::SteelToeBoots, 2.0)
+                .add(PrefabId::LongJohns, 1.0)
+                .add(PrefabId::NavyRevolver, 0.3)
+                .add(PrefabId::Amulet, 0.2)
+                .build(),
+        );
+
         // OpenAir (minimal loot)
         self.tables
             .insert(LootTableId::OpenAirGroundLoot, LootTable::builder().build());
@@ -247,6 +304,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Lantern, 3.0)
                 .add(PrefabId::CavalrySword, 1.0)
                 .add(PrefabId::CanOfBeans, 4.0)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::WoolShirt, 4.0)
                 .add(PrefabId::Overcoat, 3.0)
                 .add(PrefabId::LongJohns, 3.0)
@@ -267,6 +325,7 @@ impl LootTableRegistry {
                 .add(PrefabId::BrownBear, 0.2)
                 .add(PrefabId::Coyote, 0.4)
                 .add(PrefabId::GiantFirefly, 1.1)
+                .add(PrefabId::Dog, 0.1) // The odd stray, looking for someone to follow
                 .build(),
         );
 
@@ -287,6 +346,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Coyote, 0.6)
                 .add(PrefabId::Rattlesnake, 0.3)
                 .add(PrefabId::GiantBeetle, 1.0)
+                .add(PrefabId::Dog, 0.15)
                 .build(),
         );
 
@@ -309,6 +369,16 @@ impl LootTableRegistry {
                 .build(),
         );
 
+        self.tables.insert(
+            LootTableId::FloodedMineEnemies,
+            LootTable::builder()
+                .add(PrefabId::Rat, 2.5) // Rats thrive in the wet workings
+                .add(PrefabId::Bat, 1.0)
+                .add(PrefabId::GiantBeetle, 1.0)
+                .add(PrefabId::Bandit, 0.3) // The odd claim jumper
+                .build(),
+        );
+
         self.tables
             .insert(LootTableId::OpenAirEnemies, LootTable::builder().build());
 
@@ -344,6 +414,7 @@ impl LootTableRegistry {
                 .add(PrefabId::Pickaxe, 2.5)
                 .add(PrefabId::Apple, 2.5)
                 .add(PrefabId::CanOfBeans, 3.5)
+                .add(PrefabId::Bandage, 1.5)
                 .add(PrefabId::WoolShirt, 2.0)
                 .add(PrefabId::Overcoat, 2.0)
                 .add(PrefabId::SteelToeBoots, 1.0)
@@ -373,17 +444,216 @@ impl LootTableRegistry {
                 .add(PrefabId::Hatchet, 1.0)
                 .add(PrefabId::Apple, 2.0)
                 .add(PrefabId::CanOfBeans, 2.0)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::Cloth, 1.5)
                 .add(PrefabId::Bedroll, 1.0)
+                .add(PrefabId::Scope, 0.2)
+                .add(PrefabId::ExtendedCylinder, 0.2)
+                .add(PrefabId::ChokeTube, 0.2)
+                .add(PrefabId::Bayonet, 0.2)
                 .add(PrefabId::Amulet, 0.1)
                 .add(PrefabId::Ring, 0.1)
                 .build(),
         );
 
+        // Point of interest loot
+        self.tables.insert(
+            LootTableId::MineChestLoot,
+            LootTable::builder()
+                .add(PrefabId::Pickaxe, 4.0)
+                .add(PrefabId::Lantern, 3.0)
+                .add(PrefabId::Dynamite, 4.0)
+                .add(PrefabId::Gunpowder, 3.0)
+                .add(PrefabId::FuseCord, 2.0)
+                .add(PrefabId::GoldNugget, 3.0)
+                .add(PrefabId::CanOfBeans, 2.0)
+                .add(PrefabId::SteelToeBoots, 1.0)
+                .add(PrefabId::Bandage, 1.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::MineEnemies,
+            LootTable::builder()
+                .add(PrefabId::Bat, 1.5)
+                .add(PrefabId::Rat, 1.5)
+                .add(PrefabId::GiantBeetle, 1.0)
+                .add(PrefabId::Bandit, 0.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::GhostTownChestLoot,
+            LootTable::builder()
+                .add(PrefabId::NavyRevolver, 1.0)
+                .add(PrefabId::Duster, 1.5)
+                .add(PrefabId::WoolShirt, 2.0)
+                .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::Cloth, 1.5)
+                .add(PrefabId::Lead, 1.0)
+                .add(PrefabId::Lantern, 2.0)
+                .add(PrefabId::LeverActionRifle, 0.4)
+                .add(PrefabId::Amulet, 0.4)
+                .add(PrefabId::Ring, 0.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::GhostTownEnemies,
+            LootTable::builder()
+                .add(PrefabId::Rat, 2.0)
+                .add(PrefabId::Coyote, 1.0)
+                .add(PrefabId::Rattlesnake, 1.0)
+                .add(PrefabId::Bandit, 0.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::HideoutChestLoot,
+            LootTable::builder()
+                .add(PrefabId::GoldNugget, 3.0)
+                .add(PrefabId::Dynamite, 2.0)
+                .add(PrefabId::NavyRevolver, 1.0)
+                .add(PrefabId::DoubleBarrelShotgun, 0.8)
+                .add(PrefabId::LeverActionRifle, 0.6)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::Scope, 0.3)
+                .add(PrefabId::ExtendedCylinder, 0.3)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::HideoutEnemies,
+            LootTable::builder().add(PrefabId::Bandit, 1.0).build(),
+        );
+
+        self.tables.insert(
+            LootTableId::OasisChestLoot,
+            LootTable::builder()
+                .add(PrefabId::Apple, 4.0)
+                .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::Poncho, 1.0)
+                .add(PrefabId::Lantern, 1.0)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::OasisEnemies,
+            LootTable::builder()
+                .add(PrefabId::Rattlesnake, 1.0)
+                .add(PrefabId::Coyote, 1.0)
+                .add(PrefabId::GiantBeetle, 0.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::StagecoachChestLoot,
+            LootTable::builder()
+                .add(PrefabId::GoldNugget, 4.0)
+                .add(PrefabId::NavyRevolver, 1.0)
+                .add(PrefabId::Overcoat, 1.0)
+                .add(PrefabId::Duster, 1.0)
+                .add(PrefabId::CanOfBeans, 2.0)
+                .add(PrefabId::Bandage, 1.5)
+                .add(PrefabId::Amulet, 0.5)
+                .add(PrefabId::Ring, 0.6)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::StagecoachEnemies,
+            LootTable::builder()
+                .add(PrefabId::Bandit, 1.0)
+                .add(PrefabId::Coyote, 0.5)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::RuinsChestLoot,
+            LootTable::builder()
+                .add(PrefabId::Amulet, 1.5)
+                .add(PrefabId::Ring, 1.5)
+                .add(PrefabId::CavalrySword, 1.0)
+                .add(PrefabId::GoldNugget, 2.0)
+                .add(PrefabId::Lantern, 1.0)
+                .add(PrefabId::Bandage, 1.0)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::RuinsEnemies,
+            LootTable::builder()
+                .add(PrefabId::Rattlesnake, 1.0)
+                .add(PrefabId::Bat, 1.0)
+                .add(PrefabId::GiantBeetle, 1.0)
+                .add(PrefabId::GiantFirefly, 0.5)
+                .build(),
+        );
+
+        // Merchant stock
+        self.tables.insert(
+            LootTableId::GeneralStoreStock,
+            LootTable::builder()
+                .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Apple, 2.0)
+                .add(PrefabId::Bandage, 2.0)
+                .add(PrefabId::Canteen, 1.0)
+                .add(PrefabId::Bedroll, 0.5)
+                .add(PrefabId::Lantern, 1.0)
+                .add(PrefabId::Pickaxe, 1.0)
+                .add(PrefabId::Hatchet, 1.0)
+                .add(PrefabId::Dynamite, 1.0)
+                .add(PrefabId::Gunpowder, 1.5)
+                .add(PrefabId::FuseCord, 1.0)
+                .add(PrefabId::Cloth, 1.5)
+                .add(PrefabId::Lead, 1.0)
+                .add(PrefabId::WoolShirt, 0.5)
+                .add(PrefabId::LongJohns, 0.5)
+                .add(PrefabId::NavyRevolver, 0.3)
+                .add(PrefabId::LeverActionRifle, 0.2)
+                .add(PrefabId::DoubleBarrelShotgun, 0.2)
+                .add(PrefabId::Scope, 0.1)
+                .add(PrefabId::ChokeTube, 0.1)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::SaloonStock,
+            LootTable::builder()
+                .add(PrefabId::CanOfBeans, 3.0)
+                .add(PrefabId::Apple, 2.0)
+                .add(PrefabId::Bandage, 1.0)
+                .add(PrefabId::Ring, 0.2)
+                .add(PrefabId::Amulet, 0.2)
+                .build(),
+        );
+
+        self.tables.insert(
+            LootTableId::StablesStock,
+            LootTable::builder()
+                .add(PrefabId::Bedroll, 2.0)
+                .add(PrefabId::Apple, 2.0)
+                .add(PrefabId::Poncho, 1.0)
+                .add(PrefabId::Duster, 1.0)
+                .add(PrefabId::Overcoat, 0.5)
+                .add(PrefabId::SteelToeBoots, 1.0)
+                .add(PrefabId::Lantern, 1.0)
+                .build(),
+        );
+
         // Death loot tables
         self.tables.insert(
             LootTableId::BanditLoot,
             LootTable::builder()
                 .add(PrefabId::GoldNugget, 1.0)
+                .add(PrefabId::Bandage, 0.3)
+                .add(PrefabId::Gunpowder, 0.3)
+                .add(PrefabId::Lead, 0.2)
+                .add(PrefabId::Scope, 0.05)
+                .add(PrefabId::ExtendedCylinder, 0.05)
+                .add(PrefabId::Bayonet, 0.05)
                 .add(PrefabId::Amulet, 0.05)
                 .add(PrefabId::Ring, 0.05)
                 .build(),
@@ -435,6 +705,11 @@ impl LootTableRegistry {
             LootTable::builder().add(PrefabId::GoldNugget, 1.0).build(),
         );
 
+        self.tables.insert(
+            LootTableId::GoldVeinLoot,
+            LootTable::builder().add(PrefabId::GoldNugget, 1.0).build(),
+        );
+
         self.tables.insert(
             LootTableId::BeetleLoot,
             LootTable::builder()
@@ -1,32 +1,46 @@
 pub mod biome;
+pub mod biome_blend;
 pub mod biome_helpers;
 pub mod biome_registry;
 pub mod constraint_handler;
+pub mod crossing_builder;
 pub mod grid_helpers;
 pub mod loot_tables;
 pub mod overworld;
+pub mod overworld_pois;
 pub mod overworld_rivers;
 pub mod overworld_roads;
 pub mod overworld_towns;
 pub mod river_builder;
 pub mod road_builder;
+pub mod town_builder;
+pub mod zone_connectivity;
 pub mod zone_continuity;
 pub mod zone_factory;
 pub mod zone_generator;
 pub mod zone_grid;
+pub mod zone_template;
+pub mod zone_validation;
 
 pub use biome::*;
+pub use biome_blend::*;
 pub use biome_registry::*;
 pub use constraint_handler::*;
+pub use crossing_builder::*;
 pub use grid_helpers::*;
 pub use loot_tables::*;
 pub use overworld::*;
+pub use overworld_pois::*;
 pub use overworld_rivers::*;
 pub use overworld_roads::*;
 pub use overworld_towns::*;
 pub use river_builder::*;
 pub use road_builder::*;
+pub use town_builder::*;
+pub use zone_connectivity::*;
 pub use zone_continuity::*;
 pub use zone_factory::*;
 pub use zone_generator::*;
 pub use zone_grid::*;
+pub use zone_template::*;
+pub use zone_validation::*;
@@ -3,13 +3,14 @@ use std::collections::{HashMap, HashSet};
 use bevy_ecs::resource::Resource;
 
 use crate::{
-    cfg::SURFACE_LEVEL_Z,
+    cfg::{MAP_SIZE, SURFACE_LEVEL_Z},
     common::Perlin,
     domain::{
-        BiomeType, OverworldRiverGenerator, OverworldRoadGenerator, OverworldTownGenerator,
-        ZoneContinuity, get_zone_constraints,
+        BiomeType, OverworldPoi, OverworldPoiGenerator, OverworldRiverGenerator,
+        OverworldRoadGenerator, OverworldTownGenerator, TownBuildingType, ZoneContinuity,
+        get_zone_constraints,
     },
-    rendering::zone_xyz,
+    rendering::{zone_idx as calculate_zone_idx, zone_xyz},
 };
 
 pub struct OverworldZone {
@@ -17,11 +18,24 @@ pub struct OverworldZone {
     pub biome_type: BiomeType,
     pub constraints: ZoneContinuity,
     pub town: Option<OverworldTown>,
+    pub poi: Option<OverworldPoi>,
+    pub neighbor_biomes: NeighborBiomes,
+    pub crossing: Option<RoadCrossing>,
+}
+
+/// Biomes of the zones that share an edge with a zone, on the same z-level
+#[derive(Clone, Copy, Default)]
+pub struct NeighborBiomes {
+    pub north: Option<BiomeType>,
+    pub south: Option<BiomeType>,
+    pub east: Option<BiomeType>,
+    pub west: Option<BiomeType>,
 }
 
 #[derive(Clone)]
 pub struct OverworldTown {
     pub name: String,
+    pub buildings: Vec<TownBuildingType>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
@@ -41,7 +55,7 @@ impl RoadType {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum RiverType {
     Creek,
     Stream,
@@ -74,10 +88,37 @@ pub struct RoadSegment {
     pub road_type: RoadType,
 }
 
+/// How a road gets over a river
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrossingType {
+    Ford,
+    Bridge,
+    Ferry,
+}
+
+impl CrossingType {
+    /// Small water is waded, rivers are bridged and the mightiest need a ferry
+    pub fn for_river(river_type: RiverType) -> Self {
+        match river_type {
+            RiverType::Creek | RiverType::Stream => CrossingType::Ford,
+            RiverType::River => CrossingType::Bridge,
+            RiverType::MightyRiver => CrossingType::Ferry,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RoadCrossing {
+    pub crossing_type: CrossingType,
+    pub river_type: RiverType,
+}
+
 #[derive(Default, Clone)]
 pub struct RoadNetwork {
     pub edges: HashMap<(usize, usize), RoadSegment>,
     pub nodes: HashSet<usize>,
+    /// Zones where a road meets a river, and how it gets across
+    pub crossings: HashMap<usize, RoadCrossing>,
 }
 
 impl RoadNetwork {
@@ -104,10 +145,25 @@ impl RiverNetwork {
         self.nodes.contains(&zone_idx)
     }
 
-    pub fn get_river_at_edge(&self, from_zone: usize, to_zone: usize) -> Option<&RiverSegment> {
+    /// The biggest river flowing into or out of a zone
+    pub fn river_type_at(&self, zone_idx: usize) -> Option<RiverType> {
         self.edges
-            .get(&(from_zone, to_zone))
-            .or_else(|| self.edges.get(&(to_zone, from_zone)))
+            .iter()
+            .filter(|((from, to), _)| *from == zone_idx || *to == zone_idx)
+            .map(|(_, segment)| segment.river_type)
+            .max()
+    }
+
+    /// The river crossing between two zones. When rivers run both ways over the same edge
+    /// the bigger one wins, so the zones on either side agree on its width.
+    pub fn get_river_at_edge(&self, from_zone: usize, to_zone: usize) -> Option<&RiverSegment> {
+        [
+            self.edges.get(&(from_zone, to_zone)),
+            self.edges.get(&(to_zone, from_zone)),
+        ]
+        .into_iter()
+        .flatten()
+        .max_by_key(|segment| segment.river_type)
     }
 }
 
@@ -118,6 +174,9 @@ pub struct Overworld {
     pub towns: HashMap<usize, HashMap<usize, OverworldTown>>,
     pub road_networks: HashMap<usize, RoadNetwork>,
     pub river_networks: HashMap<usize, RiverNetwork>,
+    pub pois: HashMap<usize, OverworldPoi>,
+    /// Zones whose point of interest the player has found, kept in the save
+    pub discovered_pois: HashSet<usize>,
 }
 
 impl Overworld {
@@ -128,6 +187,8 @@ impl Overworld {
             towns: HashMap::new(),
             road_networks: HashMap::new(),
             river_networks: HashMap::new(),
+            pois: HashMap::new(),
+            discovered_pois: HashSet::new(),
         };
 
         // Generate rivers first (natural features)
@@ -136,6 +197,9 @@ impl Overworld {
         // Then generate towns (near rivers for water access)
         overworld.towns = OverworldTownGenerator::generate_towns(seed);
 
+        // Points of interest fill the wilderness between towns
+        overworld.pois = OverworldPoiGenerator::generate_pois(&overworld);
+
         // Finally generate roads (connecting towns, bridging rivers)
         overworld.generate_roads();
         overworld
@@ -148,6 +212,21 @@ impl Overworld {
             biome_type: self.get_zone_type(zone_idx),
             constraints: get_zone_constraints(self, zone_idx),
             town: self.towns.get(&z).and_then(|v| v.get(&zone_idx).cloned()),
+            poi: self.pois.get(&zone_idx).cloned(),
+            neighbor_biomes: self.get_neighbor_biomes(zone_idx),
+            crossing: self.get_crossing(zone_idx),
+        }
+    }
+
+    pub fn get_neighbor_biomes(&self, zone_idx: usize) -> NeighborBiomes {
+        let (x, y, z) = zone_xyz(zone_idx);
+        let biome_at = |x: usize, y: usize| self.get_zone_type(calculate_zone_idx(x, y, z));
+
+        NeighborBiomes {
+            north: (y > 0).then(|| biome_at(x, y - 1)),
+            south: (y + 1 < MAP_SIZE.1).then(|| biome_at(x, y + 1)),
+            east: (x + 1 < MAP_SIZE.0).then(|| biome_at(x + 1, y)),
+            west: (x > 0).then(|| biome_at(x - 1, y)),
         }
     }
 
@@ -155,6 +234,15 @@ impl Overworld {
         self.road_networks.get(&z)
     }
 
+    pub fn get_crossing(&self, zone_idx: usize) -> Option<RoadCrossing> {
+        let (_, _, z) = zone_xyz(zone_idx);
+
+        self.road_networks
+            .get(&z)
+            .and_then(|network| network.crossings.get(&zone_idx))
+            .copied()
+    }
+
     pub fn zone_has_road(&self, zone_idx: usize) -> bool {
         let (_, _, z) = zone_xyz(zone_idx);
 
@@ -182,7 +270,13 @@ impl Overworld {
         }
 
         if z > SURFACE_LEVEL_Z + 1 {
-            return BiomeType::Cavern;
+            // Deep levels - old mine workings run straight down through the rock
+            let noise = self.perlin.get(x as f32 + 100.0, y as f32 + 100.0);
+            if noise < 0.3 {
+                return BiomeType::FloodedMine;
+            } else {
+                return BiomeType::Cavern;
+            }
         }
 
         let noise = self.perlin.get(x as f32, y as f32);
@@ -214,6 +308,7 @@ impl Overworld {
     }
 
     fn generate_roads(&mut self) {
-        self.road_networks = OverworldRoadGenerator::generate_roads(&self.towns, self.seed);
+        self.road_networks =
+            OverworldRoadGenerator::generate_roads(&self.towns, &self.river_networks, self.seed);
     }
 }
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::{
+    cfg::{MAP_SIZE, SURFACE_LEVEL_Z},
+    common::{Palette, PoissonDiscSampler, PoissonDiscSettings, Rand},
+    domain::{BiomeType, LootTableId, Overworld, ZoneTemplateId},
+    rendering::zone_idx,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PoiType {
+    Mine,
+    GhostTown,
+    OutlawHideout,
+    Oasis,
+    CrashedStagecoach,
+    AncientRuins,
+}
+
+impl PoiType {
+    const ALL: [PoiType; 6] = [
+        PoiType::Mine,
+        PoiType::GhostTown,
+        PoiType::OutlawHideout,
+        PoiType::Oasis,
+        PoiType::CrashedStagecoach,
+        PoiType::AncientRuins,
+    ];
+
+    /// Biomes a point of interest of this type can turn up in
+    pub fn biomes(self) -> &'static [BiomeType] {
+        match self {
+            PoiType::Mine => &[BiomeType::Mountain, BiomeType::DustyPlains],
+            PoiType::GhostTown => &[BiomeType::Desert, BiomeType::DustyPlains, BiomeType::Forest],
+            PoiType::OutlawHideout => &[
+                BiomeType::Mountain,
+                BiomeType::Desert,
+                BiomeType::Forest,
+                BiomeType::Swamp,
+            ],
+            PoiType::Oasis => &[BiomeType::Desert],
+            PoiType::CrashedStagecoach => {
+                &[BiomeType::Desert, BiomeType::DustyPlains, BiomeType::Forest]
+            }
+            PoiType::AncientRuins => &[
+                BiomeType::Swamp,
+                BiomeType::Forest,
+                BiomeType::Cavern,
+                BiomeType::MushroomForest,
+            ],
+        }
+    }
+
+    /// Templates stamped into the zone before the biome fills in the rest
+    pub fn templates(self) -> &'static [ZoneTemplateId] {
+        match self {
+            PoiType::Mine => &[ZoneTemplateId::MineEntrance],
+            PoiType::GhostTown => &[
+                ZoneTemplateId::Homestead,
+                ZoneTemplateId::Homestead,
+                ZoneTemplateId::Homestead,
+                ZoneTemplateId::Graveyard,
+            ],
+            PoiType::OutlawHideout => &[ZoneTemplateId::BanditCamp, ZoneTemplateId::BanditCamp],
+            PoiType::Oasis => &[ZoneTemplateId::Oasis],
+            PoiType::CrashedStagecoach => &[ZoneTemplateId::Stagecoach],
+            PoiType::AncientRuins => &[ZoneTemplateId::Ruins, ZoneTemplateId::Ruins],
+        }
+    }
+
+    pub fn chest_loot_table_id(self) -> LootTableId {
+        match self {
+            PoiType::Mine => LootTableId::MineChestLoot,
+            PoiType::GhostTown => LootTableId::GhostTownChestLoot,
+            PoiType::OutlawHideout => LootTableId::HideoutChestLoot,
+            PoiType::Oasis => LootTableId::OasisChestLoot,
+            PoiType::CrashedStagecoach => LootTableId::StagecoachChestLoot,
+            PoiType::AncientRuins => LootTableId::RuinsChestLoot,
+        }
+    }
+
+    pub fn enemy_table_id(self) -> LootTableId {
+        match self {
+            PoiType::Mine => LootTableId::MineEnemies,
+            PoiType::GhostTown => LootTableId::GhostTownEnemies,
+            PoiType::OutlawHideout => LootTableId::HideoutEnemies,
+            PoiType::Oasis => LootTableId::OasisEnemies,
+            PoiType::CrashedStagecoach => LootTableId::StagecoachEnemies,
+            PoiType::AncientRuins => LootTableId::RuinsEnemies,
+        }
+    }
+
+    /// Glyph and colour used on the overworld map once discovered
+    pub fn map_glyph(self) -> (usize, Palette) {
+        match self {
+            PoiType::Mine => (23, Palette::Gray),
+            PoiType::GhostTown => (97, Palette::White),
+            PoiType::OutlawHideout => (36, Palette::Red),
+            PoiType::Oasis => (45, Palette::Green),
+            PoiType::CrashedStagecoach => (24, Palette::Brown),
+            PoiType::AncientRuins => (102, Palette::Purple),
+        }
+    }
+
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            PoiType::Mine => &[
+                "Lucky Strike Mine",
+                "Widow's Claim",
+                "Copperhead Mine",
+                "Last Chance Diggings",
+                "Silver Tongue Mine",
+            ],
+            PoiType::GhostTown => &[
+                "Bitter Springs",
+                "Hollow Creek",
+                "Gallows Flat",
+                "Rattler's Rest",
+                "Dry Bones",
+            ],
+            PoiType::OutlawHideout => &[
+                "Robbers' Roost",
+                "Hole in the Wall",
+                "Black Canyon Hideout",
+                "Coyote Den",
+                "Deadman's Hollow",
+            ],
+            PoiType::Oasis => &["Sweetwater", "Palm Springs", "Mirage Pool", "Saint's Well"],
+            PoiType::CrashedStagecoach => &[
+                "Wrecked Wells Fargo Coach",
+                "Overturned Stagecoach",
+                "Ambushed Mail Coach",
+            ],
+            PoiType::AncientRuins => &[
+                "Cliff Dwellings",
+                "Sunken Temple",
+                "Old Mission Ruins",
+                "Forgotten Pueblo",
+            ],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OverworldPoi {
+    pub poi_type: PoiType,
+    pub name: String,
+}
+
+pub struct OverworldPoiGenerator;
+
+impl OverworldPoiGenerator {
+    /// Scatters points of interest over the surface and the first level underground.
+    /// Towns keep their zones to themselves.
+    pub fn generate_pois(overworld: &Overworld) -> HashMap<usize, OverworldPoi> {
+        let mut pois = HashMap::new();
+        let seed = overworld.seed;
+
+        for z in SURFACE_LEVEL_Z..=SURFACE_LEVEL_Z + 1 {
+            let mut rand = Rand::seed(seed + 2000 + z as u32);
+            let mut sampler = PoissonDiscSampler::new(PoissonDiscSettings {
+                width: MAP_SIZE.0,
+                height: MAP_SIZE.1,
+                radius: 5.0,
+                seed: seed + 2000 + z as u32,
+            });
+
+            for (x, y) in sampler.all() {
+                let idx = zone_idx(x, y, z);
+
+                if overworld
+                    .towns
+                    .get(&z)
+                    .is_some_and(|towns| towns.contains_key(&idx))
+                {
+                    continue;
+                }
+
+                let biome = overworld.get_zone_type(idx);
+                let candidates = PoiType::ALL
+                    .into_iter()
+                    .filter(|poi_type| poi_type.biomes().contains(&biome))
+                    .collect::<Vec<_>>();
+
+                if candidates.is_empty() {
+                    continue;
+                }
+
+                let poi_type = rand.pick(&candidates);
+                let name = rand.pick(poi_type.names()).to_string();
+
+                pois.insert(idx, OverworldPoi { poi_type, name });
+            }
+        }
+
+        pois
+    }
+}
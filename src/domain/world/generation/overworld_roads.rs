@@ -6,7 +6,9 @@ use crate::{
         Grid, Perlin,
         algorithm::astar::{AStarSettings, astar},
     },
-    domain::world::generation::{OverworldTown, RoadNetwork, RoadSegment, RoadType},
+    domain::world::generation::{
+        CrossingType, OverworldTown, RiverNetwork, RoadCrossing, RoadNetwork, RoadSegment, RoadType,
+    },
     rendering::{zone_idx, zone_xyz},
 };
 
@@ -15,6 +17,7 @@ pub struct OverworldRoadGenerator;
 impl OverworldRoadGenerator {
     pub fn generate_roads(
         layers: &HashMap<usize, HashMap<usize, OverworldTown>>,
+        rivers: &HashMap<usize, RiverNetwork>,
         seed: u32,
     ) -> HashMap<usize, RoadNetwork> {
         let mut networks = HashMap::new();
@@ -71,12 +74,37 @@ impl OverworldRoadGenerator {
                 }
             }
 
+            if let Some(river_network) = rivers.get(&z) {
+                Self::record_crossings(&mut network, river_network);
+            }
+
             networks.insert(z, network);
         }
 
         networks
     }
 
+    /// Notes every zone where a road runs into a river, and what kind of crossing it gets
+    fn record_crossings(network: &mut RoadNetwork, rivers: &RiverNetwork) {
+        let crossings = network
+            .nodes
+            .iter()
+            .filter_map(|&zone_idx| {
+                let river_type = rivers.river_type_at(zone_idx)?;
+
+                Some((
+                    zone_idx,
+                    RoadCrossing {
+                        crossing_type: CrossingType::for_river(river_type),
+                        river_type,
+                    },
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        network.crossings.extend(crossings);
+    }
+
     fn calculate_distance(pos1: (usize, usize, usize), pos2: (usize, usize, usize)) -> f32 {
         let dx = pos1.0 as f32 - pos2.0 as f32;
         let dy = pos1.1 as f32 - pos2.1 as f32;
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use crate::{
     cfg::{MAP_SIZE, SURFACE_LEVEL_Z},
-    common::{PoissonDiscSampler, PoissonDiscSettings},
-    domain::world::generation::OverworldTown,
+    common::{PoissonDiscSampler, PoissonDiscSettings, Rand},
+    domain::world::generation::{OverworldTown, TownBuildingType},
     rendering::zone_idx,
 };
 
@@ -27,6 +27,7 @@ impl OverworldTownGenerator {
                 let idx = zone_idx(x, y, z);
                 let town = OverworldTown {
                     name: Self::generate_town_name(idx, seed),
+                    buildings: Self::generate_town_buildings(idx, seed),
                 };
                 towns.insert(idx, town);
             }
@@ -37,6 +38,24 @@ impl OverworldTownGenerator {
         layers
     }
 
+    /// Every town has a saloon and a general store; the rest depend on its luck
+    fn generate_town_buildings(zone_idx: usize, seed: u32) -> Vec<TownBuildingType> {
+        let mut rand = Rand::seed(seed.wrapping_add(zone_idx as u32));
+        let mut buildings = vec![TownBuildingType::Saloon, TownBuildingType::GeneralStore];
+
+        for building in [
+            TownBuildingType::SheriffOffice,
+            TownBuildingType::Church,
+            TownBuildingType::Stables,
+        ] {
+            if rand.bool(0.6) {
+                buildings.push(building);
+            }
+        }
+
+        buildings
+    }
+
     fn generate_town_name(zone_idx: usize, seed: u32) -> String {
         let names = [
             "Millbrook",
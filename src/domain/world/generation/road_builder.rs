@@ -10,7 +10,7 @@ use crate::{
             distance::Distance,
         },
     },
-    domain::{Terrain, ZoneGrid},
+    domain::{CrossingType, Terrain, ZoneGrid},
 };
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
@@ -59,18 +59,26 @@ impl RoadBuilder {
         self.connect_stairs(&grouped_bycat);
     }
 
+    /// Lays the road terrain down and returns the river tiles the road runs over.
+    /// Fords turn those tiles to shallows; bridges and ferries leave the water alone.
     pub fn apply_roads_to_terrain(
         &self,
         terrain: &mut Grid<Terrain>,
         locked: &mut Grid<bool>,
         road_terrain: Terrain,
-    ) {
+        crossing_type: CrossingType,
+    ) -> Vec<(usize, usize)> {
+        let mut river_tiles = vec![];
+
         for (x, y, &is_road) in self.road_grid.iter_xy() {
             if is_road {
                 let current_terrain = terrain.get(x, y).unwrap_or(&Terrain::Grass);
                 if *current_terrain == Terrain::River {
-                    // Place shallows where roads cross rivers
-                    terrain.set(x, y, Terrain::Shallows);
+                    if crossing_type == CrossingType::Ford {
+                        terrain.set(x, y, Terrain::Shallows);
+                    }
+
+                    river_tiles.push((x, y));
                     locked.set(x, y, true);
                 } else {
                     // Normal road placement
@@ -79,6 +87,8 @@ impl RoadBuilder {
                 }
             }
         }
+
+        river_tiles
     }
 
     fn group_connections_by_category(&self) -> HashMap<RoadCategory, Vec<RoadConnection>> {
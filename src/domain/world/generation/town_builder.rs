@@ -0,0 +1,440 @@
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Rand,
+    domain::{
+        JAIL_KEY, LootTableId, OverworldTown, Prefab, PrefabId, SpawnValue, Terrain, ZoneFactory,
+    },
+    rendering::zone_local_to_world,
+};
+
+use super::road_builder::{RoadCategory, RoadConnection};
+
+const STREET_HALF_WIDTH: usize = 1;
+const STREET_MARGIN: usize = 6;
+const BUILDING_SPACING: usize = 2;
+
+/// Creature glyphs for townsfolk who aren't tied to a particular building
+const TOWNSFOLK_GLYPHS: [i32; 6] = [16, 18, 20, 21, 23, 24];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TownBuildingType {
+    Saloon,
+    GeneralStore,
+    SheriffOffice,
+    Church,
+    Stables,
+}
+
+impl TownBuildingType {
+    /// Footprint as (width along the street, depth away from it), walls included
+    pub fn size(self) -> (usize, usize) {
+        match self {
+            TownBuildingType::Saloon => (11, 7),
+            TownBuildingType::GeneralStore => (9, 6),
+            TownBuildingType::SheriffOffice => (9, 6),
+            TownBuildingType::Church => (9, 7),
+            TownBuildingType::Stables => (9, 6),
+        }
+    }
+
+    /// The person who runs the place, as a label and creature glyph
+    pub fn proprietor(self) -> (&'static str, i32) {
+        match self {
+            TownBuildingType::Saloon => ("Bartender", 23),
+            TownBuildingType::GeneralStore => ("Shopkeeper", 24),
+            TownBuildingType::SheriffOffice => ("Sheriff", 17),
+            TownBuildingType::Church => ("Preacher", 19),
+            TownBuildingType::Stables => ("Stablehand", 18),
+        }
+    }
+
+    /// What the proprietor sells over the counter, if they're in trade
+    pub fn merchant_stock(self) -> Option<LootTableId> {
+        match self {
+            TownBuildingType::Saloon => Some(LootTableId::SaloonStock),
+            TownBuildingType::GeneralStore => Some(LootTableId::GeneralStoreStock),
+            TownBuildingType::Stables => Some(LootTableId::StablesStock),
+            TownBuildingType::SheriffOffice | TownBuildingType::Church => None,
+        }
+    }
+
+    /// The conversation the proprietor strikes up when they aren't minding a counter
+    pub fn dialogue(self) -> &'static str {
+        match self {
+            TownBuildingType::SheriffOffice => "sheriff",
+            TownBuildingType::Church => "preacher",
+            TownBuildingType::Saloon
+            | TownBuildingType::GeneralStore
+            | TownBuildingType::Stables => "townsfolk",
+        }
+    }
+
+    /// Interior layout in (along, depth) coordinates relative to the inside of the walls,
+    /// where depth 0 is the row just inside the door, along with where the proprietor stands.
+    fn furniture(self) -> (Vec<(PrefabId, usize, usize)>, (usize, usize)) {
+        match self {
+            TownBuildingType::Saloon => {
+                let mut furniture = (1..=5)
+                    .map(|a| (PrefabId::Counter, a, 3))
+                    .collect::<Vec<_>>();
+                furniture.extend([
+                    (PrefabId::Barrel, 7, 4),
+                    (PrefabId::Barrel, 8, 4),
+                    (PrefabId::Table, 1, 1),
+                    (PrefabId::Table, 7, 1),
+                    (PrefabId::Table, 7, 3),
+                ]);
+                (furniture, (3, 4))
+            }
+            TownBuildingType::GeneralStore => {
+                let mut furniture = (0..=6).map(|a| (PrefabId::Shelf, a, 3)).collect::<Vec<_>>();
+                furniture.extend(
+                    [0, 1, 2, 4, 5, 6]
+                        .into_iter()
+                        .map(|a| (PrefabId::Counter, a, 1)),
+                );
+                furniture.push((PrefabId::Barrel, 6, 0));
+                (furniture, (1, 2))
+            }
+            TownBuildingType::SheriffOffice => {
+                // A cell in the back corner, barred off from the office and locked
+                let furniture = vec![
+                    (PrefabId::Table, 1, 1),
+                    (PrefabId::JailBars, 3, 2),
+                    (PrefabId::JailBars, 3, 3),
+                    (PrefabId::JailBars, 4, 2),
+                    (PrefabId::CellDoor, 5, 2),
+                    (PrefabId::JailBars, 6, 2),
+                ];
+                (furniture, (1, 2))
+            }
+            TownBuildingType::Church => {
+                let mut furniture = [1, 2]
+                    .into_iter()
+                    .flat_map(|b| [0, 1, 5, 6].into_iter().map(move |a| (PrefabId::Pew, a, b)))
+                    .collect::<Vec<_>>();
+                furniture.push((PrefabId::Altar, 3, 3));
+                (furniture, (3, 4))
+            }
+            TownBuildingType::Stables => {
+                let furniture = vec![
+                    (PrefabId::Barrel, 0, 3),
+                    (PrefabId::Barrel, 6, 3),
+                    (PrefabId::Barrel, 6, 2),
+                ];
+                (furniture, (3, 2))
+            }
+        }
+    }
+}
+
+/// Which side of the main street a building faces the street from
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StreetSide {
+    Near,
+    Far,
+}
+
+/// The main street of a town. Coordinates are worked in "along" and "across" the street,
+/// then mapped back to zone coordinates depending on which way the street runs.
+struct MainStreet {
+    horizontal: bool,
+    center: usize,
+    start: usize,
+    end: usize,
+}
+
+impl MainStreet {
+    fn new(connections: &[RoadConnection]) -> Self {
+        let average = |categories: &[RoadCategory], pick: fn((usize, usize)) -> usize| {
+            let values = connections
+                .iter()
+                .filter(|c| categories.contains(&c.category))
+                .map(|c| pick(c.pos))
+                .collect::<Vec<_>>();
+
+            if values.is_empty() {
+                None
+            } else {
+                Some(values.iter().sum::<usize>() / values.len())
+            }
+        };
+
+        let east_west = average(&[RoadCategory::East, RoadCategory::West], |p| p.1);
+        let north_south = average(&[RoadCategory::North, RoadCategory::South], |p| p.0);
+
+        let (horizontal, center) = match (east_west, north_south) {
+            (Some(y), _) => (true, y),
+            (None, Some(x)) => (false, x),
+            (None, None) => (true, ZONE_SIZE.1 / 2),
+        };
+
+        let (along_len, across_len) = if horizontal {
+            (ZONE_SIZE.0, ZONE_SIZE.1)
+        } else {
+            (ZONE_SIZE.1, ZONE_SIZE.0)
+        };
+
+        // Leave room on both sides for the deepest building and its boardwalk
+        let max_depth = 7;
+        let min_center = STREET_HALF_WIDTH + 1 + max_depth + 2;
+        let max_center = across_len - 1 - min_center;
+        let margin = if horizontal { STREET_MARGIN } else { 2 };
+
+        Self {
+            horizontal,
+            center: center.clamp(min_center, max_center),
+            start: margin,
+            end: along_len - margin,
+        }
+    }
+
+    fn to_xy(&self, along: usize, across: usize) -> (usize, usize) {
+        if self.horizontal {
+            (along, across)
+        } else {
+            (across, along)
+        }
+    }
+
+    /// Across coordinate of the building row nearest the street, on the given side
+    fn frontage(&self, side: StreetSide) -> usize {
+        match side {
+            StreetSide::Near => self.center - STREET_HALF_WIDTH - 1,
+            StreetSide::Far => self.center + STREET_HALF_WIDTH + 1,
+        }
+    }
+
+    /// Across coordinate `depth` rows back from the frontage, away from the street
+    fn across_at_depth(&self, side: StreetSide, depth: usize) -> usize {
+        match side {
+            StreetSide::Near => self.frontage(side) - depth,
+            StreetSide::Far => self.frontage(side) + depth,
+        }
+    }
+}
+
+pub struct TownBuilder;
+
+impl TownBuilder {
+    /// Lays out a main street along the zone's roads, lines it with buildings and
+    /// fills the town with people. Everything placed here is locked so the biome leaves it alone.
+    pub fn build(
+        zone: &mut ZoneFactory,
+        town: &OverworldTown,
+        connections: &[RoadConnection],
+        road_terrain: Terrain,
+    ) {
+        let mut rand = Rand::seed(zone.zone_idx as u32);
+        let street = MainStreet::new(connections);
+
+        Self::lay_street(zone, &street, road_terrain);
+
+        let mut cursors = [street.start + 1, street.start + 1];
+        for (i, building) in town.buildings.iter().enumerate() {
+            let side = if i % 2 == 0 {
+                StreetSide::Near
+            } else {
+                StreetSide::Far
+            };
+            let cursor = &mut cursors[i % 2];
+
+            if let Some(along) = Self::find_lot(zone, &street, side, *building, *cursor) {
+                Self::place_building(zone, &street, side, *building, along);
+                *cursor = along + building.size().0 + BUILDING_SPACING;
+            }
+        }
+
+        Self::populate_street(zone, &street, &mut rand);
+    }
+
+    fn lay_street(zone: &mut ZoneFactory, street: &MainStreet, road_terrain: Terrain) {
+        for along in street.start..street.end {
+            for across in street.center - STREET_HALF_WIDTH..=street.center + STREET_HALF_WIDTH {
+                let (x, y) = street.to_xy(along, across);
+
+                // Roads, rivers and stairs already here keep what they have
+                if zone.grid_data.is_locked_tile(x, y) {
+                    continue;
+                }
+
+                zone.set_terrain(x, y, road_terrain);
+                zone.grid_data.locked.set(x, y, true);
+            }
+        }
+    }
+
+    /// First position along the street at or after `from` where the building fits,
+    /// with a free tile all the way around it
+    fn find_lot(
+        zone: &ZoneFactory,
+        street: &MainStreet,
+        side: StreetSide,
+        building: TownBuildingType,
+        from: usize,
+    ) -> Option<usize> {
+        let (width, depth) = building.size();
+
+        (from..street.end.saturating_sub(width)).find(|&along| {
+            (along - 1..=along + width).all(|a| {
+                (0..=depth + 1).all(|d| {
+                    let (x, y) = street.to_xy(a, street.across_at_depth(side, d));
+                    !zone.grid_data.is_locked_tile(x, y)
+                })
+            })
+        })
+    }
+
+    fn place_building(
+        zone: &mut ZoneFactory,
+        street: &MainStreet,
+        side: StreetSide,
+        building: TownBuildingType,
+        along: usize,
+    ) {
+        let (width, depth) = building.size();
+        let door = along + width / 2;
+
+        // Boardwalk in front of the building, so the door is never walled in
+        for a in along - 1..=along + width {
+            let (x, y) = street.to_xy(a, street.across_at_depth(side, 0));
+            zone.grid_data.locked.set(x, y, true);
+        }
+
+        for a in along..along + width {
+            for d in 1..=depth {
+                let (x, y) = street.to_xy(a, street.across_at_depth(side, d));
+                let is_wall = a == along || a == along + width - 1 || d == 1 || d == depth;
+
+                zone.set_terrain(x, y, Terrain::Dirt);
+                zone.grid_data.locked.set(x, y, true);
+
+                if a == door && d == 1 {
+                    Self::push(zone, x, y, PrefabId::Door);
+                } else if is_wall {
+                    Self::push(zone, x, y, PrefabId::WoodenWall);
+                }
+            }
+        }
+
+        let to_xy =
+            |a: usize, b: usize| street.to_xy(along + 1 + a, street.across_at_depth(side, b + 2));
+        let (furniture, (proprietor_a, proprietor_b)) = building.furniture();
+
+        for (prefab_id, a, b) in furniture {
+            let (x, y) = to_xy(a, b);
+            Self::push(zone, x, y, prefab_id);
+        }
+
+        // The cell key hangs just inside the sheriff's door
+        if building == TownBuildingType::SheriffOffice {
+            let (x, y) = to_xy(0, 0);
+            let world_pos = zone_local_to_world(zone.zone_idx, x, y);
+            let key = Prefab::new(PrefabId::Key, world_pos)
+                .with_metadata("key".to_owned(), SpawnValue::String(JAIL_KEY.to_owned()));
+            zone.push_entity(x, y, key);
+        }
+
+        // A gun for hire nursing a drink at the back table
+        if building == TownBuildingType::Saloon {
+            let (x, y) = to_xy(8, 1);
+            Self::push(zone, x, y, PrefabId::Gunslinger);
+        }
+
+        let (label, glyph) = building.proprietor();
+        let (x, y) = to_xy(proprietor_a, proprietor_b);
+
+        if let Some(stock) = building.merchant_stock() {
+            Self::push_merchant(zone, x, y, label, glyph, stock);
+        } else {
+            Self::push_townsfolk(zone, x, y, label, glyph, building.dialogue());
+        }
+    }
+
+    /// The doctor and a handful of locals out on the street
+    fn populate_street(zone: &mut ZoneFactory, street: &MainStreet, rand: &mut Rand) {
+        let mut spots = (street.start..street.end)
+            .flat_map(|along| {
+                (street.center - STREET_HALF_WIDTH..=street.center + STREET_HALF_WIDTH)
+                    .map(move |across| street.to_xy(along, across))
+            })
+            .filter(|&(x, y)| Self::is_clear_ground(zone, x, y))
+            .collect::<Vec<_>>();
+
+        if spots.is_empty() {
+            return;
+        }
+
+        // The doctor keeps to the middle of town
+        let middle = street.to_xy((street.start + street.end) / 2, street.center);
+        spots.sort_by_key(|&(x, y)| x.abs_diff(middle.0) + y.abs_diff(middle.1));
+        let (x, y) = spots.remove(0);
+        Self::push(zone, x, y, PrefabId::Doctor);
+
+        let count = rand.range_n(2, 5) as usize;
+        for _ in 0..count {
+            if spots.is_empty() {
+                break;
+            }
+
+            let (x, y) = spots.swap_remove(rand.pick_idx(&spots));
+            let glyph = rand.pick(&TOWNSFOLK_GLYPHS);
+            Self::push_townsfolk(zone, x, y, "Townsfolk", glyph, "townsfolk");
+        }
+    }
+
+    fn is_clear_ground(zone: &ZoneFactory, x: usize, y: usize) -> bool {
+        let terrain_ok = zone
+            .grid_data
+            .terrain
+            .get(x, y)
+            .is_some_and(|t| !matches!(t, Terrain::River | Terrain::OpenAir));
+        let empty = zone
+            .grid_data
+            .entities
+            .get(x, y)
+            .is_some_and(|entities| entities.is_empty());
+
+        terrain_ok && empty
+    }
+
+    fn push(zone: &mut ZoneFactory, x: usize, y: usize, prefab_id: PrefabId) {
+        let world_pos = zone_local_to_world(zone.zone_idx, x, y);
+        zone.push_entity(x, y, Prefab::new(prefab_id, world_pos));
+    }
+
+    fn push_merchant(
+        zone: &mut ZoneFactory,
+        x: usize,
+        y: usize,
+        label: &str,
+        glyph: i32,
+        stock: LootTableId,
+    ) {
+        let world_pos = zone_local_to_world(zone.zone_idx, x, y);
+        let config = Prefab::new(PrefabId::Merchant, world_pos)
+            .with_metadata("label".to_string(), SpawnValue::String(label.to_string()))
+            .with_metadata("glyph".to_string(), SpawnValue::Int(glyph))
+            .with_metadata("stock".to_string(), SpawnValue::LootTableId(stock));
+        zone.push_entity(x, y, config);
+    }
+
+    fn push_townsfolk(
+        zone: &mut ZoneFactory,
+        x: usize,
+        y: usize,
+        label: &str,
+        glyph: i32,
+        dialogue: &str,
+    ) {
+        let world_pos = zone_local_to_world(zone.zone_idx, x, y);
+        let config = Prefab::new(PrefabId::Townsfolk, world_pos)
+            .with_metadata("label".to_string(), SpawnValue::String(label.to_string()))
+            .with_metadata("glyph".to_string(), SpawnValue::Int(glyph))
+            .with_metadata(
+                "dialogue".to_string(),
+                SpawnValue::String(dialogue.to_string()),
+            );
+        zone.push_entity(x, y, config);
+    }
+}
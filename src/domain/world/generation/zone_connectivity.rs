@@ -0,0 +1,233 @@
+use crate::{
+    cfg::ZONE_SIZE,
+    common::{Grid, algorithm::dijkstra::DijkstraMap},
+    domain::{PrefabId, ZoneConstraintType, ZoneFactory},
+};
+
+/// How much more it costs to dig through a wall tile than to walk an open one.
+/// Higher values make tunnels hug existing passages instead of cutting straight through rock.
+const TUNNEL_COST: f32 = 5.0;
+
+pub struct ZoneConnectivity;
+
+impl ZoneConnectivity {
+    /// Makes every stair, shaft and edge opening in the zone (plus any extra `anchors`)
+    /// mutually reachable, carving tunnels through `walls` where they are not.
+    pub fn connect(zone: &ZoneFactory, walls: &mut Grid<bool>, anchors: &[(usize, usize)]) {
+        let mut points = Self::required_points(zone);
+        points.extend_from_slice(anchors);
+
+        // Never leave a wall sitting on top of a stair or an opening
+        for &(x, y) in &points {
+            walls.set(x, y, false);
+        }
+
+        // Any other open edge tile also has to lead somewhere, or it will strand whoever
+        // walks in from the neighbouring zone
+        points.extend(Self::open_edge_tiles(zone, walls));
+
+        let Some(&root) = points.first() else {
+            return;
+        };
+
+        let mut reach = Self::walkable_map(zone, walls);
+        reach.calculate_uniform(&[root]);
+
+        for &(x, y) in points.iter().skip(1) {
+            if reach.get_cost(x, y).is_some_and(|cost| cost.is_finite()) {
+                continue;
+            }
+
+            Self::carve_tunnel(zone, walls, &reach, (x, y));
+
+            reach = Self::walkable_map(zone, walls);
+            reach.calculate_uniform(&[root]);
+        }
+    }
+
+    /// Stairs, shafts and the middle of every open run along the zone edges
+    fn required_points(zone: &ZoneFactory) -> Vec<(usize, usize)> {
+        let constraints = &zone.ozone.constraints;
+
+        let mut points = constraints
+            .up
+            .0
+            .iter()
+            .chain(constraints.down.0.iter())
+            .map(|constraint| constraint.position)
+            .collect::<Vec<_>>();
+
+        let edges: [(&Vec<ZoneConstraintType>, fn(usize) -> (usize, usize)); 4] = [
+            (&constraints.north.0, |i| (i, 0)),
+            (&constraints.south.0, |i| (i, ZONE_SIZE.1 - 1)),
+            (&constraints.west.0, |i| (0, i)),
+            (&constraints.east.0, |i| (ZONE_SIZE.0 - 1, i)),
+        ];
+
+        for (edge, to_position) in edges {
+            let mut run_start = None;
+
+            for i in 0..=edge.len() {
+                let is_open = edge.get(i).is_some_and(|constraint| {
+                    matches!(
+                        constraint,
+                        ZoneConstraintType::None
+                            | ZoneConstraintType::Road(_)
+                            | ZoneConstraintType::River(_)
+                    )
+                });
+
+                match (is_open, run_start) {
+                    (true, None) => run_start = Some(i),
+                    (false, Some(start)) => {
+                        points.push(to_position(start + (i - start) / 2));
+                        run_start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        points
+    }
+
+    fn open_edge_tiles(zone: &ZoneFactory, walls: &Grid<bool>) -> Vec<(usize, usize)> {
+        let (width, height) = ZONE_SIZE;
+
+        (0..width)
+            .flat_map(|x| [(x, 0), (x, height - 1)])
+            .chain((1..height - 1).flat_map(|y| [(0, y), (width - 1, y)]))
+            .filter(|&(x, y)| {
+                !*walls.get(x, y).unwrap_or(&true) && !Self::is_solid_locked(zone, x, y)
+            })
+            .collect()
+    }
+
+    /// Locked tiles holding anything other than a way up or down can't be walked or dug
+    fn is_solid_locked(zone: &ZoneFactory, x: usize, y: usize) -> bool {
+        if !zone.grid_data.is_locked_tile(x, y) {
+            return false;
+        }
+
+        zone.grid_data.entities.get(x, y).is_some_and(|prefabs| {
+            prefabs.iter().any(|prefab| {
+                !matches!(
+                    prefab.prefab_id,
+                    PrefabId::StairDown
+                        | PrefabId::StairUp
+                        | PrefabId::MineShaft
+                        | PrefabId::MineLadder
+                )
+            })
+        })
+    }
+
+    fn walkable_map(zone: &ZoneFactory, walls: &Grid<bool>) -> DijkstraMap {
+        let mut map = DijkstraMap::new(ZONE_SIZE.0, ZONE_SIZE.1);
+
+        for x in 0..ZONE_SIZE.0 {
+            for y in 0..ZONE_SIZE.1 {
+                if *walls.get(x, y).unwrap_or(&true) || Self::is_solid_locked(zone, x, y) {
+                    map.set_blocked(x, y);
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Digs the cheapest tunnel from `start` back to any tile already reachable in `reach`
+    fn carve_tunnel(
+        zone: &ZoneFactory,
+        walls: &mut Grid<bool>,
+        reach: &DijkstraMap,
+        start: (usize, usize),
+    ) {
+        let goals = reach
+            .iter_costs()
+            .filter(|(_, _, cost)| cost.is_finite())
+            .map(|(x, y, _)| (x, y))
+            .collect::<Vec<_>>();
+
+        let mut dig = DijkstraMap::new(ZONE_SIZE.0, ZONE_SIZE.1);
+
+        for x in 0..ZONE_SIZE.0 {
+            for y in 0..ZONE_SIZE.1 {
+                if Self::is_solid_locked(zone, x, y) {
+                    dig.set_blocked(x, y);
+                }
+            }
+        }
+
+        dig.calculate(&goals, |_, _, nx, ny| {
+            if *walls.get(nx, ny).unwrap_or(&false) {
+                TUNNEL_COST
+            } else {
+                1.0
+            }
+        });
+
+        let mut current = start;
+
+        for _ in 0..ZONE_SIZE.0 * ZONE_SIZE.1 {
+            let Some(cost) = dig.get_cost(current.0, current.1) else {
+                return;
+            };
+
+            if cost <= 0.0 || !cost.is_finite() {
+                return;
+            }
+
+            let Some(next) = Self::downhill(&dig, current) else {
+                return;
+            };
+
+            // Widen diagonal steps so the tunnel can also be walked without cutting corners
+            if next.0 != current.0 && next.1 != current.1 {
+                let (cx, cy) = (next.0, current.1);
+
+                if Self::is_solid_locked(zone, cx, cy) {
+                    walls.set(current.0, next.1, false);
+                } else {
+                    walls.set(cx, cy, false);
+                }
+            }
+
+            walls.set(next.0, next.1, false);
+            current = next;
+        }
+    }
+
+    fn downhill(map: &DijkstraMap, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        let mut best = None;
+        let mut best_cost = map.get_cost(x, y)?;
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                if (dx == 0 && dy == 0)
+                    || nx < 0
+                    || ny < 0
+                    || nx >= map.width() as i32
+                    || ny >= map.height() as i32
+                {
+                    continue;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+
+                if let Some(cost) = map.get_cost(nx, ny)
+                    && cost.is_finite()
+                    && cost < best_cost
+                {
+                    best_cost = cost;
+                    best = Some((nx, ny));
+                }
+            }
+        }
+
+        best
+    }
+}
@@ -14,12 +14,13 @@ pub struct ZoneContinuity {
     pub down: ZoneVerticalConstraints,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ZoneConstraintType {
     None,
     Road(RoadType),
     River(RiverType),
     StairDown,
+    Shaft,
     Rock,
     Foliage,
 }
@@ -104,7 +105,7 @@ pub fn get_edge_continuity(
         let mut rand = Rand::seed(overworld.seed + zone_idx as u32 + neighbor as u32);
 
         let zone_biome = overworld.get_zone_type(zone_idx);
-        let is_cavern = zone_biome == BiomeType::Cavern;
+        let is_cavern = matches!(zone_biome, BiomeType::Cavern | BiomeType::FloodedMine);
 
         let (num_rock_sections, min_length, max_length) = if is_cavern {
             (rand.range_n(3, 7), 4, 12)
@@ -128,6 +129,7 @@ pub fn get_edge_continuity(
             BiomeType::Desert => (0.25, 1, 2), // Medium density, smaller clusters
             BiomeType::DustyPlains => (0.15, 1, 2), // Low density, small clusters
             BiomeType::Cavern => (0.15, 1, 2), // Low density, small clusters
+            BiomeType::FloodedMine => (0.15, 1, 2), // Same as caverns so shared edges agree
             BiomeType::Mountain => (0.3, 1, 2), // Moderate density, small clusters
             _ => (0.0, 0, 0),                  // No foliage for other biomes
         };
@@ -259,7 +261,7 @@ pub fn get_edge_continuity(
 }
 
 pub fn get_vertical_continuity(overworld: &Overworld, zone_idx: usize) -> ZoneVerticalConstraints {
-    let (_x, _y, z) = zone_xyz(zone_idx);
+    let (x, y, z) = zone_xyz(zone_idx);
     let mut constraints = vec![];
 
     if z < SURFACE_LEVEL_Z {
@@ -287,6 +289,22 @@ pub fn get_vertical_continuity(overworld: &Overworld, zone_idx: usize) -> ZoneVe
                 position: (stair_x, stair_y),
                 constraint: ZoneConstraintType::StairDown,
             });
+
+            // Stacked mine levels are also joined by a ladder shaft
+            let below_idx = calculate_zone_idx(x, y, below_z);
+            if overworld.get_zone_type(zone_idx) == BiomeType::FloodedMine
+                && overworld.get_zone_type(below_idx) == BiomeType::FloodedMine
+            {
+                let shaft_x = rand.range_n(5, ZONE_SIZE.0 as i32 - 5) as usize;
+                let shaft_y = rand.range_n(3, ZONE_SIZE.1 as i32 - 3) as usize;
+
+                if shaft_x.abs_diff(stair_x) + shaft_y.abs_diff(stair_y) > 10 {
+                    constraints.push(PositionalConstraint {
+                        position: (shaft_x, shaft_y),
+                        constraint: ZoneConstraintType::Shaft,
+                    });
+                }
+            }
         }
     }
 
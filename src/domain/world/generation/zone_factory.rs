@@ -1,6 +1,10 @@
-use crate::domain::{
-    ConstraintHandler, OverworldZone, Prefab, RiverBuilder, RoadBuilder, Terrain, ZoneData,
-    ZoneGridData,
+use crate::{
+    common::Rand,
+    domain::{
+        BiomeBlend, ConstraintHandler, CrossingBuilder, CrossingType, OverworldPoi, OverworldZone,
+        Prefab, RiverBuilder, RoadBuilder, TemplateTransform, Terrain, TownBuilder, ZoneData,
+        ZoneGridData,
+    },
 };
 use bevy_ecs::world::World;
 
@@ -8,12 +12,14 @@ pub struct ZoneFactory {
     pub zone_idx: usize,
     pub ozone: OverworldZone,
     pub grid_data: ZoneGridData,
+    pub blend: BiomeBlend,
 }
 
 impl ZoneFactory {
     pub fn new(ozone: OverworldZone, registry: &crate::domain::BiomeRegistry) -> Self {
         Self {
             grid_data: ZoneGridData::new(ozone.biome_type.get_primary_terrain(registry)),
+            blend: BiomeBlend::new(&ozone, registry),
             zone_idx: ozone.zone_idx,
             ozone,
         }
@@ -58,19 +64,56 @@ impl ZoneFactory {
             road_builder.build_roads(locked_grid, zone_idx);
         }
 
-        // Apply roads to terrain (will create shallows where roads cross rivers)
-        {
+        // Apply roads to terrain, then get them over any river they run into
+        let crossing_type = self
+            .ozone
+            .crossing
+            .map(|crossing| crossing.crossing_type)
+            .unwrap_or(CrossingType::Ford);
+
+        let river_tiles = {
             let (terrain, locked) = self.grid_data.get_terrain_and_locked_mut();
-            road_builder.apply_roads_to_terrain(terrain, locked, road_terrain);
+            road_builder.apply_roads_to_terrain(terrain, locked, road_terrain, crossing_type)
+        };
+
+        if let Some(crossing) = self.ozone.crossing {
+            CrossingBuilder::build(self, &road_builder.road_grid, &river_tiles, crossing);
+        }
+
+        // Towns claim their streets and lots before the biome fills in the rest
+        if let Some(town) = self.ozone.town.clone() {
+            TownBuilder::build(self, &town, &road_builder.connections, road_terrain);
+        }
+
+        if let Some(poi) = self.ozone.poi.clone() {
+            self.stamp_poi(&poi);
         }
 
         if let Some(biome) = registry.get(biome_type) {
             biome.generate(self, world);
         }
 
+        // Soften the borders with neighbouring biomes
+        BiomeBlend::apply(self);
+
         self.to_zone_data()
     }
 
+    /// Stamps the templates that make up a point of interest, stocking its containers
+    /// from the point of interest's own loot table
+    fn stamp_poi(&mut self, poi: &OverworldPoi) {
+        let mut rand = Rand::seed(self.zone_idx as u32);
+        let loot_table = poi.poi_type.chest_loot_table_id();
+
+        for template_id in poi.poi_type.templates() {
+            let transform = TemplateTransform::random(&mut rand);
+            template_id
+                .template()
+                .with_loot_table(loot_table)
+                .stamp(self, &mut rand, transform);
+        }
+    }
+
     pub fn to_zone_data(&self) -> ZoneData {
         ZoneData {
             terrain: self.grid_data.terrain.clone(),
@@ -33,6 +33,11 @@ impl ZoneGridData {
         *self.locked.get(x, y).unwrap_or(&false)
     }
 
+    /// Whether anything has already been placed on the tile
+    pub fn is_occupied_tile(&self, x: usize, y: usize) -> bool {
+        self.entities.get(x, y).is_some_and(|ents| !ents.is_empty())
+    }
+
     pub fn locked_grid(&self) -> &Grid<bool> {
         &self.locked
     }
@@ -0,0 +1,508 @@
+use std::collections::HashMap;
+
+use crate::{
+    cfg::ZONE_SIZE,
+    common::Rand,
+    domain::{
+        FactionId, LootTableId, Prefab, PrefabId, SpawnValue, Terrain, TrapKind, ZoneFactory,
+    },
+    rendering::zone_local_to_world,
+};
+
+const PLACEMENT_ATTEMPTS: usize = 40;
+
+/// What a single legend character stamps into the zone. Every stamped cell is locked,
+/// so a tile with nothing set is simply kept clear of procedural features.
+#[derive(Clone, Default)]
+pub struct TemplateTile {
+    pub terrain: Option<Terrain>,
+    pub prefab: Option<PrefabId>,
+    pub loot_table: Option<LootTableId>,
+    pub metadata: Vec<(String, SpawnValue)>,
+}
+
+impl TemplateTile {
+    pub fn clear() -> Self {
+        Self::default()
+    }
+
+    pub fn terrain(terrain: Terrain) -> Self {
+        Self {
+            terrain: Some(terrain),
+            ..Self::default()
+        }
+    }
+
+    pub fn prefab(prefab: PrefabId) -> Self {
+        Self {
+            prefab: Some(prefab),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_terrain(mut self, terrain: Terrain) -> Self {
+        self.terrain = Some(terrain);
+        self
+    }
+
+    /// Loot table for a container prefab, such as a chest
+    pub fn with_loot(mut self, loot_table: LootTableId) -> Self {
+        self.loot_table = Some(loot_table);
+        self
+    }
+
+    pub fn with_metadata(mut self, key: &str, value: SpawnValue) -> Self {
+        self.metadata.push((key.to_string(), value));
+        self
+    }
+
+    /// A hidden trap, known only to the faction that set it
+    pub fn trap(kind: TrapKind, set_by: FactionId) -> Self {
+        Self::prefab(PrefabId::Trap)
+            .with_metadata("kind", SpawnValue::TrapKind(kind))
+            .with_metadata("faction", SpawnValue::FactionId(set_by))
+    }
+}
+
+/// A hand-authored layout. Each row of the map is a string, and each character is
+/// looked up in the legend; characters missing from the legend are left to the biome.
+pub struct ZoneTemplate {
+    pub rows: &'static [&'static str],
+    pub legend: HashMap<char, TemplateTile>,
+}
+
+/// Quarter turns clockwise, applied after an optional left-right mirror
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TemplateTransform {
+    pub rotation: u8,
+    pub mirrored: bool,
+}
+
+impl TemplateTransform {
+    pub fn random(rand: &mut Rand) -> Self {
+        Self {
+            rotation: rand.range_n(0, 4) as u8,
+            mirrored: rand.bool(0.5),
+        }
+    }
+
+    /// Size of a `width` x `height` template once transformed
+    pub fn size(&self, width: usize, height: usize) -> (usize, usize) {
+        if self.rotation.is_multiple_of(2) {
+            (width, height)
+        } else {
+            (height, width)
+        }
+    }
+
+    /// Maps a cell of the transformed template back to the cell of the source map it came from
+    pub fn source(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let (sx, sy) = match self.rotation % 4 {
+            0 => (x, y),
+            1 => (y, height - 1 - x),
+            2 => (width - 1 - x, height - 1 - y),
+            _ => (width - 1 - y, x),
+        };
+
+        if self.mirrored {
+            (width - 1 - sx, sy)
+        } else {
+            (sx, sy)
+        }
+    }
+}
+
+impl ZoneTemplate {
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Fills every container in the template from the given loot table instead
+    pub fn with_loot_table(mut self, loot_table: LootTableId) -> Self {
+        for tile in self.legend.values_mut() {
+            if tile.loot_table.is_some() {
+                tile.loot_table = Some(loot_table);
+            }
+        }
+        self
+    }
+
+    fn tile_at(&self, x: usize, y: usize) -> Option<&TemplateTile> {
+        let c = self.rows.get(y)?.chars().nth(x)?;
+        self.legend.get(&c)
+    }
+
+    /// Tries a handful of random positions and stamps the template at the first one where
+    /// it doesn't touch the zone edge, rivers or anything already locked (roads, stairs, towns).
+    pub fn stamp(
+        &self,
+        zone: &mut ZoneFactory,
+        rand: &mut Rand,
+        transform: TemplateTransform,
+    ) -> bool {
+        let (width, height) = transform.size(self.width(), self.height());
+
+        if width + 2 > ZONE_SIZE.0 || height + 2 > ZONE_SIZE.1 {
+            return false;
+        }
+
+        for _ in 0..PLACEMENT_ATTEMPTS {
+            let origin_x = rand.range_n(1, (ZONE_SIZE.0 - width) as i32) as usize;
+            let origin_y = rand.range_n(1, (ZONE_SIZE.1 - height) as i32) as usize;
+
+            if self.fits(zone, origin_x, origin_y, transform) {
+                self.apply(zone, origin_x, origin_y, transform);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn cells(
+        &self,
+        transform: TemplateTransform,
+    ) -> impl Iterator<Item = (usize, usize, &TemplateTile)> {
+        let (source_width, source_height) = (self.width(), self.height());
+        let (width, height) = transform.size(source_width, source_height);
+
+        (0..height).flat_map(move |y| {
+            (0..width).filter_map(move |x| {
+                let (sx, sy) = transform.source(x, y, source_width, source_height);
+                self.tile_at(sx, sy).map(|tile| (x, y, tile))
+            })
+        })
+    }
+
+    fn fits(
+        &self,
+        zone: &ZoneFactory,
+        origin_x: usize,
+        origin_y: usize,
+        transform: TemplateTransform,
+    ) -> bool {
+        self.cells(transform).all(|(x, y, _)| {
+            let (zx, zy) = (origin_x + x, origin_y + y);
+
+            !zone.grid_data.is_locked_tile(zx, zy)
+                && zone
+                    .grid_data
+                    .terrain
+                    .get(zx, zy)
+                    .is_some_and(|t| !matches!(t, Terrain::River | Terrain::Shallows))
+        })
+    }
+
+    fn apply(
+        &self,
+        zone: &mut ZoneFactory,
+        origin_x: usize,
+        origin_y: usize,
+        transform: TemplateTransform,
+    ) {
+        let cells = self
+            .cells(transform)
+            .map(|(x, y, tile)| (origin_x + x, origin_y + y, tile.clone()))
+            .collect::<Vec<_>>();
+
+        for (x, y, tile) in cells {
+            zone.grid_data.locked.set(x, y, true);
+
+            if let Some(terrain) = tile.terrain {
+                zone.set_terrain(x, y, terrain);
+            }
+
+            if let Some(prefab_id) = tile.prefab {
+                let wpos = zone_local_to_world(zone.zone_idx, x, y);
+                let mut prefab = Prefab::new(prefab_id, wpos);
+
+                if let Some(loot_table) = tile.loot_table {
+                    prefab.metadata.insert(
+                        "loot_table_id".to_string(),
+                        SpawnValue::LootTableId(loot_table),
+                    );
+                }
+
+                prefab.metadata.extend(tile.metadata);
+
+                zone.push_entity(x, y, prefab);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZoneTemplateId {
+    Homestead,
+    BanditCamp,
+    MineEntrance,
+    Graveyard,
+    Oasis,
+    Stagecoach,
+    Ruins,
+}
+
+impl ZoneTemplateId {
+    pub fn template(self) -> ZoneTemplate {
+        let mut legend = HashMap::new();
+        legend.insert(',', TemplateTile::clear());
+        legend.insert('.', TemplateTile::terrain(Terrain::Dirt));
+
+        let rows: &'static [&'static str] = match self {
+            // An abandoned two-room cabin with a caved-in back wall
+            ZoneTemplateId::Homestead => {
+                legend.insert(
+                    '#',
+                    TemplateTile::prefab(PrefabId::WoodenWall).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    '+',
+                    TemplateTile::prefab(PrefabId::Door).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'T',
+                    TemplateTile::prefab(PrefabId::Table).with_terrain(Terrain::Dirt),
+                );
+                legend.insert('o', TemplateTile::prefab(PrefabId::Barrel));
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Dirt)
+                        .with_loot(LootTableId::CommonChestLoot),
+                );
+
+                &[
+                    ",,,,,,,,,,,,,",
+                    ",#####+####,,",
+                    ",#....#...#,,",
+                    ",#.T..+..C#,o",
+                    ",#....#...#,,",
+                    ",##.#######,,",
+                    ",,,,,,,,,,,,,",
+                ]
+            }
+            // Bedrolls around a fire inside a ring of rocks, with the take stashed in a chest
+            // and the ways in trapped
+            ZoneTemplateId::BanditCamp => {
+                legend.insert('R', TemplateTile::prefab(PrefabId::Boulder));
+                legend.insert(
+                    'f',
+                    TemplateTile::prefab(PrefabId::Campfire).with_terrain(Terrain::Dirt),
+                );
+                legend.insert('b', TemplateTile::prefab(PrefabId::Bedroll));
+                legend.insert('B', TemplateTile::prefab(PrefabId::Bandit));
+                legend.insert('o', TemplateTile::prefab(PrefabId::Barrel));
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest).with_loot(LootTableId::BanditLoot),
+                );
+                legend.insert(
+                    '^',
+                    TemplateTile::trap(TrapKind::BearTrap, FactionId::Bandits),
+                );
+                legend.insert(
+                    '-',
+                    TemplateTile::trap(TrapKind::Tripwire, FactionId::Bandits),
+                );
+
+                &[
+                    "  RR,^,RR  ",
+                    " R,,,,,,,R ",
+                    "R,,b,,,b,,R",
+                    "^,,,,f,,,,-",
+                    ",B,,,,,,,B,",
+                    "R,,b,,,o,,R",
+                    " R,,,C,,,R ",
+                    "  RR,^,RR  ",
+                ]
+            }
+            // A boarded-up adit cut into a rock face, with the last crew's tools left behind
+            ZoneTemplateId::MineEntrance => {
+                legend.insert('R', TemplateTile::prefab(PrefabId::Boulder));
+                legend.insert(
+                    '#',
+                    TemplateTile::prefab(PrefabId::WoodenWall).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'o',
+                    TemplateTile::prefab(PrefabId::Barrel).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'P',
+                    TemplateTile::prefab(PrefabId::Pickaxe).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'L',
+                    TemplateTile::prefab(PrefabId::Lantern).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Dirt)
+                        .with_loot(LootTableId::MountainChestLoot),
+                );
+
+                &[
+                    "RRRRRRRRRRR",
+                    "RRRR###RRRR",
+                    "RRR..L..RRR",
+                    "RR..o.P..RR",
+                    "R,,.....C,R",
+                    ",,,,...,,,,",
+                    ",,,,,,,,,,,",
+                ]
+            }
+            // Rows of crosses over fresh-turned dirt, and one grave that was dug back up
+            ZoneTemplateId::Graveyard => {
+                legend.insert(
+                    'g',
+                    TemplateTile::prefab(PrefabId::GraveMarker).with_terrain(Terrain::Dirt),
+                );
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Dirt)
+                        .with_loot(LootTableId::CommonChestLoot),
+                );
+
+                &[
+                    ",,,,,,,,,,,",
+                    ",g,g,g,g,g,",
+                    ",.,.,.,.,.,",
+                    ",g,g,g,g,g,",
+                    ",.,.,.,.,.,",
+                    ",g,g,,,g,C,",
+                    ",,,,,,,,,,,",
+                ]
+            }
+            // A spring-fed pool ringed with shallows and shade trees
+            ZoneTemplateId::Oasis => {
+                legend.insert('g', TemplateTile::terrain(Terrain::Grass));
+                legend.insert('=', TemplateTile::terrain(Terrain::Shallows));
+                legend.insert('~', TemplateTile::terrain(Terrain::River));
+                legend.insert(
+                    't',
+                    TemplateTile::prefab(PrefabId::Tree).with_terrain(Terrain::Grass),
+                );
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Grass)
+                        .with_loot(LootTableId::CommonChestLoot),
+                );
+
+                &[
+                    "  ggggggg  ",
+                    " gtg===gtg ",
+                    "gg==~~~==gg",
+                    "g==~~~~~==g",
+                    "gg==~~~==gC",
+                    " gtg===gtg ",
+                    "  ggggggg  ",
+                ]
+            }
+            // A coach on its side with the strongbox still inside and its wheels scattered
+            ZoneTemplateId::Stagecoach => {
+                legend.insert(
+                    '#',
+                    TemplateTile::prefab(PrefabId::WoodenWall).with_terrain(Terrain::Dirt),
+                );
+                legend.insert('w', TemplateTile::prefab(PrefabId::WagonWheel));
+                legend.insert('o', TemplateTile::prefab(PrefabId::Barrel));
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Dirt)
+                        .with_loot(LootTableId::CommonChestLoot),
+                );
+
+                &[
+                    ",,,,,,,,,,",
+                    ",w,,###,,,",
+                    ",,,#C.#,o,",
+                    ",,,,#.#,w,",
+                    ",,,,,,,,,,",
+                ]
+            }
+            // Tumbled stone walls and pillars around a forgotten cache
+            ZoneTemplateId::Ruins => {
+                legend.insert('R', TemplateTile::prefab(PrefabId::Boulder));
+                legend.insert(
+                    'C',
+                    TemplateTile::prefab(PrefabId::Chest)
+                        .with_terrain(Terrain::Dirt)
+                        .with_loot(LootTableId::CommonChestLoot),
+                );
+
+                &[
+                    "RR,RRRR,RRR",
+                    "R.........R",
+                    ",...R.R...,",
+                    "R.........R",
+                    "R...R.R..CR",
+                    "R.........,",
+                    "RRR,RR,RRRR",
+                ]
+            }
+        };
+
+        ZoneTemplate { rows, legend }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_size() {
+        let quarter = TemplateTransform {
+            rotation: 1,
+            mirrored: false,
+        };
+
+        assert_eq!(TemplateTransform::default().size(5, 3), (5, 3));
+        assert_eq!(quarter.size(5, 3), (3, 5));
+    }
+
+    #[test]
+    fn test_transform_source() {
+        // 3 wide, 2 tall source; the top-left cell is (0, 0)
+        let identity = TemplateTransform::default();
+        assert_eq!(identity.source(2, 1, 3, 2), (2, 1));
+
+        // A quarter turn clockwise puts the bottom-left source cell in the top-left
+        let quarter = TemplateTransform {
+            rotation: 1,
+            mirrored: false,
+        };
+        assert_eq!(quarter.source(0, 0, 3, 2), (0, 1));
+        assert_eq!(quarter.source(1, 2, 3, 2), (2, 0));
+
+        let half = TemplateTransform {
+            rotation: 2,
+            mirrored: false,
+        };
+        assert_eq!(half.source(0, 0, 3, 2), (2, 1));
+
+        let three_quarter = TemplateTransform {
+            rotation: 3,
+            mirrored: false,
+        };
+        assert_eq!(three_quarter.source(0, 0, 3, 2), (2, 0));
+
+        let mirrored = TemplateTransform {
+            rotation: 0,
+            mirrored: true,
+        };
+        assert_eq!(mirrored.source(0, 1, 3, 2), (2, 1));
+    }
+}
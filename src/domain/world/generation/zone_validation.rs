@@ -0,0 +1,591 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+use bevy_ecs::world::World;
+
+use crate::{
+    cfg::{MAP_SIZE, ZONE_SIZE},
+    common::{Direction, Grid, Rand},
+    domain::{
+        BiomeRegistry, Collider, ColliderFlags, LootTableRegistry, Overworld, OverworldZone,
+        Prefab, PrefabId, Prefabs, Terrain, TerrainNoise, ZoneConstraintType, ZoneData,
+        ZoneGenerator,
+    },
+    engine::StableIdRegistry,
+    rendering::{zone_idx as calculate_zone_idx, zone_xyz},
+};
+
+/// Something a generated zone got wrong
+#[derive(Clone, Debug, PartialEq)]
+pub enum ZoneViolation {
+    /// The road or river crossing this edge doesn't line up with the neighbouring zone's
+    EdgeMismatch {
+        direction: Direction,
+        index: usize,
+        ours: ZoneConstraintType,
+        theirs: ZoneConstraintType,
+    },
+    /// The edge asks for a road or river here but the terrain doesn't have one
+    EdgeNotCarved {
+        direction: Direction,
+        index: usize,
+        constraint: ZoneConstraintType,
+        terrain: Terrain,
+    },
+    /// A way up or down with nothing waiting at the same spot on the other level
+    StairMisaligned {
+        pos: (usize, usize),
+        prefab_id: PrefabId,
+        expected: PrefabId,
+        other_zone_idx: usize,
+    },
+    /// A stair or edge opening that can't be walked to from the other constraint points
+    Unreachable { pos: (usize, usize) },
+    /// A prefab sharing its tile with something that blocks walking
+    PrefabOnBlockingTile {
+        pos: (usize, usize),
+        prefab_id: PrefabId,
+        blocker: PrefabId,
+    },
+}
+
+impl fmt::Display for ZoneViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZoneViolation::EdgeMismatch {
+                direction,
+                index,
+                ours,
+                theirs,
+            } => write!(
+                f,
+                "{:?} edge tile {} is {:?} but the neighbour has {:?}",
+                direction, index, ours, theirs
+            ),
+            ZoneViolation::EdgeNotCarved {
+                direction,
+                index,
+                constraint,
+                terrain,
+            } => write!(
+                f,
+                "{:?} edge tile {} should be {:?} but is {:?}",
+                direction, index, constraint, terrain
+            ),
+            ZoneViolation::StairMisaligned {
+                pos,
+                prefab_id,
+                expected,
+                other_zone_idx,
+            } => write!(
+                f,
+                "{} at {:?} has no {} in zone {}",
+                prefab_id, pos, expected, other_zone_idx
+            ),
+            ZoneViolation::Unreachable { pos } => write!(f, "{:?} can't be reached", pos),
+            ZoneViolation::PrefabOnBlockingTile {
+                pos,
+                prefab_id,
+                blocker,
+            } => write!(f, "{} spawns inside a {} at {:?}", prefab_id, blocker, pos),
+        }
+    }
+}
+
+/// A violation found while validating many zones at once
+#[derive(Clone, Debug)]
+pub struct ZoneValidationFailure {
+    pub seed: u32,
+    pub zone_idx: usize,
+    pub violation: ZoneViolation,
+}
+
+impl fmt::Display for ZoneValidationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seed {} zone {}: {}",
+            self.seed, self.zone_idx, self.violation
+        )
+    }
+}
+
+/// Generates the zones of a single seed without running the game and checks that they
+/// agree with each other. Generated zones are kept around so neighbours are only built once.
+pub struct ZoneValidator {
+    world: World,
+    zones: HashMap<usize, ZoneData>,
+    blocking: HashMap<PrefabId, Option<PrefabId>>,
+}
+
+impl ZoneValidator {
+    pub fn new(seed: u32) -> Self {
+        let mut world = World::new();
+
+        world.insert_resource(Overworld::new(seed));
+        world.insert_resource(LootTableRegistry::new());
+        // Only needed to spawn a prefab of each kind and see if it blocks
+        world.insert_resource(Prefabs::new());
+        world.insert_resource(Rand::seed(seed));
+        world.insert_resource(TerrainNoise::new(seed));
+        world.insert_resource(StableIdRegistry::new());
+
+        Self {
+            world,
+            zones: HashMap::new(),
+            blocking: HashMap::new(),
+        }
+    }
+
+    /// Generates the zone (if it hasn't been already) and returns its data
+    pub fn zone_data(&mut self, zone_idx: usize) -> &ZoneData {
+        if !self.zones.contains_key(&zone_idx) {
+            let data = ZoneGenerator::generate_zone(&mut self.world, zone_idx);
+            self.zones.insert(zone_idx, data);
+        }
+
+        &self.zones[&zone_idx]
+    }
+
+    /// Checks every invariant for one zone, generating its neighbours above, below and
+    /// to each side as needed
+    pub fn validate(&mut self, zone_idx: usize) -> Vec<ZoneViolation> {
+        let ozone = self
+            .world
+            .resource_mut::<Overworld>()
+            .get_overworld_zone(zone_idx);
+
+        self.zone_data(zone_idx);
+
+        let mut violations = vec![];
+
+        self.check_edges(&ozone, &mut violations);
+        self.check_stairs(zone_idx, &mut violations);
+        self.check_blocked_prefabs(zone_idx, &mut violations);
+        self.check_reachability(&ozone, &mut violations);
+
+        violations
+    }
+
+    /// Both sides of a shared edge must agree on where roads and rivers cross it, and the
+    /// terrain on this side must actually carry them
+    fn check_edges(&mut self, ozone: &OverworldZone, violations: &mut Vec<ZoneViolation>) {
+        let registry = BiomeRegistry::new();
+        let road_terrain = ozone.biome_type.get_road_terrain(&registry);
+        let (x, y, z) = zone_xyz(ozone.zone_idx);
+
+        let edges = [
+            (Direction::North, y.checked_sub(1).map(|ny| (x, ny))),
+            (Direction::South, (y + 1 < MAP_SIZE.1).then_some((x, y + 1))),
+            (Direction::East, (x + 1 < MAP_SIZE.0).then_some((x + 1, y))),
+            (Direction::West, x.checked_sub(1).map(|nx| (nx, y))),
+        ];
+
+        for (direction, neighbor) in edges {
+            let Some((nx, ny)) = neighbor else {
+                continue;
+            };
+
+            let neighbor = self
+                .world
+                .resource_mut::<Overworld>()
+                .get_overworld_zone(calculate_zone_idx(nx, ny, z));
+
+            let ours = edge_constraints(ozone, &direction);
+            let theirs = edge_constraints(&neighbor, &opposite(&direction));
+            let terrain = &self.zone_data(ozone.zone_idx).terrain;
+
+            for (index, (&ours, &theirs)) in ours.iter().zip(theirs.iter()).enumerate() {
+                let crosses = |c: ZoneConstraintType| {
+                    matches!(
+                        c,
+                        ZoneConstraintType::Road(_) | ZoneConstraintType::River(_)
+                    )
+                };
+
+                if (crosses(ours) || crosses(theirs)) && ours != theirs {
+                    violations.push(ZoneViolation::EdgeMismatch {
+                        direction: direction.clone(),
+                        index,
+                        ours,
+                        theirs,
+                    });
+                    continue;
+                }
+
+                let (tx, ty) = edge_position(&direction, index);
+                let tile = *terrain.get(tx, ty).unwrap_or(&Terrain::OpenAir);
+                let is_water = matches!(tile, Terrain::River | Terrain::Shallows);
+
+                let carved = match ours {
+                    ZoneConstraintType::Road(_) => tile == road_terrain || is_water,
+                    ZoneConstraintType::River(_) => is_water,
+                    _ => true,
+                };
+
+                if !carved {
+                    violations.push(ZoneViolation::EdgeNotCarved {
+                        direction: direction.clone(),
+                        index,
+                        constraint: ours,
+                        terrain: tile,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Every way down must land on a way up at the same spot on the level below, and
+    /// every way up must come from a way down on the level above
+    fn check_stairs(&mut self, zone_idx: usize, violations: &mut Vec<ZoneViolation>) {
+        let (x, y, z) = zone_xyz(zone_idx);
+        let above = z.checked_sub(1).map(|az| calculate_zone_idx(x, y, az));
+        let below = (z + 1 < MAP_SIZE.2).then(|| calculate_zone_idx(x, y, z + 1));
+
+        let stairs = self
+            .zone_data(zone_idx)
+            .entities
+            .iter_xy()
+            .flat_map(|(x, y, prefabs)| {
+                prefabs
+                    .iter()
+                    .filter_map(move |prefab| stair_partner(&prefab.prefab_id).map(|p| (x, y, p)))
+            })
+            .collect::<Vec<_>>();
+
+        for (sx, sy, (prefab_id, expected, goes_down)) in stairs {
+            let other_zone_idx = if goes_down { below } else { above };
+
+            let aligned = other_zone_idx.is_some_and(|other| {
+                self.zone_data(other)
+                    .entities
+                    .get(sx, sy)
+                    .is_some_and(|prefabs| prefabs.iter().any(|p| p.prefab_id == expected))
+            });
+
+            if !aligned {
+                violations.push(ZoneViolation::StairMisaligned {
+                    pos: (sx, sy),
+                    prefab_id,
+                    expected,
+                    other_zone_idx: other_zone_idx.unwrap_or(zone_idx),
+                });
+            }
+        }
+    }
+
+    fn check_blocked_prefabs(&mut self, zone_idx: usize, violations: &mut Vec<ZoneViolation>) {
+        let tiles = self
+            .zone_data(zone_idx)
+            .entities
+            .iter_xy()
+            .filter(|(_, _, prefabs)| prefabs.len() > 1)
+            .map(|(x, y, prefabs)| (x, y, prefabs.clone()))
+            .collect::<Vec<_>>();
+
+        for (x, y, prefabs) in tiles {
+            let Some(blocker_idx) = prefabs.iter().position(|p| self.blocker(p).is_some()) else {
+                continue;
+            };
+
+            for (idx, prefab) in prefabs.iter().enumerate() {
+                if idx != blocker_idx {
+                    violations.push(ZoneViolation::PrefabOnBlockingTile {
+                        pos: (x, y),
+                        prefab_id: prefab.prefab_id.clone(),
+                        blocker: prefabs[blocker_idx].prefab_id.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Stairs, shafts and the road and river openings along the edges must all be
+    /// walkable to from one another
+    fn check_reachability(&mut self, ozone: &OverworldZone, violations: &mut Vec<ZoneViolation>) {
+        let constraints = &ozone.constraints;
+
+        let mut points = constraints
+            .up
+            .0
+            .iter()
+            .chain(constraints.down.0.iter())
+            .map(|constraint| constraint.position)
+            .collect::<Vec<_>>();
+
+        for direction in [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ] {
+            for (index, constraint) in edge_constraints(ozone, &direction).iter().enumerate() {
+                if matches!(
+                    constraint,
+                    ZoneConstraintType::Road(_) | ZoneConstraintType::River(_)
+                ) {
+                    points.push(edge_position(&direction, index));
+                }
+            }
+        }
+
+        let entities = self.zone_data(ozone.zone_idx).entities.clone();
+        let blocked = Grid::init_fill(ZONE_SIZE.0, ZONE_SIZE.1, |x, y| {
+            entities
+                .get(x, y)
+                .is_some_and(|prefabs| prefabs.iter().any(|p| self.blocker(p).is_some()))
+        });
+
+        // A point that is itself blocked can't be reached, so it can't be where the search starts
+        let reached = match points
+            .iter()
+            .find(|(x, y)| !*blocked.get(*x, *y).unwrap_or(&true))
+        {
+            Some(&root) => flood_fill(&blocked, root),
+            None => Grid::init(ZONE_SIZE.0, ZONE_SIZE.1, false),
+        };
+
+        for pos in points {
+            if !*reached.get(pos.0, pos.1).unwrap_or(&false) {
+                violations.push(ZoneViolation::Unreachable { pos });
+            }
+        }
+    }
+
+    /// Spawns one of each kind of prefab into the scratch world to find out whether it
+    /// blocks walking, remembering the answer
+    fn blocker(&mut self, prefab: &Prefab) -> Option<PrefabId> {
+        if let Some(blocks) = self.blocking.get(&prefab.prefab_id) {
+            return blocks.clone();
+        }
+
+        let entity = Prefabs::spawn_world(&mut self.world, prefab.clone());
+        let blocks = self
+            .world
+            .get::<Collider>(entity)
+            .is_some_and(|collider| collider.flags.contains(ColliderFlags::BLOCKS_WALK))
+            .then(|| prefab.prefab_id.clone());
+
+        self.world.despawn(entity);
+        self.blocking
+            .insert(prefab.prefab_id.clone(), blocks.clone());
+
+        blocks
+    }
+}
+
+/// Validates a single zone of a seed
+#[cfg(test)]
+pub fn validate_zone(seed: u32, zone_idx: usize) -> Vec<ZoneViolation> {
+    ZoneValidator::new(seed).validate(zone_idx)
+}
+
+/// Validates the same zones across many seeds, collecting every violation found
+pub fn validate_seeds(seeds: &[u32], zone_indices: &[usize]) -> Vec<ZoneValidationFailure> {
+    let mut failures = vec![];
+
+    for &seed in seeds {
+        let mut validator = ZoneValidator::new(seed);
+
+        for &zone_idx in zone_indices {
+            for violation in validator.validate(zone_idx) {
+                failures.push(ZoneValidationFailure {
+                    seed,
+                    zone_idx,
+                    violation,
+                });
+            }
+        }
+    }
+
+    failures
+}
+
+fn opposite(direction: &Direction) -> Direction {
+    match direction {
+        Direction::North => Direction::South,
+        Direction::South => Direction::North,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+    }
+}
+
+fn edge_constraints<'a>(
+    ozone: &'a OverworldZone,
+    direction: &Direction,
+) -> &'a [ZoneConstraintType] {
+    match direction {
+        Direction::North => &ozone.constraints.north.0,
+        Direction::South => &ozone.constraints.south.0,
+        Direction::East => &ozone.constraints.east.0,
+        Direction::West => &ozone.constraints.west.0,
+    }
+}
+
+fn edge_position(direction: &Direction, index: usize) -> (usize, usize) {
+    match direction {
+        Direction::North => (index, 0),
+        Direction::South => (index, ZONE_SIZE.1 - 1),
+        Direction::East => (ZONE_SIZE.0 - 1, index),
+        Direction::West => (0, index),
+    }
+}
+
+/// The prefab a way up or down should meet on the other level, and whether it leads down
+fn stair_partner(prefab_id: &PrefabId) -> Option<(PrefabId, PrefabId, bool)> {
+    match prefab_id {
+        PrefabId::StairDown => Some((PrefabId::StairDown, PrefabId::StairUp, true)),
+        PrefabId::StairUp => Some((PrefabId::StairUp, PrefabId::StairDown, false)),
+        PrefabId::MineShaft => Some((PrefabId::MineShaft, PrefabId::MineLadder, true)),
+        PrefabId::MineLadder => Some((PrefabId::MineLadder, PrefabId::MineShaft, false)),
+        _ => None,
+    }
+}
+
+/// Every tile walkable from `start`, moving in all eight directions
+fn flood_fill(blocked: &Grid<bool>, start: (usize, usize)) -> Grid<bool> {
+    let mut reached = Grid::init(ZONE_SIZE.0, ZONE_SIZE.1, false);
+    let mut open = VecDeque::from([start]);
+
+    reached.set(start.0, start.1, true);
+
+    while let Some((x, y)) = open.pop_front() {
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+
+                if nx < 0 || ny < 0 || nx >= ZONE_SIZE.0 as i32 || ny >= ZONE_SIZE.1 as i32 {
+                    continue;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+
+                if *reached.get(nx, ny).unwrap_or(&true) || *blocked.get(nx, ny).unwrap_or(&true) {
+                    continue;
+                }
+
+                reached.set(nx, ny, true);
+                open.push_back((nx, ny));
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cfg::SURFACE_LEVEL_Z, rendering::zone_local_to_world};
+
+    fn assert_valid(failures: &[ZoneValidationFailure]) {
+        let report = failures
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(failures.is_empty(), "invalid zones:\n{}", report);
+    }
+
+    #[test]
+    fn test_starting_zone_is_valid() {
+        let zone_idx = calculate_zone_idx(2, 6, SURFACE_LEVEL_Z);
+
+        assert_eq!(validate_zone(1, zone_idx), vec![]);
+    }
+
+    #[test]
+    fn test_validate_seeds() {
+        // Surface, mushroom forest and the deeper caverns and mines, plus the map corners
+        let zones = (SURFACE_LEVEL_Z..SURFACE_LEVEL_Z + 4)
+            .flat_map(|z| {
+                [(0, 0), (12, 7), (30, 10), (59, 19)].map(|(x, y)| calculate_zone_idx(x, y, z))
+            })
+            .collect::<Vec<_>>();
+
+        assert_valid(&validate_seeds(&[1, 42, 1234], &zones));
+    }
+
+    #[test]
+    fn test_town_and_poi_zones_are_valid() {
+        for seed in [7, 99] {
+            let mut validator = ZoneValidator::new(seed);
+            let overworld = validator.world.resource::<Overworld>();
+
+            let mut zones = overworld
+                .towns
+                .get(&SURFACE_LEVEL_Z)
+                .map(|towns| towns.keys().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
+            zones.extend(overworld.pois.keys().copied());
+            zones.sort();
+            zones.truncate(12);
+
+            let failures = zones
+                .into_iter()
+                .flat_map(|zone_idx| {
+                    validator
+                        .validate(zone_idx)
+                        .into_iter()
+                        .map(move |violation| ZoneValidationFailure {
+                            seed,
+                            zone_idx,
+                            violation,
+                        })
+                })
+                .collect::<Vec<_>>();
+
+            assert_valid(&failures);
+        }
+    }
+
+    #[test]
+    fn test_detects_blocked_stairs() {
+        let zone_idx = calculate_zone_idx(12, 7, SURFACE_LEVEL_Z);
+        let mut validator = ZoneValidator::new(1);
+
+        let (x, y) = validator
+            .zone_data(zone_idx)
+            .entities
+            .iter_xy()
+            .find(|(_, _, prefabs)| prefabs.iter().any(|p| p.prefab_id == PrefabId::StairDown))
+            .map(|(x, y, _)| (x, y))
+            .expect("surface zones always have a way down");
+
+        // Wall the stairs in and take them away from the level below
+        let below = calculate_zone_idx(12, 7, SURFACE_LEVEL_Z + 1);
+        validator.zone_data(below);
+        validator
+            .zones
+            .get_mut(&below)
+            .unwrap()
+            .entities
+            .set(x, y, vec![]);
+
+        let data = validator.zones.get_mut(&zone_idx).unwrap();
+        let wpos = zone_local_to_world(zone_idx, x, y);
+        data.entities
+            .get_mut(x, y)
+            .unwrap()
+            .push(Prefab::new(PrefabId::Boulder, wpos));
+
+        let violations = validator.validate(zone_idx);
+
+        assert!(violations.contains(&ZoneViolation::StairMisaligned {
+            pos: (x, y),
+            prefab_id: PrefabId::StairDown,
+            expected: PrefabId::StairUp,
+            other_zone_idx: below,
+        }));
+        assert!(violations.contains(&ZoneViolation::PrefabOnBlockingTile {
+            pos: (x, y),
+            prefab_id: PrefabId::StairDown,
+            blocker: PrefabId::Boulder,
+        }));
+        assert!(violations.contains(&ZoneViolation::Unreachable { pos: (x, y) }));
+    }
+}
@@ -6,9 +6,10 @@ use macroquad::prelude::trace;
 use crate::{
     domain::{
         ExplosionEvent, LoadZoneCommand, Overworld, Player, PlayerPosition, TerrainNoise, Zones,
+        player_party, recall_companion,
     },
     engine::{Clock, StableIdRegistry, deserialize, try_load_game},
-    rendering::GameCamera,
+    rendering::{GameCamera, Position},
     states::{CurrentGameState, GameState},
 };
 
@@ -40,8 +41,12 @@ impl LoadGameCommand {
         let position = game_data.player.position;
         let zone_idx = position.zone_idx();
 
-        world.insert_resource(Overworld::new(game_data.seed));
+        let mut overworld = Overworld::new(game_data.seed);
+        overworld.discovered_pois = game_data.discovered_pois.clone();
+
+        world.insert_resource(overworld);
         world.insert_resource(TerrainNoise::new(game_data.seed));
+        world.insert_resource(game_data.weather.clone());
         world.insert_resource(PlayerPosition::from_position(&position));
         world.insert_resource(StableIdRegistry::new());
         world.insert_resource(Zones {
@@ -55,15 +60,15 @@ impl LoadGameCommand {
 
         let _ = LoadZoneCommand(zone_idx).apply(world);
 
-        // Clean up any existing player entities before loading the saved player
-        let existing_players: Vec<Entity> = world
-            .query::<Entity>()
+        // Clean up any existing player entities and their party before loading the saved ones
+        let mut existing: Vec<Entity> = world
+            .query_filtered::<Entity, With<Player>>()
             .iter(world)
-            .filter(|&entity| world.get::<Player>(entity).is_some())
             .collect();
+        existing.extend(player_party(world));
 
-        for player_entity in existing_players {
-            world.despawn(player_entity);
+        for entity in existing {
+            world.despawn(entity);
         }
 
         deserialize(game_data.player.entity, world);
@@ -73,6 +78,18 @@ impl LoadGameCommand {
             deserialize(item_data.clone(), world);
         }
 
+        for companion_data in game_data.player.companions.iter() {
+            let companion = deserialize(companion_data.clone(), world);
+
+            // Anyone left waiting in a zone that isn't loaded comes to find the player
+            if world
+                .get::<Position>(companion)
+                .is_some_and(|p| p.zone_idx() != zone_idx)
+            {
+                recall_companion(world, companion);
+            }
+        }
+
         if let Some(mut clock) = world.get_resource_mut::<Clock>() {
             trace!("LOAD GAME {}", game_data.tick);
             clock.set_tick(game_data.tick);
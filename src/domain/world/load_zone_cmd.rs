@@ -1,7 +1,7 @@
 use bevy_ecs::prelude::*;
 
 use crate::{
-    domain::{GameSettings, Zone, spawn_zone, spawn_zone_load},
+    domain::{GameSettings, Zone, spawn_quest_targets, spawn_zone, spawn_zone_load},
     engine::try_load_zone,
 };
 
@@ -28,12 +28,13 @@ impl Command<Result> for LoadZoneCommand {
             }
         };
 
-        let Some(zone_data) = zone_save_data else {
-            spawn_zone(world, zone_idx);
-            return Ok(());
-        };
+        match zone_save_data {
+            Some(zone_data) => spawn_zone_load(world, zone_data),
+            None => spawn_zone(world, zone_idx),
+        }
 
-        spawn_zone_load(world, zone_data);
+        // Outlaws and lost items only turn up once the player's quests send them there
+        spawn_quest_targets(world, zone_idx);
 
         Ok(())
     }
@@ -6,6 +6,7 @@ mod prefabs;
 mod save_game_cmd;
 mod terrain;
 mod unload_zone_cmd;
+mod weather;
 mod zone;
 
 pub use generation::*;
@@ -16,4 +17,5 @@ pub use prefabs::*;
 pub use save_game_cmd::*;
 pub use terrain::*;
 pub use unload_zone_cmd::*;
+pub use weather::*;
 pub use zone::*;
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy_ecs::prelude::*;
 
@@ -10,7 +10,7 @@ use crate::{
         DynamicEntity, Energy, EquipItemAction, EquipmentSlots, FactionId, FactionMember,
         GameSaveData, Health, Inventory, Label, Level, LoadZoneCommand, MovementCapabilities,
         NeedsStableId, Overworld, Player, PlayerPosition, PlayerSaveData, Prefab, PrefabId,
-        Prefabs, StatModifiers, Stats, TerrainNoise, Vision, Zones,
+        Prefabs, StatModifiers, Stats, TerrainNoise, Vision, Weather, Zones,
     },
     engine::{Clock, StableId, StableIdRegistry, delete_save, save_game, serialize},
     rendering::{GameCamera, Glyph, GlyphTextureId, Layer, Position},
@@ -77,6 +77,7 @@ impl NewGameCommand {
         world.insert_resource(PlayerPosition::from_position(&starting_position));
         world.insert_resource(Overworld::new(self.seed));
         world.insert_resource(TerrainNoise::new(self.seed));
+        world.insert_resource(Weather::default());
         world.insert_resource(Clock::new(40000)); // 6:40am
         // world.insert_resource(Clock::new(100)); // 6:40am
         world.insert_resource(Zones {
@@ -97,6 +98,8 @@ impl NewGameCommand {
             PrefabId::Hatchet,
             PrefabId::Overcoat,
             PrefabId::SteelToeBoots,
+            PrefabId::Canteen,
+            PrefabId::Bedroll,
         ];
 
         let mut spawned_items = Vec::new();
@@ -146,9 +149,17 @@ impl NewGameCommand {
             position: starting_position,
             entity: serialized_player,
             inventory_items,
+            companions: vec![],
         };
 
-        let game_save_data = GameSaveData::new(player_save_data, 0.0, 0, self.seed);
+        let game_save_data = GameSaveData::new(
+            player_save_data,
+            0.0,
+            0,
+            self.seed,
+            HashSet::new(),
+            Weather::default(),
+        );
         save_game(&game_save_data, &self.save_name);
 
         if let Some(mut game_state) = world.get_resource_mut::<CurrentGameState>() {
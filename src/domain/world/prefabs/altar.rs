@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_altar(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(121, Palette::White, Palette::Brown, Layer::Objects)
+        .with_label("Altar")
+        .with_description(
+            "A plain table under a white cloth. A brass cross stands on it, tarnished green.",
+        )
+        .with_collider()
+        .with_destructible(6, MaterialType::Wood)
+}
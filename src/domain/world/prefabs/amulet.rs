@@ -27,7 +27,7 @@ pub fn spawn_amulet(entity: Entity, world: &mut World, config: Prefab) -> Prefab
         .with_description(
             "Worn smooth by desperate fingers. Whatever power it held has long since fled.",
         )
-        .with_item(0.1)
+        .with_item(0.1, 30)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Neck],
             EquipmentType::Accessory,
@@ -1,12 +1,14 @@
 use super::{Prefab, PrefabBuilder};
 use crate::{
     common::Palette,
-    domain::{ConsumableEffect, StackableType},
+    domain::{ConsumableEffect, Food, StackableType},
     rendering::Layer,
 };
 use bevy_ecs::{entity::Entity, world::World};
 
 pub fn spawn_apple(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Food::new(3 * 60));
+
     PrefabBuilder::new()
         .with_base_components(config.pos)
         .with_static_tracking()
@@ -15,7 +17,7 @@ pub fn spawn_apple(entity: Entity, world: &mut World, config: Prefab) -> PrefabB
         .with_description(
             "Wrinkled and bitter-sweet. Someone planted these trees long ago, before the troubles.",
         )
-        .with_item(0.2)
+        .with_item(0.2, 1)
         .with_needs_stable_id()
         .with_stackable(StackableType::Apple, 1)
         .with_consumable(ConsumableEffect::Heal(2), true)
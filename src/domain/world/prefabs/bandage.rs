@@ -0,0 +1,22 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{ConsumableEffect, StackableType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_bandage(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(106, Palette::White, Palette::Red, Layer::Objects)
+        .with_label("Bandage")
+        .with_description(
+            "A roll of boiled linen. Binds a wound tight enough to stop the bleeding and set a bad limb.",
+        )
+        .with_item(0.1, 3)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Bandage, 1)
+        .with_consumable(ConsumableEffect::TreatInjury, true)
+}
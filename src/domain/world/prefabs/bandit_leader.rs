@@ -0,0 +1,59 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{
+        CreatureType, DefaultMeleeAttack, DefaultRangedAttack, FactionId, FactionMember, LootDrop,
+        LootTableId, StatModifier, StatModifiers, StatType,
+        components::ai_controller::{AiController, AiTemplate},
+    },
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+/// A named outlaw with a price on their head, tougher than the gang they run with
+pub fn spawn_bandit_leader(_entity: Entity, _world: &mut World, config: Prefab) -> PrefabBuilder {
+    let label = if let Some(SpawnValue::String(label)) = config.metadata.get("label") {
+        label.as_str()
+    } else {
+        "Bandit Leader"
+    };
+
+    let mut stat_modifiers = StatModifiers::new();
+    stat_modifiers.add_modifier(
+        StatType::Armor,
+        StatModifier::intrinsic(4, "Bandolier".to_string()),
+    );
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_dynamic_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            11,
+            Palette::Yellow,
+            Palette::Red,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label(label)
+        .with_description(
+            "Every sheriff in the territory has this face pinned to the wall. The reward posters don't do justice to the scars.",
+        )
+        .with_energy(-100)
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_default_melee_attack(DefaultMeleeAttack::fists())
+        .with_component(DefaultRangedAttack::revolver())
+        .with_level(7)
+        .with_attributes(crate::domain::Attributes::new(4, 4, 3, 2))
+        .with_stats(crate::domain::Stats::new())
+        .with_stat_modifiers(stat_modifiers)
+        .with_loot_drop(LootDrop::new(LootTableId::HideoutChestLoot, 1.0))
+        .with_creature_type(CreatureType::Bandit)
+        .with_component(
+            AiController::new(AiTemplate::BasicAggressive, config.pos).with_ranges(40, 2, 8),
+        )
+        .with_component(FactionMember::new(FactionId::Bandits))
+        .with_movement_capabilities(crate::domain::MovementFlags::TERRESTRIAL)
+}
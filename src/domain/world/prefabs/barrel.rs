@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_barrel(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(27, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Barrel")
+        .with_description(
+            "Oak staves and iron hoops. Sloshes when kicked, and smells of whiskey or worse.",
+        )
+        .with_collider()
+        .with_destructible(4, MaterialType::Wood)
+}
@@ -0,0 +1,19 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{AttachmentType, WeaponAttachment},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_bayonet(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(20, Palette::Gray, Palette::White, Layer::Objects)
+        .with_label("Bayonet")
+        .with_description("Long spike of cold steel that lugs under a barrel. For when the talking and the shooting are done.")
+        .with_item(0.5, 12)
+        .with_weapon_attachment(WeaponAttachment::new(AttachmentType::Bayonet))
+        .with_needs_stable_id()
+}
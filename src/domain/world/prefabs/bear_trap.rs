@@ -0,0 +1,23 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{StackableType, TrapKind, TrapKit},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_bear_trap(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(TrapKit(TrapKind::BearTrap));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(98, Palette::Gray, Palette::DarkGray, Layer::Objects)
+        .with_label("Bear Trap")
+        .with_description(
+            "Toothed iron jaws on a spring stiff enough to take a man's leg. Set it ({Y|P}) where something will step.",
+        )
+        .with_item(3.0, 12)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::BearTrap, 1)
+}
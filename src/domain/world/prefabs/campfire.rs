@@ -1,8 +1,14 @@
 use super::{Prefab, PrefabBuilder};
-use crate::{common::Palette, domain::LightSource, rendering::Layer};
+use crate::{
+    common::Palette,
+    domain::{Campfire, LightSource},
+    rendering::Layer,
+};
 use bevy_ecs::{entity::Entity, world::World};
 
 pub fn spawn_campfire(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Campfire);
+
     PrefabBuilder::new()
         .with_base_components(config.pos)
         .with_static_tracking() // Campfires never move
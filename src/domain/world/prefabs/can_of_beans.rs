@@ -1,12 +1,14 @@
 use super::{Prefab, PrefabBuilder};
 use crate::{
     common::Palette,
-    domain::{ConsumableEffect, StackableType},
+    domain::{ConsumableEffect, Food, StackableType},
     rendering::Layer,
 };
 use bevy_ecs::{entity::Entity, world::World};
 
 pub fn spawn_can_of_beans(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Food::new(8 * 60));
+
     PrefabBuilder::new()
         .with_base_components(config.pos)
         .with_static_tracking()
@@ -15,7 +17,7 @@ pub fn spawn_can_of_beans(entity: Entity, world: &mut World, config: Prefab) ->
         .with_description(
             "Dented tin packed with refried beans. Tastes like survival and desperation.",
         )
-        .with_item(0.3)
+        .with_item(0.3, 2)
         .with_needs_stable_id()
         .with_stackable(StackableType::CanOfBeans, 1)
         .with_consumable(ConsumableEffect::RestoreArmor(5), true)
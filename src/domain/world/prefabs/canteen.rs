@@ -0,0 +1,23 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{Canteen, ConsumableEffect},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_canteen(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Canteen::new(3));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(55, Palette::Gray, Palette::Brown, Layer::Objects)
+        .with_label("Canteen")
+        .with_description(
+            "Tin wrapped in felt. Soak the felt and the water stays cool, or cooler than the sun would like.",
+        )
+        .with_item(1.0, 4)
+        .with_needs_stable_id()
+        .with_consumable(ConsumableEffect::Drink, false)
+}
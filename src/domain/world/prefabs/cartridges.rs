@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_cartridges(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(112, Palette::Yellow, Palette::DarkYellow, Layer::Objects)
+        .with_label("Cartridges")
+        .with_description(
+            "Hand-loaded brass, each one weighed and crimped by lamplight. Any storekeeper will pay for them.",
+        )
+        .with_item(0.1, 4)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Cartridges, 1)
+}
@@ -21,7 +21,7 @@ pub fn spawn_cavalry_sword(entity: Entity, world: &mut World, config: Prefab) ->
         .with_glyph(20, Palette::Yellow, Palette::Gray, Layer::Objects)
         .with_label(&generated_weapon.name)
         .with_description(&generated_weapon.description)
-        .with_item(3.0)
+        .with_item(3.0, 20)
         .with_equippable(Equippable::weapon_one_handed())
         .with_weapon(generated_weapon.weapon)
         .with_needs_stable_id();
@@ -0,0 +1,32 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{Door, Lock, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+/// The key the sheriff keeps for the cells
+pub const JAIL_KEY: &str = "Jail";
+
+pub fn spawn_cell_door(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let door = Door::new(false, 102, 103).barred();
+
+    let builder = PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(door.glyph(), Palette::Gray, Palette::DarkGray, Layer::Objects)
+        .with_label("Cell Door")
+        .with_description(
+            "A barred gate on iron hinges, padlocked through the frame. Somebody's carved a calendar into the post.",
+        )
+        .with_collider_flags(door.collider_flags())
+        .with_destructible(20, MaterialType::Stone);
+
+    world
+        .entity_mut(entity)
+        .insert((door, Lock::new(JAIL_KEY, 3)));
+
+    builder
+}
@@ -1,5 +1,10 @@
 use super::{Prefab, PrefabBuilder, SpawnValue};
-use crate::{common::Palette, domain::UnopenedContainer, engine::AudioKey, rendering::Layer};
+use crate::{
+    common::Palette,
+    domain::{Lock, UnopenedContainer},
+    engine::AudioKey,
+    rendering::Layer,
+};
 use bevy_ecs::{entity::Entity, world::World};
 
 pub fn spawn_chest(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
@@ -20,5 +25,11 @@ pub fn spawn_chest(entity: Entity, world: &mut World, config: Prefab) -> PrefabB
             .insert(UnopenedContainer(*loot_table_id));
     }
 
+    if let Some(SpawnValue::Int(difficulty)) = config.metadata.get("lock_difficulty") {
+        world
+            .entity_mut(entity)
+            .insert(Lock::new("Strongbox", *difficulty));
+    }
+
     builder
 }
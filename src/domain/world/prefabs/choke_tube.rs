@@ -0,0 +1,21 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{AttachmentType, WeaponAttachment},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_choke_tube(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(112, Palette::Gray, Palette::Brown, Layer::Objects)
+        .with_label("Choke Tube")
+        .with_description(
+            "Threads onto a scattergun's muzzle and squeezes the pellets into a tighter fist.",
+        )
+        .with_item(0.5, 15)
+        .with_weapon_attachment(WeaponAttachment::new(AttachmentType::ChokeTube))
+        .with_needs_stable_id()
+}
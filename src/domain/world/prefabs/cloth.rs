@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_cloth(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(106, Palette::White, Palette::Gray, Layer::Objects)
+        .with_label("Cloth")
+        .with_description(
+            "A bolt of clean calico. Worth more as bandages than as a shirt out here.",
+        )
+        .with_item(0.3, 1)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Cloth, 1)
+}
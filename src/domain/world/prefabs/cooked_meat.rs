@@ -0,0 +1,24 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{ConsumableEffect, Food, StackableType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_cooked_meat(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Food::new(10 * 60));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(58, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Cooked Meat")
+        .with_description(
+            "Charred outside, pink in the middle, smelling of woodsmoke. The best meal for fifty miles.",
+        )
+        .with_item(0.4, 3)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::CookedMeat, 1)
+        .with_consumable(ConsumableEffect::Heal(5), true)
+}
@@ -0,0 +1,37 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    domain::{Corpse, CorpseStage, CreatureType},
+    engine::Clock,
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_corpse(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let creature = if let Some(SpawnValue::CreatureType(creature)) = config.metadata.get("creature")
+    {
+        *creature
+    } else {
+        CreatureType::Coyote
+    };
+
+    let name = if let Some(SpawnValue::String(name)) = config.metadata.get("name") {
+        name.clone()
+    } else {
+        "Coyote".to_string()
+    };
+
+    let died_tick = world.resource::<Clock>().current_tick();
+    let corpse = Corpse::new(creature, &name, died_tick);
+    let label = corpse.label();
+    let (glyph, fg1, fg2) = CorpseStage::Fresh.glyph();
+
+    world.entity_mut(entity).insert(corpse);
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(glyph, fg1, fg2, Layer::Objects)
+        .with_label(&label)
+        .with_description(CorpseStage::Fresh.description())
+}
@@ -0,0 +1,15 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_counter(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(91, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Counter")
+        .with_description("Polished smooth by elbows and coin. There's a bullet hole under the lip nobody talks about.")
+        .with_collider()
+        .with_destructible(6, MaterialType::Wood)
+}
@@ -0,0 +1,34 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{Doctor, FactionId, FactionMember},
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_doctor(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Doctor);
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            22,
+            Palette::White,
+            Palette::Brown,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label("Doctor")
+        .with_description(
+            "Smells of carbolic and rye whiskey. Will set a bone or stitch a wound for anyone who walks in.",
+        )
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_level(3)
+        .with_attributes(crate::domain::Attributes::new(1, 3, 2, 4))
+        .with_stats(crate::domain::Stats::new())
+        .with_component(FactionMember::new(FactionId::Townsfolk))
+}
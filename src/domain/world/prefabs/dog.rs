@@ -0,0 +1,42 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{
+        Attributes, CreatureType, DefaultMeleeAttack, Dialogue, FactionId, FactionMember, Stats,
+        components::ai_controller::{AiController, AiTemplate},
+    },
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_dog(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Dialogue::new("stray_dog"));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_dynamic_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            35,
+            Palette::White,
+            Palette::Brown,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label("Stray Dog")
+        .with_description(
+            "All ribs and ears, with a torn rope still knotted round its neck. Someone left it behind, or it left them.",
+        )
+        .with_energy(-120)
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_default_melee_attack(DefaultMeleeAttack::bite())
+        .with_level(2)
+        .with_attributes(Attributes::new(2, 3, 2, 1))
+        .with_stats(Stats::new())
+        .with_stat_modifiers(crate::domain::StatModifiers::new())
+        .with_creature_type(CreatureType::Dog)
+        .with_component(AiController::new(AiTemplate::BasicAggressive, config.pos))
+        .with_component(FactionMember::new(FactionId::Townsfolk))
+}
@@ -0,0 +1,37 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{Door, Lock, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_door(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let is_open = matches!(config.metadata.get("open"), Some(SpawnValue::Bool(true)));
+    let door = Door::new(is_open, 104, 101);
+
+    let builder = PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(door.glyph(), Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Door")
+        .with_description(
+            "Swings on a single leather hinge. Kept shut against the dust more than the neighbours.",
+        )
+        .with_collider_flags(door.collider_flags())
+        .with_destructible(10, MaterialType::Wood);
+
+    world.entity_mut(entity).insert(door);
+
+    if let Some(SpawnValue::String(key)) = config.metadata.get("lock") {
+        let difficulty = match config.metadata.get("lock_difficulty") {
+            Some(SpawnValue::Int(difficulty)) => *difficulty,
+            _ => 2,
+        };
+
+        world.entity_mut(entity).insert(Lock::new(key, difficulty));
+    }
+
+    builder
+}
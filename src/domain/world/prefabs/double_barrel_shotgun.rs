@@ -1,7 +1,7 @@
 use super::{Prefab, PrefabBuilder, generate_weapon_from_prefab};
 use crate::{
     common::Palette,
-    domain::{EquipmentSlot, EquipmentType, Equippable, Weapon},
+    domain::{EquipmentSlot, EquipmentType, Equippable, Weapon, WeaponAttachments, WeaponFamily},
     rendering::Layer,
 };
 use bevy_ecs::{entity::Entity, world::World};
@@ -24,13 +24,14 @@ pub fn spawn_double_barrel_shotgun(
         .with_glyph(204, Palette::Gray, Palette::Brown, Layer::Objects)
         .with_label(&generated_weapon.name)
         .with_description(&generated_weapon.description)
-        .with_item(3.5)
+        .with_item(3.5, 45)
         .with_static_tracking()
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::BothHands],
             EquipmentType::Weapon,
         ))
         .with_weapon(generated_weapon.weapon)
+        .with_weapon_attachments(WeaponAttachments::for_family(WeaponFamily::Shotgun))
         .with_needs_stable_id();
 
     // Add the rarity component
@@ -21,7 +21,7 @@ pub fn spawn_duster(entity: Entity, world: &mut World, config: Prefab) -> Prefab
         .with_description(
             "Trail-beaten canvas that's seen too many sunsets. Pockets full of dust and regret.",
         )
-        .with_item(1.5)
+        .with_item(1.5, 15)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Body],
             EquipmentType::Armor,
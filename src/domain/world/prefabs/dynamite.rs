@@ -13,7 +13,7 @@ pub fn spawn_dynamite(entity: Entity, world: &mut World, config: Prefab) -> Pref
         .with_glyph(25, Palette::Red, Palette::White, Layer::Objects)
         .with_label("Dynamite")
         .with_description("Sweating nitroglycerin in the heat. The miner's prayer and last resort.")
-        .with_item(0.5)
+        .with_item(0.5, 8)
         .with_needs_stable_id()
         .with_static_tracking()
         .with_stackable(StackableType::Dynamite, 1)
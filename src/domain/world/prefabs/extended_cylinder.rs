@@ -0,0 +1,19 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{AttachmentType, WeaponAttachment},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_extended_cylinder(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(24, Palette::Gray, Palette::Gray, Layer::Objects)
+        .with_label("Extended Cylinder")
+        .with_description("A gunsmith's oddity with two extra chambers bored into the steel. Heavy, but it talks longer.")
+        .with_item(0.5, 20)
+        .with_weapon_attachment(WeaponAttachment::new(AttachmentType::ExtendedCylinder))
+        .with_needs_stable_id()
+}
@@ -0,0 +1,32 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{Ferry, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_ferry(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let landing =
+        ["landing_x", "landing_y", "landing_z"].map(|key| match config.metadata.get(key) {
+            Some(SpawnValue::Int(value)) => Some(*value as usize),
+            _ => None,
+        });
+
+    if let [Some(x), Some(y), Some(z)] = landing {
+        world
+            .entity_mut(entity)
+            .insert(Ferry { landing: (x, y, z) });
+    }
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(106, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Ferry")
+        .with_description(
+            "A flat raft strung along a rope to the far bank. The ferryman takes his fare in silence.",
+        )
+        .with_destructible(12, MaterialType::Wood)
+}
@@ -0,0 +1,34 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{ColliderFlags, Fire, LightSource},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_fire(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let fuel = if let Some(SpawnValue::Int(fuel)) = config.metadata.get("fuel") {
+        (*fuel).max(1) as u32
+    } else {
+        5
+    };
+
+    world.entity_mut(entity).insert(Fire::new(fuel));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_animated_glyph(
+            vec![36, 37, 38],
+            6.0,
+            Palette::Orange,
+            Palette::Yellow,
+            Layer::Objects,
+            true,
+        )
+        .with_label("{R|Fire}")
+        .with_description("The brush goes up like it's been waiting for it. Nothing to do but stay clear and let it burn.")
+        .with_collider_flags(ColliderFlags::BLOCKS_WALK)
+        .with_light_source(LightSource::wildfire())
+}
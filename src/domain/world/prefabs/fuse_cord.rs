@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_fuse_cord(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(105, Palette::Gray, Palette::Red, Layer::Objects)
+        .with_label("Fuse Cord")
+        .with_description(
+            "Tarred cord packed with powder. Burns a foot every half minute, or so they say.",
+        )
+        .with_item(0.1, 2)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::FuseCord, 1)
+}
@@ -11,7 +11,7 @@ pub fn spawn_gold_nugget(entity: Entity, world: &mut World, config: Prefab) -> P
         .with_description(
             "Heavy with promise and cursed with blood. Every ounce has a ghost attached.",
         )
-        .with_item(0.5)
+        .with_item(0.5, 1)
         .with_needs_stable_id()
         .with_stackable(StackableType::GoldNugget, 1)
 }
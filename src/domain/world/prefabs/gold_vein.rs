@@ -0,0 +1,29 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{BitmaskStyle, ColliderFlags, LootDrop, LootTableId, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_gold_vein(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let loot_drop = LootDrop {
+        drop_count: 2,
+        ..LootDrop::new(LootTableId::GoldVeinLoot, 1.0)
+    };
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(68, Palette::Gray, Palette::Yellow, Layer::Objects)
+        .with_bitmask(BitmaskStyle::Rocks)
+        .with_label("{Y|Gold Vein}")
+        .with_description(
+            "A seam of yellow threaded through the rock. The miners never got this far down.",
+        )
+        .with_collider_flags(ColliderFlags::WALL)
+        .with_destructible(14, MaterialType::Stone)
+        .with_light_blocker()
+        .with_loot_drop(loot_drop)
+}
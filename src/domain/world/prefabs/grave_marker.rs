@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_grave_marker(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(99, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Grave Marker")
+        .with_description(
+            "Two sticks lashed into a cross. Whatever name was carved here, the wind took it.",
+        )
+        .with_collider()
+        .with_destructible(2, MaterialType::Wood)
+}
@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_gunpowder(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(40, Palette::DarkGray, Palette::Gray, Layer::Objects)
+        .with_label("Gunpowder")
+        .with_description(
+            "A corked flask of black powder. Keep it dry and keep it away from the fire.",
+        )
+        .with_item(0.3, 3)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Gunpowder, 1)
+}
@@ -0,0 +1,53 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{
+        Attributes, CreatureType, DefaultMeleeAttack, DefaultRangedAttack, Dialogue, FactionId,
+        FactionMember, StatModifier, StatModifiers, StatType, Stats,
+        components::ai_controller::{AiController, AiTemplate},
+    },
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_gunslinger(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Dialogue::new("gunslinger"));
+
+    let mut stat_modifiers = StatModifiers::new();
+    stat_modifiers.add_modifier(
+        StatType::Armor,
+        StatModifier::intrinsic(2, "Leather Vest".to_string()),
+    );
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_dynamic_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            7,
+            Palette::White,
+            Palette::DarkGray,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label("Gunslinger")
+        .with_description(
+            "Nurses the same drink all night with their back to the wall. Quick with a gun, and for the right price it's yours.",
+        )
+        .with_energy(-100)
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_default_melee_attack(DefaultMeleeAttack::fists())
+        .with_component(DefaultRangedAttack::revolver())
+        .with_level(4)
+        .with_attributes(Attributes::new(3, 4, 2, 2))
+        .with_stats(Stats::new())
+        .with_stat_modifiers(stat_modifiers)
+        .with_creature_type(CreatureType::Gunslinger)
+        .with_component(
+            AiController::new(AiTemplate::Peaceful, config.pos).with_ranges(12, 0, 6),
+        )
+        .with_component(FactionMember::new(FactionId::Townsfolk))
+        .with_movement_capabilities(crate::domain::MovementFlags::TERRESTRIAL)
+}
@@ -1,7 +1,7 @@
 use super::{Prefab, PrefabBuilder, generate_weapon_from_prefab};
 use crate::{
     common::Palette,
-    domain::{Equippable, Weapon},
+    domain::{Equippable, Tool, ToolType, Weapon},
     rendering::Layer,
 };
 use bevy_ecs::{entity::Entity, world::World};
@@ -21,13 +21,15 @@ pub fn spawn_hatchet(entity: Entity, world: &mut World, config: Prefab) -> Prefa
         .with_glyph(21, Palette::Brown, Palette::Gray, Layer::Objects)
         .with_label(&generated_weapon.name)
         .with_description(&generated_weapon.description)
-        .with_item(2.0)
+        .with_item(2.0, 6)
         .with_equippable(Equippable::tool())
         .with_weapon(generated_weapon.weapon)
         .with_needs_stable_id();
 
-    // Add the rarity component
-    world.entity_mut(entity).insert(generated_weapon.rarity);
+    // Add the rarity component, and mark it as a tool for crafting
+    world
+        .entity_mut(entity)
+        .insert((generated_weapon.rarity, Tool(ToolType::Hatchet)));
 
     builder
 }
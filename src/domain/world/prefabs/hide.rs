@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_hide(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(106, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Hide")
+        .with_description(
+            "A rolled pelt, scraped more or less clean. Stinks to high heaven, but the tannery in town pays fair.",
+        )
+        .with_item(1.0, 6)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Hide, 1)
+}
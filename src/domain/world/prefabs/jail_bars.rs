@@ -0,0 +1,21 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{ColliderFlags, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_jail_bars(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(103, Palette::Gray, Palette::DarkGray, Layer::Objects)
+        .with_label("Jail Bars")
+        .with_description(
+            "Iron bars set deep in the floorboards. Scratched tallies cover the wall behind them.",
+        )
+        .with_collider_flags(ColliderFlags::SOLID | ColliderFlags::BLOCKS_FLY)
+        .with_destructible(20, MaterialType::Stone)
+}
@@ -0,0 +1,24 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{common::Palette, domain::Key, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_key(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let key = match config.metadata.get("key") {
+        Some(SpawnValue::String(key)) => key.clone(),
+        _ => "Old".to_string(),
+    };
+
+    let label = format!("{} Key", key);
+    world.entity_mut(entity).insert(Key(key));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(28, Palette::Yellow, Palette::DarkYellow, Layer::Objects)
+        .with_label(&label)
+        .with_description(
+            "A heavy iron key on a loop of twine. Somewhere there's a lock that's missing it.",
+        )
+        .with_item(0.1, 5)
+}
@@ -14,13 +14,14 @@ pub fn spawn_lantern(entity: Entity, world: &mut World, config: Prefab) -> Prefa
         .with_glyph(22, Palette::Gray, Palette::Yellow, Layer::Objects)
         .with_label("Lantern")
         .with_description(
-            "Brass and glass, tarnished with age. The wick burns memories as much as oil.",
+            "Brass and glass, tarnished with age. The wick burns memories as much as oil. Thrown lit, it'll set the brush alight where it breaks.",
         )
-        .with_item(1.0)
+        .with_item(1.0, 10)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::OffHand],
             EquipmentType::Tool,
         ))
+        .with_throwable_char(4, '*', Palette::Yellow.into())
         .with_light_source(LightSource::lantern())
         .with_lightable_audio(AudioKey::IgniteMatch, None)
         .with_needs_stable_id()
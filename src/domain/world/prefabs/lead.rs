@@ -0,0 +1,15 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_lead(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(28, Palette::Gray, Palette::DarkGray, Layer::Objects)
+        .with_label("Lead")
+        .with_description("A dull lump of lead, soft enough to scratch with a thumbnail. Melts down into bullets.")
+        .with_item(0.5, 2)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Lead, 1)
+}
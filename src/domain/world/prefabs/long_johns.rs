@@ -25,7 +25,7 @@ pub fn spawn_long_johns(entity: Entity, world: &mut World, config: Prefab) -> Pr
         .with_description(
             "Threadbare dignity against the cold. The frontier strips everything else away.",
         )
-        .with_item(0.5)
+        .with_item(0.5, 4)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Legs],
             EquipmentType::Armor,
@@ -0,0 +1,53 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{FactionId, FactionMember, LootTableId, Merchant},
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_merchant(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let glyph = if let Some(SpawnValue::Int(glyph)) = config.metadata.get("glyph") {
+        *glyph as usize
+    } else {
+        24
+    };
+
+    let label = if let Some(SpawnValue::String(label)) = config.metadata.get("label") {
+        label.as_str()
+    } else {
+        "Shopkeeper"
+    };
+
+    let stock = if let Some(SpawnValue::LootTableId(stock)) = config.metadata.get("stock") {
+        *stock
+    } else {
+        LootTableId::GeneralStoreStock
+    };
+
+    world.entity_mut(entity).insert(Merchant::new(stock));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            glyph,
+            Palette::White,
+            Palette::Brown,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label(label)
+        .with_description(
+            "Keeps a ledger, a scale for weighing gold and a shotgun under the counter. Buys and sells for nuggets.",
+        )
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_inventory(200.0)
+        .with_level(2)
+        .with_attributes(crate::domain::Attributes::new(2, 2, 2, 3))
+        .with_stats(crate::domain::Stats::new())
+        .with_component(FactionMember::new(FactionId::Townsfolk))
+}
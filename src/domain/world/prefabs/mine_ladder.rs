@@ -0,0 +1,14 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_mine_ladder(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(109, Palette::Brown, Palette::White, Layer::Objects)
+        .with_label("Ladder")
+        .with_description("Slick rungs climbing up the shaft to the workings above.")
+        .with_stair_up()
+}
@@ -0,0 +1,18 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_mine_rail(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(
+            110,
+            Palette::DarkGray,
+            Palette::DarkBrown,
+            Layer::GroundOverlay,
+        )
+        .with_label("Mine Rail")
+        .with_description("Rusted track laid for ore carts. The last cart left a long time ago.")
+}
@@ -0,0 +1,16 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_mine_shaft(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(109, Palette::Brown, Palette::Black, Layer::Objects)
+        .with_label("Mine Shaft")
+        .with_description(
+            "A timbered hole with a ladder nailed to one side. Cold air breathes up out of it.",
+        )
+        .with_stair_down()
+}
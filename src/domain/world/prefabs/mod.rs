@@ -1,89 +1,183 @@
+mod altar;
 mod amulet;
 mod apple;
 mod bald_cypress;
+mod bandage;
 mod bandit;
+mod bandit_leader;
+mod barrel;
 mod bat;
+mod bayonet;
+mod bear_trap;
 mod bedroll;
 mod boulder;
 mod brown_bear;
 mod cactus;
 mod campfire;
 mod can_of_beans;
+mod canteen;
+mod cartridges;
 mod cavalry_sword;
+mod cell_door;
 mod chest;
+mod choke_tube;
+mod cloth;
+mod cooked_meat;
+mod corpse;
+mod counter;
 mod coyote;
+mod doctor;
+mod dog;
+mod door;
 mod double_barrel_shotgun;
 mod duster;
 mod dynamite;
+mod extended_cylinder;
+mod ferry;
+mod fire;
+mod fuse_cord;
 mod giant_beetle;
 mod giant_firefly;
 mod giant_mushroom;
 mod gold_nugget;
+mod gold_vein;
+mod grave_marker;
+mod gunpowder;
+mod gunslinger;
 mod hatchet;
+mod hide;
+mod jail_bars;
+mod key;
 mod lantern;
+mod lead;
 mod lever_action_rifle;
 mod long_johns;
+mod merchant;
+mod mine_ladder;
+mod mine_rail;
+mod mine_shaft;
 mod navy_revolver;
 mod overcoat;
+mod pew;
 mod pickaxe;
 mod pine_tree;
 mod player;
 mod poncho;
 mod prefab_builder;
 mod prefabs;
+mod quest_item;
 mod rat;
 mod rattlesnake;
+mod raw_meat;
 mod ring;
+mod scope;
+mod shelf;
+mod snare;
 mod spawn_prefab_cmd;
 mod stair_down;
 mod stair_up;
 mod steel_toe_boots;
+mod table;
 mod terrain_tile;
+mod townsfolk;
+mod trap;
 mod tree;
+mod tripwire;
+mod venom_gland;
+mod wagon_wheel;
 mod weapon_generation_helper;
+mod wooden_bridge;
+mod wooden_wall;
 mod wool_shirt;
 
+pub use altar::*;
 pub use amulet::*;
 pub use apple::*;
 pub use bald_cypress::*;
+pub use bandage::*;
 pub use bandit::*;
+pub use bandit_leader::*;
+pub use barrel::*;
 pub use bat::*;
+pub use bayonet::*;
+pub use bear_trap::*;
 pub use bedroll::*;
 pub use boulder::*;
 pub use brown_bear::*;
 pub use cactus::*;
 pub use campfire::*;
 pub use can_of_beans::*;
+pub use canteen::*;
+pub use cartridges::*;
 pub use cavalry_sword::*;
+pub use cell_door::*;
 pub use chest::*;
+pub use choke_tube::*;
+pub use cloth::*;
+pub use cooked_meat::*;
+pub use corpse::*;
+pub use counter::*;
 pub use coyote::*;
+pub use doctor::*;
+pub use dog::*;
+pub use door::*;
 pub use double_barrel_shotgun::*;
 pub use duster::*;
 pub use dynamite::*;
+pub use extended_cylinder::*;
+pub use ferry::*;
+pub use fire::*;
+pub use fuse_cord::*;
 pub use giant_beetle::*;
 pub use giant_firefly::*;
 pub use giant_mushroom::*;
 pub use gold_nugget::*;
+pub use gold_vein::*;
+pub use grave_marker::*;
+pub use gunpowder::*;
+pub use gunslinger::*;
 pub use hatchet::*;
+pub use hide::*;
+pub use jail_bars::*;
+pub use key::*;
 pub use lantern::*;
+pub use lead::*;
 pub use lever_action_rifle::*;
 pub use long_johns::*;
+pub use merchant::*;
+pub use mine_ladder::*;
+pub use mine_rail::*;
+pub use mine_shaft::*;
 pub use navy_revolver::*;
 pub use overcoat::*;
+pub use pew::*;
 pub use pickaxe::*;
 pub use pine_tree::*;
 pub use player::*;
 pub use poncho::*;
 pub use prefab_builder::*;
 pub use prefabs::*;
+pub use quest_item::*;
 pub use rat::*;
 pub use rattlesnake::*;
+pub use raw_meat::*;
 pub use ring::*;
+pub use scope::*;
+pub use shelf::*;
+pub use snare::*;
 pub use spawn_prefab_cmd::*;
 pub use stair_down::*;
 pub use stair_up::*;
 pub use steel_toe_boots::*;
+pub use table::*;
 pub use terrain_tile::*;
+pub use townsfolk::*;
+pub use trap::*;
 pub use tree::*;
+pub use tripwire::*;
+pub use venom_gland::*;
+pub use wagon_wheel::*;
 pub use weapon_generation_helper::*;
+pub use wooden_bridge::*;
+pub use wooden_wall::*;
 pub use wool_shirt::*;
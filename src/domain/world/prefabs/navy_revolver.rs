@@ -1,7 +1,7 @@
 use super::{Prefab, PrefabBuilder, generate_weapon_from_prefab};
 use crate::{
     common::Palette,
-    domain::{EquipmentSlot, EquipmentType, Equippable, Weapon},
+    domain::{EquipmentSlot, EquipmentType, Equippable, Weapon, WeaponAttachments, WeaponFamily},
     rendering::Layer,
 };
 use bevy_ecs::{entity::Entity, world::World};
@@ -21,12 +21,13 @@ pub fn spawn_navy_revolver(entity: Entity, world: &mut World, config: Prefab) ->
         .with_glyph(201, Palette::Gray, Palette::Brown, Layer::Objects)
         .with_label(&generated_weapon.name)
         .with_description(&generated_weapon.description)
-        .with_item(1.5)
+        .with_item(1.5, 35)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::MainHand],
             EquipmentType::Weapon,
         ))
         .with_weapon(generated_weapon.weapon)
+        .with_weapon_attachments(WeaponAttachments::for_family(WeaponFamily::Pistol))
         .with_needs_stable_id();
 
     // Add the rarity component
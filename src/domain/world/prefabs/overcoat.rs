@@ -21,7 +21,7 @@ pub fn spawn_overcoat(entity: Entity, world: &mut World, config: Prefab) -> Pref
         .with_description(
             "Buffalo hide and brass buttons. Heavy with the weight of winters survived.",
         )
-        .with_item(2.0)
+        .with_item(2.0, 18)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Body],
             EquipmentType::Armor,
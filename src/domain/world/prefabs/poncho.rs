@@ -19,7 +19,7 @@ pub fn spawn_poncho(entity: Entity, world: &mut World, config: Prefab) -> Prefab
         .with_glyph(69, Palette::Yellow, Palette::Brown, Layer::Objects)
         .with_label("Poncho")
         .with_description("Faded patterns from another land. Rain runs off like tears on stone.")
-        .with_item(1.0)
+        .with_item(1.0, 8)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Body],
             EquipmentType::Armor,
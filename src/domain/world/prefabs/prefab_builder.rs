@@ -9,7 +9,8 @@ use crate::{
         InventoryAccessible, Item, Label, Level, LightBlocker, LightSource, Lightable, LootDrop,
         MaterialType, MovementCapabilities, NeedsStableId, Player, SaveFlag, StackCount, Stackable,
         StackableType, StairDown, StairUp, StatModifiers, StaticEntity, StaticEntitySpawnedEvent,
-        Stats, Throwable, Vision, Weapon, components::ai_controller::AiController,
+        Stats, Throwable, Vision, Weapon, WeaponAttachment, WeaponAttachments,
+        components::ai_controller::AiController,
     },
     engine::AudioKey,
     rendering::{AnimatedGlyph, Glyph, GlyphTextureId, Layer, Position},
@@ -66,6 +67,8 @@ pub enum PrefabComponent {
     AttributePoints(AttributePoints),
     ExplosiveProperties(ExplosiveProperties),
     AiController(AiController),
+    WeaponAttachment(WeaponAttachment),
+    WeaponAttachments(WeaponAttachments),
 }
 
 pub struct PrefabBuilder {
@@ -224,9 +227,9 @@ impl PrefabBuilder {
         self
     }
 
-    pub fn with_item(mut self, weight: f32) -> Self {
+    pub fn with_item(mut self, weight: f32, value: u32) -> Self {
         self.components
-            .push(PrefabComponent::Item(Item::new(weight)));
+            .push(PrefabComponent::Item(Item::new(weight, value)));
         self
     }
 
@@ -247,6 +250,18 @@ impl PrefabBuilder {
         self
     }
 
+    pub fn with_weapon_attachment(mut self, attachment: WeaponAttachment) -> Self {
+        self.components
+            .push(PrefabComponent::WeaponAttachment(attachment));
+        self
+    }
+
+    pub fn with_weapon_attachments(mut self, attachments: WeaponAttachments) -> Self {
+        self.components
+            .push(PrefabComponent::WeaponAttachments(attachments));
+        self
+    }
+
     pub fn with_needs_stable_id(mut self) -> Self {
         self.components
             .push(PrefabComponent::NeedsStableId(NeedsStableId));
@@ -631,6 +646,12 @@ impl PrefabBuilder {
                 PrefabComponent::AiController(c) => {
                     entity_mut.insert(c.clone());
                 }
+                PrefabComponent::WeaponAttachment(c) => {
+                    entity_mut.insert(c.clone());
+                }
+                PrefabComponent::WeaponAttachments(c) => {
+                    entity_mut.insert(c.clone());
+                }
             }
         }
 
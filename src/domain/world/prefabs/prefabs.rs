@@ -1,15 +1,28 @@
 use super::{
-    SpawnPrefabCommand, spawn_amulet, spawn_apple, spawn_bald_cypress, spawn_bandit, spawn_bat,
+    SpawnPrefabCommand, spawn_altar, spawn_amulet, spawn_apple, spawn_bald_cypress, spawn_bandage,
+    spawn_bandit, spawn_bandit_leader, spawn_barrel, spawn_bat, spawn_bayonet, spawn_bear_trap,
     spawn_bedroll, spawn_boulder, spawn_brown_bear, spawn_cactus, spawn_campfire,
-    spawn_can_of_beans, spawn_cavalry_sword, spawn_chest, spawn_coyote,
-    spawn_double_barrel_shotgun, spawn_duster, spawn_dynamite, spawn_giant_beetle,
-    spawn_giant_firefly, spawn_giant_mushroom, spawn_hatchet, spawn_lantern,
-    spawn_lever_action_rifle, spawn_long_johns, spawn_navy_revolver, spawn_overcoat, spawn_pickaxe,
-    spawn_pine_tree, spawn_player, spawn_poncho, spawn_rat, spawn_rattlesnake, spawn_ring,
-    spawn_stair_down, spawn_stair_up, spawn_steel_toe_boots, spawn_terrain_tile, spawn_tree,
-    spawn_wool_shirt,
+    spawn_can_of_beans, spawn_canteen, spawn_cartridges, spawn_cavalry_sword, spawn_cell_door,
+    spawn_chest, spawn_choke_tube, spawn_cloth, spawn_cooked_meat, spawn_corpse, spawn_counter,
+    spawn_coyote, spawn_doctor, spawn_dog, spawn_door, spawn_double_barrel_shotgun, spawn_duster,
+    spawn_dynamite, spawn_extended_cylinder, spawn_ferry, spawn_fire, spawn_fuse_cord,
+    spawn_giant_beetle, spawn_giant_firefly, spawn_giant_mushroom, spawn_gold_vein,
+    spawn_grave_marker, spawn_gunpowder, spawn_gunslinger, spawn_hatchet, spawn_hide,
+    spawn_jail_bars, spawn_key, spawn_lantern, spawn_lead, spawn_lever_action_rifle,
+    spawn_long_johns, spawn_merchant, spawn_mine_ladder, spawn_mine_rail, spawn_mine_shaft,
+    spawn_navy_revolver, spawn_overcoat, spawn_pew, spawn_pickaxe, spawn_pine_tree, spawn_player,
+    spawn_poncho, spawn_quest_item, spawn_rat, spawn_rattlesnake, spawn_raw_meat, spawn_ring,
+    spawn_scope, spawn_shelf, spawn_snare, spawn_stair_down, spawn_stair_up, spawn_steel_toe_boots,
+    spawn_table, spawn_terrain_tile, spawn_townsfolk, spawn_trap, spawn_tree, spawn_tripwire,
+    spawn_venom_gland, spawn_wagon_wheel, spawn_wooden_bridge, spawn_wooden_wall, spawn_wool_shirt,
+};
+use crate::{
+    domain::{
+        InInventory, Inventory, Item, LootTableId, NeedsStableId, Terrain,
+        inventory::InventoryChangedEvent, spawn_gold_nugget,
+    },
+    engine::{StableId, StableIdRegistry},
 };
-use crate::domain::{LootTableId, Terrain, spawn_gold_nugget};
 use bevy_ecs::{entity::Entity, prelude::Resource, system::Commands, world::World};
 use std::{collections::HashMap, fmt};
 
@@ -20,12 +33,33 @@ pub enum PrefabId {
     Tree,
     Boulder,
     Campfire,
+    Fire,
     GoldNugget,
     Cactus,
     CavalrySword,
     Chest,
     GiantMushroom,
     Bandit,
+    BanditLeader,
+    Doctor,
+    Merchant,
+    Townsfolk,
+    Gunslinger,
+    WoodenWall,
+    WoodenBridge,
+    Ferry,
+    Door,
+    CellDoor,
+    Key,
+    Table,
+    Counter,
+    Shelf,
+    Barrel,
+    Pew,
+    Altar,
+    JailBars,
+    GraveMarker,
+    WagonWheel,
     BrownBear,
     Rattlesnake,
     Bat,
@@ -33,16 +67,37 @@ pub enum PrefabId {
     Rat,
     GiantFirefly,
     GiantBeetle,
+    Dog,
     Hatchet,
     Lantern,
     Pickaxe,
     StairDown,
     StairUp,
+    MineShaft,
+    MineLadder,
+    MineRail,
+    GoldVein,
     TerrainTile(Terrain),
     Dynamite,
     Apple,
     CanOfBeans,
+    Canteen,
+    Bandage,
     Bedroll,
+    RawMeat,
+    CookedMeat,
+    Gunpowder,
+    FuseCord,
+    Cloth,
+    Lead,
+    Cartridges,
+    Hide,
+    VenomGland,
+    BearTrap,
+    Tripwire,
+    Snare,
+    Trap,
+    Corpse,
     LongJohns,
     Duster,
     Poncho,
@@ -52,8 +107,13 @@ pub enum PrefabId {
     LeverActionRifle,
     DoubleBarrelShotgun,
     NavyRevolver,
+    Scope,
+    ExtendedCylinder,
+    ChokeTube,
+    Bayonet,
     Amulet,
     Ring,
+    QuestItem,
     Player,
 }
 
@@ -74,7 +134,10 @@ pub enum SpawnValue {
     Bool(bool),
     LootTableId(LootTableId),
     ItemRarity(crate::domain::ItemRarity),
+    CreatureType(crate::domain::CreatureType),
     Palette(crate::common::Palette),
+    FactionId(crate::domain::FactionId),
+    TrapKind(crate::domain::TrapKind),
 }
 
 impl Prefab {
@@ -122,11 +185,32 @@ impl Prefabs {
         self.register(PrefabId::Tree, spawn_tree);
         self.register(PrefabId::Boulder, spawn_boulder);
         self.register(PrefabId::Campfire, spawn_campfire);
+        self.register(PrefabId::Fire, spawn_fire);
         self.register(PrefabId::Cactus, spawn_cactus);
         self.register(PrefabId::CavalrySword, spawn_cavalry_sword);
         self.register(PrefabId::Chest, spawn_chest);
         self.register(PrefabId::GiantMushroom, spawn_giant_mushroom);
         self.register(PrefabId::Bandit, spawn_bandit);
+        self.register(PrefabId::BanditLeader, spawn_bandit_leader);
+        self.register(PrefabId::Doctor, spawn_doctor);
+        self.register(PrefabId::Merchant, spawn_merchant);
+        self.register(PrefabId::Townsfolk, spawn_townsfolk);
+        self.register(PrefabId::Gunslinger, spawn_gunslinger);
+        self.register(PrefabId::WoodenWall, spawn_wooden_wall);
+        self.register(PrefabId::WoodenBridge, spawn_wooden_bridge);
+        self.register(PrefabId::Ferry, spawn_ferry);
+        self.register(PrefabId::Door, spawn_door);
+        self.register(PrefabId::CellDoor, spawn_cell_door);
+        self.register(PrefabId::Key, spawn_key);
+        self.register(PrefabId::Table, spawn_table);
+        self.register(PrefabId::Counter, spawn_counter);
+        self.register(PrefabId::Shelf, spawn_shelf);
+        self.register(PrefabId::Barrel, spawn_barrel);
+        self.register(PrefabId::Pew, spawn_pew);
+        self.register(PrefabId::Altar, spawn_altar);
+        self.register(PrefabId::JailBars, spawn_jail_bars);
+        self.register(PrefabId::GraveMarker, spawn_grave_marker);
+        self.register(PrefabId::WagonWheel, spawn_wagon_wheel);
         self.register(PrefabId::BrownBear, spawn_brown_bear);
         self.register(PrefabId::Rattlesnake, spawn_rattlesnake);
         self.register(PrefabId::Bat, spawn_bat);
@@ -134,16 +218,37 @@ impl Prefabs {
         self.register(PrefabId::Rat, spawn_rat);
         self.register(PrefabId::GiantFirefly, spawn_giant_firefly);
         self.register(PrefabId::GiantBeetle, spawn_giant_beetle);
+        self.register(PrefabId::Dog, spawn_dog);
         self.register(PrefabId::Hatchet, spawn_hatchet);
         self.register(PrefabId::Lantern, spawn_lantern);
         self.register(PrefabId::Pickaxe, spawn_pickaxe);
         self.register(PrefabId::StairDown, spawn_stair_down);
         self.register(PrefabId::StairUp, spawn_stair_up);
+        self.register(PrefabId::MineShaft, spawn_mine_shaft);
+        self.register(PrefabId::MineLadder, spawn_mine_ladder);
+        self.register(PrefabId::MineRail, spawn_mine_rail);
+        self.register(PrefabId::GoldVein, spawn_gold_vein);
         self.register(PrefabId::GoldNugget, spawn_gold_nugget);
         self.register(PrefabId::Dynamite, spawn_dynamite);
         self.register(PrefabId::Apple, spawn_apple);
         self.register(PrefabId::CanOfBeans, spawn_can_of_beans);
+        self.register(PrefabId::Canteen, spawn_canteen);
+        self.register(PrefabId::Bandage, spawn_bandage);
         self.register(PrefabId::Bedroll, spawn_bedroll);
+        self.register(PrefabId::RawMeat, spawn_raw_meat);
+        self.register(PrefabId::CookedMeat, spawn_cooked_meat);
+        self.register(PrefabId::Gunpowder, spawn_gunpowder);
+        self.register(PrefabId::FuseCord, spawn_fuse_cord);
+        self.register(PrefabId::Cloth, spawn_cloth);
+        self.register(PrefabId::Lead, spawn_lead);
+        self.register(PrefabId::Cartridges, spawn_cartridges);
+        self.register(PrefabId::Hide, spawn_hide);
+        self.register(PrefabId::VenomGland, spawn_venom_gland);
+        self.register(PrefabId::BearTrap, spawn_bear_trap);
+        self.register(PrefabId::Tripwire, spawn_tripwire);
+        self.register(PrefabId::Snare, spawn_snare);
+        self.register(PrefabId::Trap, spawn_trap);
+        self.register(PrefabId::Corpse, spawn_corpse);
         self.register(PrefabId::LongJohns, spawn_long_johns);
         self.register(PrefabId::Duster, spawn_duster);
         self.register(PrefabId::Poncho, spawn_poncho);
@@ -153,8 +258,13 @@ impl Prefabs {
         self.register(PrefabId::LeverActionRifle, spawn_lever_action_rifle);
         self.register(PrefabId::DoubleBarrelShotgun, spawn_double_barrel_shotgun);
         self.register(PrefabId::NavyRevolver, spawn_navy_revolver);
+        self.register(PrefabId::Scope, spawn_scope);
+        self.register(PrefabId::ExtendedCylinder, spawn_extended_cylinder);
+        self.register(PrefabId::ChokeTube, spawn_choke_tube);
+        self.register(PrefabId::Bayonet, spawn_bayonet);
         self.register(PrefabId::Amulet, spawn_amulet);
         self.register(PrefabId::Ring, spawn_ring);
+        self.register(PrefabId::QuestItem, spawn_quest_item);
         self.register(PrefabId::Player, spawn_player);
 
         self.register(PrefabId::TerrainTile(Terrain::Grass), spawn_terrain_tile);
@@ -168,6 +278,8 @@ impl Prefabs {
         self.register(PrefabId::TerrainTile(Terrain::Sand), spawn_terrain_tile);
         self.register(PrefabId::TerrainTile(Terrain::Shallows), spawn_terrain_tile);
         self.register(PrefabId::TerrainTile(Terrain::Swamp), spawn_terrain_tile);
+        self.register(PrefabId::TerrainTile(Terrain::Mud), spawn_terrain_tile);
+        self.register(PrefabId::TerrainTile(Terrain::Ash), spawn_terrain_tile);
     }
 
     pub fn register(&mut self, id: PrefabId, spawn_fn: SpawnFunction) {
@@ -209,6 +321,51 @@ impl Prefabs {
 
         entity
     }
+
+    /// Puts a freshly made item straight into an inventory without it being picked up,
+    /// for goods that were never lying around. Returns `None` if it doesn't fit.
+    pub fn spawn_in_inventory(world: &mut World, config: Prefab, owner: Entity) -> Option<Entity> {
+        let spawn_fn = *world
+            .resource::<Prefabs>()
+            .spawn_functions
+            .get(&config.prefab_id)?;
+        let owner_id = world.get::<StableId>(owner)?.0;
+
+        let entity = world.spawn_empty().id();
+        spawn_fn(entity, world, config)
+            .for_container()
+            .build(entity, world);
+
+        let weight = world.get::<Item>(entity).map(|i| i.weight).unwrap_or(1.0);
+        let fits = world
+            .get::<Inventory>(owner)
+            .is_some_and(|inventory| inventory.has_space_for_weight(weight));
+
+        if !fits {
+            world.entity_mut(entity).despawn();
+            return None;
+        }
+
+        let stable_id = {
+            let mut registry = world.resource_mut::<StableIdRegistry>();
+            let id = registry.generate_id();
+            registry.register(entity, id);
+            id
+        };
+
+        world
+            .entity_mut(entity)
+            .insert((stable_id, InInventory::new(owner_id)))
+            .remove::<NeedsStableId>();
+
+        if let Some(mut inventory) = world.get_mut::<Inventory>(owner) {
+            inventory.add_item(stable_id.0, weight);
+        }
+
+        world.send_event(InventoryChangedEvent);
+
+        Some(entity)
+    }
 }
 
 impl Default for Prefabs {
@@ -225,12 +382,33 @@ impl fmt::Display for PrefabId {
             PrefabId::Tree => write!(f, "Tree"),
             PrefabId::Boulder => write!(f, "Boulder"),
             PrefabId::Campfire => write!(f, "Campfire"),
+            PrefabId::Fire => write!(f, "Fire"),
             PrefabId::GoldNugget => write!(f, "Gold Nugget"),
             PrefabId::Cactus => write!(f, "Cactus"),
             PrefabId::CavalrySword => write!(f, "Cavalry Sword"),
             PrefabId::Chest => write!(f, "Chest"),
             PrefabId::GiantMushroom => write!(f, "Giant Mushroom"),
             PrefabId::Bandit => write!(f, "Bandit"),
+            PrefabId::BanditLeader => write!(f, "Bandit Leader"),
+            PrefabId::Doctor => write!(f, "Doctor"),
+            PrefabId::Merchant => write!(f, "Merchant"),
+            PrefabId::Townsfolk => write!(f, "Townsfolk"),
+            PrefabId::Gunslinger => write!(f, "Gunslinger"),
+            PrefabId::WoodenWall => write!(f, "Wooden Wall"),
+            PrefabId::WoodenBridge => write!(f, "Wooden Bridge"),
+            PrefabId::Ferry => write!(f, "Ferry"),
+            PrefabId::Door => write!(f, "Door"),
+            PrefabId::CellDoor => write!(f, "Cell Door"),
+            PrefabId::Key => write!(f, "Key"),
+            PrefabId::Table => write!(f, "Table"),
+            PrefabId::Counter => write!(f, "Counter"),
+            PrefabId::Shelf => write!(f, "Shelf"),
+            PrefabId::Barrel => write!(f, "Barrel"),
+            PrefabId::Pew => write!(f, "Pew"),
+            PrefabId::Altar => write!(f, "Altar"),
+            PrefabId::JailBars => write!(f, "Jail Bars"),
+            PrefabId::GraveMarker => write!(f, "Grave Marker"),
+            PrefabId::WagonWheel => write!(f, "Wagon Wheel"),
             PrefabId::BrownBear => write!(f, "Brown Bear"),
             PrefabId::Rattlesnake => write!(f, "Rattlesnake"),
             PrefabId::Bat => write!(f, "Bat"),
@@ -238,15 +416,36 @@ impl fmt::Display for PrefabId {
             PrefabId::Rat => write!(f, "Rat"),
             PrefabId::GiantFirefly => write!(f, "Giant Firefly"),
             PrefabId::GiantBeetle => write!(f, "Giant Beetle"),
+            PrefabId::Dog => write!(f, "Stray Dog"),
             PrefabId::Hatchet => write!(f, "Hatchet"),
             PrefabId::Lantern => write!(f, "Lantern"),
             PrefabId::Pickaxe => write!(f, "Pickaxe"),
             PrefabId::StairDown => write!(f, "Stair Down"),
             PrefabId::StairUp => write!(f, "Stair Up"),
+            PrefabId::MineShaft => write!(f, "Mine Shaft"),
+            PrefabId::MineLadder => write!(f, "Mine Ladder"),
+            PrefabId::MineRail => write!(f, "Mine Rail"),
+            PrefabId::GoldVein => write!(f, "Gold Vein"),
             PrefabId::Dynamite => write!(f, "Dynamite"),
             PrefabId::Apple => write!(f, "Apple"),
             PrefabId::CanOfBeans => write!(f, "Can of Beans"),
+            PrefabId::Canteen => write!(f, "Canteen"),
+            PrefabId::Bandage => write!(f, "Bandage"),
             PrefabId::Bedroll => write!(f, "Bedroll"),
+            PrefabId::RawMeat => write!(f, "Raw Meat"),
+            PrefabId::CookedMeat => write!(f, "Cooked Meat"),
+            PrefabId::Gunpowder => write!(f, "Gunpowder"),
+            PrefabId::FuseCord => write!(f, "Fuse Cord"),
+            PrefabId::Cloth => write!(f, "Cloth"),
+            PrefabId::Lead => write!(f, "Lead"),
+            PrefabId::Cartridges => write!(f, "Cartridges"),
+            PrefabId::Hide => write!(f, "Hide"),
+            PrefabId::VenomGland => write!(f, "Venom Gland"),
+            PrefabId::BearTrap => write!(f, "Bear Trap"),
+            PrefabId::Tripwire => write!(f, "Tripwire"),
+            PrefabId::Snare => write!(f, "Snare"),
+            PrefabId::Trap => write!(f, "Set Trap"),
+            PrefabId::Corpse => write!(f, "Corpse"),
             PrefabId::LongJohns => write!(f, "Long Johns"),
             PrefabId::Duster => write!(f, "Duster"),
             PrefabId::Poncho => write!(f, "Poncho"),
@@ -256,8 +455,13 @@ impl fmt::Display for PrefabId {
             PrefabId::LeverActionRifle => write!(f, "Lever Action Rifle"),
             PrefabId::DoubleBarrelShotgun => write!(f, "Double Barrel Shotgun"),
             PrefabId::NavyRevolver => write!(f, "Navy Revolver"),
+            PrefabId::Scope => write!(f, "Scope"),
+            PrefabId::ExtendedCylinder => write!(f, "Extended Cylinder"),
+            PrefabId::ChokeTube => write!(f, "Choke Tube"),
+            PrefabId::Bayonet => write!(f, "Bayonet"),
             PrefabId::Amulet => write!(f, "Amulet"),
             PrefabId::Ring => write!(f, "Ring"),
+            PrefabId::QuestItem => write!(f, "Quest Item"),
             PrefabId::Player => write!(f, "Player"),
             PrefabId::TerrainTile(terrain) => match terrain {
                 Terrain::Grass => write!(f, "Grass Tile"),
@@ -269,7 +473,33 @@ impl fmt::Display for PrefabId {
                 Terrain::Shallows => write!(f, "Shallows Tile"),
                 Terrain::OpenAir => write!(f, "Open Air Tile"),
                 Terrain::Swamp => write!(f, "Swamp Tile"),
+                Terrain::Mud => write!(f, "Mud Tile"),
+                Terrain::Ash => write!(f, "Ash Tile"),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::Scavenger, engine::StableIdRegistry};
+
+    #[test]
+    fn test_scavengers_spawn_as_scavengers() {
+        let mut world = World::new();
+        world.insert_resource(Prefabs::new());
+        world.insert_resource(StableIdRegistry::new());
+
+        for prefab_id in [PrefabId::Coyote, PrefabId::Rat] {
+            let entity =
+                Prefabs::spawn_world(&mut world, Prefab::new(prefab_id.clone(), (1, 1, 0)));
+
+            assert!(
+                world.get::<Scavenger>(entity).is_some(),
+                "{} can't scavenge",
+                prefab_id
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{common::Palette, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+/// Something a quest sends the player to fetch or carry. The name and glyph come from
+/// the quest that spawns it.
+pub fn spawn_quest_item(_entity: Entity, _world: &mut World, config: Prefab) -> PrefabBuilder {
+    let glyph = if let Some(SpawnValue::Int(glyph)) = config.metadata.get("glyph") {
+        *glyph as usize
+    } else {
+        26
+    };
+
+    let label = if let Some(SpawnValue::String(label)) = config.metadata.get("label") {
+        label.as_str()
+    } else {
+        "Parcel"
+    };
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(glyph, Palette::Yellow, Palette::Brown, Layer::Objects)
+        .with_label(label)
+        .with_description("Worth more to someone else than it'll ever be worth to you.")
+        .with_item(1.0, 0)
+        .with_needs_stable_id()
+}
@@ -4,13 +4,15 @@ use crate::{
     domain::{
         Attributes, CreatureType, DefaultMeleeAttack, FactionId, FactionMember, LootDrop,
         LootTableId, Stats,
-        components::ai_controller::{AiController, AiTemplate},
+        components::ai_controller::{AiController, AiTemplate, Scavenger},
     },
     rendering::{GlyphTextureId, Layer},
 };
 use bevy_ecs::{entity::Entity, world::World};
 
 pub fn spawn_rat(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Scavenger);
+
     PrefabBuilder::new()
         .with_base_components(config.pos)
         .with_dynamic_tracking() // Rats can move
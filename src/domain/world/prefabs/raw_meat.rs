@@ -0,0 +1,24 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{ConsumableEffect, Food, StackableType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_raw_meat(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Food::new(2 * 60));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(98, Palette::Red, Palette::White, Layer::Objects)
+        .with_label("Raw Meat")
+        .with_description(
+            "A stringy cut, still warm. Edible the way boot leather is edible. Better over a fire.",
+        )
+        .with_item(0.5, 1)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::RawMeat, 1)
+        .with_consumable(ConsumableEffect::Heal(1), true)
+}
@@ -27,7 +27,7 @@ pub fn spawn_ring(entity: Entity, world: &mut World, config: Prefab) -> PrefabBu
         .with_description(
             "Simple band of tarnished metal. Promises and curses wear the same weight.",
         )
-        .with_item(0.05)
+        .with_item(0.05, 25)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Ring1, EquipmentSlot::Ring2],
             EquipmentType::Accessory,
@@ -0,0 +1,21 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{AttachmentType, WeaponAttachment},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_scope(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking() // Items on ground don't move
+        .with_glyph(105, Palette::Gray, Palette::Blue, Layer::Objects)
+        .with_label("Scope")
+        .with_description(
+            "Brass tube and ground glass. Brings the far ridge close enough to spit on.",
+        )
+        .with_item(0.5, 25)
+        .with_weapon_attachment(WeaponAttachment::new(AttachmentType::Scope))
+        .with_needs_stable_id()
+}
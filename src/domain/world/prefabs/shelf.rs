@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_shelf(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(76, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Shelf")
+        .with_description(
+            "Tins, tack and sundries stacked to the rafters, all of it thick with dust.",
+        )
+        .with_collider()
+        .with_destructible(4, MaterialType::Wood)
+}
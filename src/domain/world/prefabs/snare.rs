@@ -0,0 +1,23 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{StackableType, TrapKind, TrapKit},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_snare(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(TrapKit(TrapKind::Snare));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(115, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Snare")
+        .with_description(
+            "A running noose of rawhide on a bent sapling. Set it ({Y|P}) on a game trail and come back for supper.",
+        )
+        .with_item(0.3, 3)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Snare, 1)
+}
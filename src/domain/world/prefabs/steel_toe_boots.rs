@@ -21,7 +21,7 @@ pub fn spawn_steel_toe_boots(entity: Entity, world: &mut World, config: Prefab)
         .with_description(
             "Leather cracked like drought earth. Every scuff tells a story nobody wants to hear.",
         )
-        .with_item(1.5)
+        .with_item(1.5, 12)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Feet],
             EquipmentType::Armor,
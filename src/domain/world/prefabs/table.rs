@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_table(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(122, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_label("Table")
+        .with_description(
+            "Rings from a hundred glasses worn into the wood. One leg is shorter than the others.",
+        )
+        .with_collider()
+        .with_destructible(4, MaterialType::Wood)
+}
@@ -0,0 +1,61 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    common::Palette,
+    domain::{
+        DefaultMeleeAttack, Dialogue, FactionId, FactionMember,
+        components::ai_controller::{AiController, AiTemplate},
+    },
+    rendering::{GlyphTextureId, Layer},
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_townsfolk(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let glyph = if let Some(SpawnValue::Int(glyph)) = config.metadata.get("glyph") {
+        *glyph as usize
+    } else {
+        16
+    };
+
+    let label = if let Some(SpawnValue::String(label)) = config.metadata.get("label") {
+        label.as_str()
+    } else {
+        "Townsfolk"
+    };
+
+    let dialogue = if let Some(SpawnValue::String(dialogue)) = config.metadata.get("dialogue") {
+        dialogue.as_str()
+    } else {
+        "townsfolk"
+    };
+
+    world.entity_mut(entity).insert(Dialogue::new(dialogue));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_dynamic_tracking()
+        .with_needs_stable_id()
+        .with_glyph_and_texture(
+            glyph,
+            Palette::White,
+            Palette::Brown,
+            Layer::Actors,
+            GlyphTextureId::Creatures,
+        )
+        .with_label(label)
+        .with_description(
+            "Dust on their boots and a wary eye on strangers. Keeps to their business if you keep to yours.",
+        )
+        .with_energy(-100)
+        .with_health()
+        .with_actor_collider()
+        .with_hide_when_not_visible()
+        .with_default_melee_attack(DefaultMeleeAttack::fists())
+        .with_level(2)
+        .with_attributes(crate::domain::Attributes::new(2, 2, 2, 2))
+        .with_stats(crate::domain::Stats::new())
+        .with_component(
+            AiController::new(AiTemplate::Peaceful, config.pos).with_ranges(12, 4, 6),
+        )
+        .with_component(FactionMember::new(FactionId::Townsfolk))
+        .with_movement_capabilities(crate::domain::MovementFlags::TERRESTRIAL)
+}
@@ -0,0 +1,41 @@
+use super::{Prefab, PrefabBuilder, SpawnValue};
+use crate::{
+    domain::{Trap, TrapKind},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_trap(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    let kind = if let Some(SpawnValue::TrapKind(kind)) = config.metadata.get("kind") {
+        *kind
+    } else {
+        TrapKind::BearTrap
+    };
+
+    let set_by = if let Some(SpawnValue::FactionId(faction_id)) = config.metadata.get("faction") {
+        Some(*faction_id)
+    } else {
+        None
+    };
+
+    world.entity_mut(entity).insert(Trap::new(kind, set_by));
+
+    let (idx, fg1, fg2) = kind.glyph();
+    let description = match kind {
+        TrapKind::BearTrap => {
+            "Iron jaws set open under a scatter of dirt. Whoever steps here isn't going anywhere for a while."
+        }
+        TrapKind::Tripwire => {
+            "A wire strung ankle-high to the pin of a dynamite charge. Best give it a wide berth."
+        }
+        TrapKind::Snare => "A noose laid on a game trail, waiting on a coyote or a jackrabbit.",
+    };
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(idx, fg1, fg2, Layer::Objects)
+        .with_label(kind.label())
+        .with_description(description)
+}
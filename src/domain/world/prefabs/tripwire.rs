@@ -0,0 +1,23 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{StackableType, TrapKind, TrapKit},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_tripwire(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(TrapKit(TrapKind::Tripwire));
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(112, Palette::Red, Palette::Brown, Layer::Objects)
+        .with_label("Tripwire")
+        .with_description(
+            "A stick of dynamite with a pull-friction igniter and a length of wire. Set it ({Y|P}) across a trail and don't forget where.",
+        )
+        .with_item(0.6, 10)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::Tripwire, 1)
+}
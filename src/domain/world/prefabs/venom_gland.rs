@@ -0,0 +1,17 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::StackableType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_venom_gland(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_glyph(98, Palette::Green, Palette::Yellow, Layer::Objects)
+        .with_label("Venom Gland")
+        .with_description(
+            "A rattler's poison sac, cut out whole. Snake-oil men and sawbones alike will pay good gold for one.",
+        )
+        .with_item(0.1, 10)
+        .with_needs_stable_id()
+        .with_stackable(StackableType::VenomGland, 1)
+}
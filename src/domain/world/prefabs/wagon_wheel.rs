@@ -0,0 +1,15 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{common::Palette, domain::MaterialType, rendering::Layer};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_wagon_wheel(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(24, Palette::Brown, Palette::Gray, Layer::Objects)
+        .with_label("Wagon Wheel")
+        .with_description("Iron-shod and split at the hub. It rolled a good way before it fell.")
+        .with_collider()
+        .with_destructible(3, MaterialType::Wood)
+}
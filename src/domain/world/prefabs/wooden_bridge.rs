@@ -0,0 +1,22 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{Bridge, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_wooden_bridge(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    world.entity_mut(entity).insert(Bridge);
+
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(92, Palette::Brown, Palette::DarkBrown, Layer::GroundOverlay)
+        .with_label("Wooden Bridge")
+        .with_description(
+            "Rough-hewn planks spiked to cottonwood pilings. Dry as kindling, and it creaks under every boot.",
+        )
+        .with_destructible(10, MaterialType::Wood)
+}
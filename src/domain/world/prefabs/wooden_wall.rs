@@ -0,0 +1,21 @@
+use super::{Prefab, PrefabBuilder};
+use crate::{
+    common::Palette,
+    domain::{BitmaskStyle, ColliderFlags, MaterialType},
+    rendering::Layer,
+};
+use bevy_ecs::{entity::Entity, world::World};
+
+pub fn spawn_wooden_wall(entity: Entity, world: &mut World, config: Prefab) -> PrefabBuilder {
+    PrefabBuilder::new()
+        .with_base_components(config.pos)
+        .with_static_tracking()
+        .with_needs_stable_id()
+        .with_glyph(242, Palette::Brown, Palette::DarkBrown, Layer::Objects)
+        .with_bitmask(BitmaskStyle::Planks)
+        .with_label("Wooden Wall")
+        .with_description("Sun-bleached planks nailed up in a hurry. Every knothole whistles when the wind blows.")
+        .with_collider_flags(ColliderFlags::WALL)
+        .with_destructible(8, MaterialType::Wood)
+        .with_light_blocker()
+}
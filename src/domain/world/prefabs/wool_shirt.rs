@@ -19,7 +19,7 @@ pub fn spawn_wool_shirt(entity: Entity, world: &mut World, config: Prefab) -> Pr
         .with_glyph(71, Palette::Gray, Palette::White, Layer::Objects)
         .with_label("Wool Shirt")
         .with_description("Rough-spun and twice-mended. Smells of campfire smoke and old sweat.")
-        .with_item(0.8)
+        .with_item(0.8, 5)
         .with_equippable(Equippable::new(
             vec![EquipmentSlot::Body],
             EquipmentType::Armor,
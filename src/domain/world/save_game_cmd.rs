@@ -4,7 +4,7 @@ use macroquad::prelude::get_time;
 use crate::{
     domain::{
         GameSaveData, GameSettings, Inventory, Overworld, Player, PlayerSaveData,
-        UnloadZoneCommand, Zone,
+        UnloadZoneCommand, Weather, Zone, player_party,
     },
     engine::{Clock, StableId, StableIdRegistry, save_game, serialize},
     rendering::Position,
@@ -59,6 +59,13 @@ impl SaveGameCommand {
             .map(|overworld| overworld.seed)
             .unwrap_or(12345);
 
+        let discovered_pois = world
+            .get_resource::<Overworld>()
+            .map(|overworld| overworld.discovered_pois.clone())
+            .unwrap_or_default();
+
+        let weather = world.get_resource::<Weather>().cloned().unwrap_or_default();
+
         let serialized_player = serialize(player_entity, world);
 
         // Collect and serialize player's inventory items (following unload_zone_cmd pattern)
@@ -79,12 +86,25 @@ impl SaveGameCommand {
             }
         }
 
+        let companions = player_party(world)
+            .into_iter()
+            .map(|companion| serialize(companion, world))
+            .collect();
+
         let player_save = PlayerSaveData {
             position: player_position,
             entity: serialized_player,
             inventory_items,
+            companions,
         };
-        let game_data = GameSaveData::new(player_save, get_time(), current_tick, seed);
+        let game_data = GameSaveData::new(
+            player_save,
+            get_time(),
+            current_tick,
+            seed,
+            discovered_pois,
+            weather,
+        );
         save_game(&game_data, &save_name);
 
         let mut q_zones = world.query::<&Zone>();
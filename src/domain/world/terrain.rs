@@ -16,6 +16,8 @@ pub enum Terrain {
     Sand = 7,
     Shallows = 8,
     Swamp = 9,
+    Mud = 10,
+    Ash = 11,
 }
 
 impl Terrain {
@@ -24,6 +26,8 @@ impl Terrain {
             Terrain::Grass => vec![0, 1, 2],
             Terrain::DyingGrass => vec![16, 17, 0, 1, 2],
             Terrain::Swamp => vec![48, 49, 50, 51],
+            Terrain::Mud => vec![48, 49],
+            Terrain::Ash => vec![16, 17, 0, 1],
             Terrain::Gravel => vec![16, 17, 0, 1],
             Terrain::Dirt => vec![4, 5],
             // Terrain::Dirt => vec![48, 49],
@@ -46,9 +50,30 @@ impl Terrain {
             Terrain::Shallows => "{B|Shallows}",
             Terrain::OpenAir => "{B|Open Air}",
             Terrain::Swamp => "{P|Swamp}",
+            Terrain::Mud => "{x|Mud}",
+            Terrain::Ash => "{u|Ash}",
         }
         .to_owned()
     }
+
+    /// How much longer a step onto this terrain takes than one onto dry ground
+    pub fn move_cost_multiplier(&self) -> f32 {
+        match self {
+            Terrain::River => 2.0,
+            Terrain::Shallows => 1.5,
+            Terrain::Mud => 1.25,
+            _ => 1.0,
+        }
+    }
+
+    /// How readily fire takes hold here, from nothing at all up to tinder dry
+    pub fn flammability(&self) -> f32 {
+        match self {
+            Terrain::DyingGrass => 1.0,
+            Terrain::Grass => 0.5,
+            _ => 0.0,
+        }
+    }
 }
 
 #[derive(Resource)]
@@ -193,6 +218,38 @@ impl TerrainNoise {
         }
     }
 
+    pub fn mud(&mut self, pos: (usize, usize)) -> Style {
+        let v = self.dirt.get(pos.0 as f32, pos.1 as f32);
+        let mud_tiles = Terrain::Mud.tiles();
+
+        let tile_idx = (v * mud_tiles.len() as f32) as usize;
+        let tile_idx = tile_idx.min(mud_tiles.len() - 1);
+
+        Style {
+            idx: mud_tiles[tile_idx],
+            fg1: Palette::DarkBrown.into(),
+            fg2: None,
+            bg: None,
+            outline: None,
+        }
+    }
+
+    pub fn ash(&mut self, pos: (usize, usize)) -> Style {
+        let v = self.gravel.get(pos.0 as f32, pos.1 as f32);
+        let ash_tiles = Terrain::Ash.tiles();
+
+        let tile_idx = (v * ash_tiles.len() as f32) as usize;
+        let tile_idx = tile_idx.min(ash_tiles.len() - 1);
+
+        Style {
+            idx: ash_tiles[tile_idx],
+            fg1: Palette::DarkGray.into(),
+            fg2: None,
+            bg: None,
+            outline: None,
+        }
+    }
+
     pub fn swamp(&mut self, pos: (usize, usize)) -> Style {
         let v = self.dying_grass.get(pos.0 as f32, pos.1 as f32);
         let swamp_tiles = Terrain::Swamp.tiles();
@@ -226,6 +283,8 @@ impl TerrainNoise {
             Terrain::Sand => self.sand(pos),
             Terrain::Shallows => self.shallows(pos),
             Terrain::Swamp => self.swamp(pos),
+            Terrain::Mud => self.mud(pos),
+            Terrain::Ash => self.ash(pos),
         }
     }
 }
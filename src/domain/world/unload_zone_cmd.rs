@@ -3,7 +3,7 @@ use macroquad::prelude::trace;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::{GameSettings, Inventory, Zone},
+    domain::{GameSettings, Inventory, Zone, player_party, recall_companion},
     engine::{SerializableComponent, StableId, StableIdRegistry, save_zone, serialize},
 };
 
@@ -22,6 +22,7 @@ impl Command<Result> for UnloadZoneCommand {
         let lc = world.last_change_tick();
         let t = world.change_tick();
 
+        let party = player_party(world);
         let mut q_zones = world.query::<(Entity, &Zone)>();
         let q_save_flag = world.query::<&SaveFlag>();
         let mut q_inventory = world.query::<&Inventory>();
@@ -35,9 +36,16 @@ impl Command<Result> for UnloadZoneCommand {
 
         let mut ent_data = vec![];
         let mut despawns = vec![];
+        let mut companions = vec![];
 
         for v in zone.entities.iter() {
             for e in v {
+                // The player's party is saved with the player, and outlives the zone
+                if party.contains(e) {
+                    companions.push(*e);
+                    continue;
+                }
+
                 despawns.push(*e);
 
                 if !q_save_flag.contains(*e, world, t, lc) {
@@ -85,6 +93,10 @@ impl Command<Result> for UnloadZoneCommand {
             for e in despawns.iter() {
                 world.despawn(*e);
             }
+
+            for companion in companions {
+                recall_companion(world, companion);
+            }
         }
 
         Ok(())
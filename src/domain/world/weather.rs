@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use bevy_ecs::resource::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cfg::{MAP_SIZE, SURFACE_LEVEL_Z},
+    common::{Palette, Rand},
+    domain::{BiomeType, Overworld},
+    engine::{AudioKey, TICKS_PER_MINUTE},
+    rendering::{zone_idx, zone_xyz},
+};
+
+/// Width and height of a weather region, in zones
+const REGION_SIZE: usize = 4;
+const TICKS_PER_HOUR: u32 = TICKS_PER_MINUTE * 60;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum WeatherType {
+    #[default]
+    Clear,
+    DustStorm,
+    Rain,
+    /// Dry lightning with a howling wind, the kind that starts wildfires
+    Thunderstorm,
+}
+
+impl WeatherType {
+    pub fn label_formatted(&self) -> String {
+        match self {
+            WeatherType::Clear => "{C|Clear}",
+            WeatherType::DustStorm => "{y|Dust Storm}",
+            WeatherType::Rain => "{B|Rain}",
+            WeatherType::Thunderstorm => "{P|Thunderstorm}",
+        }
+        .to_owned()
+    }
+
+    /// What a region's sky does next, weighted by the biome it sits over
+    fn roll(biome: BiomeType, rand: &mut Rand) -> Self {
+        let weights: &[(WeatherType, f32)] = match biome {
+            BiomeType::Desert => &[
+                (WeatherType::Clear, 0.6),
+                (WeatherType::DustStorm, 0.3),
+                (WeatherType::Thunderstorm, 0.1),
+            ],
+            BiomeType::DustyPlains => &[
+                (WeatherType::Clear, 0.65),
+                (WeatherType::DustStorm, 0.2),
+                (WeatherType::Rain, 0.05),
+                (WeatherType::Thunderstorm, 0.1),
+            ],
+            BiomeType::Forest => &[
+                (WeatherType::Clear, 0.55),
+                (WeatherType::Rain, 0.3),
+                (WeatherType::Thunderstorm, 0.15),
+            ],
+            BiomeType::Swamp => &[
+                (WeatherType::Clear, 0.45),
+                (WeatherType::Rain, 0.4),
+                (WeatherType::Thunderstorm, 0.15),
+            ],
+            BiomeType::Mountain => &[
+                (WeatherType::Clear, 0.6),
+                (WeatherType::Rain, 0.2),
+                (WeatherType::Thunderstorm, 0.2),
+            ],
+            _ => &[(WeatherType::Clear, 1.0)],
+        };
+
+        let mut roll = rand.random();
+
+        for (weather_type, weight) in weights {
+            if roll < *weight {
+                return *weather_type;
+            }
+            roll -= weight;
+        }
+
+        WeatherType::Clear
+    }
+
+    fn duration_ticks(&self, rand: &mut Rand) -> u32 {
+        let (min_hours, max_hours) = match self {
+            WeatherType::Clear => (2, 8),
+            WeatherType::DustStorm => (1, 3),
+            WeatherType::Rain => (2, 6),
+            WeatherType::Thunderstorm => (1, 2),
+        };
+
+        let hours = rand.range_n(min_hours, max_hours + 1) as u32;
+        hours * TICKS_PER_HOUR
+    }
+
+    /// A region's weather as it plays out over one zone. Storms stop at the edge of the
+    /// biomes they can't reach, and nothing gets underground.
+    pub fn for_biome(self, biome: BiomeType) -> Self {
+        if !biome.uses_daylight_cycle() {
+            return WeatherType::Clear;
+        }
+
+        match (self, biome) {
+            (WeatherType::DustStorm, BiomeType::Forest | BiomeType::Swamp) => WeatherType::Clear,
+            (WeatherType::Rain, BiomeType::Desert) => WeatherType::Clear,
+            _ => self,
+        }
+    }
+
+    /// How far the player can see, relative to a clear day
+    pub fn vision_multiplier(&self) -> f32 {
+        match self {
+            WeatherType::Clear => 1.0,
+            WeatherType::DustStorm => 0.4,
+            WeatherType::Rain => 0.8,
+            WeatherType::Thunderstorm => 0.85,
+        }
+    }
+
+    /// Darkens and tints the ambient light under the clouds or dust
+    pub fn modulate_ambient(&self, color: u32, intensity: f32) -> (u32, f32) {
+        let (tint, blend, dimming) = match self {
+            WeatherType::Clear => return (color, intensity),
+            WeatherType::DustStorm => (Palette::DarkYellow.into(), 0.45, 0.85),
+            WeatherType::Rain => (Palette::Gray.into(), 0.4, 0.85),
+            WeatherType::Thunderstorm => (Palette::DarkPurple.into(), 0.35, 0.75),
+        };
+
+        (blend_color(color, tint, blend), intensity * dimming)
+    }
+
+    /// Nothing to loop yet, until there are real rain and wind recordings to play
+    pub fn ambient_audio(&self) -> Option<AudioKey> {
+        None
+    }
+
+    pub fn is_wet(&self) -> bool {
+        matches!(self, WeatherType::Rain)
+    }
+
+    /// Which way the wind blows a fire along, and how hard. East is positive x.
+    pub fn wind(&self) -> (f32, f32) {
+        match self {
+            WeatherType::Clear => (0.3, 0.0),
+            WeatherType::DustStorm => (1.0, 0.0),
+            WeatherType::Rain => (-0.2, 0.0),
+            WeatherType::Thunderstorm => (-1.0, 0.0),
+        }
+    }
+
+    /// How dry the air is, and so how eagerly fire spreads
+    pub fn dryness(&self) -> f32 {
+        match self {
+            WeatherType::Clear => 1.0,
+            WeatherType::DustStorm => 1.4,
+            WeatherType::Rain => 0.0,
+            WeatherType::Thunderstorm => 1.2,
+        }
+    }
+
+    pub fn has_lightning(&self) -> bool {
+        matches!(self, WeatherType::Thunderstorm)
+    }
+
+    pub fn arrival_message(&self) -> &'static str {
+        match self {
+            WeatherType::Clear => "The sky clears.",
+            WeatherType::DustStorm => "A wall of {y|dust} rolls in, stinging your eyes.",
+            WeatherType::Rain => "It starts to {B|rain}.",
+            WeatherType::Thunderstorm => "Dry {P|thunder} rumbles overhead.",
+        }
+    }
+}
+
+fn blend_color(from: u32, to: u32, t: f32) -> u32 {
+    let channel = |shift: u32| {
+        let a = ((from >> shift) & 0xFF) as f32;
+        let b = ((to >> shift) & 0xFF) as f32;
+        ((a + (b - a) * t).round() as u32).min(255) << shift
+    };
+
+    channel(16) | channel(8) | channel(0)
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct RegionWeather {
+    pub weather_type: WeatherType,
+    pub until_tick: u32,
+}
+
+/// Weather over the surface, simulated per region of `REGION_SIZE` zones so the sky
+/// changes together across neighbouring zones. Regions only advance when visited.
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct Weather {
+    regions: HashMap<usize, RegionWeather>,
+    /// Weather over the player's zone, `None` until the first update after a load
+    #[serde(skip)]
+    pub active: Option<WeatherType>,
+}
+
+impl Weather {
+    pub fn current(&self) -> WeatherType {
+        self.active.unwrap_or_default()
+    }
+
+    fn region_idx(x: usize, y: usize) -> usize {
+        let regions_wide = MAP_SIZE.0.div_ceil(REGION_SIZE);
+        (y / REGION_SIZE) * regions_wide + x / REGION_SIZE
+    }
+
+    /// The weather over a zone right now, rolling its region forward if the last spell ran out
+    pub fn weather_at(
+        &mut self,
+        overworld: &Overworld,
+        zone: usize,
+        tick: u32,
+        rand: &mut Rand,
+    ) -> WeatherType {
+        let (x, y, _) = zone_xyz(zone);
+
+        // The region's weather comes from the biome at its heart
+        let center_x = ((x / REGION_SIZE) * REGION_SIZE + REGION_SIZE / 2).min(MAP_SIZE.0 - 1);
+        let center_y = ((y / REGION_SIZE) * REGION_SIZE + REGION_SIZE / 2).min(MAP_SIZE.1 - 1);
+        let region_biome = overworld.get_zone_type(zone_idx(center_x, center_y, SURFACE_LEVEL_Z));
+
+        // Unvisited regions start partway through a calm spell, so storms arrive rather than appear
+        let region = self
+            .regions
+            .entry(Self::region_idx(x, y))
+            .or_insert_with(|| {
+                let calm = WeatherType::Clear.duration_ticks(rand) as f32 * rand.random();
+
+                RegionWeather {
+                    weather_type: WeatherType::Clear,
+                    until_tick: tick + calm as u32,
+                }
+            });
+
+        if region.until_tick <= tick {
+            // Storms always blow over into calm before the next one
+            let next = if region.weather_type == WeatherType::Clear {
+                WeatherType::roll(region_biome, rand)
+            } else {
+                WeatherType::Clear
+            };
+
+            region.weather_type = next;
+            region.until_tick = tick + next.duration_ticks(rand);
+        }
+
+        region.weather_type.for_biome(overworld.get_zone_type(zone))
+    }
+}
@@ -7,10 +7,11 @@ use crate::{
     cfg::{CARDINALS_OFFSET, MAP_SIZE, RENDER_DORMANT, ZONE_SIZE},
     common::{Grid, HashGrid},
     domain::{
-        Collider, ColliderCache, InActiveZone, LoadZoneCommand, PlayerMovedEvent, Prefab, PrefabId,
-        Prefabs, StaticEntity, StaticEntitySpawnedEvent, Terrain, UnloadZoneCommand, ZoneGenerator,
+        Collider, ColliderCache, GameLogEvent, InActiveZone, KnowledgeLevel, LoadZoneCommand,
+        LogMessage, Overworld, PlayerMovedEvent, Prefab, PrefabId, Prefabs, StaticEntity,
+        StaticEntitySpawnedEvent, Terrain, UnloadZoneCommand, ZoneGenerator,
     },
-    engine::{SerializedEntity, deserialize_all},
+    engine::{Clock, SerializedEntity, deserialize_all},
     rendering::{
         Position, world_to_zone_idx, world_to_zone_local, zone_idx, zone_local_to_world, zone_xyz,
     },
@@ -212,6 +213,35 @@ pub fn activate_zones_by_player(
     }
 }
 
+// mark points of interest as discovered when the player first walks into their zone
+pub fn discover_pois_by_player(
+    mut e_player_moved: EventReader<PlayerMovedEvent>,
+    mut overworld: ResMut<Overworld>,
+    mut e_log: EventWriter<GameLogEvent>,
+    clock: Res<Clock>,
+) {
+    for e in e_player_moved.read() {
+        let player_zone_idx = world_to_zone_idx(e.x, e.y, e.z);
+
+        if overworld.discovered_pois.contains(&player_zone_idx) {
+            continue;
+        }
+
+        let Some(poi) = overworld.pois.get(&player_zone_idx) else {
+            continue;
+        };
+
+        let text = format!("You come upon {{Y|{}}}.", poi.name);
+        overworld.discovered_pois.insert(player_zone_idx);
+
+        e_log.write(GameLogEvent {
+            message: LogMessage::Discovery { text },
+            tick: clock.current_tick(),
+            knowledge: KnowledgeLevel::Player,
+        });
+    }
+}
+
 // determine which zones should
 //  - be loaded
 //  - be unloaded
@@ -149,6 +149,7 @@ pub struct Audio {
     pub sounds: HashMap<AudioKey, Sound>,
     pub collections: HashMap<AudioCollection, Vec<AudioKey>>,
     playback_queue: Vec<QueuedAudioEntry>,
+    ambient: Option<AudioKey>,
 }
 
 impl Audio {
@@ -182,6 +183,7 @@ impl Audio {
             sounds,
             collections,
             playback_queue: Vec::new(),
+            ambient: None,
         }
     }
 
@@ -201,6 +203,31 @@ impl Audio {
         }
     }
 
+    /// Loop a sound in the background until another ambience replaces it, `None` silences it
+    pub fn set_ambient(&mut self, key: Option<AudioKey>, volume: f32) {
+        if self.ambient == key {
+            return;
+        }
+
+        if let Ok(ctx) = self.ctx.lock() {
+            if let Some(current) = self.ambient {
+                self.get(current).stop(&ctx);
+            }
+
+            if let Some(next) = key {
+                self.get(next).play(
+                    &ctx,
+                    PlaySoundParams {
+                        looped: true,
+                        volume,
+                    },
+                );
+            }
+        }
+
+        self.ambient = key;
+    }
+
     pub fn play_random_from_collection(
         &self,
         collection: AudioCollection,
@@ -24,25 +24,30 @@ use crate::{
     common::Rand,
     domain::{
         ActiveConditions, AiController, ApplyVisibilityEffects, AttributePoints, Attributes,
-        Bitmasker, BumpAttack, Collider, ConditionBlink, Consumable, CreatureType,
-        DefaultMeleeAttack, DefaultRangedAttack, Description, Destructible, DynamicEntity, Energy,
-        EquipmentSlots, Equippable, Equipped, ExplosionEvent, ExplosiveProperties, FactionMember,
-        FactionRelations, Fuse, GameSettings, Health, HideWhenNotVisible, HitBlink, InActiveZone,
-        InInventory, Inventory, InventoryAccessible, IsExplored, IsVisible, Item, ItemRarity,
+        Bedroll, Bitmasker, Bridge, BumpAttack, Campfire, Canteen, Collider, Companion,
+        ConditionBlink, Consumable, Corpse, CreatureType, DefaultMeleeAttack, DefaultRangedAttack,
+        Description, Destructible, Dialogue, DialogueMemory, DialogueRegistry, Doctor, Door,
+        DynamicEntity, Energy, EquipmentSlots, Equippable, Equipped, ExplosionEvent,
+        ExplosiveProperties, FactionMember, FactionRelations, Ferry, Fire, Following, Food, Fuse,
+        GameSettings, Health, HideWhenNotVisible, HitBlink, InActiveZone, InInventory, Injuries,
+        Inventory, InventoryAccessible, IsExplored, IsVisible, Item, ItemRarity, Key,
         KnockbackAnimation, Label, Level, LightSource, LightStateChangedEvent, LoadGameResult,
-        LoadZoneEvent, LootDrop, LootTableRegistry, MovementCapabilities, NeedsStableId,
-        NewGameResult, Player, PlayerMovedEvent, Prefabs, RecalculateColliderFlagsEvent,
-        RefreshBitmask, SaveFlag, SaveGameResult, SetZoneStatusEvent, SmoothMovement, StackCount,
+        LoadZoneEvent, Lock, LootDrop, LootTableRegistry, Merchant, MovementCapabilities, Needs,
+        NeedsStableId, NewGameResult, Player, PlayerMovedEvent, Prefabs, QuestLog, QuestTarget,
+        RecalculateColliderFlagsEvent, RecipeRegistry, RefreshBitmask, Reputation, SaveFlag,
+        SaveGameResult, Scavenger, SetZoneStatusEvent, Sleeping, SmoothMovement, StackCount,
         Stackable, StairDown, StairUp, StatModifiers, StaticEntity, StaticEntitySpawnedEvent,
-        Stats, Throwable, TurnState, UnloadZoneEvent, UnopenedContainer, Vision, Weapon, Zones,
+        Stats, Throwable, Tool, Trap, TrapKit, TurnState, UnloadZoneEvent, UnopenedContainer,
+        Vision, Weapon, WeaponAttachment, WeaponAttachments, Weather, Zones,
         inventory::InventoryChangedEvent,
         on_bitmask_spawn, on_refresh_bitmask,
         systems::bump_attack_system::bump_attack_system,
         systems::condition_blink_system::condition_blink_system,
         systems::destruction_system::EntityDestroyedEvent,
         systems::dynamic_label_system::{
-            ensure_labels_initialized, mark_dirty_on_equipment_change, mark_dirty_on_fuse_change,
-            mark_dirty_on_light_change, mark_dirty_on_stack_change, update_labels,
+            ensure_labels_initialized, mark_dirty_on_attachment_change,
+            mark_dirty_on_equipment_change, mark_dirty_on_fuse_change, mark_dirty_on_light_change,
+            mark_dirty_on_stack_change, update_labels,
         },
         systems::hit_blink_system::hit_blink_system,
         systems::knockback_animation_system::knockback_animation_system,
@@ -63,10 +68,11 @@ use crate::{
     rendering::{CrtShader, Glyph, TilesetRegistry},
     states::{
         AttributesStatePlugin, CleanupStateExplore, CleanupStatePlay, ContainerStatePlugin,
-        CurrentAppState, CurrentGameState, DebugSpawnStatePlugin, ExploreStatePlugin,
-        GameOverStatePlugin, InventoryStatePlugin, LoadGameStatePlugin, MainMenuStatePlugin,
-        NewGameStatePlugin, OverworldStatePlugin, PauseStatePlugin, PlayStatePlugin,
-        SettingsStatePlugin, ThrowStatePlugin, update_app_states, update_game_states,
+        CraftingStatePlugin, CurrentAppState, CurrentGameState, DebugSpawnStatePlugin,
+        DialogueStatePlugin, ExploreStatePlugin, GameOverStatePlugin, InventoryStatePlugin,
+        JournalStatePlugin, LoadGameStatePlugin, MainMenuStatePlugin, NewGameStatePlugin,
+        OverworldStatePlugin, PauseStatePlugin, PlayStatePlugin, SettingsStatePlugin,
+        ThrowStatePlugin, TradeStatePlugin, update_app_states, update_game_states,
     },
     ui::{
         DialogState, ListContext, UiFocus, clear_mouse_capture_when_not_hovering,
@@ -85,6 +91,7 @@ mod domain;
 mod engine;
 mod rendering;
 mod states;
+mod tools;
 mod ui;
 
 fn window_conf() -> Conf {
@@ -102,8 +109,16 @@ fn window_conf() -> Conf {
     }
 }
 
-#[macroquad::main(window_conf)]
-async fn main() {
+fn main() {
+    // Command line tools run headless and never open a window
+    if let Some(exit_code) = tools::run_from_args() {
+        std::process::exit(exit_code);
+    }
+
+    macroquad::Window::from_config(window_conf(), run());
+}
+
+async fn run() {
     #[cfg(feature = "tracy")]
     tracy_client::Client::start();
 
@@ -156,25 +171,54 @@ async fn main() {
     reg.register::<SmoothMovement>();
     reg.register::<Destructible>();
     reg.register::<Weapon>();
+    reg.register::<WeaponAttachment>();
+    reg.register::<WeaponAttachments>();
     reg.register::<ItemRarity>();
     reg.register::<DefaultMeleeAttack>();
     reg.register::<DefaultRangedAttack>();
     reg.register::<CreatureType>();
+    reg.register::<Doctor>();
+    reg.register::<Merchant>();
+    reg.register::<Dialogue>();
+    reg.register::<DialogueMemory>();
+    reg.register::<QuestLog>();
+    reg.register::<QuestTarget>();
+    reg.register::<Bridge>();
+    reg.register::<Ferry>();
     reg.register::<AiController>();
+    reg.register::<Following>();
+    reg.register::<Companion>();
+    reg.register::<Scavenger>();
+    reg.register::<Needs>();
+    reg.register::<Food>();
+    reg.register::<Canteen>();
+    reg.register::<Bedroll>();
+    reg.register::<Campfire>();
+    reg.register::<Sleeping>();
+    reg.register::<Corpse>();
+    reg.register::<Fire>();
+    reg.register::<Door>();
+    reg.register::<Lock>();
+    reg.register::<Key>();
+    reg.register::<Trap>();
+    reg.register::<TrapKit>();
     reg.register::<Level>();
     reg.register::<Attributes>();
     reg.register::<AttributePoints>();
     reg.register::<Stats>();
     reg.register::<StatModifiers>();
+    reg.register::<Injuries>();
     reg.register::<UnopenedContainer>();
     reg.register::<LootDrop>();
     reg.register::<Stackable>();
     reg.register::<StackCount>();
     reg.register::<Throwable>();
+    reg.register::<Tool>();
     reg.register::<ExplosiveProperties>();
     reg.register::<Fuse>();
     reg.register::<LightSource>();
     reg.register::<FactionMember>();
+    reg.register::<Reputation>();
     reg.register::<ui::Bar>();
 
     app.add_plugin(ExitAppPlugin)
@@ -187,9 +231,13 @@ async fn main() {
         .add_plugin(DebugSpawnStatePlugin)
         .add_plugin(InventoryStatePlugin)
         .add_plugin(ContainerStatePlugin::new())
+        .add_plugin(TradeStatePlugin::new())
+        .add_plugin(DialogueStatePlugin::new())
         .add_plugin(ThrowStatePlugin)
         .add_plugin(AttributesStatePlugin)
         .add_plugin(OverworldStatePlugin)
+        .add_plugin(JournalStatePlugin)
+        .add_plugin(CraftingStatePlugin)
         .add_plugin(PauseStatePlugin)
         .add_plugin(GameOverStatePlugin)
         .register_event::<LoadGameResult>()
@@ -213,6 +261,8 @@ async fn main() {
         .insert_resource(audio_registry)
         .insert_resource(reg)
         .insert_resource(LootTableRegistry::new())
+        .insert_resource(DialogueRegistry::new())
+        .insert_resource(RecipeRegistry::new())
         .init_resource::<LevelUpParticleQueue>()
         .init_resource::<GameLog>()
         .init_resource::<Mouse>()
@@ -233,6 +283,7 @@ async fn main() {
         .init_resource::<GameSettings>()
         .init_resource::<TurnState>()
         .init_resource::<Clock>()
+        .init_resource::<Weather>()
         .init_resource::<Bitmasker>()
         .init_resource::<Prefabs>()
         .init_resource::<Rand>()
@@ -297,6 +348,7 @@ async fn main() {
                     mark_dirty_on_fuse_change,
                     mark_dirty_on_light_change,
                     mark_dirty_on_stack_change,
+                    mark_dirty_on_attachment_change,
                 )
                     .after(ensure_labels_initialized),
                 update_labels.after(ensure_labels_initialized),
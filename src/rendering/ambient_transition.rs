@@ -1,6 +1,8 @@
 use bevy_ecs::prelude::*;
 use macroquad::prelude::*;
 
+const TRANSITION_DURATION: f32 = 0.25;
+
 #[derive(Resource)]
 pub struct AmbientTransition {
     current_ambient: Vec4,
@@ -16,7 +18,7 @@ impl Default for AmbientTransition {
             current_ambient: default_ambient,
             target_ambient: default_ambient,
             transition_progress: 1.0,
-            transition_duration: 0.25,
+            transition_duration: TRANSITION_DURATION,
         }
     }
 }
@@ -30,6 +32,14 @@ impl AmbientTransition {
         self.current_ambient = self.get_interpolated_ambient();
         self.target_ambient = target_ambient;
         self.transition_progress = 0.0;
+        self.transition_duration = TRANSITION_DURATION;
+    }
+
+    /// Jumps straight to a bright ambient and fades back over `duration`, for lightning
+    pub fn flash(&mut self, flash_ambient: Vec4, duration: f32) {
+        self.current_ambient = flash_ambient;
+        self.transition_progress = 0.0;
+        self.transition_duration = duration;
     }
 
     pub fn update(&mut self, dt: f32) {
@@ -96,10 +96,38 @@ impl ConditionType {
                 )
             }
 
-            // Conditions that don't need particle effects (behavioral effects)
-            ConditionType::Feared { .. }
+            ConditionType::Wet => Some(
+                ParticleSpawner::new(Vec2::ZERO)
+                    .glyph_animation(GlyphAnimation::RandomPool {
+                        glyphs: vec!['·', '\''],
+                        change_rate: None,
+                        last_change: 0.0,
+                    })
+                    .color_curve(ColorCurve::Constant(0x53ACE7))
+                    .alpha_curve(AlphaCurve::EaseOut {
+                        values: vec![0.6, 0.1],
+                    })
+                    .velocity_curve(VelocityCurve::Constant(Vec2::new(0.0, 1.5)))
+                    .spawn_area(SpawnArea::Circle {
+                        radius: 0.4,
+                        distribution: Distribution::Uniform,
+                    })
+                    .priority(135)
+                    .spawn_rate(2.0)
+                    .lifetime_range(0.5..0.9),
+            ),
+
+            // Conditions that don't need particle effects (behavioral effects and needs)
+            ConditionType::Pinned
+            | ConditionType::Feared { .. }
             | ConditionType::Taunted { .. }
-            | ConditionType::Confused { .. } => None,
+            | ConditionType::Confused { .. }
+            | ConditionType::Hungry
+            | ConditionType::Starving
+            | ConditionType::Thirsty
+            | ConditionType::Parched
+            | ConditionType::Tired
+            | ConditionType::Exhausted => None,
         }
     }
 }
@@ -333,8 +333,9 @@ pub fn update_persistent_spawners(
         // Update spawn timing
         persistent.time_since_last_spawn += dt;
 
+        // Busy spawners like weather emit more than one particle a frame
         let spawn_interval = 1.0 / persistent.spawner_config.spawn_rate;
-        if persistent.time_since_last_spawn >= spawn_interval {
+        while persistent.time_since_last_spawn >= spawn_interval {
             spawn_particle(&mut cmds, &persistent.spawner_config, &mut rand);
             persistent.time_since_last_spawn -= spawn_interval;
         }
@@ -1,10 +1,13 @@
 mod state;
 mod state_attributes;
 mod state_container;
+mod state_crafting;
 mod state_debug_spawn;
+mod state_dialogue;
 mod state_explore;
 mod state_game_over;
 mod state_inventory;
+mod state_journal;
 mod state_load_game;
 mod state_main_menu;
 mod state_new_game;
@@ -13,14 +16,18 @@ mod state_pause;
 mod state_play;
 mod state_settings;
 mod state_throw;
+mod state_trade;
 
 pub use state::*;
 pub use state_attributes::*;
 pub use state_container::*;
+pub use state_crafting::*;
 pub use state_debug_spawn::*;
+pub use state_dialogue::*;
 pub use state_explore::*;
 pub use state_game_over::*;
 pub use state_inventory::*;
+pub use state_journal::*;
 pub use state_load_game::*;
 pub use state_main_menu::*;
 pub use state_new_game::*;
@@ -29,3 +36,4 @@ pub use state_pause::*;
 pub use state_play::*;
 pub use state_settings::*;
 pub use state_throw::*;
+pub use state_trade::*;
@@ -54,10 +54,14 @@ pub enum GameState {
     Overworld,
     Inventory,
     Container,
+    Trade,
+    Dialogue,
     EquipSlotSelect,
     Throw,
     DebugSpawn,
     Attributes,
+    Journal,
+    Crafting,
     GameOver,
 }
 
@@ -72,10 +76,14 @@ impl fmt::Display for GameState {
             GameState::Overworld => write!(f, "Overworld"),
             GameState::Inventory => write!(f, "Inventory"),
             GameState::Container => write!(f, "Container"),
+            GameState::Trade => write!(f, "Trade"),
+            GameState::Dialogue => write!(f, "Dialogue"),
             GameState::EquipSlotSelect => write!(f, "Equip Slot Select"),
             GameState::Throw => write!(f, "Throw"),
             GameState::DebugSpawn => write!(f, "Debug Spawn"),
             GameState::Attributes => write!(f, "Attributes"),
+            GameState::Journal => write!(f, "Journal"),
+            GameState::Crafting => write!(f, "Crafting"),
             GameState::GameOver => write!(f, "Game Over"),
         }
     }
@@ -591,6 +591,7 @@ fn spawn_stat_dialog(
                     ModifierSource::Equipment { item_id } => format!("  Equipment #{}: {:+}", item_id, modifier.value),
                     ModifierSource::Intrinsic { name } => format!("  {}: {:+}", name, modifier.value),
                     ModifierSource::Condition { condition_id } => format!("  Condition {}: {:+}", condition_id, modifier.value),
+                    ModifierSource::Injury { injury } => format!("  {}: {:+}", injury.display_name(), modifier.value),
                 };
 
                 cmds.spawn((
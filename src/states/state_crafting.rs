@@ -0,0 +1,298 @@
+use bevy_ecs::{prelude::*, schedule::common_conditions::resource_changed, system::SystemId};
+use macroquad::input::KeyCode;
+
+use crate::{
+    common::Palette,
+    domain::{
+        CraftAction, Player, Recipe, RecipeRegistry, count_stacked, game_loop, has_tool,
+        inventory::InventoryChangedEvent, is_at_station, missing_requirements,
+    },
+    engine::{App, AudioKey, Plugin, TICKS_PER_MINUTE},
+    rendering::{Position, ScreenSize, Text},
+    states::{CurrentGameState, GameState, GameStatePlugin, cleanup_system},
+    ui::{
+        Button, FullScreenBackground, List, ListContext, ListItemData, setup_fullscreen_backgrounds,
+    },
+};
+
+#[derive(Resource)]
+struct CraftingCallbacks {
+    back_to_explore: SystemId,
+    craft_recipe: SystemId,
+}
+
+/// Which list entry the detail panel was drawn for, and whether it's out of date
+#[derive(Resource, Default)]
+struct CraftingScreen {
+    shown_context: Option<u64>,
+    needs_refresh: bool,
+}
+
+#[derive(Component)]
+pub struct CleanupStateCrafting;
+
+#[derive(Component)]
+struct RecipeList;
+
+#[derive(Component)]
+struct RecipeDetailLine;
+
+pub struct CraftingStatePlugin;
+
+impl Plugin for CraftingStatePlugin {
+    fn build(&self, app: &mut App) {
+        GameStatePlugin::new(GameState::Crafting)
+            .on_enter(
+                app,
+                (
+                    setup_crafting_callbacks,
+                    setup_crafting_screen,
+                    setup_fullscreen_backgrounds,
+                )
+                    .chain(),
+            )
+            .on_update(
+                app,
+                (
+                    mark_crafting_screen_dirty,
+                    refresh_crafting_screen,
+                    game_loop,
+                )
+                    .chain(),
+            )
+            .on_update(
+                app,
+                setup_fullscreen_backgrounds.run_if(resource_changed::<ScreenSize>),
+            )
+            .on_leave(
+                app,
+                (
+                    cleanup_system::<CleanupStateCrafting>,
+                    cleanup_system::<FullScreenBackground>,
+                    remove_crafting_callbacks,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn setup_crafting_callbacks(world: &mut World) {
+    let callbacks = CraftingCallbacks {
+        back_to_explore: world.register_system(back_to_explore),
+        craft_recipe: world.register_system(craft_selected_recipe),
+    };
+
+    world.insert_resource(callbacks);
+    world.insert_resource(CraftingScreen {
+        shown_context: None,
+        needs_refresh: true,
+    });
+}
+
+fn remove_crafting_callbacks(mut cmds: Commands) {
+    cmds.remove_resource::<CraftingCallbacks>();
+    cmds.remove_resource::<CraftingScreen>();
+}
+
+fn back_to_explore(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Explore;
+}
+
+fn craft_selected_recipe(
+    mut cmds: Commands,
+    list_context: Res<ListContext>,
+    registry: Res<RecipeRegistry>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let Ok(player) = q_player.single() else {
+        return;
+    };
+
+    let Some(recipe) = list_context
+        .context_data
+        .and_then(|index| registry.all().get(index as usize))
+    else {
+        return;
+    };
+
+    cmds.queue(CraftAction::new(player, &recipe.id));
+}
+
+fn setup_crafting_screen(mut cmds: Commands, callbacks: Res<CraftingCallbacks>) {
+    cmds.spawn((
+        Text::new("{Y|CRAFTING}").bg(Palette::Black),
+        Position::new_f32(2., 1., 0.),
+        CleanupStateCrafting,
+    ));
+
+    cmds.spawn((
+        Position::new_f32(2., 1.5, 0.),
+        Button::new("({Y|K}) BACK TO EXPLORE", callbacks.back_to_explore)
+            .hotkey(KeyCode::K)
+            .with_audio(AudioKey::ButtonBack1),
+        CleanupStateCrafting,
+    ));
+
+    cmds.spawn((
+        List::new(vec![]).with_focus_order(1000),
+        Position::new_f32(2., 3., 0.),
+        RecipeList,
+        CleanupStateCrafting,
+    ));
+
+    cmds.spawn((
+        Text::new("[{Y|ENTER}] Craft").bg(Palette::Black),
+        Position::new_f32(2., 12., 0.),
+        CleanupStateCrafting,
+    ));
+}
+
+/// Redraw when the player's pack changes, or when they move on to another recipe
+fn mark_crafting_screen_dirty(
+    mut screen: ResMut<CraftingScreen>,
+    list_context: Res<ListContext>,
+    mut e_inventory_changed: EventReader<InventoryChangedEvent>,
+) {
+    if !e_inventory_changed.is_empty() {
+        e_inventory_changed.clear();
+        screen.needs_refresh = true;
+    }
+
+    if list_context.context_data != screen.shown_context {
+        screen.needs_refresh = true;
+    }
+}
+
+/// A requirement line, green when it's met and red when it isn't
+fn requirement_line(met: bool, text: &str) -> String {
+    if met {
+        format!("  {{G|{}}}", text)
+    } else {
+        format!("  {{R|{}}}", text)
+    }
+}
+
+fn recipe_detail_lines(world: &mut World, player: Entity, recipe: &Recipe) -> Vec<String> {
+    let mut lines = vec![
+        format!("{{Y|{}}}", recipe.name),
+        format!(
+            "Makes {} x{}",
+            recipe.output.item.prefab_id(),
+            recipe.output.count
+        ),
+        format!(
+            "Takes {} minutes",
+            (recipe.energy as u32).div_ceil(TICKS_PER_MINUTE)
+        ),
+        String::new(),
+        "Ingredients".to_string(),
+    ];
+
+    for ingredient in recipe.ingredients.iter() {
+        let have = count_stacked(world, player, ingredient.item);
+        lines.push(requirement_line(
+            have >= ingredient.count,
+            &format!(
+                "{} {}/{}",
+                ingredient.item.prefab_id(),
+                have,
+                ingredient.count
+            ),
+        ));
+    }
+
+    if !recipe.tools.is_empty() {
+        lines.push("Tools".to_string());
+        for tool in recipe.tools.iter() {
+            lines.push(requirement_line(
+                has_tool(world, player, *tool),
+                &tool.to_string(),
+            ));
+        }
+    }
+
+    if let Some(station) = recipe.station {
+        lines.push("Made at".to_string());
+        let at_station = is_at_station(world, player, station);
+        lines.push(requirement_line(at_station, station.describe()));
+    }
+
+    lines
+}
+
+fn refresh_crafting_screen(world: &mut World) {
+    let needs_refresh = world
+        .get_resource::<CraftingScreen>()
+        .is_some_and(|screen| screen.needs_refresh);
+
+    if !needs_refresh {
+        return;
+    }
+
+    let Ok(player) = world.query_filtered::<Entity, With<Player>>().single(world) else {
+        return;
+    };
+
+    let Some(craft_recipe) = world
+        .get_resource::<CraftingCallbacks>()
+        .map(|callbacks| callbacks.craft_recipe)
+    else {
+        return;
+    };
+
+    let recipes = world.resource::<RecipeRegistry>().all().to_vec();
+
+    let list_items = recipes
+        .iter()
+        .enumerate()
+        .map(|(index, recipe)| {
+            let label = if missing_requirements(world, player, recipe).is_empty() {
+                recipe.name.clone()
+            } else {
+                format!("{{U|{}}}", recipe.name)
+            };
+
+            ListItemData::new(&label, craft_recipe).with_context(index as u64)
+        })
+        .collect::<Vec<_>>();
+
+    if let Ok(mut list) = world
+        .query_filtered::<&mut List, With<RecipeList>>()
+        .single_mut(world)
+    {
+        list.items = list_items;
+    }
+
+    let shown_context = world.resource::<ListContext>().context_data;
+    let shown_recipe = shown_context
+        .map(|index| index as usize)
+        .filter(|index| *index < recipes.len())
+        .unwrap_or(0);
+
+    let old_lines = world
+        .query_filtered::<Entity, With<RecipeDetailLine>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for line in old_lines {
+        world.despawn(line);
+    }
+
+    if let Some(recipe) = recipes.get(shown_recipe) {
+        let lines = recipe_detail_lines(world, player, recipe);
+
+        for (i, line) in lines.iter().enumerate() {
+            world.spawn((
+                Text::new(line).bg(Palette::Black),
+                Position::new_f32(24., 3. + i as f32 * 0.5, 0.),
+                RecipeDetailLine,
+                CleanupStateCrafting,
+            ));
+        }
+    }
+
+    if let Some(mut screen) = world.get_resource_mut::<CraftingScreen>() {
+        screen.shown_context = shown_context;
+        screen.needs_refresh = false;
+    }
+}
@@ -19,12 +19,26 @@ const SPAWNABLE_PREFABS: &[PrefabId] = &[
     PrefabId::PineTree,
     PrefabId::Boulder,
     PrefabId::Campfire,
+    PrefabId::Fire,
     PrefabId::GoldNugget,
     PrefabId::Cactus,
     PrefabId::CavalrySword,
     PrefabId::Chest,
     PrefabId::GiantMushroom,
     PrefabId::Bandit,
+    PrefabId::BanditLeader,
+    PrefabId::Doctor,
+    PrefabId::Merchant,
+    PrefabId::Townsfolk,
+    PrefabId::Gunslinger,
+    PrefabId::Dog,
+    PrefabId::WoodenWall,
+    PrefabId::WoodenBridge,
+    PrefabId::Door,
+    PrefabId::CellDoor,
+    PrefabId::Key,
+    PrefabId::Table,
+    PrefabId::Barrel,
     PrefabId::Hatchet,
     PrefabId::Lantern,
     PrefabId::Pickaxe,
@@ -33,7 +47,23 @@ const SPAWNABLE_PREFABS: &[PrefabId] = &[
     PrefabId::Dynamite,
     PrefabId::Apple,
     PrefabId::CanOfBeans,
+    PrefabId::Canteen,
+    PrefabId::Bandage,
     PrefabId::Bedroll,
+    PrefabId::RawMeat,
+    PrefabId::CookedMeat,
+    PrefabId::Gunpowder,
+    PrefabId::FuseCord,
+    PrefabId::Cloth,
+    PrefabId::Lead,
+    PrefabId::Cartridges,
+    PrefabId::Hide,
+    PrefabId::VenomGland,
+    PrefabId::BearTrap,
+    PrefabId::Tripwire,
+    PrefabId::Snare,
+    PrefabId::Trap,
+    PrefabId::Corpse,
     PrefabId::LongJohns,
     PrefabId::Duster,
     PrefabId::Poncho,
@@ -0,0 +1,256 @@
+use bevy_ecs::{prelude::*, schedule::common_conditions::resource_changed, system::SystemId};
+use macroquad::input::KeyCode;
+
+use crate::{
+    domain::{ChooseDialogueOptionAction, DialogueRegistry, Label},
+    engine::{App, AudioKey, KeyInput, Plugin},
+    rendering::{Layer, Position, ScreenSize, wrap_text},
+    states::{CurrentGameState, GameState, GameStatePlugin, cleanup_system},
+    ui::{
+        ActivatableBuilder, Dialog, DialogContent, DialogState, DialogText, DialogTextStyle, List,
+        ListContext, ListItemData, center_dialogs_on_screen_change,
+    },
+};
+
+const DIALOGUE_WIDTH: f32 = 36.0;
+
+const OPTION_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+];
+
+#[derive(Resource)]
+pub struct DialogueCallbacks {
+    pub choose_focused_option: SystemId,
+    pub end_conversation: SystemId,
+}
+
+#[derive(Component, Clone)]
+pub struct CleanupStateDialogue;
+
+#[derive(Component)]
+pub struct DialogueOptionsList;
+
+/// Who's talking and where they've got to in the conversation
+#[derive(Resource)]
+pub struct DialogueContext {
+    pub player_entity: Entity,
+    pub speaker_entity: Entity,
+    pub tree_id: String,
+    pub node_id: String,
+}
+
+pub struct DialogueStatePlugin;
+
+impl DialogueStatePlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Plugin for DialogueStatePlugin {
+    fn build(&self, app: &mut App) {
+        GameStatePlugin::new(GameState::Dialogue)
+            .on_enter(app, setup_dialogue_callbacks)
+            .on_update(
+                app,
+                (
+                    handle_dialogue_input,
+                    render_dialogue_node.run_if(resource_changed::<DialogueContext>),
+                )
+                    .chain(),
+            )
+            .on_update(
+                app,
+                center_dialogs_on_screen_change.run_if(resource_changed::<ScreenSize>),
+            )
+            .on_leave(
+                app,
+                (
+                    cleanup_system::<CleanupStateDialogue>,
+                    remove_dialogue_callbacks,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn setup_dialogue_callbacks(world: &mut World) {
+    let callbacks = DialogueCallbacks {
+        choose_focused_option: world.register_system(choose_focused_option),
+        end_conversation: world.register_system(end_conversation),
+    };
+    world.insert_resource(callbacks);
+}
+
+fn remove_dialogue_callbacks(mut cmds: Commands, mut dialog_state: ResMut<DialogState>) {
+    cmds.remove_resource::<DialogueCallbacks>();
+    cmds.remove_resource::<DialogueContext>();
+    dialog_state.is_open = false;
+}
+
+fn end_conversation(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Explore;
+}
+
+fn choose_focused_option(mut cmds: Commands, list_context: Res<ListContext>) {
+    let Some(option_index) = list_context.context_data else {
+        return;
+    };
+
+    cmds.queue(ChooseDialogueOptionAction {
+        option_index: option_index as usize,
+    });
+}
+
+/// Number keys pick the matching line straight away
+fn handle_dialogue_input(
+    mut cmds: Commands,
+    keys: Res<KeyInput>,
+    q_options_list: Query<&List, With<DialogueOptionsList>>,
+) {
+    let Ok(list) = q_options_list.single() else {
+        return;
+    };
+
+    for (key, item) in OPTION_KEYS.iter().zip(list.items.iter()) {
+        if keys.is_pressed(*key)
+            && let Some(option_index) = item.context_data
+        {
+            cmds.queue(ChooseDialogueOptionAction {
+                option_index: option_index as usize,
+            });
+            return;
+        }
+    }
+}
+
+/// Lays out whatever the speaker is saying now and the lines the player can answer with
+fn render_dialogue_node(world: &mut World) {
+    let stale = world
+        .query_filtered::<Entity, With<CleanupStateDialogue>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for entity in stale {
+        if let Ok(entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.despawn();
+        }
+    }
+
+    let Some(context) = world.get_resource::<DialogueContext>() else {
+        return;
+    };
+
+    let player = context.player_entity;
+    let speaker = context.speaker_entity;
+
+    let Some(node) = world
+        .get_resource::<DialogueRegistry>()
+        .and_then(|registry| registry.get_node(&context.tree_id, &context.node_id))
+        .cloned()
+    else {
+        world.resource_mut::<CurrentGameState>().next = GameState::Explore;
+        return;
+    };
+
+    let (choose_option, end_conversation) = {
+        let callbacks = world.resource::<DialogueCallbacks>();
+        (callbacks.choose_focused_option, callbacks.end_conversation)
+    };
+
+    let speaker_name = world
+        .get::<Label>(speaker)
+        .map(|l| l.get().to_string())
+        .unwrap_or_else(|| "Stranger".to_string());
+
+    let list_items = node
+        .options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| option.is_available(world, player, speaker))
+        .take(OPTION_KEYS.len())
+        .enumerate()
+        .map(|(n, (idx, option))| {
+            ListItemData::new(
+                &format!("({{Y|{}}}) {}", n + 1, option.display_text()),
+                choose_option,
+            )
+            .with_context(idx as u64)
+        })
+        .collect::<Vec<_>>();
+
+    let available_width = ((DIALOGUE_WIDTH as usize).saturating_sub(3)) * 2; // Account for 0.5-width text chars
+    let text_lines = wrap_text(&node.text, available_width);
+    let text_height = text_lines.len() as f32 * 0.5; // 0.5 units per line
+
+    // Border + title + gap + speech + gap + options + gap + leave button + border
+    let dialog_height = (2.5 + text_height + 0.5 + list_items.len() as f32 + 3.0).ceil();
+
+    let screen = world.resource::<ScreenSize>();
+    let dialog_x = ((screen.tile_w as f32 - DIALOGUE_WIDTH) / 2.0).round();
+    let dialog_y = ((screen.tile_h as f32 - dialog_height) / 2.0).round();
+
+    let dialog_entity = world
+        .spawn((
+            Dialog::new(&speaker_name, DIALOGUE_WIDTH, dialog_height),
+            Position::new_f32(dialog_x, dialog_y, 0.0),
+            CleanupStateDialogue,
+        ))
+        .id();
+
+    for (i, line) in text_lines.into_iter().enumerate() {
+        world.spawn((
+            DialogText {
+                value: line,
+                style: DialogTextStyle::Description,
+            },
+            DialogContent {
+                parent_dialog: dialog_entity,
+                order: 10 + i,
+            },
+            Position::new_f32(dialog_x + 1.5, dialog_y + 2.5 + i as f32 * 0.5, 0.0),
+            CleanupStateDialogue,
+            ChildOf(dialog_entity),
+        ));
+    }
+
+    world.spawn((
+        List::new(list_items).with_focus_order(1000),
+        Position::new_f32(dialog_x + 1.0, dialog_y + 3.0 + text_height, 0.0),
+        DialogContent {
+            parent_dialog: dialog_entity,
+            order: 100,
+        },
+        DialogueOptionsList,
+        CleanupStateDialogue,
+    ));
+
+    world.spawn((
+        ActivatableBuilder::new("[{Y|ESC}] Leave", end_conversation)
+            .with_audio(AudioKey::ButtonBack1)
+            .with_hotkey(KeyCode::Escape)
+            .with_focus_order(3000)
+            .as_button(Layer::DialogContent),
+        DialogContent {
+            parent_dialog: dialog_entity,
+            order: 200,
+        },
+        Position::new_f32(
+            dialog_x + (DIALOGUE_WIDTH / 2.0) - 3.0,
+            dialog_y + dialog_height - 2.0,
+            0.0,
+        ),
+        CleanupStateDialogue,
+        ChildOf(dialog_entity),
+    ));
+
+    world.resource_mut::<DialogState>().is_open = true;
+}
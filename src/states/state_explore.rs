@@ -8,17 +8,19 @@ use crate::{
     cfg::ZONE_SIZE,
     common::{Palette, hex},
     domain::{
-        ActiveConditions, AiController, ConditionType, CreatureType, DefaultMeleeAttack,
-        Description, EquipmentSlot, EquipmentSlots, FactionId, Health, IgnoreLighting, Item, Label,
-        Level, Player, PlayerDebug, PlayerMovedEvent, PlayerPosition, Stats, TargetCycling, Weapon,
-        WeaponType, Zone, collect_valid_targets, game_loop, handle_item_pickup,
-        init_targeting_resource, player_input, render_player_debug, render_target_crosshair,
-        render_target_info, spawn_targeting_ui, update_mouse_targeting, update_target_cycling,
+        ActiveConditions, AiController, Condition, ConditionType, CreatureType, DefaultMeleeAttack,
+        Description, EquipmentSlot, EquipmentSlots, FactionId, FiringPattern, Health,
+        IgnoreLighting, Item, Label, Level, Player, PlayerDebug, PlayerMovedEvent, PlayerPosition,
+        Stats, TargetCycling, Weapon, WeaponType, Zone, collect_valid_targets, game_loop,
+        handle_item_pickup, init_targeting_resource, player_input, render_player_debug,
+        render_target_crosshair, render_target_info, spawn_targeting_ui, update_mouse_targeting,
+        update_target_cycling,
     },
     engine::{App, KeyInput, Mouse, Plugin, SerializableComponent, StableId, StableIdRegistry},
     rendering::{
-        Glyph, Layer, Position, ScreenSize, Text, Visibility, setup_zone_outline_state,
-        spawn_zone_outline, world_to_zone_idx, world_to_zone_local, zone_local_to_world,
+        Glyph, GlyphTextureId, Layer, Position, ScreenSize, Text, Visibility,
+        setup_zone_outline_state, spawn_zone_outline, world_to_zone_idx, world_to_zone_local,
+        zone_local_to_world,
     },
     states::{CurrentGameState, GameStatePlugin, cleanup_system},
     ui::{
@@ -38,6 +40,8 @@ struct ExploreCallbacks {
     open_inventory: SystemId,
     open_debug_spawn: SystemId,
     open_attributes: SystemId,
+    open_journal: SystemId,
+    open_crafting: SystemId,
     open_pause: SystemId,
     examine_entity: SystemId,
     close_examine_dialog: SystemId,
@@ -69,6 +73,7 @@ impl Plugin for ExploreStatePlugin {
                     update_mouse_targeting,
                     render_target_crosshair,
                     render_target_info,
+                    render_target_pattern_preview,
                     render_player_debug,
                     render_tick_display,
                     render_lighting_debug,
@@ -150,6 +155,9 @@ pub struct PlayerAmmoBar;
 #[derive(Component)]
 pub struct PlayerConditionDisplay;
 
+#[derive(Component)]
+pub struct TargetPatternTile;
+
 #[derive(Component)]
 pub struct PlayerConditionItem {
     pub condition_type: ConditionType,
@@ -187,6 +195,8 @@ fn setup_callbacks(world: &mut World) {
         open_inventory: world.register_system(open_inventory),
         open_debug_spawn: world.register_system(open_debug_spawn),
         open_attributes: world.register_system(open_attributes),
+        open_journal: world.register_system(open_journal),
+        open_crafting: world.register_system(open_crafting),
         open_pause: world.register_system(open_pause),
         examine_entity: world.register_system(examine_entity_at_mouse),
         close_examine_dialog: world.register_system(close_examine_dialog),
@@ -211,6 +221,14 @@ fn open_attributes(mut game_state: ResMut<CurrentGameState>) {
     game_state.next = GameState::Attributes;
 }
 
+fn open_journal(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Journal;
+}
+
+fn open_crafting(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Crafting;
+}
+
 fn open_pause(mut game_state: ResMut<CurrentGameState>) {
     game_state.next = GameState::Pause;
 }
@@ -797,12 +815,7 @@ fn update_target_condition_display(
         for (entity, item, mut text) in q_existing_displays.iter_mut() {
             if item.condition_type == condition.condition_type {
                 // Update existing display
-                let color_char = condition.condition_type.get_display_color_char();
-                let icon = condition.condition_type.get_icon_glyph();
-                text.value = format!(
-                    "{{{}|{} {} ({})}}",
-                    color_char, icon, condition.condition_type, condition.duration_remaining
-                );
+                text.value = condition_text(condition);
 
                 // Update position in case order changed
                 cmds.entity(entity)
@@ -814,12 +827,7 @@ fn update_target_condition_display(
 
         if !found_existing {
             // Spawn new display
-            let color_char = condition.condition_type.get_display_color_char();
-            let icon = condition.condition_type.get_icon_glyph();
-            let text_value = format!(
-                "{{{}|{} {} ({})}}",
-                color_char, icon, condition.condition_type, condition.duration_remaining
-            );
+            let text_value = condition_text(condition);
 
             cmds.spawn((
                 TargetConditionItem {
@@ -932,6 +940,73 @@ fn update_player_ammo_bar(
     ammo_text.value = format!("[{}] {}/{}", bar_chars, current_ammo, clip_size);
 }
 
+/// Highlight the tiles a shot at the current target would sweep, for weapons
+/// whose rounds reach beyond the target (shotgun cones, rifle over-penetration)
+fn render_target_pattern_preview(
+    mut cmds: Commands,
+    target_cycling: Res<TargetCycling>,
+    q_player: Query<(&Position, &EquipmentSlots), With<Player>>,
+    q_weapons: Query<&Weapon>,
+    q_existing: Query<Entity, With<TargetPatternTile>>,
+    registry: Option<Res<StableIdRegistry>>,
+    mut last_preview: Local<Option<((usize, usize, usize), (usize, usize), FiringPattern, usize)>>,
+) {
+    let preview = (|| {
+        let registry = registry.as_ref()?;
+        let (player_pos, equipment) = q_player.single().ok()?;
+        let weapon_id = equipment.get_equipped_item(EquipmentSlot::MainHand)?;
+        let weapon = q_weapons
+            .get(registry.get_entity(StableId(weapon_id))?)
+            .ok()?;
+        let idx = target_cycling.current_index?;
+        let (_, target_pos, _) = target_cycling.targets.get(idx)?;
+        let pattern = weapon.firing_pattern();
+
+        if pattern == FiringPattern::Single {
+            return None;
+        }
+
+        Some((
+            player_pos.world(),
+            (target_pos.0 as usize, target_pos.1 as usize),
+            pattern,
+            weapon.range.unwrap_or(1),
+        ))
+    })();
+
+    // Leaving the state cleans up the tiles, so a matching cache alone isn't enough
+    let drawn = preview.is_none() || !q_existing.is_empty();
+
+    if *last_preview == preview && drawn {
+        return;
+    }
+
+    *last_preview = preview;
+
+    for entity in q_existing.iter() {
+        cmds.entity(entity).despawn();
+    }
+
+    let Some((player_pos, target_pos, pattern, range)) = preview else {
+        return;
+    };
+
+    for (x, y) in pattern.affected_tiles((player_pos.0, player_pos.1), target_pos, range) {
+        cmds.spawn((
+            Glyph::idx(0)
+                .fg1(Palette::Orange)
+                .alpha(0.4)
+                .texture(GlyphTextureId::Bitmasks)
+                .layer(Layer::Overlay),
+            Position::new_world((x, y, player_pos.2)),
+            Visibility::Visible,
+            IgnoreLighting,
+            TargetPatternTile,
+            CleanupStateExplore,
+        ));
+    }
+}
+
 fn update_player_condition_display(
     q_player: Query<&ActiveConditions, With<Player>>,
     mut q_existing_displays: Query<
@@ -969,12 +1044,7 @@ fn update_player_condition_display(
         for (entity, item, mut text) in q_existing_displays.iter_mut() {
             if item.condition_type == condition.condition_type {
                 // Update existing display
-                let color_char = condition.condition_type.get_display_color_char();
-                let icon = condition.condition_type.get_icon_glyph();
-                text.value = format!(
-                    "{{{}|{} {} ({})}}",
-                    color_char, icon, condition.condition_type, condition.duration_remaining
-                );
+                text.value = condition_text(condition);
 
                 // Update position in case order changed
                 cmds.entity(entity)
@@ -986,12 +1056,7 @@ fn update_player_condition_display(
 
         if !found_existing {
             // Spawn new display
-            let color_char = condition.condition_type.get_display_color_char();
-            let icon = condition.condition_type.get_icon_glyph();
-            let text_value = format!(
-                "{{{}|{} {} ({})}}",
-                color_char, icon, condition.condition_type, condition.duration_remaining
-            );
+            let text_value = condition_text(condition);
 
             cmds.spawn((
                 PlayerConditionItem {
@@ -1005,6 +1070,21 @@ fn update_player_condition_display(
     }
 }
 
+/// Icon, name and time left, colored for the condition. Needs have no time left to show.
+fn condition_text(condition: &Condition) -> String {
+    let color_char = condition.condition_type.get_display_color_char();
+    let icon = condition.condition_type.get_icon_glyph();
+
+    if condition.condition_type.is_need() {
+        return format!("{{{}|{} {}}}", color_char, icon, condition.condition_type);
+    }
+
+    format!(
+        "{{{}|{} {} ({})}}",
+        color_char, icon, condition.condition_type, condition.duration_remaining
+    )
+}
+
 fn update_player_xp_label(
     q_player: Query<&Level, (With<Player>, Changed<Level>)>,
     mut q_xp_label: Query<&mut Text, With<PlayerXPLabel>>,
@@ -1086,6 +1166,19 @@ fn spawn_ui_buttons(cmds: &mut Commands, callbacks: &ExploreCallbacks) {
 
     cmds.spawn((
         Position::new_f32(0.5, ui_button_y + 2.0, 0.),
+        Button::new("({Y|J}) JOURNAL", callbacks.open_journal).hotkey(macroquad::input::KeyCode::J),
+        CleanupStateExplore,
+    ));
+
+    cmds.spawn((
+        Position::new_f32(0.5, ui_button_y + 2.5, 0.),
+        Button::new("({Y|K}) CRAFTING", callbacks.open_crafting)
+            .hotkey(macroquad::input::KeyCode::K),
+        CleanupStateExplore,
+    ));
+
+    cmds.spawn((
+        Position::new_f32(0.5, ui_button_y + 3.0, 0.),
         Button::new("({Y|ESC}) PAUSE", callbacks.open_pause)
             .hotkey(macroquad::input::KeyCode::Escape),
         CleanupStateExplore,
@@ -1209,6 +1302,11 @@ fn get_examinable_entity_priority(
 }
 
 fn get_examinable_entity_priority_world(entity: Entity, world: &World) -> Option<(Entity, u32)> {
+    // Hidden traps and the like can't be looked at until they're spotted
+    if world.get::<Visibility>(entity) == Some(&Visibility::Hidden) {
+        return None;
+    }
+
     // Priority levels (lower number = higher priority)
     if world.get::<CreatureType>(entity).is_some() {
         Some((entity, 1)) // Highest priority: Creatures
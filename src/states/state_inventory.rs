@@ -8,13 +8,18 @@ use macroquad::input::{KeyCode, is_key_pressed};
 use crate::{
     common::Palette,
     domain::{
-        Consumable, ConsumeAction, DropItemAction, EquipItemAction, EquipmentSlot, Equippable,
-        Equipped, ExplosiveProperties, Fuse, HitEffect, Inventory, Item, ItemRarity, Label,
-        LightSource, LightStateChangedEvent, Lightable, ModifierSource, Player, PlayerPosition,
-        StackCount, StatModifiers, Throwable, ToggleLightAction, UnequipItemAction, Weapon,
-        WeaponType, game_loop, inventory::InventoryChangedEvent,
+        Bedroll, Canteen, Consumable, ConsumableEffect, ConsumeAction, DropItemAction,
+        EquipItemAction, EquipmentSlot, EquipmentSlots, Equippable, Equipped, ExplosiveProperties,
+        FillCanteenAction, Fuse, HitEffect, InstallAttachmentAction, Inventory, Item, ItemRarity,
+        Label, LightSource, LightStateChangedEvent, Lightable, ModifierSource, Player,
+        PlayerPosition, RemoveAttachmentAction, SetTrapAction, SleepAction, StackCount,
+        StatModifiers, Throwable, ToggleLightAction, TrapKit, UnequipItemAction, Weapon,
+        WeaponAttachment, WeaponAttachments, WeaponType, game_loop,
+        inventory::InventoryChangedEvent,
+        systems::game_log_system::{GameLogEvent, KnowledgeLevel, LogMessage},
+        unit_value,
     },
-    engine::{App, AudioKey, Plugin, StableId, StableIdRegistry},
+    engine::{App, AudioKey, Clock, Plugin, StableId, StableIdRegistry},
     rendering::{Glyph, Layer, Position, ScreenSize, Text},
     states::{CurrentGameState, GameState, GameStatePlugin, ThrowContext, cleanup_system},
     ui::{
@@ -34,6 +39,11 @@ struct ItemQueries<'w, 's> {
     throwable: Query<'w, 's, &'static Throwable>,
     explosive: Query<'w, 's, &'static ExplosiveProperties>,
     fuse: Query<'w, 's, &'static Fuse>,
+    attachment: Query<'w, 's, &'static WeaponAttachment>,
+    attachments: Query<'w, 's, &'static WeaponAttachments>,
+    canteen: Query<'w, 's, &'static Canteen>,
+    bedroll: Query<'w, 's, &'static Bedroll>,
+    trap_kit: Query<'w, 's, &'static TrapKit>,
 }
 
 #[derive(Resource)]
@@ -49,6 +59,11 @@ struct InventoryCallbacks {
     open_item_actions: SystemId,
     examine_item: SystemId,
     close_examine_dialog: SystemId,
+    install_attachment: SystemId,
+    strip_attachments: SystemId,
+    fill_canteen: SystemId,
+    sleep: SystemId,
+    set_trap: SystemId,
 }
 
 #[derive(Resource, Default)]
@@ -215,6 +230,11 @@ fn setup_inventory_callbacks(world: &mut World) {
     let toggle_light = world.register_system(toggle_light_selected_item_from_dialog);
     let eat_item = world.register_system(eat_selected_item_from_dialog);
     let throw_item = world.register_system(throw_selected_item_from_dialog);
+    let install_attachment = world.register_system(install_selected_attachment_from_dialog);
+    let strip_attachments = world.register_system(strip_selected_weapon_attachments_from_dialog);
+    let fill_canteen = world.register_system(fill_selected_canteen_from_dialog);
+    let sleep = world.register_system(sleep_on_selected_bedroll_from_dialog);
+    let set_trap = world.register_system(set_selected_trap_from_dialog);
 
     let callbacks = InventoryCallbacks {
         back_to_explore: world.register_system(back_to_explore),
@@ -228,6 +248,11 @@ fn setup_inventory_callbacks(world: &mut World) {
         open_item_actions: world.register_system(handle_item_click),
         examine_item: world.register_system(examine_item),
         close_examine_dialog: world.register_system(close_examine_dialog),
+        install_attachment,
+        strip_attachments,
+        fill_canteen,
+        sleep,
+        set_trap,
     };
 
     world.insert_resource(callbacks);
@@ -238,14 +263,7 @@ fn handle_item_click(
     list_context: Res<ListContext>,
     id_registry: Res<StableIdRegistry>,
     q_labels: Query<&Label>,
-    q_equippable: Query<&Equippable>,
-    q_equipped: Query<&Equipped>,
-    q_lightable: Query<&Lightable>,
-    q_light_source: Query<&LightSource>,
-    q_consumable: Query<&Consumable>,
-    q_throwable: Query<&Throwable>,
-    q_explosive: Query<&ExplosiveProperties>,
-    q_fuse: Query<&Fuse>,
+    item_queries: ItemQueries,
     dialog_state: ResMut<DialogState>,
     callbacks: Res<InventoryCallbacks>,
     screen: Res<ScreenSize>,
@@ -256,14 +274,7 @@ fn handle_item_click(
             item_id,
             &id_registry,
             &q_labels,
-            &q_equippable,
-            &q_equipped,
-            &q_lightable,
-            &q_light_source,
-            &q_consumable,
-            &q_throwable,
-            &q_explosive,
-            &q_fuse,
+            &item_queries,
             dialog_state,
             &callbacks,
             &screen,
@@ -274,16 +285,18 @@ fn handle_item_click(
 fn build_item_action_list(
     item_id: u64,
     id_registry: &StableIdRegistry,
-    q_equippable: &Query<&Equippable>,
-    q_equipped: &Query<&Equipped>,
-    q_lightable: &Query<&Lightable>,
-    q_light_source: &Query<&LightSource>,
-    q_consumable: &Query<&Consumable>,
-    q_throwable: &Query<&Throwable>,
-    q_explosive: &Query<&ExplosiveProperties>,
-    q_fuse: &Query<&Fuse>,
+    item_queries: &ItemQueries,
     callbacks: &InventoryCallbacks,
 ) -> Vec<ListItemData> {
+    let q_equippable = &item_queries.equippable;
+    let q_equipped = &item_queries.equipped;
+    let q_lightable = &item_queries.lightable;
+    let q_light_source = &item_queries.light_source;
+    let q_consumable = &item_queries.consumable;
+    let q_throwable = &item_queries.throwable;
+    let q_explosive = &item_queries.explosive;
+    let q_fuse = &item_queries.fuse;
+
     let Some(item_entity) = id_registry.get_entity(StableId(item_id)) else {
         return Vec::new();
     };
@@ -330,9 +343,29 @@ fn build_item_action_list(
         }
     }
 
-    if q_consumable.get(item_entity).is_ok() {
+    if let Ok(consumable) = q_consumable.get(item_entity) {
+        let label = if matches!(consumable.effect, ConsumableEffect::Drink) {
+            "({Y|C}) Drink"
+        } else {
+            "({Y|C}) Eat"
+        };
+        list_items.push(ListItemData::new(label, callbacks.eat_item).with_hotkey(KeyCode::C));
+    }
+
+    if item_queries.canteen.get(item_entity).is_ok() {
+        list_items.push(
+            ListItemData::new("({Y|F}) Fill", callbacks.fill_canteen).with_hotkey(KeyCode::F),
+        );
+    }
+
+    if item_queries.bedroll.get(item_entity).is_ok() {
+        list_items
+            .push(ListItemData::new("({Y|S}) Sleep", callbacks.sleep).with_hotkey(KeyCode::S));
+    }
+
+    if item_queries.trap_kit.get(item_entity).is_ok() {
         list_items
-            .push(ListItemData::new("({Y|C}) Eat", callbacks.eat_item).with_hotkey(KeyCode::C));
+            .push(ListItemData::new("({Y|P}) Set", callbacks.set_trap).with_hotkey(KeyCode::P));
     }
 
     if q_throwable.get(item_entity).is_ok() {
@@ -340,6 +373,22 @@ fn build_item_action_list(
             .push(ListItemData::new("({Y|T}) Throw", callbacks.throw_item).with_hotkey(KeyCode::T));
     }
 
+    if item_queries.attachment.get(item_entity).is_ok() {
+        list_items.push(
+            ListItemData::new("({Y|A}) Attach", callbacks.install_attachment)
+                .with_hotkey(KeyCode::A),
+        );
+    }
+
+    if let Ok(attachments) = item_queries.attachments.get(item_entity)
+        && !attachments.is_empty()
+    {
+        list_items.push(
+            ListItemData::new("({Y|R}) Strip Attachments", callbacks.strip_attachments)
+                .with_hotkey(KeyCode::R),
+        );
+    }
+
     list_items
         .push(ListItemData::new("({Y|X}) Examine", callbacks.examine_item).with_hotkey(KeyCode::X));
 
@@ -355,14 +404,7 @@ fn spawn_item_actions_dialog(
     item_id: u64,
     id_registry: &StableIdRegistry,
     q_labels: &Query<&Label>,
-    q_equippable: &Query<&Equippable>,
-    q_equipped: &Query<&Equipped>,
-    q_lightable: &Query<&Lightable>,
-    q_light_source: &Query<&LightSource>,
-    q_consumable: &Query<&Consumable>,
-    q_throwable: &Query<&Throwable>,
-    q_explosive: &Query<&ExplosiveProperties>,
-    q_fuse: &Query<&Fuse>,
+    item_queries: &ItemQueries,
     mut dialog_state: ResMut<DialogState>,
     callbacks: &InventoryCallbacks,
     screen: &ScreenSize,
@@ -376,19 +418,7 @@ fn spawn_item_actions_dialog(
         .map(|l| l.get())
         .unwrap_or("Unknown Item");
 
-    let list_items = build_item_action_list(
-        item_id,
-        id_registry,
-        q_equippable,
-        q_equipped,
-        q_lightable,
-        q_light_source,
-        q_consumable,
-        q_throwable,
-        q_explosive,
-        q_fuse,
-        callbacks,
-    );
+    let list_items = build_item_action_list(item_id, id_registry, item_queries, callbacks);
 
     // Calculate dialog height based on number of actions
     let dialog_width = 24.0;
@@ -560,6 +590,79 @@ fn eat_selected_item_from_dialog(
     }
 }
 
+fn fill_selected_canteen_from_dialog(
+    mut cmds: Commands,
+    context: Res<InventoryContext>,
+    q_action_dialog: Query<&ItemActionDialog>,
+) {
+    if let Ok(action_dialog) = q_action_dialog.single() {
+        cmds.queue(FillCanteenAction::new(
+            action_dialog.item_id,
+            context.player_entity,
+        ));
+    }
+}
+
+fn sleep_on_selected_bedroll_from_dialog(
+    mut cmds: Commands,
+    mut game_state: ResMut<CurrentGameState>,
+    context: Res<InventoryContext>,
+    q_dialogs: Query<Entity, With<Dialog>>,
+    q_dialog_content: Query<Entity, With<DialogContent>>,
+    mut dialog_state: ResMut<DialogState>,
+) {
+    cmds.queue(SleepAction {
+        entity: context.player_entity,
+    });
+
+    for dialog_entity in q_dialogs.iter() {
+        cmds.entity(dialog_entity).despawn();
+    }
+    for content_entity in q_dialog_content.iter() {
+        cmds.entity(content_entity).despawn();
+    }
+    dialog_state.is_open = false;
+
+    // Sleep plays out on the map, where an ambush can be seen coming
+    game_state.next = GameState::Explore;
+}
+
+fn set_selected_trap_from_dialog(
+    mut cmds: Commands,
+    context: Res<InventoryContext>,
+    id_registry: Res<StableIdRegistry>,
+    q_trap_kits: Query<&TrapKit>,
+    q_action_dialog: Query<&ItemActionDialog>,
+    q_dialogs: Query<Entity, With<Dialog>>,
+    q_dialog_content: Query<Entity, With<DialogContent>>,
+    mut dialog_state: ResMut<DialogState>,
+) {
+    let Ok(action_dialog) = q_action_dialog.single() else {
+        return;
+    };
+
+    let Some(kit) = id_registry
+        .get_entity(StableId(action_dialog.item_id))
+        .and_then(|item_entity| q_trap_kits.get(item_entity).ok())
+    else {
+        return;
+    };
+
+    cmds.queue(SetTrapAction {
+        actor: context.player_entity,
+        kind: kit.0,
+    });
+
+    // Close dialog, the last one in the stack is gone once it's set
+    for dialog_entity in q_dialogs.iter() {
+        cmds.entity(dialog_entity).despawn();
+    }
+    for content_entity in q_dialog_content.iter() {
+        cmds.entity(content_entity).despawn();
+    }
+    dialog_state.is_open = false;
+}
+
 fn throw_selected_item_from_dialog(
     mut cmds: Commands,
     mut game_state: ResMut<CurrentGameState>,
@@ -600,6 +703,82 @@ fn throw_selected_item_from_dialog(
     }
 }
 
+fn install_selected_attachment_from_dialog(
+    mut cmds: Commands,
+    context: Res<InventoryContext>,
+    id_registry: Res<StableIdRegistry>,
+    q_inventory: Query<&Inventory>,
+    q_equipment: Query<&EquipmentSlots>,
+    q_attachment: Query<&WeaponAttachment>,
+    q_attachments: Query<&WeaponAttachments>,
+    q_action_dialog: Query<&ItemActionDialog>,
+    clock: Res<Clock>,
+) {
+    let Ok(action_dialog) = q_action_dialog.single() else {
+        return;
+    };
+
+    let Some(attachment) = id_registry
+        .get_entity(StableId(action_dialog.item_id))
+        .and_then(|e| q_attachment.get(e).ok())
+    else {
+        return;
+    };
+
+    let accepts = |weapon_id: u64| {
+        id_registry
+            .get_entity(StableId(weapon_id))
+            .and_then(|e| q_attachments.get(e).ok())
+            .is_some_and(|slots| slots.can_install(attachment.attachment_type))
+    };
+
+    // Prefer the weapon in hand, otherwise the first compatible weapon in the pack
+    let main_hand = q_equipment
+        .get(context.player_entity)
+        .ok()
+        .and_then(|equipment| equipment.get_equipped_item(EquipmentSlot::MainHand))
+        .filter(|id| accepts(*id));
+
+    let weapon_id = main_hand.or_else(|| {
+        q_inventory
+            .get(context.player_entity)
+            .ok()
+            .and_then(|inventory| inventory.item_ids.iter().copied().find(|id| accepts(*id)))
+    });
+
+    let Some(weapon_id) = weapon_id else {
+        cmds.send_event(GameLogEvent {
+            message: LogMessage::Custom(format!(
+                "No weapon to fit the {{Y|{}}} to",
+                attachment.attachment_type.display_name()
+            )),
+            tick: clock.get_tick(),
+            knowledge: KnowledgeLevel::Player,
+        });
+        return;
+    };
+
+    cmds.queue(InstallAttachmentAction {
+        entity: context.player_entity,
+        attachment_id: action_dialog.item_id,
+        weapon_id,
+    });
+}
+
+fn strip_selected_weapon_attachments_from_dialog(
+    mut cmds: Commands,
+    context: Res<InventoryContext>,
+    q_action_dialog: Query<&ItemActionDialog>,
+) {
+    if let Ok(action_dialog) = q_action_dialog.single() {
+        cmds.queue(RemoveAttachmentAction {
+            entity: context.player_entity,
+            weapon_id: action_dialog.item_id,
+            slot: None,
+        });
+    }
+}
+
 fn examine_item(world: &mut World) {
     let action_dialog = {
         let mut q_action_dialog = world.query::<&ItemActionDialog>();
@@ -906,7 +1085,11 @@ fn update_item_detail_panel(
     q_items: Query<&Item>,
     q_equipped: Query<&Equipped>,
     q_stack_counts: Query<&StackCount>,
-    q_weapons: Query<&Weapon>,
+    (q_weapons, q_attachments, q_attachment): (
+        Query<&Weapon>,
+        Query<&WeaponAttachments>,
+        Query<&WeaponAttachment>,
+    ),
     q_rarities: Query<&ItemRarity>,
     q_stat_modifiers: Query<&StatModifiers>,
     q_existing_stat_lines: Query<
@@ -978,6 +1161,10 @@ fn update_item_detail_panel(
 
     if let Ok(item) = q_items.get(item_entity) {
         stats.push(format!("- Weight: {:.1} kg", item.weight));
+        stats.push(format!(
+            "- Value: {} gold",
+            unit_value(Some(item), q_rarities.get(item_entity).ok())
+        ));
     }
 
     if let Ok(stack) = q_stack_counts.get(item_entity) {
@@ -1061,6 +1248,46 @@ fn update_item_detail_panel(
             properties.push(format!("- Clip size: {}", clip_size));
         }
 
+        if let Some(spread) = weapon.spread_degrees {
+            properties.push(format!("- Spread: {:.0}°", spread));
+        }
+
+        if let Ok(attachments) = q_attachments.get(item_entity) {
+            let installed = attachments
+                .installed()
+                .iter()
+                .map(|a| a.display_name())
+                .collect::<Vec<_>>();
+
+            if installed.is_empty() {
+                let slots = attachments
+                    .available_slots()
+                    .iter()
+                    .map(|slot| slot.display_name())
+                    .collect::<Vec<_>>();
+                properties.push(format!("- Slots: {}", slots.join(", ")));
+            } else {
+                properties.push(format!("- Attachments: {}", installed.join(", ")));
+            }
+        }
+
+        // Spawn Properties lines
+        for property in properties {
+            cmds.spawn((
+                Text::new(&property).fg1(Palette::White).layer(Layer::Ui),
+                Position::new_f32(stat_x, current_y, 0.),
+                CleanupStateInventory,
+                ItemDetailPropertyLine,
+            ));
+            current_y += 0.5;
+        }
+    } else if let Ok(attachment) = q_attachment.get(item_entity) {
+        let attachment_type = attachment.attachment_type;
+        let properties = [
+            format!("- Slot: {}", attachment_type.slot().display_name()),
+            format!("- Effect: {}", attachment_type.effect_summary()),
+        ];
+
         // Spawn Properties lines
         for property in properties {
             cmds.spawn((
@@ -1123,6 +1350,10 @@ fn update_item_detail_panel(
         // Weight
         if let Ok(item) = q_items.get(item_entity) {
             props.push(format!("- Weight: {:.1} kg", item.weight));
+            props.push(format!(
+                "- Value: {} gold",
+                unit_value(Some(item), q_rarities.get(item_entity).ok())
+            ));
         }
 
         // Stack count
@@ -1453,14 +1684,7 @@ fn refresh_action_dialog_with_timer(
         let new_items = build_item_action_list(
             action_dialog.item_id,
             &id_registry,
-            &item_queries.equippable,
-            &item_queries.equipped,
-            &item_queries.lightable,
-            &item_queries.light_source,
-            &item_queries.consumable,
-            &item_queries.throwable,
-            &item_queries.explosive,
-            &item_queries.fuse,
+            &item_queries,
             &callbacks,
         );
 
@@ -0,0 +1,188 @@
+use bevy_ecs::{prelude::*, system::SystemId};
+use macroquad::input::KeyCode;
+
+use crate::{
+    common::Palette,
+    domain::{Player, PlayerPosition, QuestLog, QuestStatus, zone_distance},
+    engine::{AudioKey, Plugin},
+    rendering::{Position, ScreenSize, Text, world_to_zone_idx, zone_xyz},
+    states::{CurrentGameState, GameStatePlugin, cleanup_system},
+    ui::{Button, FullScreenBackground, setup_fullscreen_backgrounds},
+};
+
+use super::GameState;
+
+#[derive(Resource)]
+struct JournalCallbacks {
+    back_to_explore: SystemId,
+}
+
+pub struct JournalStatePlugin;
+
+impl Plugin for JournalStatePlugin {
+    fn build(&self, app: &mut crate::engine::App) {
+        GameStatePlugin::new(GameState::Journal)
+            .on_enter(
+                app,
+                (
+                    setup_callbacks,
+                    on_enter_journal,
+                    render_quest_log,
+                    setup_fullscreen_backgrounds,
+                )
+                    .chain(),
+            )
+            .on_update(
+                app,
+                setup_fullscreen_backgrounds.run_if(resource_changed::<ScreenSize>),
+            )
+            .on_leave(
+                app,
+                (
+                    cleanup_system::<CleanupStateJournal>,
+                    cleanup_system::<FullScreenBackground>,
+                    remove_journal_callbacks,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[derive(Component)]
+pub struct CleanupStateJournal;
+
+fn setup_callbacks(world: &mut World) {
+    let callbacks = JournalCallbacks {
+        back_to_explore: world.register_system(back_to_explore),
+    };
+
+    world.insert_resource(callbacks);
+}
+
+fn back_to_explore(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Explore;
+}
+
+fn remove_journal_callbacks(mut cmds: Commands) {
+    cmds.remove_resource::<JournalCallbacks>();
+}
+
+fn on_enter_journal(mut cmds: Commands, callbacks: Res<JournalCallbacks>) {
+    cmds.spawn((
+        Text::new("{Y|JOURNAL}").bg(Palette::Black),
+        Position::new_f32(2., 1., 0.),
+        CleanupStateJournal,
+    ));
+
+    cmds.spawn((
+        Position::new_f32(2., 1.5, 0.),
+        Button::new("({Y|J}) BACK TO EXPLORE", callbacks.back_to_explore)
+            .hotkey(KeyCode::J)
+            .with_audio(AudioKey::ButtonBack1),
+        CleanupStateJournal,
+    ));
+}
+
+/// Roughly which way and how far a zone is, like "3 zones NE"
+fn direction_text(from: usize, to: usize) -> String {
+    let (fx, fy, fz) = zone_xyz(from);
+    let (tx, ty, tz) = zone_xyz(to);
+
+    let vertical = match tz.cmp(&fz) {
+        std::cmp::Ordering::Greater => ", below",
+        std::cmp::Ordering::Less => ", above",
+        std::cmp::Ordering::Equal => "",
+    };
+
+    if fx == tx && fy == ty {
+        return if vertical.is_empty() {
+            "here".to_string()
+        } else {
+            vertical.trim_start_matches(", ").to_string()
+        };
+    }
+
+    let north_south = match ty.cmp(&fy) {
+        std::cmp::Ordering::Less => "N",
+        std::cmp::Ordering::Greater => "S",
+        std::cmp::Ordering::Equal => "",
+    };
+    let east_west = match tx.cmp(&fx) {
+        std::cmp::Ordering::Greater => "E",
+        std::cmp::Ordering::Less => "W",
+        std::cmp::Ordering::Equal => "",
+    };
+
+    let distance = zone_distance(from, to);
+    let zones = if distance == 1 { "zone" } else { "zones" };
+
+    format!(
+        "{} {} {}{}{}",
+        distance, zones, north_south, east_west, vertical
+    )
+}
+
+/// Quests still in hand come first, then everything finished or failed, newest first
+fn render_quest_log(
+    mut cmds: Commands,
+    q_log: Query<&QuestLog, With<Player>>,
+    player_pos: Res<PlayerPosition>,
+) {
+    let start_x = 2.0;
+    let mut y = 3.0;
+
+    let quests = q_log
+        .single()
+        .map(|log| log.quests.iter().rev().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    if quests.is_empty() {
+        cmds.spawn((
+            Text::new("You haven't taken on any work yet. Folks in town might have some.")
+                .bg(Palette::Black),
+            Position::new_f32(start_x, y, 0.),
+            CleanupStateJournal,
+        ));
+        return;
+    }
+
+    let player_world = player_pos.world();
+    let player_zone = world_to_zone_idx(player_world.0, player_world.1, player_world.2);
+
+    let (in_progress, finished): (Vec<_>, Vec<_>) =
+        quests.into_iter().partition(|q| q.is_in_progress());
+
+    for quest in in_progress.into_iter().chain(finished) {
+        let (marker, detail) = match quest.status {
+            QuestStatus::Active => (
+                "{Y|*}",
+                format!(
+                    "{} ({})",
+                    quest.objective.describe(),
+                    direction_text(player_zone, quest.objective.zone_idx())
+                ),
+            ),
+            QuestStatus::ReadyToTurnIn => ("{G|!}", format!("Report back to {}", quest.giver)),
+            QuestStatus::Completed => ("{G|+}", "Completed".to_string()),
+            QuestStatus::Failed => ("{R|x}", "Failed".to_string()),
+        };
+
+        cmds.spawn((
+            Text::new(&format!(
+                "{} {} {{w|- {} gold}}",
+                marker, quest.title, quest.reward
+            ))
+            .bg(Palette::Black),
+            Position::new_f32(start_x, y, 0.),
+            CleanupStateJournal,
+        ));
+
+        cmds.spawn((
+            Text::new(&format!("  {}", detail)).bg(Palette::Black),
+            Position::new_f32(start_x, y + 0.5, 0.),
+            CleanupStateJournal,
+        ));
+
+        y += 1.5;
+    }
+}
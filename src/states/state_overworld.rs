@@ -127,6 +127,7 @@ fn render_overworld_map(
                 BiomeType::DustyPlains => (1, Palette::DarkYellow),
                 BiomeType::Cavern => (129, Palette::Gray),
                 BiomeType::MushroomForest => (78, Palette::Purple),
+                BiomeType::FloodedMine => (23, Palette::Blue),
                 BiomeType::Mountain => (30, Palette::White),
                 BiomeType::Swamp => (48, Palette::DarkPurple),
             };
@@ -134,6 +135,10 @@ fn render_overworld_map(
             let is_player_zone = x == player_zone_pos.0 && y == player_zone_pos.1;
 
             let has_town = ozone.town.is_some();
+            let discovered_poi = ozone
+                .poi
+                .as_ref()
+                .filter(|_| overworld.discovered_pois.contains(&idx));
             let has_road = overworld.zone_has_road(idx);
             let has_river = overworld.zone_has_river(idx);
 
@@ -152,6 +157,22 @@ fn render_overworld_map(
                         Palette::Black
                     },
                 )
+            } else if let Some(poi) = discovered_poi {
+                let (poi_glyph, poi_fg1) = poi.poi_type.map_glyph();
+                (
+                    poi_glyph,
+                    poi_fg1,
+                    poi_fg1,
+                    if is_player_zone {
+                        Palette::Red
+                    } else if has_river {
+                        Palette::Blue
+                    } else if has_road {
+                        Palette::Brown
+                    } else {
+                        Palette::Black
+                    },
+                )
             } else if is_player_zone {
                 (zone_glyph, zone_fg1, zone_fg1, Palette::Red)
             } else if has_river {
@@ -216,13 +237,19 @@ fn display_overworld_debug_at_mouse(
         "No town".to_string()
     };
 
+    let poi_value = match &ozone.poi {
+        Some(poi) if overworld.discovered_pois.contains(&zone_idx) => poi.name.clone(),
+        _ => "None".to_string(),
+    };
+
     let debug_info = format!(
-        "Zone {{C|({}, {})}}\nIndex: {{C|{}}}\nType: {{C|{}}}\nTown: {{C|{}}}\nRoad: {{C|{}}}\nRiver: {{C|{}}}\nConnections: {{C|{}}}",
+        "Zone {{C|({}, {})}}\nIndex: {{C|{}}}\nType: {{C|{}}}\nTown: {{C|{}}}\nPOI: {{C|{}}}\nRoad: {{C|{}}}\nRiver: {{C|{}}}\nConnections: {{C|{}}}",
         zone_x,
         zone_y,
         zone_idx,
         ozone.biome_type,
         town_value,
+        poi_value,
         if has_road { "Yes" } else { "No" },
         if has_river { "Yes" } else { "No" },
         road_connections
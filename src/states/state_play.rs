@@ -5,10 +5,10 @@ use serde::{Deserialize, Serialize};
 use crate::{
     domain::{
         activate_zones_by_player, auto_assign_stable_ids, cleanup_despawned_stable_ids,
-        load_nearby_zones, manage_zone_cache, on_load_zone, on_set_zone_status, on_unload_zone,
-        register_game_systems, register_new_stable_ids,
+        discover_pois_by_player, load_nearby_zones, manage_zone_cache, on_load_zone,
+        on_set_zone_status, on_unload_zone, register_game_systems, register_new_stable_ids,
     },
-    engine::{App, Plugin, SerializableComponent},
+    engine::{App, Audio, Plugin, SerializableComponent},
     rendering::{ScreenSize, on_zone_status_change, update_camera},
     states::{
         AppState, AppStatePlugin, CurrentGameState, GameState, cleanup_system, in_game_state,
@@ -37,6 +37,7 @@ impl Plugin for PlayStatePlugin {
                     (
                         // Zone management systems must run first
                         activate_zones_by_player,
+                        discover_pois_by_player,
                         load_nearby_zones,
                         on_load_zone,
                         on_unload_zone,
@@ -70,6 +71,11 @@ fn on_enter_play_state(mut game_state: ResMut<CurrentGameState>) {
     game_state.next = GameState::Explore;
 }
 
-fn on_leave_play_state(mut game_state: ResMut<CurrentGameState>) {
+fn on_leave_play_state(mut game_state: ResMut<CurrentGameState>, audio: Option<ResMut<Audio>>) {
     game_state.next = GameState::None;
+
+    // Weather ambience picks back up once the next game works out its sky
+    if let Some(mut audio) = audio {
+        audio.set_ambient(None, 0.0);
+    }
 }
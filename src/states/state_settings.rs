@@ -20,6 +20,7 @@ struct SettingsCallbacks {
     toggle_camera_mode: SystemId,
     toggle_smooth_movement: SystemId,
     toggle_saves: SystemId,
+    toggle_survival_needs: SystemId,
     back_to_menu: SystemId,
 }
 
@@ -37,6 +38,7 @@ struct SettingsUIEntities {
     save_name: Entity,
     input_rate: Entity,
     input_delay: Entity,
+    survival_needs: Entity,
 }
 
 pub struct SettingsStatePlugin;
@@ -71,6 +73,7 @@ fn setup_callbacks(world: &mut World) {
         toggle_camera_mode: world.register_system(toggle_camera_mode),
         toggle_smooth_movement: world.register_system(toggle_smooth_movement),
         toggle_saves: world.register_system(toggle_saves),
+        toggle_survival_needs: world.register_system(toggle_survival_needs),
         back_to_menu: world.register_system(back_to_menu),
     };
 
@@ -119,6 +122,10 @@ fn toggle_smooth_movement(mut settings: ResMut<GameSettings>) {
     settings.smooth_movement = !settings.smooth_movement;
 }
 
+fn toggle_survival_needs(mut settings: ResMut<GameSettings>) {
+    settings.survival_needs = !settings.survival_needs;
+}
+
 fn back_to_menu(mut app_state: ResMut<CurrentAppState>) {
     app_state.next = AppState::MainMenu;
 }
@@ -283,9 +290,27 @@ fn render_settings_menu(mut cmds: Commands, callbacks: Res<SettingsCallbacks>) {
         ))
         .id();
 
-    // Controls
+    // Difficulty Section
     cmds.spawn((
+        Text::new("{Y|DIFFICULTY}"),
         Position::new_f32(4., 15., 0.),
+        CleanupSettings,
+    ));
+
+    let survival_needs = cmds
+        .spawn((
+            Position::new_f32(6., 15.5, 0.),
+            ActivatableBuilder::new("", callbacks.toggle_survival_needs)
+                .with_hotkey(KeyCode::H)
+                .with_focus_order(4000)
+                .as_button(Layer::Ui),
+            CleanupSettings,
+        ))
+        .id();
+
+    // Controls
+    cmds.spawn((
+        Position::new_f32(4., 17., 0.),
         ActivatableBuilder::new("({R|ESC}) BACK TO MAIN MENU", callbacks.back_to_menu)
             .with_hotkey(KeyCode::Escape)
             .with_audio(AudioKey::ButtonBack1)
@@ -308,6 +333,7 @@ fn render_settings_menu(mut cmds: Commands, callbacks: Res<SettingsCallbacks>) {
         save_name,
         input_rate,
         input_delay,
+        survival_needs,
     });
 }
 
@@ -427,6 +453,18 @@ fn update_settings_display(
         ));
     }
 
+    // Update survival needs
+    if let Ok(mut button) = q_button.get_mut(ui_entities.survival_needs) {
+        button.set_label(format!(
+            "({{Y|H}}) Hunger, Thirst & Fatigue: {}",
+            if settings.survival_needs {
+                "{G|ON}"
+            } else {
+                "{R|OFF}"
+            }
+        ));
+    }
+
     if let Ok(mut text) = q_text.get_mut(ui_entities.save_name) {
         text.value = format!("({{Y|0}}) Save Name: {{G|{}}}", settings.save_name);
     }
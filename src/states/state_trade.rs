@@ -0,0 +1,447 @@
+use bevy_ecs::{prelude::*, schedule::common_conditions::resource_changed, system::SystemId};
+use macroquad::input::KeyCode;
+
+use crate::{
+    common::Palette,
+    domain::{
+        Inventory, Item, ItemRarity, Label, Player, StackCount, Stackable, StackableType,
+        TradeDirection, TradeItemAction, game_loop, haggle, inventory::InventoryChangedEvent,
+        unit_value,
+    },
+    engine::{App, AudioKey, KeyInput, Plugin, StableId, StableIdRegistry},
+    rendering::{Layer, Position, ScreenSize, Text},
+    states::{CurrentGameState, GameState, GameStatePlugin, cleanup_system},
+    ui::{
+        ActivatableBuilder, Dialog, DialogState, List, ListContext, ListItem, ListItemData,
+        UiFocus, center_dialogs_on_screen_change, spawn_examine_dialog,
+    },
+};
+
+#[derive(Resource)]
+pub struct TradeCallbacks {
+    pub examine_item: SystemId,
+    pub close_dialog: SystemId,
+    pub back_to_explore: SystemId,
+    pub trade_focused_item: SystemId,
+}
+
+#[derive(Component, Clone)]
+pub struct CleanupStateTrade;
+
+#[derive(Component)]
+pub struct PlayerWaresList;
+
+#[derive(Component)]
+pub struct MerchantWaresList;
+
+#[derive(Component)]
+pub struct PlayerGoldText;
+
+#[derive(Component)]
+pub struct MerchantGoldText;
+
+#[derive(Resource)]
+pub struct TradeContext {
+    pub player_entity: Entity,
+    pub merchant_entity: Entity,
+    /// How the merchant feels about the player, which sets their prices
+    pub standing: i8,
+}
+
+/// Components needed to put a price on an item
+type PricingQueries<'w, 's> = (
+    Query<'w, 's, &'static Label>,
+    Query<'w, 's, &'static Item>,
+    Query<'w, 's, &'static ItemRarity>,
+    Query<'w, 's, &'static Stackable>,
+    Query<'w, 's, &'static StackCount>,
+);
+
+pub struct TradeStatePlugin;
+
+impl TradeStatePlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Plugin for TradeStatePlugin {
+    fn build(&self, app: &mut App) {
+        GameStatePlugin::new(GameState::Trade)
+            .on_enter(app, (setup_trade_callbacks, setup_trade_screen).chain())
+            .on_update(
+                app,
+                (handle_trade_input, refresh_trade_display, game_loop).chain(),
+            )
+            .on_update(
+                app,
+                center_dialogs_on_screen_change.run_if(resource_changed::<ScreenSize>),
+            )
+            .on_leave(
+                app,
+                (cleanup_system::<CleanupStateTrade>, remove_trade_callbacks).chain(),
+            );
+    }
+}
+
+fn setup_trade_callbacks(world: &mut World) {
+    let callbacks = TradeCallbacks {
+        examine_item: world.register_system(examine_selected_item),
+        close_dialog: world.register_system(close_dialog),
+        back_to_explore: world.register_system(back_to_explore),
+        trade_focused_item: world.register_system(trade_focused_item),
+    };
+    world.insert_resource(callbacks);
+}
+
+fn remove_trade_callbacks(mut cmds: Commands) {
+    cmds.remove_resource::<TradeCallbacks>();
+    cmds.remove_resource::<TradeContext>();
+}
+
+fn back_to_explore(mut game_state: ResMut<CurrentGameState>) {
+    game_state.next = GameState::Explore;
+}
+
+/// Buys or sells whichever item has focus, depending on which side of the counter it's on
+fn trade_focused_item(
+    mut cmds: Commands,
+    ui_focus: Res<UiFocus>,
+    list_context: Res<ListContext>,
+    context: Res<TradeContext>,
+    q_player_lists: Query<Entity, With<PlayerWaresList>>,
+    q_merchant_lists: Query<Entity, With<MerchantWaresList>>,
+    q_list_items: Query<&ListItem>,
+) {
+    let Some(focused_list_item) = ui_focus
+        .focused_element
+        .and_then(|e| q_list_items.get(e).ok())
+    else {
+        return;
+    };
+
+    let Some(item_id) = list_context.context_data else {
+        return;
+    };
+
+    let direction = if q_player_lists.contains(focused_list_item.parent_list) {
+        TradeDirection::Sell
+    } else if q_merchant_lists.contains(focused_list_item.parent_list) {
+        TradeDirection::Buy
+    } else {
+        return;
+    };
+
+    cmds.queue(TradeItemAction {
+        customer_entity: context.player_entity,
+        merchant_entity: context.merchant_entity,
+        item_stable_id: StableId(item_id),
+        direction,
+    });
+}
+
+fn count_gold(
+    inventory: &Inventory,
+    id_registry: &StableIdRegistry,
+    pricing: &PricingQueries,
+) -> u32 {
+    let (_, _, _, q_stackables, q_stack_counts) = pricing;
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| id_registry.get_entity(StableId(id)))
+        .filter(|&e| {
+            q_stackables
+                .get(e)
+                .is_ok_and(|s| s.stack_type == StackableType::GoldNugget)
+        })
+        .filter_map(|e| q_stack_counts.get(e).ok())
+        .map(|s| s.count)
+        .sum()
+}
+
+fn build_wares_list_items(
+    inventory: &Inventory,
+    standing: i8,
+    direction: TradeDirection,
+    id_registry: &StableIdRegistry,
+    pricing: &PricingQueries,
+    callbacks: &TradeCallbacks,
+) -> Vec<ListItemData> {
+    let (q_labels, q_items, q_rarities, q_stackables, _) = pricing;
+
+    inventory
+        .item_ids
+        .iter()
+        .filter_map(|&id| id_registry.get_entity(StableId(id)).map(|e| (id, e)))
+        .filter(|&(_, e)| {
+            // Gold is the currency, it doesn't go on the counter
+            !q_stackables
+                .get(e)
+                .is_ok_and(|s| s.stack_type == StackableType::GoldNugget)
+        })
+        .map(|(id, e)| {
+            let label = q_labels.get(e).map(|l| l.get()).unwrap_or("Unknown");
+            let value = unit_value(q_items.get(e).ok(), q_rarities.get(e).ok());
+            let price = haggle(value, standing, direction);
+
+            let price_text = if price == 0 {
+                "{w|-}".to_string()
+            } else {
+                format!("{{Y|{}g}}", price)
+            };
+
+            ListItemData::new(&format!("{} {}", label, price_text), callbacks.examine_item)
+                .with_hotkey(KeyCode::X)
+                .with_context(id)
+        })
+        .collect()
+}
+
+fn examine_selected_item(world: &mut World) {
+    let list_context = world.get_resource::<ListContext>().unwrap();
+    let Some(item_id) = list_context.context_data else {
+        return;
+    };
+
+    let id_registry = world.get_resource::<StableIdRegistry>().unwrap();
+    let Some(item_entity) = id_registry.get_entity(StableId(item_id)) else {
+        return;
+    };
+
+    let close_dialog_id = {
+        let callbacks = world.get_resource::<TradeCallbacks>().unwrap();
+        callbacks.close_dialog
+    };
+
+    let player_entity = {
+        let mut q_player = world.query_filtered::<Entity, With<Player>>();
+        q_player.single(world).unwrap()
+    };
+
+    spawn_examine_dialog(world, item_entity, player_entity, close_dialog_id);
+
+    if let Some(mut dialog_state) = world.get_resource_mut::<DialogState>() {
+        dialog_state.is_open = true;
+    }
+}
+
+fn close_dialog(
+    mut cmds: Commands,
+    q_dialogs: Query<Entity, With<Dialog>>,
+    mut dialog_state: ResMut<DialogState>,
+) {
+    for dialog_entity in q_dialogs.iter() {
+        cmds.entity(dialog_entity).despawn();
+    }
+    dialog_state.is_open = false;
+}
+
+fn gold_text(gold: u32) -> String {
+    format!("Gold: {{Y|{}}}", gold)
+}
+
+fn setup_trade_screen(
+    mut cmds: Commands,
+    callbacks: Res<TradeCallbacks>,
+    q_inventory: Query<&Inventory>,
+    pricing: PricingQueries,
+    id_registry: Res<StableIdRegistry>,
+    context: Option<Res<TradeContext>>,
+) {
+    let Some(context) = context else {
+        return;
+    };
+
+    let Ok(player_inventory) = q_inventory.get(context.player_entity) else {
+        return;
+    };
+
+    let Ok(merchant_inventory) = q_inventory.get(context.merchant_entity) else {
+        return;
+    };
+
+    let left_x = 2.0;
+    let right_x = 21.0;
+
+    cmds.spawn((
+        Text::new("YOUR GOODS")
+            .fg1(Palette::Yellow)
+            .bg(Palette::Black)
+            .layer(Layer::Ui),
+        Position::new_f32(left_x, 1., 0.),
+        CleanupStateTrade,
+    ));
+
+    cmds.spawn((
+        Text::new(&gold_text(count_gold(
+            player_inventory,
+            &id_registry,
+            &pricing,
+        )))
+        .fg1(Palette::White)
+        .layer(Layer::Ui),
+        Position::new_f32(left_x, 2., 0.),
+        CleanupStateTrade,
+        PlayerGoldText,
+    ));
+
+    let player_list_items = build_wares_list_items(
+        player_inventory,
+        context.standing,
+        TradeDirection::Sell,
+        &id_registry,
+        &pricing,
+        &callbacks,
+    );
+
+    cmds.spawn((
+        List::new(player_list_items).with_focus_order(1000),
+        Position::new_f32(left_x, 3.5, 0.),
+        CleanupStateTrade,
+        PlayerWaresList,
+    ));
+
+    let merchant_label = pricing
+        .0
+        .get(context.merchant_entity)
+        .map(|l| l.get())
+        .unwrap_or("Merchant");
+
+    cmds.spawn((
+        Text::new(merchant_label)
+            .fg1(Palette::Yellow)
+            .bg(Palette::Black)
+            .layer(Layer::Ui),
+        Position::new_f32(right_x, 1., 0.),
+        CleanupStateTrade,
+    ));
+
+    cmds.spawn((
+        Text::new(&gold_text(count_gold(
+            merchant_inventory,
+            &id_registry,
+            &pricing,
+        )))
+        .fg1(Palette::White)
+        .layer(Layer::Ui),
+        Position::new_f32(right_x, 2., 0.),
+        CleanupStateTrade,
+        MerchantGoldText,
+    ));
+
+    let merchant_list_items = build_wares_list_items(
+        merchant_inventory,
+        context.standing,
+        TradeDirection::Buy,
+        &id_registry,
+        &pricing,
+        &callbacks,
+    );
+
+    cmds.spawn((
+        List::new(merchant_list_items).with_focus_order(2000),
+        Position::new_f32(right_x, 3.5, 0.),
+        CleanupStateTrade,
+        MerchantWaresList,
+    ));
+
+    let help_y = 12.0;
+
+    cmds.spawn((
+        Position::new_f32(left_x, help_y, 0.),
+        ActivatableBuilder::new("({Y|I}) BACK", callbacks.back_to_explore)
+            .with_hotkey(KeyCode::I)
+            .with_hotkey(KeyCode::Escape)
+            .with_audio(AudioKey::ButtonBack1)
+            .with_focus_order(3000)
+            .as_button(Layer::Ui),
+        CleanupStateTrade,
+    ));
+
+    cmds.spawn((
+        Text::new("  [{Y|TAB}] Switch Side   [{Y|X}] Examine   [{Y|T}] Buy/Sell")
+            .fg1(Palette::White)
+            .layer(Layer::Ui),
+        Position::new_f32(left_x + 8.0, help_y, 0.),
+        CleanupStateTrade,
+    ));
+}
+
+fn refresh_trade_display(
+    q_inventory: Query<&Inventory>,
+    pricing: PricingQueries,
+    mut q_lists: ParamSet<(
+        Query<&mut List, With<PlayerWaresList>>,
+        Query<&mut List, With<MerchantWaresList>>,
+    )>,
+    mut q_gold_texts: ParamSet<(
+        Query<&mut Text, With<PlayerGoldText>>,
+        Query<&mut Text, With<MerchantGoldText>>,
+    )>,
+    context: Res<TradeContext>,
+    id_registry: Res<StableIdRegistry>,
+    callbacks: Res<TradeCallbacks>,
+    mut e_inventory_changed: EventReader<InventoryChangedEvent>,
+) {
+    if e_inventory_changed.is_empty() {
+        return;
+    }
+    e_inventory_changed.clear();
+
+    let Ok(player_inventory) = q_inventory.get(context.player_entity) else {
+        return;
+    };
+
+    let Ok(merchant_inventory) = q_inventory.get(context.merchant_entity) else {
+        return;
+    };
+
+    if let Ok(mut player_list) = q_lists.p0().single_mut() {
+        player_list.items = build_wares_list_items(
+            player_inventory,
+            context.standing,
+            TradeDirection::Sell,
+            &id_registry,
+            &pricing,
+            &callbacks,
+        );
+    }
+
+    if let Ok(mut merchant_list) = q_lists.p1().single_mut() {
+        merchant_list.items = build_wares_list_items(
+            merchant_inventory,
+            context.standing,
+            TradeDirection::Buy,
+            &id_registry,
+            &pricing,
+            &callbacks,
+        );
+    }
+
+    if let Ok(mut text) = q_gold_texts.p0().single_mut() {
+        text.value = gold_text(count_gold(player_inventory, &id_registry, &pricing));
+    }
+
+    if let Ok(mut text) = q_gold_texts.p1().single_mut() {
+        text.value = gold_text(count_gold(merchant_inventory, &id_registry, &pricing));
+    }
+}
+
+fn handle_trade_input(
+    keys: Res<KeyInput>,
+    mut game_state: ResMut<CurrentGameState>,
+    callbacks: Res<TradeCallbacks>,
+    dialog_state: Res<DialogState>,
+    mut commands: Commands,
+) {
+    if keys.is_pressed(KeyCode::I) {
+        game_state.next = GameState::Explore;
+        return;
+    }
+
+    if keys.is_pressed(KeyCode::T) && !dialog_state.is_open {
+        commands.run_system(callbacks.trade_focused_item);
+    }
+}
@@ -0,0 +1,627 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::PathBuf,
+};
+
+use macroquad::prelude::{Color, Image};
+
+use super::parse_number;
+use crate::{
+    cfg::{MAP_SIZE, SURFACE_LEVEL_Z, ZONE_SIZE},
+    common::{Grid, Palette},
+    domain::{
+        BiomeType, CrossingType, Overworld, PoiType, PositionalConstraint, RiverType,
+        ZoneConstraintType, get_vertical_continuity,
+    },
+    rendering::{zone_idx, zone_xyz},
+};
+
+const USAGE: &str = "\
+usage: quadboy inspect-world [options]
+
+Builds the overworld for one or more seeds without opening a window, prints
+summary statistics for each z-layer and dumps the layers as maps.
+
+options:
+  --seed <n>       seed to build, repeat to compare several (default 1)
+  --z <n>          z-layer to inspect, repeatable (default every layer from the surface down)
+  --format <fmt>   ascii, ansi, png or none (default ascii)
+  --out <dir>      directory png maps are written to (default .)
+  --scale <px>     pixels per zone in png maps (default 8)";
+
+const LEGEND: &str = "\
+legend: # town  ! point of interest  H bridge  F ferry  = ford  ~ river  + road
+        f forest  d desert  . dusty plains  ^ mountain  s swamp
+        c cavern  m mushroom forest  w flooded mine";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Ascii,
+    Ansi,
+    Png,
+    None,
+}
+
+struct InspectOptions {
+    seeds: Vec<u32>,
+    layers: Vec<usize>,
+    format: ExportFormat,
+    out_dir: PathBuf,
+    scale: usize,
+}
+
+impl InspectOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self {
+            seeds: vec![],
+            layers: vec![],
+            format: ExportFormat::Ascii,
+            out_dir: PathBuf::from("."),
+            scale: 8,
+        };
+
+        let mut args = args.iter();
+
+        while let Some(flag) = args.next() {
+            if !matches!(
+                flag.as_str(),
+                "--seed" | "--z" | "--format" | "--out" | "--scale"
+            ) {
+                return Err(format!("unknown option '{}'", flag));
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", flag))?;
+
+            match flag.as_str() {
+                "--seed" => options.seeds.push(parse_number(flag, value)?),
+                "--z" => {
+                    let z = parse_number(flag, value)?;
+
+                    if z >= MAP_SIZE.2 {
+                        return Err(format!("--z must be below {}", MAP_SIZE.2));
+                    }
+
+                    options.layers.push(z);
+                }
+                "--format" => {
+                    options.format = match value.as_str() {
+                        "ascii" => ExportFormat::Ascii,
+                        "ansi" => ExportFormat::Ansi,
+                        "png" => ExportFormat::Png,
+                        "none" => ExportFormat::None,
+                        other => return Err(format!("unknown format '{}'", other)),
+                    }
+                }
+                "--out" => options.out_dir = PathBuf::from(value),
+                "--scale" => {
+                    options.scale = parse_number(flag, value)?;
+
+                    if options.scale == 0 || options.scale * MAP_SIZE.0 > u16::MAX as usize {
+                        return Err("--scale is out of range".to_string());
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if options.seeds.is_empty() {
+            options.seeds.push(1);
+        }
+
+        if options.layers.is_empty() {
+            options.layers = (SURFACE_LEVEL_Z..MAP_SIZE.2).collect();
+        }
+
+        Ok(options)
+    }
+}
+
+/// Entry point for `quadboy inspect-world`
+pub fn inspect_world(args: &[String]) -> i32 {
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return 0;
+    }
+
+    let options = match InspectOptions::parse(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return 2;
+        }
+    };
+
+    if options.format == ExportFormat::Png
+        && let Err(err) = std::fs::create_dir_all(&options.out_dir)
+    {
+        eprintln!("could not create {}: {}", options.out_dir.display(), err);
+        return 1;
+    }
+
+    for &seed in &options.seeds {
+        let overworld = Overworld::new(seed);
+        let layers = options
+            .layers
+            .iter()
+            .map(|&z| LayerMap::build(&overworld, z))
+            .collect::<Vec<_>>();
+
+        let mut checksum = DefaultHasher::new();
+
+        println!("seed {}", seed);
+
+        for layer in &layers {
+            let stats = layer.stats();
+            layer.fingerprint().hash(&mut checksum);
+
+            println!("{}", stats.describe(layer.z));
+        }
+
+        println!("  checksum {:016x}\n", checksum.finish());
+
+        for layer in &layers {
+            match options.format {
+                ExportFormat::Ascii | ExportFormat::Ansi => {
+                    println!("seed {} z{}", seed, layer.z);
+                    println!("{}", layer.to_text(options.format == ExportFormat::Ansi));
+                }
+                ExportFormat::Png => {
+                    let path = options
+                        .out_dir
+                        .join(format!("seed-{}-z{}.png", seed, layer.z));
+
+                    layer
+                        .to_image(options.scale)
+                        .export_png(&path.to_string_lossy());
+                    println!("wrote {}", path.display());
+                }
+                ExportFormat::None => {}
+            }
+        }
+    }
+
+    if matches!(options.format, ExportFormat::Ascii | ExportFormat::Ansi) {
+        println!("{}", LEGEND);
+    }
+
+    0
+}
+
+/// Everything the inspector shows about a single zone
+#[derive(Clone, Default)]
+struct ZoneCell {
+    biome: Option<BiomeType>,
+    river: Option<RiverType>,
+    road: bool,
+    crossing: Option<CrossingType>,
+    town: Option<String>,
+    poi: Option<PoiType>,
+    stairs_down: Vec<(usize, usize)>,
+    stairs_up: Vec<(usize, usize)>,
+    shafts: Vec<(usize, usize)>,
+}
+
+struct LayerMap {
+    z: usize,
+    cells: Grid<ZoneCell>,
+    road_edges: Vec<(usize, usize)>,
+    river_edges: Vec<(usize, usize, RiverType)>,
+}
+
+impl LayerMap {
+    fn build(overworld: &Overworld, z: usize) -> Self {
+        let cells = Grid::init_fill(MAP_SIZE.0, MAP_SIZE.1, |x, y| {
+            let idx = zone_idx(x, y, z);
+            let below = get_vertical_continuity(overworld, idx);
+            let above = (z > 0)
+                .then(|| get_vertical_continuity(overworld, zone_idx(x, y, z - 1)))
+                .map(|constraints| constraints.0)
+                .unwrap_or_default();
+
+            let positions_of = |constraints: &[PositionalConstraint], kind: ZoneConstraintType| {
+                constraints
+                    .iter()
+                    .filter(|constraint| constraint.constraint == kind)
+                    .map(|constraint| constraint.position)
+                    .collect::<Vec<_>>()
+            };
+
+            ZoneCell {
+                biome: Some(overworld.get_zone_type(idx)),
+                river: overworld
+                    .river_networks
+                    .get(&z)
+                    .and_then(|network| network.river_type_at(idx)),
+                road: overworld.zone_has_road(idx),
+                crossing: overworld.get_crossing(idx).map(|c| c.crossing_type),
+                town: overworld
+                    .towns
+                    .get(&z)
+                    .and_then(|towns| towns.get(&idx))
+                    .map(|town| town.name.clone()),
+                poi: overworld.pois.get(&idx).map(|poi| poi.poi_type),
+                stairs_down: positions_of(&below.0, ZoneConstraintType::StairDown),
+                stairs_up: positions_of(&above, ZoneConstraintType::StairDown),
+                shafts: positions_of(&below.0, ZoneConstraintType::Shaft),
+            }
+        });
+
+        let road_edges = overworld
+            .road_networks
+            .get(&z)
+            .map(|network| {
+                // Roads are stored in both directions, keep one of each
+                network
+                    .edges
+                    .keys()
+                    .copied()
+                    .filter(|(from, to)| from < to)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let river_edges = overworld
+            .river_networks
+            .get(&z)
+            .map(|network| {
+                network
+                    .edges
+                    .iter()
+                    .map(|((from, to), segment)| (*from, *to, segment.river_type))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            z,
+            cells,
+            road_edges,
+            river_edges,
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = &ZoneCell> {
+        (0..MAP_SIZE.1)
+            .flat_map(|y| (0..MAP_SIZE.0).map(move |x| (x, y)))
+            .filter_map(|(x, y)| self.cells.get(x, y))
+    }
+
+    fn stats(&self) -> LayerStats {
+        let mut stats = LayerStats::default();
+
+        for cell in self.cells() {
+            if let Some(biome) = cell.biome {
+                *stats.biomes.entry(biome.to_string()).or_default() += 1;
+            }
+
+            if let Some(crossing) = cell.crossing {
+                *stats
+                    .crossings
+                    .entry(format!("{:?}", crossing))
+                    .or_default() += 1;
+            }
+
+            stats.towns += cell.town.is_some() as usize;
+            stats.pois += cell.poi.is_some() as usize;
+            stats.stairs += cell.stairs_down.len();
+            stats.shafts += cell.shafts.len();
+        }
+
+        stats.road_segments = self.road_edges.len();
+        stats.river_segments = self.river_edges.len();
+
+        // Roads run between zone centres, so each segment is roughly one zone across
+        stats.road_length = self
+            .road_edges
+            .iter()
+            .map(|&(from, to)| {
+                let (_, from_y, _) = zone_xyz(from);
+                let (_, to_y, _) = zone_xyz(to);
+
+                if from_y == to_y {
+                    ZONE_SIZE.0
+                } else {
+                    ZONE_SIZE.1
+                }
+            })
+            .sum();
+
+        stats
+    }
+
+    /// Everything about the layer that generation decides, used to check two runs match
+    fn fingerprint(&self) -> String {
+        let mut out = String::new();
+
+        for cell in self.cells() {
+            let _ = write!(
+                out,
+                "{}|{:?}|{}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?};",
+                cell.biome.map(|b| b.to_string()).unwrap_or_default(),
+                cell.river,
+                cell.road,
+                cell.crossing,
+                cell.town,
+                cell.poi,
+                cell.stairs_down,
+                cell.stairs_up,
+                cell.shafts,
+            );
+        }
+
+        let mut edges = self.road_edges.clone();
+        edges.sort();
+        let _ = write!(out, "{:?}", edges);
+
+        out
+    }
+
+    fn to_text(&self, ansi: bool) -> String {
+        let mut out = String::new();
+
+        for y in 0..MAP_SIZE.1 {
+            for x in 0..MAP_SIZE.0 {
+                let Some(cell) = self.cells.get(x, y) else {
+                    continue;
+                };
+
+                let (glyph, fg, bg) = cell_style(cell);
+
+                if ansi {
+                    let (fr, fgreen, fb) = rgb(fg);
+                    let (br, bgreen, bb) = rgb(bg);
+                    let _ = write!(
+                        out,
+                        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m{}",
+                        fr, fgreen, fb, br, bgreen, bb, glyph
+                    );
+                } else {
+                    out.push(glyph);
+                }
+            }
+
+            if ansi {
+                out.push_str("\x1b[0m");
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn to_image(&self, scale: usize) -> Image {
+        let mut canvas = Canvas::new(MAP_SIZE.0 * scale, MAP_SIZE.1 * scale, Palette::Black);
+
+        for x in 0..MAP_SIZE.0 {
+            for y in 0..MAP_SIZE.1 {
+                if let Some(cell) = self.cells.get(x, y) {
+                    let (_, _, bg) = biome_style(cell.biome);
+                    canvas.fill_rect(x * scale, y * scale, scale, scale, bg);
+                }
+            }
+        }
+
+        let center = |idx: usize| {
+            let (x, y, _) = zone_xyz(idx);
+            (x * scale + scale / 2, y * scale + scale / 2)
+        };
+
+        for &(from, to, river_type) in &self.river_edges {
+            let width = match river_type {
+                RiverType::Creek | RiverType::Stream => 1,
+                RiverType::River => 2,
+                RiverType::MightyRiver => 3,
+            };
+
+            canvas.line(center(from), center(to), width, Palette::Blue);
+        }
+
+        for &(from, to) in &self.road_edges {
+            canvas.line(center(from), center(to), 1, Palette::Brown);
+        }
+
+        for x in 0..MAP_SIZE.0 {
+            for y in 0..MAP_SIZE.1 {
+                let Some(cell) = self.cells.get(x, y) else {
+                    continue;
+                };
+
+                let (cx, cy) = (x * scale + scale / 2, y * scale + scale / 2);
+
+                if let Some(crossing) = cell.crossing {
+                    let color = match crossing {
+                        CrossingType::Ford => Palette::Cyan,
+                        CrossingType::Bridge => Palette::DarkBrown,
+                        CrossingType::Ferry => Palette::Orange,
+                    };
+
+                    canvas.fill_rect(cx.saturating_sub(1), cy.saturating_sub(1), 2, 2, color);
+                }
+
+                if cell.town.is_some() {
+                    canvas.fill_rect(
+                        cx.saturating_sub(2),
+                        cy.saturating_sub(2),
+                        4,
+                        4,
+                        Palette::Yellow,
+                    );
+                } else if let Some(poi) = cell.poi {
+                    let (_, color) = poi.map_glyph();
+                    canvas.fill_rect(cx.saturating_sub(1), cy.saturating_sub(1), 3, 3, color);
+                }
+
+                // Stairs sit where they would inside the zone, scaled down to the cell
+                let to_cell = |(sx, sy): (usize, usize)| {
+                    (
+                        x * scale + sx * scale / ZONE_SIZE.0,
+                        y * scale + sy * scale / ZONE_SIZE.1,
+                    )
+                };
+
+                for &pos in &cell.stairs_up {
+                    let (px, py) = to_cell(pos);
+                    canvas.plot(px, py, Palette::Gray);
+                }
+
+                for &pos in &cell.stairs_down {
+                    let (px, py) = to_cell(pos);
+                    canvas.plot(px, py, Palette::White);
+                }
+
+                for &pos in &cell.shafts {
+                    let (px, py) = to_cell(pos);
+                    canvas.plot(px, py, Palette::Red);
+                }
+            }
+        }
+
+        canvas.image
+    }
+}
+
+#[derive(Default)]
+struct LayerStats {
+    biomes: BTreeMap<String, usize>,
+    crossings: BTreeMap<String, usize>,
+    towns: usize,
+    pois: usize,
+    road_segments: usize,
+    road_length: usize,
+    river_segments: usize,
+    stairs: usize,
+    shafts: usize,
+}
+
+impl LayerStats {
+    fn describe(&self, z: usize) -> String {
+        let list = |counts: &BTreeMap<String, usize>| {
+            counts
+                .iter()
+                .map(|(name, count)| format!("{} {}", name, count))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        format!(
+            "  z{:<2} towns {}, pois {}, roads {} segments (~{} tiles), rivers {} segments, stairs {}, shafts {}\n      biomes: {}\n      crossings: {}",
+            z,
+            self.towns,
+            self.pois,
+            self.road_segments,
+            self.road_length,
+            self.river_segments,
+            self.stairs,
+            self.shafts,
+            list(&self.biomes),
+            if self.crossings.is_empty() {
+                "none".to_string()
+            } else {
+                list(&self.crossings)
+            },
+        )
+    }
+}
+
+/// Map glyph, glyph colour and background for a zone. Features are drawn over the biome
+/// in order of importance.
+fn cell_style(cell: &ZoneCell) -> (char, Palette, Palette) {
+    let (biome_glyph, biome_fg, bg) = biome_style(cell.biome);
+
+    if cell.town.is_some() {
+        ('#', Palette::Yellow, bg)
+    } else if let Some(poi) = cell.poi {
+        ('!', poi.map_glyph().1, bg)
+    } else if let Some(crossing) = cell.crossing {
+        match crossing {
+            CrossingType::Ford => ('=', Palette::Cyan, bg),
+            CrossingType::Bridge => ('H', Palette::Brown, bg),
+            CrossingType::Ferry => ('F', Palette::Orange, bg),
+        }
+    } else if cell.river.is_some() {
+        ('~', Palette::Blue, bg)
+    } else if cell.road {
+        ('+', Palette::Brown, bg)
+    } else {
+        (biome_glyph, biome_fg, bg)
+    }
+}
+
+fn biome_style(biome: Option<BiomeType>) -> (char, Palette, Palette) {
+    match biome {
+        Some(BiomeType::Forest) => ('f', Palette::DarkGreen, Palette::Green),
+        Some(BiomeType::Desert) => ('d', Palette::DarkYellow, Palette::Yellow),
+        Some(BiomeType::DustyPlains) => ('.', Palette::DarkOrange, Palette::DarkYellow),
+        Some(BiomeType::Cavern) => ('c', Palette::DarkGray, Palette::Gray),
+        Some(BiomeType::MushroomForest) => ('m', Palette::DarkPurple, Palette::Purple),
+        Some(BiomeType::FloodedMine) => ('w', Palette::DarkBlue, Palette::DarkCyan),
+        Some(BiomeType::Mountain) => ('^', Palette::DarkGray, Palette::White),
+        Some(BiomeType::Swamp) => ('s', Palette::Purple, Palette::DarkPurple),
+        Some(BiomeType::OpenAir) | None => (' ', Palette::Black, Palette::Black),
+    }
+}
+
+fn rgb(palette: Palette) -> (u8, u8, u8) {
+    let value = palette as u32;
+    ((value >> 16) as u8, (value >> 8) as u8, value as u8)
+}
+
+/// A plain pixel buffer for drawing maps without a window
+struct Canvas {
+    image: Image,
+    width: usize,
+    height: usize,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize, background: Palette) -> Self {
+        Self {
+            image: Image::gen_image_color(width as u16, height as u16, background.into()),
+            width,
+            height,
+        }
+    }
+
+    fn plot(&mut self, x: usize, y: usize, palette: Palette) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        // `Image::export_png` flips rows on the way out, so draw upside down
+        let color: Color = palette.into();
+        self.image
+            .set_pixel(x as u32, (self.height - 1 - y) as u32, color);
+    }
+
+    fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, palette: Palette) {
+        for px in x..x + width {
+            for py in y..y + height {
+                self.plot(px, py, palette);
+            }
+        }
+    }
+
+    fn line(&mut self, from: (usize, usize), to: (usize, usize), width: usize, palette: Palette) {
+        let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1);
+        let offset = width / 2;
+
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let x = from.0 as f32 + (to.0 as f32 - from.0 as f32) * t;
+            let y = from.1 as f32 + (to.1 as f32 - from.1 as f32) * t;
+
+            self.fill_rect(
+                (x.round() as usize).saturating_sub(offset),
+                (y.round() as usize).saturating_sub(offset),
+                width,
+                width,
+                palette,
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+mod inspect_world;
+mod validate_zones;
+
+pub use inspect_world::*;
+pub use validate_zones::*;
+
+/// Runs a command line tool when one is named as the first argument, returning its exit code.
+/// Returns `None` when the game should start as normal.
+pub fn run_from_args() -> Option<i32> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("inspect-world") => Some(inspect_world(&args[1..])),
+        Some("validate-zones") => Some(validate_zones(&args[1..])),
+        _ => None,
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
@@ -0,0 +1,127 @@
+use std::time::Instant;
+
+use super::parse_number;
+use crate::{
+    cfg::{MAP_SIZE, SURFACE_LEVEL_Z},
+    domain::validate_seeds,
+    rendering::zone_idx,
+};
+
+const USAGE: &str = "\
+usage: quadboy validate-zones [options]
+
+Generates zones for one or more seeds without opening a window and checks that
+roads and rivers line up across edges, stairs line up between levels, every
+stair and edge opening can be walked to and nothing spawns inside a wall.
+
+options:
+  --seed <n>       seed to generate, repeat to check several (default 1)
+  --z <n>          validate every zone on this z-layer, repeatable (default the surface)
+  --zone <idx>     validate a single zone, repeatable (overrides --z)";
+
+struct ValidateOptions {
+    seeds: Vec<u32>,
+    zones: Vec<usize>,
+}
+
+impl ValidateOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut seeds = vec![];
+        let mut layers = vec![];
+        let mut zones = vec![];
+
+        let mut args = args.iter();
+
+        while let Some(flag) = args.next() {
+            if !matches!(flag.as_str(), "--seed" | "--z" | "--zone") {
+                return Err(format!("unknown option '{}'", flag));
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", flag))?;
+
+            match flag.as_str() {
+                "--seed" => seeds.push(parse_number(flag, value)?),
+                "--z" => {
+                    let z = parse_number(flag, value)?;
+
+                    if z >= MAP_SIZE.2 {
+                        return Err(format!("--z must be below {}", MAP_SIZE.2));
+                    }
+
+                    layers.push(z);
+                }
+                "--zone" => {
+                    let idx = parse_number(flag, value)?;
+
+                    if idx >= MAP_SIZE.0 * MAP_SIZE.1 * MAP_SIZE.2 {
+                        return Err(format!("zone {} is outside the map", idx));
+                    }
+
+                    zones.push(idx);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if seeds.is_empty() {
+            seeds.push(1);
+        }
+
+        if layers.is_empty() {
+            layers.push(SURFACE_LEVEL_Z);
+        }
+
+        if zones.is_empty() {
+            zones = layers
+                .iter()
+                .flat_map(|&z| {
+                    (0..MAP_SIZE.0)
+                        .flat_map(move |x| (0..MAP_SIZE.1).map(move |y| zone_idx(x, y, z)))
+                })
+                .collect();
+        }
+
+        Ok(Self { seeds, zones })
+    }
+}
+
+/// Entry point for `quadboy validate-zones`. Exits with 1 when any zone is invalid.
+pub fn validate_zones(args: &[String]) -> i32 {
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return 0;
+    }
+
+    let options = match ValidateOptions::parse(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return 2;
+        }
+    };
+
+    let mut total = 0;
+
+    for &seed in &options.seeds {
+        let started = Instant::now();
+        let failures = validate_seeds(&[seed], &options.zones);
+
+        for failure in &failures {
+            println!("{}", failure);
+        }
+
+        println!(
+            "seed {}: {} zones, {} violations ({:.1}s)",
+            seed,
+            options.zones.len(),
+            failures.len(),
+            started.elapsed().as_secs_f32()
+        );
+
+        total += failures.len();
+    }
+
+    if total > 0 { 1 } else { 0 }
+}
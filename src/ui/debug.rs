@@ -1,6 +1,8 @@
 use crate::{
     common::Palette,
-    domain::{ColliderFlags, IgnoreLighting, IsExplored, Label, PlayerPosition, StackCount, Zone},
+    domain::{
+        ColliderFlags, IgnoreLighting, IsExplored, Label, PlayerPosition, StackCount, Weather, Zone,
+    },
     engine::{Clock, KeyInput, Mouse},
     rendering::{
         Glyph, Layer, LightingData, Position, Text, Visibility, world_to_zone_idx,
@@ -46,7 +48,10 @@ pub fn display_entity_names_at_mouse(
     mouse: Res<Mouse>,
     player_pos: Res<PlayerPosition>,
     q_zones: Query<&Zone>,
-    q_names: Query<(&Label, Option<&StackCount>), With<IsExplored>>,
+    q_names: Query<
+        (&Label, Option<&StackCount>, Option<&Visibility>),
+        (With<IsExplored>, Without<MouseHoverText>),
+    >,
     mut q_hover_text: Query<(&mut Text, &mut Position, &mut Visibility), With<MouseHoverText>>,
 ) {
     let mouse_x = mouse.world.0.floor() as usize;
@@ -66,7 +71,12 @@ pub fn display_entity_names_at_mouse(
     };
 
     for entity in entities {
-        if let Ok((name, stack_count)) = q_names.get(*entity) {
+        if let Ok((name, stack_count, entity_visibility)) = q_names.get(*entity) {
+            // Hidden traps don't give themselves away by name
+            if entity_visibility == Some(&Visibility::Hidden) {
+                continue;
+            }
+
             let mut name = name.get().to_string();
 
             if let Some(stack) = stack_count
@@ -97,6 +107,7 @@ pub fn display_entity_names_at_mouse(
 
 pub fn render_tick_display(
     clock: Res<Clock>,
+    weather: Res<Weather>,
     mut q_tick_display: Query<&mut Text, With<TickDisplay>>,
 ) {
     let Ok(mut text) = q_tick_display.single_mut() else {
@@ -104,12 +115,13 @@ pub fn render_tick_display(
     };
 
     text.value = format!(
-        "{{G|{}}}.{{g|{:03}}} {{G|Day {}}} {{Y|{:02}}}:{{g|{:02}}}",
+        "{{G|{}}}.{{g|{:03}}} {{G|Day {}}} {{Y|{:02}}}:{{g|{:02}}} {}",
         clock.current_turn(),
         clock.sub_turn(),
         clock.get_day() + 1,
         clock.get_hour(),
         clock.get_minute() % 60,
+        weather.current().label_formatted(),
     );
 }
 
@@ -221,6 +233,9 @@ pub fn debug_collider_flags(
     if !flags.contains(ColliderFlags::BLOCKS_SWIM) {
         flag_list.push("SWIM".to_owned());
     }
+    if !flags.contains(ColliderFlags::BLOCKS_PROJECTILE) {
+        flag_list.push("SHOOT".to_owned());
+    }
     if flags.contains(ColliderFlags::IS_ACTOR) {
         flag_list.push("{C|IS_ACTOR}".to_owned());
     }
@@ -13,6 +13,7 @@ pub struct ExamineDialogBuilder {
     width: f32,
     close_callback: SystemId,
     relationship_text: Option<String>,
+    value_text: Option<String>,
 }
 
 impl ExamineDialogBuilder {
@@ -22,6 +23,7 @@ impl ExamineDialogBuilder {
             width: 24.0,
             close_callback,
             relationship_text: None,
+            value_text: None,
         }
     }
 
@@ -30,6 +32,11 @@ impl ExamineDialogBuilder {
         self
     }
 
+    pub fn with_value_text(mut self, value_text: Option<String>) -> Self {
+        self.value_text = value_text;
+        self
+    }
+
     pub fn spawn(
         self,
         cmds: &mut Commands,
@@ -65,21 +72,22 @@ impl ExamineDialogBuilder {
             (description_lines.len() as f32 * 0.5) + 0.5 // 0.5 units per line + spacing
         };
 
-        let relationship_height = if self.relationship_text.is_some() {
-            0.5 // 0.5 height for text
-        } else {
-            0.0
-        };
+        // Relationship and value sit directly under the name
+        let subtitles = [&self.relationship_text, &self.value_text]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let subtitle_height = subtitles.len() as f32 * 0.5; // 0.5 height per line
 
-        let description_gap = if self.relationship_text.is_some() && !description_lines.is_empty() {
-            0.5 // Extra gap before description when relationship text is present
+        let description_gap = if !subtitles.is_empty() && !description_lines.is_empty() {
+            0.5 // Extra gap before description when there are subtitle lines
         } else {
             0.0
         };
 
         let gap_after_title = 0.5;
-        let gap_before_button = if !description_lines.is_empty() || self.relationship_text.is_some()
-        {
+        let gap_before_button = if !description_lines.is_empty() || !subtitles.is_empty() {
             0.5
         } else {
             0.5
@@ -88,12 +96,12 @@ impl ExamineDialogBuilder {
         let total_height = (2.0
             + title_height
             + gap_after_title
-            + relationship_height
+            + subtitle_height
             + description_gap
             + description_height
             + gap_before_button
             + 2.0)
-            .ceil(); // Icon + title + gap + relationship + gap + description + gap + button, rounded up
+            .ceil(); // Icon + title + gap + subtitles + gap + description + gap + button, rounded up
 
         // Calculate centered position before creating dialog and children
         let center_x = ((screen.tile_w as f32 - self.width) / 2.0).round();
@@ -167,15 +175,15 @@ impl ExamineDialogBuilder {
         content_y += 0.5; // Gap after title
         order += title_lines.len();
 
-        // Add relationship text directly under the name if provided
-        if let Some(relationship_text) = &self.relationship_text {
-            let relationship_visual_length = text_content_length(relationship_text);
-            let relationship_x = centered_position.x + (self.width / 2.0)
-                - (relationship_visual_length as f32 * 0.25);
+        // Add relationship and value text directly under the name if provided
+        for subtitle in &subtitles {
+            let subtitle_visual_length = text_content_length(subtitle);
+            let subtitle_x =
+                centered_position.x + (self.width / 2.0) - (subtitle_visual_length as f32 * 0.25);
 
             cmds.spawn((
                 DialogText {
-                    value: relationship_text.clone(),
+                    value: subtitle.to_string(),
                     style: DialogTextStyle::Normal,
                 },
                 DialogContent {
@@ -183,7 +191,7 @@ impl ExamineDialogBuilder {
                     order,
                 },
                 Position::new_f32(
-                    relationship_x,
+                    subtitle_x,
                     centered_position.y + content_y,
                     centered_position.z,
                 ),
@@ -191,13 +199,13 @@ impl ExamineDialogBuilder {
                 ChildOf(dialog_entity),
             ));
 
-            content_y += 0.5; // Space for relationship text
+            content_y += 0.5; // Space for the subtitle line
             order += 1;
+        }
 
-            // Add gap before description if description exists
-            if !description_lines.is_empty() {
-                content_y += 0.5; // Extra gap before description
-            }
+        // Add gap before description if description exists
+        if !subtitles.is_empty() && !description_lines.is_empty() {
+            content_y += 0.5; // Extra gap before description
         }
 
         // Add description if available
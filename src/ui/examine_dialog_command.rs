@@ -4,7 +4,10 @@ use bevy_ecs::{
 };
 
 use crate::{
-    domain::{Description, FactionMember, Label, get_effective_relationship},
+    domain::{
+        Description, FactionMember, Item, Label, StackCount, get_effective_relationship,
+        item_unit_value, item_value,
+    },
     engine::StableId,
     rendering::{Glyph, ScreenSize},
     states::CleanupStateExplore,
@@ -48,6 +51,29 @@ impl Command for SpawnExamineDialogCommand {
             None
         };
 
+        // What an item would fetch, before any haggling
+        let value_text = if world.get::<Item>(self.entity).is_some() {
+            let value = item_value(world, self.entity);
+            let count = world
+                .get::<StackCount>(self.entity)
+                .map(|s| s.count)
+                .unwrap_or(1);
+
+            Some(if value == 0 {
+                "{w|Worthless}".to_string()
+            } else if count > 1 {
+                format!(
+                    "Worth {{Y|{}}} gold ({{Y|{}}} each)",
+                    value,
+                    item_unit_value(world, self.entity)
+                )
+            } else {
+                format!("Worth {{Y|{}}} gold", value)
+            })
+        } else {
+            None
+        };
+
         let entity = self.entity;
         let close_callback = self.close_callback;
 
@@ -61,6 +87,7 @@ impl Command for SpawnExamineDialogCommand {
                       screen: Res<ScreenSize>| {
                     ExamineDialogBuilder::new(entity, close_callback)
                         .with_relationship_text(relationship_text.clone())
+                        .with_value_text(value_text.clone())
                         .spawn(
                             &mut cmds,
                             &q_labels,